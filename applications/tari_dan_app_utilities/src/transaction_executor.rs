@@ -20,8 +20,18 @@ use tari_dan_engine::{
     transaction::{TransactionError, TransactionProcessor, TransactionProcessorConfig},
 };
 use tari_dan_storage::consensus_models::VersionedSubstateIdLockIntent;
-use tari_engine_types::{commit_result::ExecuteResult, substate::Substate, virtual_substate::VirtualSubstates};
-use tari_template_lib::{crypto::RistrettoPublicKeyBytes, prelude::NonFungibleAddress};
+use tari_engine_types::{
+    commit_result::ExecuteResult,
+    instruction_result::InstructionResult,
+    substate::Substate,
+    virtual_substate::VirtualSubstates,
+};
+use tari_template_lib::{
+    args::Arg,
+    crypto::RistrettoPublicKeyBytes,
+    models::ComponentAddress,
+    prelude::NonFungibleAddress,
+};
 use tari_transaction::Transaction;
 
 const _LOG_TARGET: &str = "tari::dan::transaction_executor";
@@ -99,6 +109,44 @@ impl<TTemplateProvider> TariDanTransactionProcessor<TTemplateProvider> {
             config,
         }
     }
+
+    /// Sets whether an execution trace is recorded for the transactions executed by this processor. Intended for dry
+    /// runs.
+    pub fn with_tracing(mut self, enabled: bool) -> Self {
+        self.config.enable_tracing = enabled;
+        self
+    }
+}
+
+impl<TTemplateProvider> TariDanTransactionProcessor<TTemplateProvider>
+where TTemplateProvider: TemplateProvider<Template = LoadedTemplate>
+{
+    /// Calls a non-mutating component method against the given state without a transaction. No signer proofs are
+    /// available to the call and no fees are charged.
+    pub fn call_view(
+        &self,
+        component_address: &ComponentAddress,
+        method: &str,
+        args: Vec<Arg>,
+        state_store: ReadOnlyMemoryStateStore,
+        virtual_substates: VirtualSubstates,
+    ) -> Result<InstructionResult, TransactionProcessorError> {
+        let auth_params = AuthParams {
+            initial_ownership_proofs: vec![],
+        };
+
+        let processor = TransactionProcessor::new(
+            self.config.clone(),
+            self.template_provider.clone(),
+            state_store,
+            auth_params,
+            virtual_substates,
+            vec![],
+        );
+        let result = processor.call_view(component_address, method, args)?;
+
+        Ok(result)
+    }
 }
 
 impl<TTemplateProvider> TransactionExecutor for TariDanTransactionProcessor<TTemplateProvider>
@@ -0,0 +1,134 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use clap::{Args, Subcommand};
+use tari_common_types::types::PublicKey;
+use tari_dan_common_types::optional::Optional;
+use tari_utilities::{hex::Hex, ByteArray};
+use tari_wallet_daemon_client::{types::AddressBookAddRequest, WalletDaemonClient};
+
+use crate::{from_hex::FromHex, table::Table, table_row};
+
+#[derive(Debug, Subcommand, Clone)]
+pub enum AddressBookSubcommand {
+    Add(AddArgs),
+    Get(GetArgs),
+    List(ListArgs),
+    #[clap(alias = "rm")]
+    Remove(RemoveArgs),
+}
+
+#[derive(Debug, Args, Clone)]
+pub struct AddArgs {
+    pub name: String,
+    pub public_key: FromHex<Vec<u8>>,
+    #[clap(long, short = 'm')]
+    pub memo: Option<String>,
+}
+
+#[derive(Debug, Args, Clone)]
+pub struct GetArgs {
+    pub name: String,
+}
+
+#[derive(Debug, Args, Clone)]
+pub struct ListArgs {
+    #[clap(long, short = 'l')]
+    pub limit: Option<u64>,
+    #[clap(long, short = 'o')]
+    pub offset: Option<u64>,
+}
+
+#[derive(Debug, Args, Clone)]
+pub struct RemoveArgs {
+    pub name: String,
+}
+
+impl AddressBookSubcommand {
+    pub async fn handle(self, mut client: WalletDaemonClient) -> Result<(), anyhow::Error> {
+        match self {
+            Self::Add(args) => handle_add(args, &mut client).await?,
+            Self::Get(args) => handle_get(args, &mut client).await?,
+            Self::List(args) => handle_list(args, &mut client).await?,
+            Self::Remove(args) => handle_remove(args, &mut client).await?,
+        }
+        Ok(())
+    }
+}
+
+async fn handle_add(args: AddArgs, client: &mut WalletDaemonClient) -> Result<(), anyhow::Error> {
+    let public_key = PublicKey::from_canonical_bytes(&args.public_key.into_inner()).map_err(anyhow::Error::msg)?;
+    let resp = client
+        .address_book_add(AddressBookAddRequest {
+            name: args.name,
+            public_key,
+            memo: args.memo,
+        })
+        .await?;
+
+    println!("✅ Contact {} added", resp.contact);
+    println!("Account address: {}", resp.account_address);
+    Ok(())
+}
+
+async fn handle_get(args: GetArgs, client: &mut WalletDaemonClient) -> Result<(), anyhow::Error> {
+    let resp = client.address_book_get(args.name).await?;
+
+    println!("Name: {}", resp.contact.name);
+    println!("Public key: {}", resp.contact.public_key);
+    println!("Account address: {}", resp.account_address);
+    if let Some(memo) = resp.contact.memo {
+        println!("Memo: {}", memo);
+    }
+    Ok(())
+}
+
+async fn handle_list(args: ListArgs, client: &mut WalletDaemonClient) -> Result<(), anyhow::Error> {
+    let resp = client
+        .address_book_list(args.offset.unwrap_or(0), args.limit.unwrap_or(100))
+        .await?;
+
+    if resp.contacts.is_empty() {
+        println!("No contacts found");
+        return Ok(());
+    }
+
+    let mut table = Table::new();
+    table.enable_row_count();
+    table.set_titles(vec!["Name", "Public Key", "Account Address", "Memo"]);
+    println!("Contacts ({} total):", resp.total);
+    for contact in resp.contacts {
+        table.add_row(table_row!(
+            contact.name,
+            contact.public_key,
+            contact.account_address(),
+            contact.memo.as_deref().unwrap_or("")
+        ));
+    }
+    table.print_stdout();
+    Ok(())
+}
+
+async fn handle_remove(args: RemoveArgs, client: &mut WalletDaemonClient) -> Result<(), anyhow::Error> {
+    let resp = client.address_book_remove(args.name).await?;
+    println!("✅ Contact {} removed", resp.contact);
+    Ok(())
+}
+
+/// Resolves a transfer destination given either as a hex-encoded public key or as the name of a contact in the
+/// wallet's address book.
+pub async fn resolve_destination_public_key(
+    destination: &str,
+    client: &mut WalletDaemonClient,
+) -> Result<PublicKey, anyhow::Error> {
+    if let Ok(public_key) = PublicKey::from_hex(destination) {
+        return Ok(public_key);
+    }
+
+    let resp = client
+        .address_book_get(destination)
+        .await
+        .optional()?
+        .ok_or_else(|| anyhow::anyhow!("'{}' is not a valid public key or known contact", destination))?;
+    Ok(resp.contact.public_key)
+}
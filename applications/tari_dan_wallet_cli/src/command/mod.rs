@@ -22,7 +22,12 @@
 
 use clap::Subcommand;
 
-use self::{auth::AuthSubcommand, nfts::AccountNftSubcommand, webrtc::WebRtcSubcommand};
+use self::{
+    address_book::AddressBookSubcommand,
+    auth::AuthSubcommand,
+    nfts::AccountNftSubcommand,
+    webrtc::WebRtcSubcommand,
+};
 use crate::command::{
     account::AccountsSubcommand,
     key::KeysSubcommand,
@@ -32,6 +37,7 @@ use crate::command::{
 };
 
 mod account;
+mod address_book;
 mod auth;
 mod key;
 mod nfts;
@@ -59,4 +65,6 @@ pub enum Command {
     AccountNft(AccountNftSubcommand),
     #[clap(subcommand)]
     Validator(ValidatorSubcommand),
+    #[clap(subcommand, alias = "contacts")]
+    AddressBook(AddressBookSubcommand),
 }
@@ -31,14 +31,18 @@ use std::{
 };
 
 use anyhow::anyhow;
+use chrono::{NaiveDate, NaiveDateTime};
 use clap::{Args, Subcommand};
 use tari_bor::decode_exact;
-use tari_common_types::types::PublicKey;
 use tari_dan_common_types::{Epoch, SubstateAddress, SubstateRequirement};
 use tari_dan_engine::abi::Type;
-use tari_dan_wallet_sdk::apis::confidential_transfer::ConfidentialTransferInputSelection;
+use tari_dan_wallet_sdk::{
+    apis::confidential_transfer::ConfidentialTransferInputSelection,
+    models::{TransactionDirection, TransactionStatus},
+};
 use tari_engine_types::{
     commit_result::{FinalizeResult, RejectReason, TransactionResult},
+    execution_trace::{ExecutionTrace, TraceEvent},
     instruction::Instruction,
     instruction_result::InstructionResult,
     parse_template_address,
@@ -51,7 +55,7 @@ use tari_template_lib::{
     args::Arg,
     constants::CONFIDENTIAL_TARI_RESOURCE_ADDRESS,
     models::{Amount, BucketId, NonFungibleAddress, NonFungibleId},
-    prelude::ResourceAddress,
+    prelude::{ComponentAddress, ResourceAddress},
 };
 use tari_transaction::{Transaction, TransactionId, UnsignedTransaction};
 use tari_transaction_manifest::{parse_manifest, ManifestValue};
@@ -61,6 +65,8 @@ use tari_wallet_daemon_client::{
         AccountGetResponse,
         AccountsTransferRequest,
         ConfidentialTransferRequest,
+        TransactionGetAllRequest,
+        TransactionGetBalanceHistoryRequest,
         TransactionGetResultRequest,
         TransactionSubmitDryRunRequest,
         TransactionSubmitRequest,
@@ -71,7 +77,12 @@ use tari_wallet_daemon_client::{
     WalletDaemonClient,
 };
 
-use crate::from_hex::FromHex;
+use crate::{
+    command::address_book::resolve_destination_public_key,
+    export::{Export, ExportFormat},
+    from_hex::FromHex,
+    table_row,
+};
 
 #[derive(Debug, Subcommand, Clone)]
 pub enum TransactionSubcommand {
@@ -80,6 +91,9 @@ pub enum TransactionSubcommand {
     SubmitManifest(SubmitManifestArgs),
     Send(SendArgs),
     ConfidentialTransfer(ConfidentialTransferArgs),
+    #[clap(alias = "ls")]
+    List(ListArgs),
+    BalanceHistory(BalanceHistoryArgs),
 }
 
 #[derive(Debug, Args, Clone)]
@@ -112,6 +126,9 @@ pub struct CommonSubmitArgs {
     pub dump_outputs_into: Option<ComponentAddressOrName>,
     #[clap(long)]
     pub dry_run: bool,
+    /// Print a step-by-step execution trace of the dry run
+    #[clap(long, requires = "dry_run")]
+    pub trace: bool,
     #[clap(long)]
     pub max_fee: Option<u64>,
     #[clap(long, short = 'f', alias = "fee-account")]
@@ -135,7 +152,8 @@ pub struct SubmitManifestArgs {
 pub struct SendArgs {
     amount: u64,
     resource_address: ResourceAddress,
-    destination_public_key: FromHex<Vec<u8>>,
+    /// The destination public key (hex) or the name of a contact in the address book
+    destination: String,
     #[clap(flatten)]
     common: CommonSubmitArgs,
     source_account_name: Option<ComponentAddressOrName>,
@@ -144,7 +162,8 @@ pub struct SendArgs {
 #[derive(Debug, Args, Clone)]
 pub struct ConfidentialTransferArgs {
     amount: u64,
-    destination_public_key: FromHex<Vec<u8>>,
+    /// The destination public key (hex) or the name of a contact in the address book
+    destination: String,
     #[clap(flatten)]
     common: CommonSubmitArgs,
     #[clap(long, short = 'a', alias = "account")]
@@ -154,6 +173,54 @@ pub struct ConfidentialTransferArgs {
     resource_address: Option<ResourceAddress>,
 }
 
+#[derive(Debug, Args, Clone)]
+pub struct ListArgs {
+    #[clap(long, short = 's')]
+    status: Option<TransactionStatus>,
+    /// Only list transactions involving this account
+    #[clap(long, short = 'a', alias = "account")]
+    account: Option<ComponentAddressOrName>,
+    /// Only list transactions involving this resource
+    #[clap(long, short = 'r')]
+    resource_address: Option<ResourceAddress>,
+    /// Direction relative to the account (incoming or outgoing). Requires --account.
+    #[clap(long, short = 'd')]
+    direction: Option<TransactionDirection>,
+    /// Only list transactions created on or after this date (YYYY-MM-DD or YYYY-MM-DDTHH:MM:SS)
+    #[clap(long, value_parser = parse_date_time)]
+    from: Option<NaiveDateTime>,
+    /// Only list transactions created before this date (YYYY-MM-DD or YYYY-MM-DDTHH:MM:SS)
+    #[clap(long, value_parser = parse_date_time)]
+    to: Option<NaiveDateTime>,
+    #[clap(long, default_value_t = 0)]
+    offset: u64,
+    #[clap(long, short = 'l')]
+    limit: Option<u64>,
+    #[clap(flatten)]
+    export: ExportArgs,
+}
+
+#[derive(Debug, Args, Clone)]
+pub struct BalanceHistoryArgs {
+    /// The account to show the history for. If not provided, the default account is used.
+    #[clap(long, short = 'a', alias = "account")]
+    account: Option<ComponentAddressOrName>,
+    #[clap(long, short = 'r')]
+    resource_address: Option<ResourceAddress>,
+    #[clap(flatten)]
+    export: ExportArgs,
+}
+
+#[derive(Debug, Args, Clone)]
+pub struct ExportArgs {
+    /// Output format: table, csv or json
+    #[clap(long, short = 'f', default_value = "table")]
+    format: ExportFormat,
+    /// Write the output to this file instead of stdout
+    #[clap(long, short = 'o')]
+    output: Option<PathBuf>,
+}
+
 #[derive(Debug, Subcommand, Clone)]
 pub enum CliInstruction {
     CallFunction {
@@ -186,6 +253,8 @@ impl TransactionSubcommand {
             TransactionSubcommand::ConfidentialTransfer(args) => {
                 handle_confidential_transfer(args, &mut client).await?;
             },
+            TransactionSubcommand::List(args) => handle_list(args, &mut client).await?,
+            TransactionSubcommand::BalanceHistory(args) => handle_balance_history(args, &mut client).await?,
         }
         Ok(())
     }
@@ -209,6 +278,122 @@ async fn handle_get(args: GetArgs, client: &mut WalletDaemonClient) -> Result<()
     Ok(())
 }
 
+async fn handle_list(args: ListArgs, client: &mut WalletDaemonClient) -> Result<(), anyhow::Error> {
+    let component = match args.account {
+        Some(account) => Some(resolve_component_address(account, client).await?),
+        None => None,
+    };
+    if args.direction.is_some() && component.is_none() {
+        return Err(anyhow!("--direction requires --account"));
+    }
+
+    let resp = client
+        .list_transactions(TransactionGetAllRequest {
+            status: args.status,
+            component,
+            resource_address: args.resource_address,
+            direction: args.direction,
+            from_date: args.from,
+            to_date: args.to,
+            offset: args.offset,
+            limit: args.limit,
+        })
+        .await?;
+
+    let mut export = Export::new(vec!["transaction_id", "status", "updated_at", "fee", "result"]);
+    for (transaction, result, status, updated_at) in &resp.transactions {
+        export.add_row(table_row![
+            transaction.id(),
+            status,
+            updated_at,
+            result
+                .as_ref()
+                .map(|r| r.fee_receipt.total_fees_paid().to_string())
+                .unwrap_or_default(),
+            result
+                .as_ref()
+                .map(|r| match r.result {
+                    TransactionResult::Accept(_) => "Accept".to_string(),
+                    TransactionResult::AcceptFeeRejectRest(_, ref reason) => format!("Fee only: {}", reason),
+                    TransactionResult::Reject(ref reason) => format!("Reject: {}", reason),
+                })
+                .unwrap_or_default()
+        ]);
+    }
+    export.write_to(args.export.format, args.export.output.as_ref())?;
+    if matches!(args.export.format, ExportFormat::Table) {
+        println!(
+            "Showing {} of {} transaction(s) from offset {}",
+            resp.transactions.len(),
+            resp.total,
+            args.offset
+        );
+    }
+
+    Ok(())
+}
+
+async fn handle_balance_history(
+    args: BalanceHistoryArgs,
+    client: &mut WalletDaemonClient,
+) -> Result<(), anyhow::Error> {
+    let resp = client
+        .get_balance_history(TransactionGetBalanceHistoryRequest {
+            account: args.account,
+            resource_address: args.resource_address,
+        })
+        .await?;
+
+    let mut export = Export::new(vec![
+        "transaction_id",
+        "timestamp",
+        "vault",
+        "resource",
+        "balance_before",
+        "balance_after",
+        "change",
+    ]);
+    for change in &resp.changes {
+        export.add_row(table_row![
+            change.transaction_id,
+            change.timestamp,
+            change.vault_address,
+            change.resource_address,
+            change.balance_before.map(|b| b.to_string()).unwrap_or_default(),
+            change.balance_after,
+            change.change().map(|c| c.to_string()).unwrap_or_default()
+        ]);
+    }
+    if matches!(args.export.format, ExportFormat::Table) {
+        println!("Balance history for account {}", resp.account_address);
+    }
+    export.write_to(args.export.format, args.export.output.as_ref())?;
+
+    Ok(())
+}
+
+async fn resolve_component_address(
+    account: ComponentAddressOrName,
+    client: &mut WalletDaemonClient,
+) -> Result<ComponentAddress, anyhow::Error> {
+    if let Some(address) = account.component_address() {
+        return Ok(*address);
+    }
+    let resp = client.accounts_get(account).await?;
+    resp.account
+        .address
+        .as_component_address()
+        .ok_or_else(|| anyhow!("Invalid account address {}", resp.account.address))
+}
+
+fn parse_date_time(s: &str) -> Result<NaiveDateTime, anyhow::Error> {
+    if let Ok(date_time) = NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S") {
+        return Ok(date_time);
+    }
+    let date = NaiveDate::parse_from_str(s, "%Y-%m-%d").map_err(|e| anyhow!("Invalid date '{}': {}", s, e))?;
+    Ok(date.and_hms_opt(0, 0, 0).expect("midnight is always valid"))
+}
+
 pub async fn handle_submit(args: SubmitArgs, client: &mut WalletDaemonClient) -> Result<(), anyhow::Error> {
     let SubmitArgs { instruction, common } = args;
     let instruction = match instruction {
@@ -274,8 +459,12 @@ pub async fn handle_submit(args: SubmitArgs, client: &mut WalletDaemonClient) ->
                 autofill_inputs: vec![],
                 detect_inputs: common.detect_inputs.unwrap_or(true),
                 proof_ids: vec![],
+                trace: common.trace,
             })
             .await?;
+        if common.trace {
+            print_execution_trace(resp.result.trace.as_ref());
+        }
         wait_transaction_result(resp.transaction_id, client).await?;
     } else {
         let request = TransactionSubmitRequest {
@@ -338,8 +527,12 @@ async fn handle_submit_manifest(
                 autofill_inputs: vec![],
                 detect_inputs: common.detect_inputs.unwrap_or(true),
                 proof_ids: vec![],
+                trace: common.trace,
             })
             .await?;
+        if common.trace {
+            print_execution_trace(resp.result.trace.as_ref());
+        }
         summarize(&resp.result.finalize, timer.elapsed());
     } else {
         let request = TransactionSubmitRequest {
@@ -363,12 +556,11 @@ pub async fn handle_send(args: SendArgs, client: &mut WalletDaemonClient) -> Res
         source_account_name,
         amount,
         resource_address,
-        destination_public_key,
+        destination,
         common,
     } = args;
 
-    let destination_public_key =
-        PublicKey::from_canonical_bytes(&destination_public_key.into_inner()).map_err(anyhow::Error::msg)?;
+    let destination_public_key = resolve_destination_public_key(&destination, client).await?;
 
     let fee = common.max_fee.map(|f| f.try_into()).transpose()?;
     let resp = client
@@ -399,13 +591,11 @@ pub async fn handle_confidential_transfer(
         source_account,
         resource_address,
         amount,
-        destination_public_key,
+        destination,
         common,
     } = args;
 
-    // let AccountByNameResponse { account, .. } = client.accounts_get_by_name(&source_account_name).await?;
-    let destination_public_key =
-        PublicKey::from_canonical_bytes(&destination_public_key.into_inner()).map_err(anyhow::Error::msg)?;
+    let destination_public_key = resolve_destination_public_key(&destination, client).await?;
     let resp = client
         .accounts_confidential_transfer(ConfidentialTransferRequest {
             account: source_account,
@@ -557,6 +747,24 @@ pub fn print_substate_diff(diff: &SubstateDiff) {
     }
 }
 
+pub fn print_execution_trace(trace: Option<&ExecutionTrace>) {
+    println!("========= Execution Trace =========");
+    let Some(trace) = trace else {
+        println!("No execution trace was returned");
+        println!();
+        return;
+    };
+    for (i, entry) in trace.iter().enumerate() {
+        let indent = "  ".repeat(entry.depth);
+        match entry.event {
+            TraceEvent::CallFramePushed { .. } => println!("{:>5} {}▶ {}", i, indent, entry.event),
+            TraceEvent::CallFramePopped => println!("{:>5} {}◀ {}", i, indent, entry.event),
+            _ => println!("{:>5} {}  {}", i, indent, entry.event),
+        }
+    }
+    println!();
+}
+
 fn print_reject_reason(reason: &RejectReason) {
     println!("❌️ Transaction rejected: {}", reason);
 }
@@ -651,7 +859,7 @@ fn display_vec<W: fmt::Write>(writer: &mut W, ty: &Type, result: &InstructionRes
                 stringify_slice(&result.decode::<Vec<NonFungibleId>>().unwrap())
             )?;
         },
-        Type::Other { .. } => {
+        Type::Option(_) | Type::Map(_, _) | Type::Other { .. } => {
             write!(writer, "{}", serde_json::to_string_pretty(&result.indexed).unwrap())?;
         },
     }
@@ -726,6 +934,9 @@ pub fn print_execution_results(results: &[InstructionResult]) {
             Type::Other { ref name } if name == "Bucket" => {
                 println!("{}: {}", name, result.decode::<BucketId>().unwrap());
             },
+            ty @ (Type::Option(_) | Type::Map(_, _)) => {
+                println!("{}: {}", ty, serde_json::to_string_pretty(&result.indexed).unwrap());
+            },
             Type::Other { ref name } => {
                 println!("{}: {}", name, serde_json::to_string_pretty(&result.indexed).unwrap());
             },
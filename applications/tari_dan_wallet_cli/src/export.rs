@@ -0,0 +1,141 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use std::{fs, io, io::Write, path::PathBuf, str::FromStr};
+
+use crate::table::Table;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub enum ExportFormat {
+    #[default]
+    Table,
+    Csv,
+    Json,
+}
+
+impl FromStr for ExportFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().trim() {
+            "table" => Ok(ExportFormat::Table),
+            "csv" => Ok(ExportFormat::Csv),
+            "json" => Ok(ExportFormat::Json),
+            _ => Err(anyhow::anyhow!("Invalid export format {}", s)),
+        }
+    }
+}
+
+/// Tabular data that can be rendered as a table, CSV or JSON.
+pub struct Export<'a> {
+    titles: Vec<&'a str>,
+    rows: Vec<Vec<String>>,
+}
+
+impl<'a> Export<'a> {
+    pub fn new(titles: Vec<&'a str>) -> Self {
+        Self {
+            titles,
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn add_row(&mut self, row: Vec<String>) -> &mut Self {
+        assert_eq!(row.len(), self.titles.len(), "BUG: row length does not match titles");
+        self.rows.push(row);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    pub fn render<T: Write>(&self, format: ExportFormat, out: &mut T) -> io::Result<()> {
+        match format {
+            ExportFormat::Table => {
+                let mut table = Table::new();
+                table.set_titles(self.titles.clone());
+                for row in &self.rows {
+                    table.add_row(row.clone());
+                }
+                table.enable_row_count();
+                table.render(out)
+            },
+            ExportFormat::Csv => {
+                writeln!(out, "{}", csv_line(self.titles.iter().copied()))?;
+                for row in &self.rows {
+                    writeln!(out, "{}", csv_line(row.iter().map(String::as_str)))?;
+                }
+                Ok(())
+            },
+            ExportFormat::Json => {
+                let records = self
+                    .rows
+                    .iter()
+                    .map(|row| {
+                        self.titles
+                            .iter()
+                            .zip(row)
+                            .map(|(title, value)| ((*title).to_string(), serde_json::Value::String(value.clone())))
+                            .collect::<serde_json::Map<_, _>>()
+                    })
+                    .collect::<Vec<_>>();
+                serde_json::to_writer_pretty(&mut *out, &records)?;
+                writeln!(out)
+            },
+        }
+    }
+
+    /// Renders to the given file, or stdout if no file is given.
+    pub fn write_to(&self, format: ExportFormat, output: Option<&PathBuf>) -> io::Result<()> {
+        match output {
+            Some(path) => {
+                let mut file = fs::File::create(path)?;
+                self.render(format, &mut file)?;
+                println!("✅ Wrote {} record(s) to {}", self.rows.len(), path.display());
+                Ok(())
+            },
+            None => self.render(format, &mut io::stdout()),
+        }
+    }
+}
+
+fn csv_line<'a, I: IntoIterator<Item = &'a str>>(fields: I) -> String {
+    fields.into_iter().map(csv_escape).collect::<Vec<_>>().join(",")
+}
+
+fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn renders_csv_with_escaping() {
+        let mut export = Export::new(vec!["id", "memo"]);
+        export.add_row(vec!["1".to_string(), "plain".to_string()]);
+        export.add_row(vec!["2".to_string(), "a, \"quoted\" memo".to_string()]);
+        let mut buf = io::Cursor::new(Vec::new());
+        export.render(ExportFormat::Csv, &mut buf).unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&buf.into_inner()),
+            "id,memo\n1,plain\n2,\"a, \"\"quoted\"\" memo\"\n"
+        );
+    }
+
+    #[test]
+    fn renders_json_records() {
+        let mut export = Export::new(vec!["id", "amount"]);
+        export.add_row(vec!["1".to_string(), "100".to_string()]);
+        let mut buf = io::Cursor::new(Vec::new());
+        export.render(ExportFormat::Json, &mut buf).unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&buf.into_inner()).unwrap();
+        assert_eq!(value, serde_json::json!([{"id": "1", "amount": "100"}]));
+    }
+}
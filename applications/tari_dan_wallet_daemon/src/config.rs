@@ -26,6 +26,7 @@ use config::Config;
 use serde::{Deserialize, Serialize};
 use tari_common::{configuration::CommonConfig, ConfigurationError, DefaultConfigLoader, SubConfigPath};
 use tari_dan_common_types::crypto::create_secret;
+use tari_dan_wallet_sdk::signer::ExternalSignerTransport;
 
 #[derive(Debug, Clone)]
 pub struct ApplicationConfig {
@@ -70,6 +71,12 @@ pub struct WalletDaemonConfig {
     /// utility. If this is not set, the value lookup table will be generated on the fly which will have a large
     /// performance cost when brute forcing high-value outputs.
     pub value_lookup_table_file: Option<PathBuf>,
+    /// Delegate account keys and transaction signing to an external signer (e.g. a hardware wallet bridge) instead of
+    /// the wallet key manager. Confidential transfers are not supported with an external signer.
+    pub external_signer: Option<ExternalSignerTransport>,
+    /// The time to wait for the external signer to respond to a request. Defaults to 30 seconds.
+    #[serde(with = "humantime_serde::option")]
+    pub external_signer_timeout: Option<Duration>,
 }
 
 impl Default for WalletDaemonConfig {
@@ -85,6 +92,8 @@ impl Default for WalletDaemonConfig {
             jwt_secret_key: Some(create_secret()),
             http_ui_address: Some("127.0.0.1:5100".parse().unwrap()),
             value_lookup_table_file: None,
+            external_signer: None,
+            external_signer_timeout: None,
         }
     }
 }
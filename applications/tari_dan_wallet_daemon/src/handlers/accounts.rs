@@ -16,27 +16,33 @@ use tari_crypto::{
 use tari_dan_common_types::{optional::Optional, SubstateRequirement};
 use tari_dan_wallet_crypto::ConfidentialProofStatement;
 use tari_dan_wallet_sdk::{
-    apis::{confidential_transfer::TransferParams, jwt::JrpcPermission, key_manager, substate::ValidatorScanResult},
+    apis::{
+        confidential_transfer::{BatchTransferParams, BatchTransferRecipient, TransferParams},
+        jwt::JrpcPermission,
+        key_manager,
+        substate::ValidatorScanResult,
+    },
     models::NewAccountInfo,
+    signer::{TransactionSigner, TransactionSignerError},
     storage::WalletStore,
     DanWalletSdk,
 };
 use tari_dan_wallet_storage_sqlite::SqliteWalletStore;
 use tari_engine_types::{
+    commit_result::FinalizeResult,
     component::new_component_address_from_public_key,
     confidential::ConfidentialClaim,
     instruction::Instruction,
     substate::{Substate, SubstateId},
 };
-use tari_key_manager::key_manager::DerivedKey;
 use tari_template_builtin::ACCOUNT_TEMPLATE_ADDRESS;
 use tari_template_lib::{
     args,
     constants::{XTR_FAUCET_COMPONENT_ADDRESS, XTR_FAUCET_VAULT_ADDRESS},
-    models::{Amount, UnclaimedConfidentialOutputAddress},
+    models::{Amount, ComponentAddress, ResourceAddress, UnclaimedConfidentialOutputAddress},
     prelude::CONFIDENTIAL_TARI_RESOURCE_ADDRESS,
 };
-use tari_transaction::Transaction;
+use tari_transaction::{Transaction, TransactionId};
 use tari_wallet_daemon_client::{
     types::{
         AccountGetDefaultRequest,
@@ -45,6 +51,8 @@ use tari_wallet_daemon_client::{
         AccountInfo,
         AccountSetDefaultRequest,
         AccountSetDefaultResponse,
+        AccountsBatchTransferRequest,
+        AccountsBatchTransferResponse,
         AccountsCreateFreeTestCoinsRequest,
         AccountsCreateFreeTestCoinsResponse,
         AccountsCreateRequest,
@@ -60,10 +68,14 @@ use tari_wallet_daemon_client::{
         BalanceEntry,
         ClaimBurnRequest,
         ClaimBurnResponse,
+        ConfidentialBatchTransferRequest,
+        ConfidentialBatchTransferResponse,
         ConfidentialTransferRequest,
         ConfidentialTransferResponse,
         RevealFundsRequest,
         RevealFundsResponse,
+        TransferRecipient,
+        TransferRecipientResult,
     },
     ComponentAddressOrName,
 };
@@ -72,16 +84,19 @@ use tokio::task;
 use super::context::HandlerContext;
 use crate::{
     handlers::helpers::{
+        estimate_fee,
         get_account,
         get_account_or_default,
         get_account_with_inputs,
         invalid_params,
+        max_fee_or_estimate,
         wait_for_result,
         wait_for_result_and_account,
     },
     indexer_jrpc_impl::IndexerJsonRpcNetworkInterface,
     services::TransactionSubmittedEvent,
     DEFAULT_FEE,
+    DEFAULT_FEE_MARGIN_PERCENT,
 };
 
 const LOG_TARGET: &str = "tari::dan::wallet_daemon::handlers::transaction";
@@ -108,10 +123,9 @@ pub async fn handle_create(
         .await?;
 
     let signing_key_index = req.key_id.unwrap_or(default_account.key_index);
-    let signing_key = key_manager_api.derive_key(key_manager::TRANSACTION_BRANCH, signing_key_index)?;
 
     let owner_key = key_manager_api.next_key(key_manager::TRANSACTION_BRANCH)?;
-    let owner_pk = PublicKey::from_secret_key(&owner_key.key);
+    let owner_pk = sdk.signer().public_key(owner_key.key_index)?;
 
     info!(
         target: LOG_TARGET,
@@ -122,11 +136,13 @@ pub async fn handle_create(
     );
 
     let max_fee = req.max_fee.unwrap_or(DEFAULT_FEE);
-    let transaction = Transaction::builder()
-        .fee_transaction_pay_from_component(default_account.address.as_component_address().unwrap(), max_fee)
-        .create_account(owner_pk.clone())
-        .with_inputs(inputs)
-        .build_and_seal(&signing_key.key);
+    let transaction = sdk.signer().build_and_seal(
+        signing_key_index,
+        Transaction::builder()
+            .fee_transaction_pay_from_component(default_account.address.as_component_address().unwrap(), max_fee)
+            .create_account(owner_pk.clone())
+            .with_inputs(inputs),
+    )?;
 
     let mut events = context.notifier().subscribe();
     let tx_id = context
@@ -185,16 +201,11 @@ pub async fn handle_list(
     sdk.jwt_api().check_auth(token, &[JrpcPermission::Admin])?;
     let accounts = sdk.accounts_api().get_many(req.offset, req.limit)?;
     let total = sdk.accounts_api().count()?;
-    let km = sdk.key_manager_api();
     let accounts = accounts
         .into_iter()
         .map(|a| {
-            let key = km.derive_key(key_manager::TRANSACTION_BRANCH, a.key_index)?;
-            let pk = PublicKey::from_secret_key(&key.key);
-            Ok(AccountInfo {
-                account: a,
-                public_key: pk,
-            })
+            let public_key = sdk.signer().public_key(a.key_index)?;
+            Ok(AccountInfo { account: a, public_key })
         })
         .collect::<Result<_, anyhow::Error>>()?;
 
@@ -211,10 +222,6 @@ pub async fn handle_invoke(
 
     let account = get_account_or_default(req.account, &sdk.accounts_api())?;
 
-    let signing_key = sdk
-        .key_manager_api()
-        .derive_key(key_manager::TRANSACTION_BRANCH, account.key_index)?;
-
     let inputs = sdk.substate_api().load_dependent_substates(&[&account.address])?;
 
     let inputs = inputs
@@ -222,11 +229,13 @@ pub async fn handle_invoke(
         .map(|s| SubstateRequirement::new(s.substate_id.clone(), Some(s.version)));
 
     let account_address = account.address.as_component_address().unwrap();
-    let transaction = Transaction::builder()
-        .fee_transaction_pay_from_component(account_address, req.max_fee.unwrap_or(DEFAULT_FEE))
-        .call_method(account_address, &req.method, req.args)
-        .with_inputs(inputs)
-        .build_and_seal(&signing_key.key);
+    let transaction = sdk.signer().build_and_seal(
+        account.key_index,
+        Transaction::builder()
+            .fee_transaction_pay_from_component(account_address, req.max_fee.unwrap_or(DEFAULT_FEE))
+            .call_method(account_address, &req.method, req.args)
+            .with_inputs(inputs),
+    )?;
 
     let mut events = context.notifier().subscribe();
     let tx_id = context
@@ -290,9 +299,7 @@ pub async fn handle_get(
     let sdk = context.wallet_sdk();
     sdk.jwt_api().check_auth(token, &[JrpcPermission::Admin])?;
     let account = get_account(&req.name_or_address, &sdk.accounts_api())?;
-    let km = sdk.key_manager_api();
-    let key = km.derive_key(key_manager::TRANSACTION_BRANCH, account.key_index)?;
-    let public_key = PublicKey::from_secret_key(&key.key);
+    let public_key = sdk.signer().public_key(account.key_index)?;
     Ok(AccountGetResponse { account, public_key })
 }
 
@@ -304,9 +311,7 @@ pub async fn handle_get_default(
     let sdk = context.wallet_sdk();
     sdk.jwt_api().check_auth(token, &[JrpcPermission::AccountInfo])?;
     let account = get_account_or_default(None, &sdk.accounts_api())?;
-    let km = sdk.key_manager_api();
-    let key = km.derive_key(key_manager::TRANSACTION_BRANCH, account.key_index)?;
-    let public_key = PublicKey::from_secret_key(&key.key);
+    let public_key = sdk.signer().public_key(account.key_index)?;
     Ok(AccountGetResponse { account, public_key })
 }
 
@@ -325,6 +330,8 @@ pub async fn handle_reveal_funds(
     // spawn a task that will continue running.
     task::spawn(async move {
         let account = get_account_or_default(req.account, &sdk.accounts_api())?;
+        // Fails before any funds are locked if the signer does not hold the account key in-process
+        let account_secret = sdk.signer().secret_key(account.key_index)?;
 
         let vault = sdk
             .accounts_api()
@@ -340,10 +347,6 @@ pub async fn handle_reveal_funds(
                 .lock_outputs_by_amount(&vault.address, amount_to_reveal, proof_id)?;
         let input_amount = Amount::try_from(input_value)?;
 
-        let account_key = sdk
-            .key_manager_api()
-            .derive_key(key_manager::TRANSACTION_BRANCH, account.key_index)?;
-
         let output_mask = sdk.key_manager_api().next_key(key_manager::TRANSACTION_BRANCH)?;
         let (_, public_nonce) = PublicKey::random_keypair(&mut OsRng);
 
@@ -352,7 +355,7 @@ pub async fn handle_reveal_funds(
             remaining_confidential_amount.as_u64_checked().unwrap(),
             &output_mask.key,
             &public_nonce,
-            &account_key.key,
+            &account_secret,
         )?;
 
         let output_statement = ConfidentialProofStatement {
@@ -431,7 +434,9 @@ pub async fn handle_reveal_funds(
             .into_iter()
             .map(|addr| SubstateRequirement::new(addr.substate_id.clone(), Some(addr.version)));
 
-        let transaction = builder.with_inputs(inputs).build_and_seal(&account_key.key);
+        let transaction = sdk
+            .signer()
+            .build_and_seal(account.key_index, builder.with_inputs(inputs))?;
 
         sdk.confidential_outputs_api()
             .proofs_set_transaction_hash(proof_id, *transaction.id())?;
@@ -527,10 +532,10 @@ pub async fn handle_claim_burn(
 
     let mut inputs = vec![];
     let accounts_api = sdk.accounts_api();
-    let (account_address, account_secret_key, new_account_name) =
+    let (account_address, key_index, account_public_key, new_account_name) =
         get_or_create_account(&account, &accounts_api, key_id, sdk, &mut inputs)?;
-
-    let account_public_key = PublicKey::from_secret_key(&account_secret_key.key);
+    // The claimed output is encrypted to the account key, which must be held in-process to unmask it
+    let account_secret_key = sdk.signer().secret_key(key_index)?;
 
     info!(
         target: LOG_TARGET,
@@ -569,7 +574,7 @@ pub async fn handle_claim_burn(
     let unmasked_output = sdk.confidential_crypto_api().unblind_output(
         &output.commitment,
         &output.encrypted_data,
-        &account_secret_key.key,
+        &account_secret_key,
         &reciprocal_claim_public_key,
     )?;
 
@@ -634,7 +639,7 @@ pub async fn handle_claim_burn(
         inputs,
         &account_public_key,
         max_fee,
-        account_secret_key,
+        key_index,
         &accounts_api,
         context,
     )
@@ -655,7 +660,7 @@ async fn finish_claiming<T: WalletStore>(
     mut inputs: Vec<SubstateRequirement>,
     account_public_key: &RistrettoPublicKey,
     max_fee: Amount,
-    account_secret_key: DerivedKey<RistrettoPublicKey>,
+    key_index: u64,
     accounts_api: &tari_dan_wallet_sdk::apis::accounts::AccountsApi<'_, T>,
     context: &HandlerContext,
 ) -> Result<
@@ -693,10 +698,12 @@ async fn finish_claiming<T: WalletStore>(
         method: "pay_fee".to_string(),
         args: args![max_fee],
     });
-    let transaction = Transaction::builder()
-        .with_fee_instructions(instructions)
-        .with_inputs(inputs)
-        .build_and_seal(&account_secret_key.key);
+    let transaction = sdk.signer().build_and_seal(
+        key_index,
+        Transaction::builder()
+            .with_fee_instructions(instructions)
+            .with_inputs(inputs),
+    )?;
     let is_first_account = accounts_api.count()? == 0;
     let mut events = context.notifier().subscribe();
     let tx_id = context
@@ -706,7 +713,7 @@ async fn finish_claiming<T: WalletStore>(
             vec![],
             new_account_name.map(|name| NewAccountInfo {
                 name: Some(name),
-                key_index: account_secret_key.key_index,
+                key_index,
                 is_default: is_first_account,
             }),
         )
@@ -754,11 +761,9 @@ pub async fn handle_create_free_test_coins(
         SubstateRequirement::unversioned(XTR_FAUCET_VAULT_ADDRESS),
     ];
     let accounts_api = sdk.accounts_api();
-    let (account_address, account_secret_key, new_account_name) =
+    let (account_address, key_index, account_public_key, new_account_name) =
         get_or_create_account(&account, &accounts_api, key_id, sdk, &mut inputs)?;
 
-    let account_public_key = PublicKey::from_secret_key(&account_secret_key.key);
-
     let instructions = vec![Instruction::CallMethod {
         component_address: XTR_FAUCET_COMPONENT_ADDRESS,
         method: "take".to_string(),
@@ -774,7 +779,7 @@ pub async fn handle_create_free_test_coins(
         inputs,
         &account_public_key,
         max_fee,
-        account_secret_key,
+        key_index,
         &accounts_api,
         context,
     )
@@ -798,7 +803,7 @@ fn get_or_create_account<T: WalletStore>(
     key_id: Option<u64>,
     sdk: &DanWalletSdk<SqliteWalletStore, IndexerJsonRpcNetworkInterface>,
     inputs: &mut Vec<SubstateRequirement>,
-) -> Result<(SubstateId, DerivedKey<RistrettoPublicKey>, Option<String>), anyhow::Error> {
+) -> Result<(SubstateId, u64, PublicKey, Option<String>), anyhow::Error> {
     let maybe_account = match account {
         Some(ref addr_or_name) => get_account(addr_or_name, accounts_api).optional()?,
         None => {
@@ -810,16 +815,14 @@ fn get_or_create_account<T: WalletStore>(
             Some(account)
         },
     };
-    let (account_address, account_secret_key, new_account_name) = match maybe_account {
+    let result = match maybe_account {
         Some(account) => {
             let key_index = key_id.unwrap_or(account.key_index);
-            let account_secret_key = sdk
-                .key_manager_api()
-                .derive_key(key_manager::TRANSACTION_BRANCH, key_index)?;
+            let account_public_key = sdk.signer().public_key(key_index)?;
             let account_substate = sdk.substate_api().get_substate(&account.address)?;
             inputs.push(account_substate.address.into());
 
-            (account.address, account_secret_key, None)
+            (account.address, key_index, account_public_key, None)
         },
         None => {
             let name = account
@@ -827,21 +830,30 @@ fn get_or_create_account<T: WalletStore>(
                 .unwrap()
                 .name()
                 .ok_or_else(|| anyhow!("Account name must be provided when creating a new account"))?;
-            let account_secret_key = key_id
-                .map(|idx| sdk.key_manager_api().derive_key(key_manager::TRANSACTION_BRANCH, idx))
-                .unwrap_or_else(|| sdk.key_manager_api().next_key(key_manager::TRANSACTION_BRANCH))?;
-            let account_pk = PublicKey::from_secret_key(&account_secret_key.key);
+            let key_index = match key_id {
+                Some(idx) => idx,
+                None => {
+                    sdk.key_manager_api()
+                        .next_key(key_manager::TRANSACTION_BRANCH)?
+                        .key_index
+                },
+            };
+            let account_public_key = sdk.signer().public_key(key_index)?;
 
-            let account_address = new_component_address_from_public_key(&ACCOUNT_TEMPLATE_ADDRESS, &account_pk);
+            let account_address = new_component_address_from_public_key(&ACCOUNT_TEMPLATE_ADDRESS, &account_public_key);
 
             // We have no involved substate addresses, so we need to add an output
-            (account_address.into(), account_secret_key, Some(name.to_string()))
+            (
+                account_address.into(),
+                key_index,
+                account_public_key,
+                Some(name.to_string()),
+            )
         },
     };
-    Ok((account_address, account_secret_key, new_account_name))
+    Ok(result)
 }
 
-#[allow(clippy::too_many_lines)]
 pub async fn handle_transfer(
     context: &HandlerContext,
     token: Option<String>,
@@ -850,7 +862,193 @@ pub async fn handle_transfer(
     let sdk = context.wallet_sdk().clone();
     sdk.jwt_api().check_auth(token, &[JrpcPermission::Admin])?;
 
-    let (account, mut inputs) = get_account_with_inputs(req.account, &sdk)?;
+    let transfer = prepare_transfer(
+        &sdk,
+        req.account,
+        vec![TransferRecipient {
+            destination_public_key: req.destination_public_key,
+            resource_address: req.resource_address,
+            amount: req.amount,
+        }],
+        req.proof_from_badge_resource,
+    )
+    .await?;
+    let outcome = submit_transfer(context, &transfer, req.max_fee, req.dry_run).await?;
+    if !req.dry_run {
+        info!(
+            target: LOG_TARGET,
+            "✅ Transfer transaction {} finalized. Fee: {}", outcome.transaction_id, outcome.fee
+        );
+    }
+
+    Ok(AccountsTransferResponse {
+        transaction_id: outcome.transaction_id,
+        fee: outcome.fee,
+        fee_refunded: outcome.fee_refunded,
+        result: outcome.result,
+    })
+}
+
+pub async fn handle_confidential_transfer(
+    context: &HandlerContext,
+    token: Option<String>,
+    req: ConfidentialTransferRequest,
+) -> Result<ConfidentialTransferResponse, anyhow::Error> {
+    let sdk = context.wallet_sdk().clone();
+    sdk.jwt_api().check_auth(token, &[JrpcPermission::Admin])?;
+    let notifier = context.notifier().clone();
+
+    if req.amount.is_negative() {
+        return Err(invalid_params("amount", Some("must be positive")));
+    }
+    let transaction_service = context.transaction_service().clone();
+
+    task::spawn(async move {
+        let account = get_account_or_default(req.account, &sdk.accounts_api())?;
+
+        let transfer = sdk
+            .confidential_transfer_api()
+            .transfer(TransferParams {
+                from_account: account.address.as_component_address().unwrap(),
+                input_selection: req.input_selection,
+                amount: req.amount,
+                destination_public_key: req.destination_public_key,
+                resource_address: req.resource_address,
+                max_fee: req.max_fee.unwrap_or(DEFAULT_FEE),
+                output_to_revealed: req.output_to_revealed,
+                proof_from_resource: req.proof_from_badge_resource,
+                is_dry_run: req.dry_run,
+            })
+            .await?;
+
+        if req.dry_run {
+            let transaction_id = *transfer.transaction.id();
+            let exec_result = transaction_service
+                .submit_dry_run_transaction(
+                    transfer.transaction,
+                    transfer.inputs.into_iter().map(Into::into).collect(),
+                )
+                .await?;
+            let finalize = exec_result.finalize;
+            return Ok(ConfidentialTransferResponse {
+                transaction_id,
+                fee: finalize.fee_receipt.total_fees_paid,
+                result: finalize,
+            });
+        }
+
+        let mut events = notifier.subscribe();
+        let tx_id = transaction_service
+            .submit_transaction(
+                transfer.transaction,
+                transfer.inputs.into_iter().map(Into::into).collect(),
+            )
+            .await?;
+
+        notifier.notify(TransactionSubmittedEvent {
+            transaction_id: tx_id,
+            new_account: None,
+        });
+
+        let finalized = wait_for_result(&mut events, tx_id).await?;
+        if let Some(reject) = finalized.finalize.result.reject() {
+            return Err(anyhow::anyhow!("Fee transaction rejected: {}", reject));
+        }
+        if let Some(reason) = finalized.finalize.reject() {
+            return Err(anyhow::anyhow!(
+                "Fee transaction succeeded (fees charged) however the transaction failed: {}",
+                reason
+            ));
+        }
+
+        Ok(ConfidentialTransferResponse {
+            transaction_id: tx_id,
+            fee: finalized.final_fee,
+            result: finalized.finalize,
+        })
+    })
+    .await?
+}
+
+pub async fn handle_batch_transfer(
+    context: &HandlerContext,
+    token: Option<String>,
+    req: AccountsBatchTransferRequest,
+) -> Result<AccountsBatchTransferResponse, anyhow::Error> {
+    let sdk = context.wallet_sdk().clone();
+    sdk.jwt_api().check_auth(token, &[JrpcPermission::Admin])?;
+
+    if req.recipients.is_empty() {
+        return Err(invalid_params("recipients", Some("at least one recipient is required")));
+    }
+    if req.recipients.iter().any(|r| !r.amount.is_positive()) {
+        return Err(invalid_params("recipients", Some("all amounts must be positive")));
+    }
+
+    let transfer = prepare_transfer(&sdk, req.account, req.recipients, req.proof_from_badge_resource).await?;
+    let outcome = submit_transfer(context, &transfer, req.max_fee, req.dry_run).await?;
+    if !req.dry_run {
+        info!(
+            target: LOG_TARGET,
+            "✅ Batch transfer transaction {} to {} recipient(s) finalized. Fee: {}",
+            outcome.transaction_id,
+            transfer.recipients.len(),
+            outcome.fee
+        );
+    }
+
+    Ok(AccountsBatchTransferResponse {
+        transaction_id: outcome.transaction_id,
+        fee: outcome.fee,
+        fee_refunded: outcome.fee_refunded,
+        recipients: transfer.recipients,
+        result: outcome.result,
+    })
+}
+
+/// The instructions and inputs of a transfer of revealed funds from an account to one or more recipients, excluding
+/// the fee instructions.
+struct PreparedTransfer {
+    key_index: u64,
+    source_account_address: ComponentAddress,
+    instructions: Vec<Instruction>,
+    resource_inputs: Vec<SubstateRequirement>,
+    required_inputs: Vec<SubstateRequirement>,
+    recipients: Vec<TransferRecipientResult>,
+}
+
+impl PreparedTransfer {
+    fn build_transaction(
+        &self,
+        signer: &dyn TransactionSigner,
+        max_fee: Amount,
+    ) -> Result<Transaction, TransactionSignerError> {
+        signer.build_and_seal(
+            self.key_index,
+            Transaction::builder()
+                .fee_transaction_pay_from_component(self.source_account_address, max_fee)
+                .with_instructions(self.instructions.clone())
+                .with_inputs(self.resource_inputs.clone()),
+        )
+    }
+}
+
+struct TransferOutcome {
+    transaction_id: TransactionId,
+    fee: Amount,
+    fee_refunded: Amount,
+    result: FinalizeResult,
+}
+
+/// Resolves the inputs and builds the instructions that withdraw each amount from the account and deposit it into the
+/// recipient's account, creating recipient accounts that do not exist yet.
+async fn prepare_transfer(
+    sdk: &DanWalletSdk<SqliteWalletStore, IndexerJsonRpcNetworkInterface>,
+    account: Option<ComponentAddressOrName>,
+    recipients: Vec<TransferRecipient>,
+    proof_from_badge_resource: Option<ResourceAddress>,
+) -> Result<PreparedTransfer, anyhow::Error> {
+    let (account, mut inputs) = get_account_with_inputs(account, sdk)?;
 
     // get the source account component address
     let source_account_address = account
@@ -858,47 +1056,62 @@ pub async fn handle_transfer(
         .as_component_address()
         .ok_or_else(|| anyhow!("Invalid account address"))?;
 
-    // add the input for the source account vault substate
-    let src_vault = sdk
-        .accounts_api()
-        .get_vault_by_resource(&account.address, &req.resource_address)?;
-    let src_vault_substate = sdk.substate_api().get_substate(&src_vault.address)?;
-    inputs.push(src_vault_substate.address);
+    // add the inputs for each source vault and resource being transferred
+    let mut resource_addresses = recipients.iter().map(|r| r.resource_address).collect::<Vec<_>>();
+    resource_addresses.sort();
+    resource_addresses.dedup();
+    let mut resource_inputs = Vec::with_capacity(resource_addresses.len());
+    for resource_address in resource_addresses {
+        let src_vault = sdk
+            .accounts_api()
+            .get_vault_by_resource(&account.address, &resource_address)?;
+        let src_vault_substate = sdk.substate_api().get_substate(&src_vault.address)?;
+        inputs.push(src_vault_substate.address);
 
-    // add the input for the resource address to be transfered
-    let resource_substate = sdk
-        .substate_api()
-        .scan_for_substate(&SubstateId::Resource(req.resource_address), None)
-        .await?;
-    let resource_substate_address = SubstateRequirement::new(
-        resource_substate.address.substate_id.clone(),
-        Some(resource_substate.address.version),
-    );
-    inputs.push(resource_substate.address);
+        let resource_substate = sdk
+            .substate_api()
+            .scan_for_substate(&SubstateId::Resource(resource_address), None)
+            .await?;
+        resource_inputs.push(SubstateRequirement::new(
+            resource_substate.address.substate_id.clone(),
+            Some(resource_substate.address.version),
+        ));
+        inputs.push(resource_substate.address);
+    }
 
     let mut instructions = vec![];
-    let mut fee_instructions = vec![];
-
-    let destination_account_address =
-        new_component_address_from_public_key(&ACCOUNT_TEMPLATE_ADDRESS, &req.destination_public_key);
-    let existing_account = sdk
-        .substate_api()
-        .scan_for_substate(&SubstateId::Component(destination_account_address), None)
-        .await
-        .optional()?;
 
-    if let Some(ValidatorScanResult { address, .. }) = existing_account {
-        inputs.push(address);
-    } else {
-        instructions.push(Instruction::CreateAccount {
-            public_key_address: req.destination_public_key,
-            owner_rule: None,
-            access_rules: None,
-            workspace_bucket: None,
-        });
+    // resolve each destination account once, creating the ones that do not exist yet
+    let mut created_accounts = Vec::new();
+    let mut existing_accounts = Vec::new();
+    for recipient in &recipients {
+        let destination_public_key = &recipient.destination_public_key;
+        if created_accounts.contains(destination_public_key) || existing_accounts.contains(destination_public_key) {
+            continue;
+        }
+        let destination_account_address =
+            new_component_address_from_public_key(&ACCOUNT_TEMPLATE_ADDRESS, destination_public_key);
+        let existing_account = sdk
+            .substate_api()
+            .scan_for_substate(&SubstateId::Component(destination_account_address), None)
+            .await
+            .optional()?;
+
+        if let Some(ValidatorScanResult { address, .. }) = existing_account {
+            inputs.push(address);
+            existing_accounts.push(destination_public_key.clone());
+        } else {
+            instructions.push(Instruction::CreateAccount {
+                public_key_address: destination_public_key.clone(),
+                owner_rule: None,
+                access_rules: None,
+                workspace_bucket: None,
+            });
+            created_accounts.push(destination_public_key.clone());
+        }
     }
 
-    if let Some(ref badge) = req.proof_from_badge_resource {
+    if let Some(ref badge) = proof_from_badge_resource {
         instructions.extend([
             Instruction::CallMethod {
                 component_address: source_account_address,
@@ -909,54 +1122,84 @@ pub async fn handle_transfer(
         ]);
     }
 
-    // build the transaction
-    let max_fee = req.max_fee.unwrap_or(DEFAULT_FEE);
-    instructions.extend([
-        Instruction::CallMethod {
-            component_address: source_account_address,
-            method: "withdraw".to_string(),
-            args: args![req.resource_address, req.amount],
-        },
-        Instruction::PutLastInstructionOutputOnWorkspace {
-            key: b"bucket".to_vec(),
-        },
-        Instruction::CallMethod {
-            component_address: destination_account_address,
-            method: "deposit".to_string(),
-            args: args![Workspace("bucket")],
-        },
-    ]);
+    let mut recipient_results = Vec::with_capacity(recipients.len());
+    for (i, recipient) in recipients.into_iter().enumerate() {
+        let destination_account_address =
+            new_component_address_from_public_key(&ACCOUNT_TEMPLATE_ADDRESS, &recipient.destination_public_key);
+        let bucket = format!("bucket_{}", i);
+        instructions.extend([
+            Instruction::CallMethod {
+                component_address: source_account_address,
+                method: "withdraw".to_string(),
+                args: args![recipient.resource_address, recipient.amount],
+            },
+            Instruction::PutLastInstructionOutputOnWorkspace {
+                key: bucket.as_bytes().to_vec(),
+            },
+            Instruction::CallMethod {
+                component_address: destination_account_address,
+                method: "deposit".to_string(),
+                args: args![Workspace(bucket)],
+            },
+        ]);
+        recipient_results.push(TransferRecipientResult {
+            account_created: created_accounts.contains(&recipient.destination_public_key),
+            destination_public_key: recipient.destination_public_key,
+            destination_account: destination_account_address,
+            resource_address: recipient.resource_address,
+            amount: recipient.amount,
+        });
+    }
 
-    if req.proof_from_badge_resource.is_some() {
+    if proof_from_badge_resource.is_some() {
         instructions.push(Instruction::DropAllProofsInWorkspace);
     }
 
-    fee_instructions.extend([Instruction::CallMethod {
-        component_address: source_account_address,
-        method: "pay_fee".to_string(),
-        args: args![max_fee],
-    }]);
-
-    let account_secret_key = sdk
-        .key_manager_api()
-        .derive_key(key_manager::TRANSACTION_BRANCH, account.key_index)?;
+    Ok(PreparedTransfer {
+        key_index: account.key_index,
+        source_account_address,
+        instructions,
+        resource_inputs,
+        required_inputs: inputs.into_iter().map(Into::into).collect(),
+        recipients: recipient_results,
+    })
+}
 
-    let transaction = Transaction::builder()
-        .with_fee_instructions(fee_instructions)
-        .with_instructions(instructions)
-        .with_inputs(vec![resource_substate_address])
-        .build_and_seal(&account_secret_key.key);
+/// Signs and submits a prepared transfer. If no max fee is given, it is estimated by dry-running the transaction. A
+/// dry run returns immediately, otherwise this waits for the transaction to be finalized.
+async fn submit_transfer(
+    context: &HandlerContext,
+    transfer: &PreparedTransfer,
+    max_fee: Option<Amount>,
+    dry_run: bool,
+) -> Result<TransferOutcome, anyhow::Error> {
+    let signer = context.wallet_sdk().signer();
+    let max_fee = max_fee_or_estimate(max_fee, DEFAULT_FEE_MARGIN_PERCENT, || async {
+        let fee_receipt = estimate_fee(
+            context.transaction_service(),
+            transfer.build_transaction(signer, DEFAULT_FEE)?,
+            transfer.required_inputs.clone(),
+        )
+        .await?;
+        info!(
+            target: LOG_TARGET,
+            "Estimated transfer fee: {}",
+            fee_receipt.total_fees_charged()
+        );
+        Ok(fee_receipt)
+    })
+    .await?;
+    let transaction = transfer.build_transaction(signer, max_fee)?;
 
-    let required_inputs = inputs.into_iter().map(Into::into).collect();
     // If dry run we can return the result immediately
-    if req.dry_run {
+    if dry_run {
         let transaction_id = *transaction.id();
         let execute_result = context
             .transaction_service()
-            .submit_dry_run_transaction(transaction, required_inputs)
+            .submit_dry_run_transaction(transaction, transfer.required_inputs.clone())
             .await?;
         let finalize = execute_result.finalize;
-        return Ok(AccountsTransferResponse {
+        return Ok(TransferOutcome {
             transaction_id,
             fee: finalize.fee_receipt.total_fees_paid,
             fee_refunded: finalize.fee_receipt.total_fee_payment - finalize.fee_receipt.total_fees_paid,
@@ -968,9 +1211,14 @@ pub async fn handle_transfer(
     let mut events = context.notifier().subscribe();
     let tx_id = context
         .transaction_service()
-        .submit_transaction(transaction, required_inputs)
+        .submit_transaction(transaction, transfer.required_inputs.clone())
         .await?;
 
+    context.notifier().notify(TransactionSubmittedEvent {
+        transaction_id: tx_id,
+        new_account: None,
+    });
+
     let finalized = wait_for_result(&mut events, tx_id).await?;
 
     if let Some(reject) = finalized.finalize.result.reject() {
@@ -982,14 +1230,8 @@ pub async fn handle_transfer(
             reason
         ));
     }
-    info!(
-        target: LOG_TARGET,
-        "✅ Transfer transaction {} finalized. Fee: {}",
-        finalized.transaction_id,
-        finalized.final_fee
-    );
 
-    Ok(AccountsTransferResponse {
+    Ok(TransferOutcome {
         transaction_id: tx_id,
         fee: finalized.final_fee,
         fee_refunded: max_fee - finalized.final_fee,
@@ -997,17 +1239,20 @@ pub async fn handle_transfer(
     })
 }
 
-pub async fn handle_confidential_transfer(
+pub async fn handle_confidential_batch_transfer(
     context: &HandlerContext,
     token: Option<String>,
-    req: ConfidentialTransferRequest,
-) -> Result<ConfidentialTransferResponse, anyhow::Error> {
+    req: ConfidentialBatchTransferRequest,
+) -> Result<ConfidentialBatchTransferResponse, anyhow::Error> {
     let sdk = context.wallet_sdk().clone();
     sdk.jwt_api().check_auth(token, &[JrpcPermission::Admin])?;
     let notifier = context.notifier().clone();
 
-    if req.amount.is_negative() {
-        return Err(invalid_params("amount", Some("must be positive")));
+    if req.recipients.is_empty() {
+        return Err(invalid_params("recipients", Some("at least one recipient is required")));
+    }
+    if req.recipients.iter().any(|r| !r.amount.is_positive()) {
+        return Err(invalid_params("recipients", Some("all amounts must be positive")));
     }
     let transaction_service = context.transaction_service().clone();
 
@@ -1016,19 +1261,37 @@ pub async fn handle_confidential_transfer(
 
         let transfer = sdk
             .confidential_transfer_api()
-            .transfer(TransferParams {
+            .batch_transfer(BatchTransferParams {
                 from_account: account.address.as_component_address().unwrap(),
                 input_selection: req.input_selection,
-                amount: req.amount,
-                destination_public_key: req.destination_public_key,
                 resource_address: req.resource_address,
+                recipients: req
+                    .recipients
+                    .into_iter()
+                    .map(|r| BatchTransferRecipient {
+                        destination_public_key: r.destination_public_key,
+                        amount: r.amount,
+                    })
+                    .collect(),
                 max_fee: req.max_fee.unwrap_or(DEFAULT_FEE),
                 output_to_revealed: req.output_to_revealed,
                 proof_from_resource: req.proof_from_badge_resource,
-                is_dry_run: req.dry_run,
             })
             .await?;
 
+        let recipients = transfer
+            .recipients
+            .into_iter()
+            .map(|r| TransferRecipientResult {
+                destination_public_key: r.destination_public_key,
+                destination_account: r.destination_account,
+                resource_address: req.resource_address,
+                amount: r.amount,
+                account_created: r.account_created,
+            })
+            .collect();
+        let transfer = transfer.transfer;
+
         if req.dry_run {
             let transaction_id = *transfer.transaction.id();
             let exec_result = transaction_service
@@ -1038,9 +1301,10 @@ pub async fn handle_confidential_transfer(
                 )
                 .await?;
             let finalize = exec_result.finalize;
-            return Ok(ConfidentialTransferResponse {
+            return Ok(ConfidentialBatchTransferResponse {
                 transaction_id,
                 fee: finalize.fee_receipt.total_fees_paid,
+                recipients,
                 result: finalize,
             });
         }
@@ -1069,9 +1333,10 @@ pub async fn handle_confidential_transfer(
             ));
         }
 
-        Ok(ConfidentialTransferResponse {
+        Ok(ConfidentialBatchTransferResponse {
             transaction_id: tx_id,
             fee: finalized.final_fee,
+            recipients,
             result: finalized.finalize,
         })
     })
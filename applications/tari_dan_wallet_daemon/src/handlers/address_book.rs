@@ -0,0 +1,85 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use tari_dan_common_types::optional::Optional;
+use tari_dan_wallet_sdk::{apis::jwt::JrpcPermission, models::Contact};
+use tari_wallet_daemon_client::types::{
+    AddressBookAddRequest,
+    AddressBookAddResponse,
+    AddressBookGetRequest,
+    AddressBookGetResponse,
+    AddressBookListRequest,
+    AddressBookListResponse,
+    AddressBookRemoveRequest,
+    AddressBookRemoveResponse,
+};
+
+use super::context::HandlerContext;
+use crate::handlers::error::HandlerError;
+
+pub async fn handle_add(
+    context: &HandlerContext,
+    token: Option<String>,
+    req: AddressBookAddRequest,
+) -> Result<AddressBookAddResponse, anyhow::Error> {
+    let sdk = context.wallet_sdk();
+    sdk.jwt_api().check_auth(token, &[JrpcPermission::Admin])?;
+    let contact = Contact {
+        name: req.name,
+        public_key: req.public_key,
+        memo: req.memo,
+    };
+    sdk.address_book_api().add_contact(&contact)?;
+    let account_address = contact.account_address();
+    Ok(AddressBookAddResponse {
+        contact,
+        account_address,
+    })
+}
+
+pub async fn handle_get(
+    context: &HandlerContext,
+    token: Option<String>,
+    req: AddressBookGetRequest,
+) -> Result<AddressBookGetResponse, anyhow::Error> {
+    let sdk = context.wallet_sdk();
+    sdk.jwt_api().check_auth(token, &[JrpcPermission::Admin])?;
+    let contact = sdk
+        .address_book_api()
+        .get_contact(&req.name)
+        .optional()?
+        .ok_or(HandlerError::NotFound)?;
+    let account_address = contact.account_address();
+    Ok(AddressBookGetResponse {
+        contact,
+        account_address,
+    })
+}
+
+pub async fn handle_list(
+    context: &HandlerContext,
+    token: Option<String>,
+    req: AddressBookListRequest,
+) -> Result<AddressBookListResponse, anyhow::Error> {
+    let sdk = context.wallet_sdk();
+    sdk.jwt_api().check_auth(token, &[JrpcPermission::Admin])?;
+    let address_book = sdk.address_book_api();
+    let contacts = address_book.get_contacts(req.offset, req.limit)?;
+    let total = address_book.count()?;
+    Ok(AddressBookListResponse { contacts, total })
+}
+
+pub async fn handle_remove(
+    context: &HandlerContext,
+    token: Option<String>,
+    req: AddressBookRemoveRequest,
+) -> Result<AddressBookRemoveResponse, anyhow::Error> {
+    let sdk = context.wallet_sdk();
+    sdk.jwt_api().check_auth(token, &[JrpcPermission::Admin])?;
+    let contact = sdk
+        .address_book_api()
+        .remove_contact(&req.name)
+        .optional()?
+        .ok_or(HandlerError::NotFound)?;
+    Ok(AddressBookRemoveResponse { contact })
+}
@@ -59,6 +59,8 @@ pub async fn handle_create_transfer_proof(
     }
 
     let account = get_account_or_default(req.account, &sdk.accounts_api())?;
+    // Fails before any funds are locked if the signer does not hold the account key in-process
+    let account_secret = sdk.signer().secret_key(account.key_index)?;
     let vault = sdk
         .accounts_api()
         .get_vault_by_resource(&account.address, &req.resource_address)?;
@@ -81,9 +83,6 @@ pub async fn handle_create_transfer_proof(
     // TODO: Any errors from here need to unlock the outputs, ideally just roll back (refactor required but doable).
 
     // TODO: Wrap up key/encrypted data handling in the wallet SDK
-    let account_secret = sdk
-        .key_manager_api()
-        .derive_key(key_manager::TRANSACTION_BRANCH, account.key_index)?;
     let output_mask = sdk.key_manager_api().next_key(key_manager::TRANSACTION_BRANCH)?;
     let (_, public_nonce) = PublicKey::random_keypair(&mut OsRng);
 
@@ -91,7 +90,7 @@ pub async fn handle_create_transfer_proof(
         req.amount.as_u64_checked().unwrap(),
         &output_mask.key,
         &public_nonce,
-        &account_secret.key,
+        &account_secret,
     )?;
 
     let known_resource_substate_address = sdk
@@ -247,6 +246,14 @@ pub async fn handle_view_vault_balance(
     let sdk = context.wallet_sdk();
     sdk.jwt_api().check_auth(token, &[JrpcPermission::Admin])?;
 
+    // View keys are derived from the wallet seed, which does not hold the keys of the wallet when an external signer
+    // is configured
+    if context.config().external_signer.is_some() {
+        return Err(anyhow!(
+            "Viewing confidential balances is not supported with an external signer"
+        ));
+    }
+
     let substate = sdk.substate_api().scan_for_substate(&req.vault_id.into(), None).await?;
     let vault = substate
         .substate
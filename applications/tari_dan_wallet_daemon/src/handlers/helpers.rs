@@ -1,23 +1,28 @@
 //   Copyright 2023 The Tari Project
 //   SPDX-License-Identifier: BSD-3-Clause
 
-use std::fmt::Display;
+use std::{fmt::Display, future::Future};
 
-use tari_dan_common_types::optional::Optional;
+use tari_dan_common_types::{optional::Optional, SubstateRequirement};
 use tari_dan_wallet_sdk::{
     apis::accounts::{AccountsApi, AccountsApiError},
     models::{Account, VersionedSubstateId},
     DanWalletSdk,
 };
 use tari_dan_wallet_storage_sqlite::SqliteWalletStore;
-use tari_engine_types::substate::SubstateId;
-use tari_transaction::TransactionId;
+use tari_engine_types::{
+    commit_result::{FinalizeResult, RejectReason, TransactionResult},
+    fees::FeeReceipt,
+    substate::SubstateId,
+};
+use tari_template_lib::models::Amount;
+use tari_transaction::{Transaction, TransactionId};
 use tari_wallet_daemon_client::ComponentAddressOrName;
 use tokio::sync::broadcast;
 
 use crate::{
     indexer_jrpc_impl::IndexerJsonRpcNetworkInterface,
-    services::{TransactionFinalizedEvent, WalletEvent},
+    services::{TransactionFinalizedEvent, TransactionServiceHandle, WalletEvent},
 };
 
 pub async fn wait_for_result(
@@ -150,3 +155,182 @@ pub(super) fn invalid_params<T: Display>(field: &str, details: Option<T>) -> any
     )
     .into()
 }
+
+/// Dry-runs the transaction and returns its fee receipt. A transaction rejected only because the fee payment was
+/// insufficient still has a complete cost breakdown, so only other rejections are treated as errors.
+pub async fn estimate_fee(
+    transaction_service: &TransactionServiceHandle,
+    transaction: Transaction,
+    required_inputs: Vec<SubstateRequirement>,
+) -> Result<FeeReceipt, anyhow::Error> {
+    let exec_result = transaction_service
+        .submit_dry_run_transaction(transaction, required_inputs)
+        .await?;
+    fee_receipt_from_dry_run(exec_result.finalize)
+}
+
+/// Returns the fee receipt of a dry run unless the dry run was rejected for a reason other than insufficient fees.
+pub fn fee_receipt_from_dry_run(finalize: FinalizeResult) -> Result<FeeReceipt, anyhow::Error> {
+    match finalize.full_reject() {
+        None | Some(RejectReason::FeesNotPaid(_)) => Ok(finalize.fee_receipt),
+        Some(reason) => Err(anyhow::anyhow!(
+            "Unable to estimate fee because the transaction would be rejected: {}",
+            reason
+        )),
+    }
+}
+
+/// Returns `max_fee` if given, otherwise the fees charged by `estimate` plus a safety margin of `margin_percent`.
+pub async fn max_fee_or_estimate<F, Fut>(
+    max_fee: Option<Amount>,
+    margin_percent: u64,
+    estimate: F,
+) -> Result<Amount, anyhow::Error>
+where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<FeeReceipt, anyhow::Error>>,
+{
+    if let Some(max_fee) = max_fee {
+        return Ok(max_fee);
+    }
+    let fee_receipt = estimate().await?;
+    Ok(recommended_max_fee(fee_receipt.total_fees_charged(), margin_percent))
+}
+
+/// Returns the fees charged plus a safety margin of `margin_percent`, rounded up. The result saturates at
+/// `Amount::MAX`.
+pub fn recommended_max_fee(fees_charged: Amount, margin_percent: u64) -> Amount {
+    // i64 * u64 cannot overflow an i128
+    let fees_charged = i128::from(fees_charged.value());
+    let margin = (fees_charged * i128::from(margin_percent) + 99) / 100;
+    Amount::new(i64::try_from(fees_charged + margin).unwrap_or(i64::MAX))
+}
+
+#[cfg(test)]
+mod tests {
+    use tari_engine_types::fees::FeeSource;
+
+    use super::*;
+
+    fn fee_receipt(fees_charged: u64) -> FeeReceipt {
+        let mut receipt = FeeReceipt::default();
+        if fees_charged > 0 {
+            receipt.cost_breakdown.insert(FeeSource::RuntimeCall, fees_charged);
+        }
+        receipt
+    }
+
+    fn dry_run_result(result: TransactionResult, fees_charged: u64) -> FinalizeResult {
+        FinalizeResult::new(Default::default(), vec![], vec![], result, fee_receipt(fees_charged))
+    }
+
+    mod recommended_max_fee {
+        use super::*;
+
+        #[test]
+        fn it_adds_the_margin() {
+            assert_eq!(recommended_max_fee(Amount(1000), 20), Amount(1200));
+            assert_eq!(recommended_max_fee(Amount(1000), 0), Amount(1000));
+            assert_eq!(recommended_max_fee(Amount(1000), 100), Amount(2000));
+        }
+
+        #[test]
+        fn it_rounds_the_margin_up() {
+            // 10% of 1001 is 100.1
+            assert_eq!(recommended_max_fee(Amount(1001), 10), Amount(1102));
+            // 1% of 1 is 0.01
+            assert_eq!(recommended_max_fee(Amount(1), 1), Amount(2));
+        }
+
+        #[test]
+        fn it_recommends_zero_for_a_zero_fee() {
+            assert_eq!(recommended_max_fee(Amount::zero(), 20), Amount::zero());
+        }
+
+        #[test]
+        fn it_saturates_instead_of_overflowing() {
+            assert_eq!(recommended_max_fee(Amount::MAX, 20), Amount::MAX);
+            assert_eq!(recommended_max_fee(Amount(i64::MAX / 2), 150), Amount::MAX);
+            assert_eq!(recommended_max_fee(Amount(1000), u64::MAX), Amount::MAX);
+        }
+    }
+
+    mod fee_receipt_from_dry_run {
+        use super::*;
+
+        #[test]
+        fn it_returns_the_receipt_of_an_accepted_dry_run() {
+            let receipt =
+                fee_receipt_from_dry_run(dry_run_result(TransactionResult::Accept(Default::default()), 1234)).unwrap();
+            assert_eq!(receipt.total_fees_charged(), Amount(1234));
+        }
+
+        #[test]
+        fn it_returns_the_receipt_of_a_zero_fee_dry_run() {
+            let receipt =
+                fee_receipt_from_dry_run(dry_run_result(TransactionResult::Accept(Default::default()), 0)).unwrap();
+            assert_eq!(receipt.total_fees_charged(), Amount::zero());
+        }
+
+        #[test]
+        fn it_returns_the_receipt_if_fees_were_not_paid() {
+            let receipt = fee_receipt_from_dry_run(dry_run_result(
+                TransactionResult::Reject(RejectReason::FeesNotPaid("insufficient fee".to_string())),
+                500,
+            ))
+            .unwrap();
+            assert_eq!(receipt.total_fees_charged(), Amount(500));
+        }
+
+        #[test]
+        fn it_errors_if_the_dry_run_is_rejected_for_another_reason() {
+            fee_receipt_from_dry_run(dry_run_result(
+                TransactionResult::Reject(RejectReason::ExecutionFailure("boom".to_string())),
+                500,
+            ))
+            .unwrap_err();
+        }
+    }
+
+    mod max_fee_or_estimate {
+        use super::*;
+
+        #[tokio::test]
+        async fn it_uses_the_given_max_fee_without_estimating() {
+            let max_fee = max_fee_or_estimate(Some(Amount(10)), 20, || async {
+                panic!("estimate should not be called when a max fee is given")
+            })
+            .await
+            .unwrap();
+            assert_eq!(max_fee, Amount(10));
+        }
+
+        #[tokio::test]
+        async fn it_estimates_the_max_fee_with_a_margin() {
+            let max_fee = max_fee_or_estimate(None, 20, || async { Ok(fee_receipt(1000)) })
+                .await
+                .unwrap();
+            assert_eq!(max_fee, Amount(1200));
+        }
+
+        #[tokio::test]
+        async fn it_estimates_zero_for_a_zero_fee_dry_run() {
+            let max_fee = max_fee_or_estimate(None, 20, || async { Ok(fee_receipt(0)) })
+                .await
+                .unwrap();
+            assert_eq!(max_fee, Amount::zero());
+        }
+
+        #[tokio::test]
+        async fn it_fails_if_the_estimate_fails() {
+            max_fee_or_estimate(None, 20, || async {
+                fee_receipt_from_dry_run(dry_run_result(
+                    TransactionResult::Reject(RejectReason::ExecutionFailure("boom".to_string())),
+                    500,
+                ))
+            })
+            .await
+            .unwrap_err();
+        }
+    }
+}
@@ -3,8 +3,12 @@
 
 use tari_common_types::types::PublicKey;
 use tari_crypto::keys::PublicKey as PublicKeyTrait;
-use tari_dan_wallet_sdk::apis::{jwt::JrpcPermission, key_manager};
+use tari_dan_wallet_sdk::{
+    apis::{jwt::JrpcPermission, key_manager},
+    signer::TransactionSignerError,
+};
 use tari_wallet_daemon_client::types::{
+    KeyBranch,
     KeysCreateRequest,
     KeysCreateResponse,
     KeysListRequest,
@@ -27,9 +31,15 @@ pub async fn handle_create(
         .specific_index
         .map(|idx| key_manager.derive_key(req.branch.as_str(), idx))
         .unwrap_or_else(|| key_manager.next_key(req.branch.as_str()))?;
+    // Transaction keys are provided by the signer, which may hold them outside the wallet
+    let public_key = if matches!(req.branch, KeyBranch::Transaction) {
+        sdk.signer().public_key(key.key_index)?
+    } else {
+        PublicKey::from_secret_key(&key.key)
+    };
     Ok(KeysCreateResponse {
         id: key.key_index,
-        public_key: PublicKey::from_secret_key(&key.key),
+        public_key,
     })
 }
 
@@ -40,7 +50,15 @@ pub async fn handle_list(
 ) -> Result<KeysListResponse, anyhow::Error> {
     let sdk = context.wallet_sdk();
     sdk.jwt_api().check_auth(token, &[JrpcPermission::KeyList])?;
-    let keys = sdk.key_manager_api().get_all_keys(req.branch.as_str())?;
+    let keys = if matches!(req.branch, KeyBranch::Transaction) {
+        sdk.key_manager_api()
+            .get_all_key_indexes(key_manager::TRANSACTION_BRANCH)?
+            .into_iter()
+            .map(|(index, active)| Ok((index, sdk.signer().public_key(index)?, active)))
+            .collect::<Result<_, TransactionSignerError>>()?
+    } else {
+        sdk.key_manager_api().get_all_keys(req.branch.as_str())?
+    };
     Ok(KeysListResponse { keys })
 }
 
@@ -53,9 +71,7 @@ pub async fn handle_set_active(
     sdk.jwt_api().check_auth(token, &[JrpcPermission::Admin])?;
     let km = sdk.key_manager_api();
     km.set_active_key(key_manager::TRANSACTION_BRANCH, req.index)?;
-    let (_, key) = km.get_active_key(key_manager::TRANSACTION_BRANCH)?;
+    let public_key = sdk.signer().public_key(req.index)?;
 
-    Ok(KeysSetActiveResponse {
-        public_key: PublicKey::from_secret_key(&key.key),
-    })
+    Ok(KeysSetActiveResponse { public_key })
 }
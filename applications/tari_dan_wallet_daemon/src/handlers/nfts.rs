@@ -5,13 +5,9 @@ use std::{collections::BTreeMap, str::FromStr};
 
 use anyhow::anyhow;
 use log::info;
-use tari_common_types::types::PublicKey;
-use tari_crypto::{keys::PublicKey as PK, ristretto::RistrettoSecretKey, tari_utilities::ByteArray};
+use tari_crypto::tari_utilities::ByteArray;
 use tari_dan_common_types::SubstateRequirement;
-use tari_dan_wallet_sdk::{
-    apis::{jwt::JrpcPermission, key_manager},
-    models::Account,
-};
+use tari_dan_wallet_sdk::{apis::jwt::JrpcPermission, models::Account};
 use tari_engine_types::{instruction::Instruction, substate::SubstateId};
 use tari_template_builtin::ACCOUNT_NFT_TEMPLATE_ADDRESS;
 use tari_template_lib::{
@@ -81,15 +77,11 @@ pub async fn handle_mint_account_nft(
     req: MintAccountNftRequest,
 ) -> Result<MintAccountNftResponse, anyhow::Error> {
     let sdk = context.wallet_sdk();
-    let key_manager_api = sdk.key_manager_api();
     sdk.jwt_api().check_auth(token.clone(), &[JrpcPermission::Admin])?;
 
     let account = get_account(&req.account, &sdk.accounts_api())?;
 
-    let signing_key_index = account.key_index;
-    let signing_key = key_manager_api.derive_key(key_manager::TRANSACTION_BRANCH, signing_key_index)?;
-
-    let owner_pk = PublicKey::from_secret_key(&signing_key.key);
+    let owner_pk = sdk.signer().public_key(account.key_index)?;
     let owner_token =
         NonFungibleAddress::from_public_key(RistrettoPublicKeyBytes::from_bytes(owner_pk.as_bytes()).unwrap());
 
@@ -102,7 +94,6 @@ pub async fn handle_mint_account_nft(
             let resp = create_account_nft(
                 context,
                 &account,
-                &signing_key.key,
                 owner_token,
                 req.create_account_nft_fee.unwrap_or(DEFAULT_FEE),
                 token.clone(),
@@ -137,7 +128,6 @@ pub async fn handle_mint_account_nft(
         token,
         account,
         component_address,
-        &signing_key.key,
         req.mint_fee.unwrap_or(DEFAULT_FEE),
         metadata,
     )
@@ -174,7 +164,6 @@ async fn mint_account_nft(
     token: Option<String>,
     account: Account,
     component_address: ComponentAddress,
-    owner_sk: &RistrettoSecretKey,
     fee: Amount,
     metadata: Metadata,
 ) -> Result<TransactionFinalizedEvent, anyhow::Error> {
@@ -207,10 +196,12 @@ async fn mint_account_nft(
         },
     ];
 
-    let transaction = Transaction::builder()
-        .fee_transaction_pay_from_component(account.address.as_component_address().unwrap(), fee)
-        .with_instructions(instructions)
-        .build_and_seal(owner_sk);
+    let transaction = sdk.signer().build_and_seal(
+        account.key_index,
+        Transaction::builder()
+            .fee_transaction_pay_from_component(account.address.as_component_address().unwrap(), fee)
+            .with_instructions(instructions),
+    )?;
 
     let mut events = context.notifier().subscribe();
     let tx_id = context
@@ -236,7 +227,6 @@ async fn mint_account_nft(
 async fn create_account_nft(
     context: &HandlerContext,
     account: &Account,
-    owner_sk: &RistrettoSecretKey,
     owner_token: NonFungibleAddress,
     fee: Amount,
     token: Option<String>,
@@ -249,11 +239,13 @@ async fn create_account_nft(
         .locate_dependent_substates(&[account.address.clone()])
         .await?;
 
-    let transaction = Transaction::builder()
-        .fee_transaction_pay_from_component(account.address.as_component_address().unwrap(), fee)
-        .call_function(ACCOUNT_NFT_TEMPLATE_ADDRESS, "create", args![owner_token,])
-        .with_inputs(inputs)
-        .build_and_seal(owner_sk);
+    let transaction = sdk.signer().build_and_seal(
+        account.key_index,
+        Transaction::builder()
+            .fee_transaction_pay_from_component(account.address.as_component_address().unwrap(), fee)
+            .call_function(ACCOUNT_NFT_TEMPLATE_ADDRESS, "create", args![owner_token,])
+            .with_inputs(inputs),
+    )?;
 
     let tx_id = sdk
         .transaction_api()
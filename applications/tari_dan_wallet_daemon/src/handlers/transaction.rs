@@ -8,7 +8,10 @@ use futures::{future, future::Either};
 use log::*;
 use tari_dan_app_utilities::json_encoding;
 use tari_dan_common_types::{optional::Optional, Epoch, SubstateRequirement};
-use tari_dan_wallet_sdk::apis::{jwt::JrpcPermission, key_manager};
+use tari_dan_wallet_sdk::{
+    apis::{jwt::JrpcPermission, key_manager},
+    models::TransactionFilter,
+};
 use tari_template_lib::{args, models::Amount};
 use tari_transaction::Transaction;
 use tari_wallet_daemon_client::types::{
@@ -17,8 +20,12 @@ use tari_wallet_daemon_client::types::{
     CallInstructionRequest,
     PublishTemplateRequest,
     PublishTemplateResponse,
+    TransactionEstimateFeeRequest,
+    TransactionEstimateFeeResponse,
     TransactionGetAllRequest,
     TransactionGetAllResponse,
+    TransactionGetBalanceHistoryRequest,
+    TransactionGetBalanceHistoryResponse,
     TransactionGetRequest,
     TransactionGetResponse,
     TransactionGetResultRequest,
@@ -34,8 +41,12 @@ use tokio::time;
 
 use super::{accounts, context::HandlerContext};
 use crate::{
-    handlers::{helpers::get_account_or_default, HandlerError},
+    handlers::{
+        helpers::{estimate_fee, get_account_or_default, invalid_params, recommended_max_fee},
+        HandlerError,
+    },
     services::WalletEvent,
+    DEFAULT_FEE_MARGIN_PERCENT,
 };
 
 const LOG_TARGET: &str = "tari::dan::wallet_daemon::handlers::transaction";
@@ -99,8 +110,7 @@ pub async fn handle_submit(
         .check_auth(token, &[JrpcPermission::TransactionSend(None)])?;
     let key_api = sdk.key_manager_api();
     // Fetch the key to sign the transaction
-    // TODO: Ideally the SDK should take care of signing the transaction internally
-    let (_, key) = key_api.get_key_or_active(key_manager::TRANSACTION_BRANCH, req.signing_key_index)?;
+    let (key_index, _) = key_api.get_key_or_active(key_manager::TRANSACTION_BRANCH, req.signing_key_index)?;
 
     let autofill_inputs = req.autofill_inputs;
     let detected_inputs = if req.detect_inputs {
@@ -130,10 +140,12 @@ pub async fn handle_submit(
         req.detect_inputs_use_unversioned,
     );
 
-    let transaction = Transaction::builder()
-        .with_unsigned_transaction(req.transaction)
-        .with_inputs(detected_inputs)
-        .build_and_seal(&key.key);
+    let transaction = sdk.signer().build_and_seal(
+        key_index,
+        Transaction::builder()
+            .with_unsigned_transaction(req.transaction)
+            .with_inputs(detected_inputs),
+    )?;
 
     for input in transaction.inputs() {
         debug!(target: LOG_TARGET, "Input: {}", input)
@@ -170,8 +182,7 @@ pub async fn handle_submit_dry_run(
         .check_auth(token, &[JrpcPermission::TransactionSend(None)])?;
     let key_api = sdk.key_manager_api();
     // Fetch the key to sign the transaction
-    // TODO: Ideally the SDK should take care of signing the transaction internally
-    let (_, key) = key_api.get_key_or_active(key_manager::TRANSACTION_BRANCH, req.signing_key_index)?;
+    let (key_index, _) = key_api.get_key_or_active(key_manager::TRANSACTION_BRANCH, req.signing_key_index)?;
 
     let autofill_inputs = req.autofill_inputs;
     let detected_inputs = if req.detect_inputs {
@@ -183,10 +194,12 @@ pub async fn handle_submit_dry_run(
         vec![]
     };
 
-    let transaction = Transaction::builder()
-        .with_unsigned_transaction(req.transaction)
-        .with_inputs(detected_inputs)
-        .build_and_seal(&key.key);
+    let transaction = sdk.signer().build_and_seal(
+        key_index,
+        Transaction::builder()
+            .with_unsigned_transaction(req.transaction)
+            .with_inputs(detected_inputs),
+    )?;
 
     for proof_id in req.proof_ids {
         // update the proofs table with the corresponding transaction hash
@@ -201,7 +214,7 @@ pub async fn handle_submit_dry_run(
     );
     let exec_result = context
         .transaction_service()
-        .submit_dry_run_transaction(transaction, autofill_inputs)
+        .submit_dry_run_transaction_with_trace(transaction, autofill_inputs, req.trace)
         .await?;
 
     let json_result = json_encoding::encode_finalize_result_into_json(&exec_result.finalize)?;
@@ -213,6 +226,47 @@ pub async fn handle_submit_dry_run(
     })
 }
 
+pub async fn handle_estimate_fee(
+    context: &HandlerContext,
+    token: Option<String>,
+    req: TransactionEstimateFeeRequest,
+) -> Result<TransactionEstimateFeeResponse, anyhow::Error> {
+    let sdk = context.wallet_sdk();
+    sdk.jwt_api()
+        .check_auth(token, &[JrpcPermission::TransactionSend(None)])?;
+    let (key_index, _) = sdk
+        .key_manager_api()
+        .get_key_or_active(key_manager::TRANSACTION_BRANCH, req.signing_key_index)?;
+
+    let detected_inputs = if req.detect_inputs {
+        let substates = req.transaction.to_referenced_substates()?;
+        let substates = substates.into_iter().collect::<Vec<_>>();
+        sdk.substate_api().locate_dependent_substates(&substates).await?
+    } else {
+        vec![]
+    };
+
+    let transaction = sdk.signer().build_and_seal(
+        key_index,
+        Transaction::builder()
+            .with_unsigned_transaction(req.transaction)
+            .with_inputs(detected_inputs),
+    )?;
+
+    let fee_receipt = estimate_fee(context.transaction_service(), transaction, req.autofill_inputs).await?;
+    let total_fees_charged = fee_receipt.total_fees_charged();
+    let recommended_max_fee = recommended_max_fee(
+        total_fees_charged,
+        req.fee_margin_percent.unwrap_or(DEFAULT_FEE_MARGIN_PERCENT),
+    );
+
+    Ok(TransactionEstimateFeeResponse {
+        fee_receipt,
+        total_fees_charged,
+        recommended_max_fee,
+    })
+}
+
 pub async fn handle_get(
     context: &HandlerContext,
     token: Option<String>,
@@ -246,15 +300,51 @@ pub async fn handle_get_all(
         .wallet_sdk()
         .jwt_api()
         .check_auth(token, &[JrpcPermission::TransactionGet])?;
-    let transactions = context
-        .wallet_sdk()
-        .transaction_api()
-        .fetch_all(req.status, req.component)?;
+    if req.direction.is_some() && req.component.is_none() {
+        return Err(invalid_params(
+            "direction",
+            Some("a component must be provided to filter by direction"),
+        ));
+    }
+    let filter = TransactionFilter {
+        status: req.status,
+        component: req.component,
+        resource: req.resource_address,
+        direction: req.direction,
+        from_date: req.from_date,
+        to_date: req.to_date,
+    };
+    let transaction_api = context.wallet_sdk().transaction_api();
+    let total = transaction_api.count(&filter)?;
+    let transactions = transaction_api.fetch_all(&filter, req.offset, req.limit)?;
     Ok(TransactionGetAllResponse {
         transactions: transactions
             .into_iter()
             .map(|tx| (tx.transaction, tx.finalize, tx.status, tx.last_update_time))
             .collect(),
+        total,
+    })
+}
+
+pub async fn handle_get_balance_history(
+    context: &HandlerContext,
+    token: Option<String>,
+    req: TransactionGetBalanceHistoryRequest,
+) -> Result<TransactionGetBalanceHistoryResponse, anyhow::Error> {
+    let sdk = context.wallet_sdk();
+    let account = get_account_or_default(req.account, &sdk.accounts_api())?;
+    sdk.jwt_api()
+        .check_auth(token, &[JrpcPermission::AccountBalance(account.address.clone())])?;
+    let account_address = account
+        .address
+        .as_component_address()
+        .ok_or_else(|| anyhow!("Invalid account address"))?;
+    let changes = sdk
+        .transaction_api()
+        .balance_history(account_address, req.resource_address)?;
+    Ok(TransactionGetBalanceHistoryResponse {
+        account_address,
+        changes,
     })
 }
 
@@ -396,6 +486,7 @@ pub async fn handle_publish_template(
             autofill_inputs: vec![],
             detect_inputs: req.detect_inputs,
             proof_ids: vec![],
+            trace: false,
         };
         let resp = handle_submit_dry_run(context, token, request).await?;
         if let Some(reject) = resp.result.finalize.full_reject() {
@@ -3,7 +3,7 @@
 
 use anyhow::anyhow;
 use log::*;
-use tari_dan_wallet_sdk::apis::{jwt::JrpcPermission, key_manager};
+use tari_dan_wallet_sdk::apis::jwt::JrpcPermission;
 use tari_engine_types::instruction::Instruction;
 use tari_template_lib::args;
 use tari_transaction::Transaction;
@@ -70,13 +70,10 @@ pub async fn handle_claim_validator_fees(
 
     // TODO: At the moment fees can only be claimed by the account of the wallet.
     // In future we should change it to allow a separate public key
-    let account_secret_key = sdk
-        .key_manager_api()
-        .derive_key(key_manager::TRANSACTION_BRANCH, account.key_index)?;
-
-    let transaction = Transaction::builder()
-        .with_fee_instructions(fee_instructions)
-        .build_and_seal(&account_secret_key.key);
+    let transaction = sdk.signer().build_and_seal(
+        account.key_index,
+        Transaction::builder().with_fee_instructions(fee_instructions),
+    )?;
 
     // send the transaction
     let required_inputs = inputs.into_iter().map(Into::into).collect();
@@ -102,6 +102,7 @@ impl WalletNetworkInterface for IndexerJsonRpcNetworkInterface {
                 filter_by_type,
                 limit,
                 offset,
+                ..Default::default()
             })
             .await?;
         let substates = result
@@ -138,6 +139,7 @@ impl WalletNetworkInterface for IndexerJsonRpcNetworkInterface {
                 transaction,
                 required_substates,
                 is_dry_run: false,
+                trace: false,
             })
             .await?;
         Ok(result.transaction_id)
@@ -147,6 +149,7 @@ impl WalletNetworkInterface for IndexerJsonRpcNetworkInterface {
         &self,
         transaction: Transaction,
         required_substates: Vec<SubstateRequirement>,
+        trace: bool,
     ) -> Result<TransactionQueryResult, Self::Error> {
         let mut client = self.get_client()?;
         let resp = client
@@ -154,6 +157,7 @@ impl WalletNetworkInterface for IndexerJsonRpcNetworkInterface {
                 transaction,
                 required_substates,
                 is_dry_run: true,
+                trace,
             })
             .await?;
 
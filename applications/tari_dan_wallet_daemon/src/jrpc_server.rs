@@ -29,6 +29,7 @@ use tower_http::{cors::CorsLayer, trace::TraceLayer};
 use super::handlers::{substates, templates, HandlerContext};
 use crate::handlers::{
     accounts,
+    address_book,
     confidential,
     error::HandlerError,
     keys,
@@ -126,11 +127,13 @@ async fn handler(
             "submit_instruction" => call_handler(context, value, token, transaction::handle_submit_instruction).await,
             "submit" => call_handler(context, value, token, transaction::handle_submit).await,
             "submit_dry_run" => call_handler(context, value, token, transaction::handle_submit_dry_run).await,
+            "estimate_fee" => call_handler(context, value, token, transaction::handle_estimate_fee).await,
             "publish_template" => call_handler(context, value, token, transaction::handle_publish_template).await,
             "get" => call_handler(context, value, token, transaction::handle_get).await,
             "get_result" => call_handler(context, value, token, transaction::handle_get_result).await,
             "wait_result" => call_handler(context, value, token, transaction::handle_wait_result).await,
             "get_all" => call_handler(context, value, token, transaction::handle_get_all).await,
+            "get_balance_history" => call_handler(context, value, token, transaction::handle_get_balance_history).await,
             _ => Ok(value.method_not_found(&value.method)),
         },
         Some(("accounts", method)) => match method {
@@ -146,12 +149,23 @@ async fn handler(
             "confidential_transfer" => {
                 call_handler(context, value, token, accounts::handle_confidential_transfer).await
             },
+            "batch_transfer" => call_handler(context, value, token, accounts::handle_batch_transfer).await,
+            "confidential_batch_transfer" => {
+                call_handler(context, value, token, accounts::handle_confidential_batch_transfer).await
+            },
             "set_default" => call_handler(context, value, token, accounts::handle_set_default).await,
             "create_free_test_coins" => {
                 call_handler(context, value, token, accounts::handle_create_free_test_coins).await
             },
             _ => Ok(value.method_not_found(&value.method)),
         },
+        Some(("address_book", method)) => match method {
+            "add" => call_handler(context, value, token, address_book::handle_add).await,
+            "get" => call_handler(context, value, token, address_book::handle_get).await,
+            "list" => call_handler(context, value, token, address_book::handle_list).await,
+            "remove" => call_handler(context, value, token, address_book::handle_remove).await,
+            _ => Ok(value.method_not_found(&value.method)),
+        },
         Some(("confidential", method)) => match method {
             "create_transfer_proof" => {
                 call_handler(context, value, token, confidential::handle_create_transfer_proof).await
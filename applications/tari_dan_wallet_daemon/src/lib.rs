@@ -30,7 +30,7 @@ mod notify;
 mod services;
 mod webrtc;
 
-use std::{fs, panic, process};
+use std::{fs, panic, process, sync::Arc};
 
 use log::*;
 use tari_dan_common_types::optional::Optional;
@@ -39,6 +39,7 @@ use tari_dan_wallet_sdk::{
         config::{ConfigApi, ConfigKey},
         key_manager,
     },
+    signer::ExternalSigner,
     DanWalletSdk,
     WalletSdkConfig,
 };
@@ -59,6 +60,8 @@ use crate::{
 const LOG_TARGET: &str = "tari::dan::wallet_daemon";
 
 const DEFAULT_FEE: Amount = Amount::new(1500);
+/// Safety margin added to estimated fees when no max fee is provided
+const DEFAULT_FEE_MARGIN_PERCENT: u64 = 20;
 
 pub async fn run_tari_dan_wallet_daemon(
     config: ApplicationConfig,
@@ -140,6 +143,14 @@ pub fn initialize_wallet_sdk(
         config.dan_wallet_daemon.indexer_node_json_rpc_url.clone()
     };
     let indexer = IndexerJsonRpcNetworkInterface::new(indexer_jrpc_endpoint);
-    let wallet_sdk = DanWalletSdk::initialize(store, indexer, sdk_config)?;
+    let mut wallet_sdk = DanWalletSdk::initialize(store, indexer, sdk_config)?;
+    if let Some(transport) = config.dan_wallet_daemon.external_signer.clone() {
+        info!(target: LOG_TARGET, "Using external transaction signer {:?}", transport);
+        let mut signer = ExternalSigner::new(transport);
+        if let Some(timeout) = config.dan_wallet_daemon.external_signer_timeout {
+            signer = signer.with_timeout(timeout);
+        }
+        wallet_sdk = wallet_sdk.with_signer(Arc::new(signer));
+    }
     Ok(wallet_sdk)
 }
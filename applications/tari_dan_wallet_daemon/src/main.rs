@@ -26,7 +26,6 @@ use tari_common::initialize_logging;
 use tari_crypto::{keys::PublicKey, ristretto::RistrettoPublicKey};
 use tari_dan_app_utilities::configuration::load_configuration;
 use tari_dan_wallet_daemon::{cli::Cli, config::ApplicationConfig, initialize_wallet_sdk, run_tari_dan_wallet_daemon};
-use tari_dan_wallet_sdk::apis::key_manager;
 use tari_shutdown::Shutdown;
 
 #[tokio::main]
@@ -47,11 +46,9 @@ async fn main() -> Result<(), anyhow::Error> {
 
     if let Some(index) = cli.derive_secret {
         let sdk = initialize_wallet_sdk(&config)?;
-        let secret = sdk
-            .key_manager_api()
-            .derive_key(key_manager::TRANSACTION_BRANCH, index)?;
-        println!("Secret: {}", secret.key.reveal());
-        println!("Public key: {}", RistrettoPublicKey::from_secret_key(&secret.key));
+        let secret = sdk.signer().secret_key(index)?;
+        println!("Secret: {}", secret.reveal());
+        println!("Public key: {}", RistrettoPublicKey::from_secret_key(&secret));
         return Ok(());
     }
 
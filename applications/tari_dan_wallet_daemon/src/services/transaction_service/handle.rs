@@ -22,6 +22,7 @@ pub(super) enum TransactionServiceRequest {
     SubmitDryRunTransaction {
         transaction: Transaction,
         required_substates: Vec<SubstateRequirement>,
+        trace: bool,
         reply: Reply<Result<ExecuteResult, TransactionServiceError>>,
     },
 }
@@ -61,12 +62,24 @@ impl TransactionServiceHandle {
         &self,
         transaction: Transaction,
         required_substates: Vec<SubstateRequirement>,
+    ) -> Result<ExecuteResult, TransactionServiceError> {
+        self.submit_dry_run_transaction_with_trace(transaction, required_substates, false)
+            .await
+    }
+
+    /// Submits a dry run transaction. If `trace` is true, the result includes an execution trace.
+    pub async fn submit_dry_run_transaction_with_trace(
+        &self,
+        transaction: Transaction,
+        required_substates: Vec<SubstateRequirement>,
+        trace: bool,
     ) -> Result<ExecuteResult, TransactionServiceError> {
         let (reply_tx, reply_rx) = oneshot::channel();
         self.sender
             .send(TransactionServiceRequest::SubmitDryRunTransaction {
                 transaction,
                 required_substates,
+                trace,
                 reply: reply_tx,
             })
             .await
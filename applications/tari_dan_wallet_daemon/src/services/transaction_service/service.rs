@@ -6,7 +6,7 @@ use std::{sync::Arc, time::Duration};
 use log::*;
 use tari_dan_common_types::{optional::IsNotFoundError, SubstateRequirement};
 use tari_dan_wallet_sdk::{
-    models::{NewAccountInfo, TransactionStatus},
+    models::{NewAccountInfo, TransactionFilter, TransactionStatus},
     network::WalletNetworkInterface,
     storage::WalletStore,
     DanWalletSdk,
@@ -119,15 +119,16 @@ where
             TransactionServiceRequest::SubmitDryRunTransaction {
                 transaction,
                 required_substates,
+                trace,
                 reply,
             } => {
                 let transaction_id = *transaction.id();
                 let transaction_api = self.wallet_sdk.transaction_api();
                 match transaction_api
-                    .submit_dry_run_transaction(transaction, required_substates)
+                    .submit_dry_run_transaction(transaction, required_substates, trace)
                     .await
                 {
-                    Ok(finalized_transaction) => {
+                    Ok((finalized_transaction, trace)) => {
                         // Unlock all proofs related to the transaction
                         transaction_api.release_all_outputs_for_transaction(transaction_id)?;
 
@@ -140,6 +141,7 @@ where
                             .send(finalize.map(|finalize| ExecuteResult {
                                 finalize,
                                 execution_time: finalized_transaction.execution_time.unwrap_or_default(),
+                                trace,
                             }))
                             .map_err(|_| TransactionServiceError::ServiceShutdown)?;
                     },
@@ -205,7 +207,11 @@ where
         notify: &Notify<WalletEvent>,
     ) -> Result<(), TransactionServiceError> {
         let transaction_api = wallet_sdk.transaction_api();
-        let new_transactions = transaction_api.fetch_all(Some(TransactionStatus::New), None)?;
+        let new_transactions = transaction_api.fetch_all(
+            &TransactionFilter::default().with_status(TransactionStatus::New),
+            0,
+            None,
+        )?;
         let log_level = if new_transactions.is_empty() {
             Level::Debug
         } else {
@@ -238,7 +244,11 @@ where
         notify: &Notify<WalletEvent>,
     ) -> Result<(), TransactionServiceError> {
         let transaction_api = wallet_sdk.transaction_api();
-        let pending_transactions = transaction_api.fetch_all(Some(TransactionStatus::Pending), None)?;
+        let pending_transactions = transaction_api.fetch_all(
+            &TransactionFilter::default().with_status(TransactionStatus::Pending),
+            0,
+            None,
+        )?;
         let log_level = if pending_transactions.is_empty() {
             Level::Debug
         } else {
@@ -0,0 +1,250 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use std::str::FromStr;
+
+use anyhow::anyhow;
+use tari_common_types::types::{FixedHash, PublicKey};
+use tari_crypto::tari_utilities::hex::Hex;
+use tari_dan_common_types::{Epoch, NodeHeight, ShardGroup};
+use tari_dan_storage::consensus_models::{BlockId, Decision};
+use tari_indexer_client::types::{BlockTransaction, EpochStats, GetBlockResponse, IndexedBlock};
+use tari_transaction::TransactionId;
+
+use crate::storage::{models::block, SqlSubstateStore, SubstateStore, SubstateStoreReadTransaction};
+
+/// Read access to the blocks persisted by the event scanner
+pub struct BlockManager<TStore = SqlSubstateStore> {
+    substate_store: TStore,
+}
+
+impl<TStore: SubstateStore> BlockManager<TStore> {
+    pub fn new(substate_store: TStore) -> Self {
+        Self { substate_store }
+    }
+
+    pub async fn list_blocks(
+        &self,
+        epoch: Option<Epoch>,
+        shard_group: Option<ShardGroup>,
+        offset: u64,
+        limit: u64,
+    ) -> Result<Vec<IndexedBlock>, anyhow::Error> {
+        let mut tx = self.substate_store.create_read_tx()?;
+        let rows = tx.list_blocks(epoch, shard_group, offset, limit)?;
+        rows.iter().map(Self::to_indexed_block).collect()
+    }
+
+    pub async fn get_block(&self, block_id: &BlockId) -> Result<Option<GetBlockResponse>, anyhow::Error> {
+        let mut tx = self.substate_store.create_read_tx()?;
+        let Some(row) = tx.get_block(block_id)? else {
+            return Ok(None);
+        };
+
+        Ok(Some(GetBlockResponse {
+            block: Self::to_indexed_block(&row)?,
+            header: serde_json::from_str(&row.header)?,
+            justify: serde_json::from_str(&row.justify)?,
+            commands: serde_json::from_str(&row.commands)?,
+        }))
+    }
+
+    /// Returns `None` if the block has not been scanned
+    pub async fn get_transactions_in_block(
+        &self,
+        block_id: &BlockId,
+    ) -> Result<Option<Vec<BlockTransaction>>, anyhow::Error> {
+        let mut tx = self.substate_store.create_read_tx()?;
+        if tx.get_block(block_id)?.is_none() {
+            return Ok(None);
+        }
+        let rows = tx.get_block_transactions(block_id)?;
+        let transactions = rows
+            .into_iter()
+            .map(|row| {
+                Ok(BlockTransaction {
+                    transaction_id: TransactionId::from_hex(&row.transaction_id)?,
+                    command: row.command,
+                    decision: Decision::from_str(&row.decision)?,
+                    transaction_fee: row.transaction_fee as u64,
+                })
+            })
+            .collect::<Result<_, anyhow::Error>>()?;
+        Ok(Some(transactions))
+    }
+
+    pub async fn get_epoch_stats(&self, epoch: Epoch) -> Result<EpochStats, anyhow::Error> {
+        let mut tx = self.substate_store.create_read_tx()?;
+        let row = tx.get_epoch_stats(epoch)?;
+        Ok(EpochStats {
+            epoch,
+            num_blocks: row.num_blocks as u64,
+            num_dummy_blocks: row.num_dummy_blocks as u64,
+            num_shard_groups: row.num_shard_groups as u64,
+            num_commands: row.num_commands as u64,
+            num_transactions: row.num_transactions as u64,
+            total_leader_fees: row.total_leader_fees as u64,
+            first_block_timestamp: row.first_block_timestamp.map(|t| t as u64),
+            last_block_timestamp: row.last_block_timestamp.map(|t| t as u64),
+        })
+    }
+
+    fn to_indexed_block(row: &block::Block) -> Result<IndexedBlock, anyhow::Error> {
+        Ok(IndexedBlock {
+            block_id: parse_block_id(&row.block_id)?,
+            parent_id: parse_block_id(&row.parent_id)?,
+            epoch: Epoch(row.epoch as u64),
+            shard_group: ShardGroup::decode_from_u32(row.shard_group as u32)
+                .ok_or_else(|| anyhow!("Invalid shard group {}", row.shard_group))?,
+            height: NodeHeight(row.height as u64),
+            proposed_by: PublicKey::from_hex(&row.proposed_by)?,
+            timestamp: row.timestamp as u64,
+            is_dummy: row.is_dummy,
+            command_count: row.command_count as u64,
+            transaction_count: row.transaction_count as u64,
+            total_leader_fee: row.total_leader_fee as u64,
+        })
+    }
+}
+
+pub fn parse_block_id(s: &str) -> Result<BlockId, anyhow::Error> {
+    Ok(BlockId::from(FixedHash::from_hex(s)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use tari_common::configuration::Network;
+    use tari_dan_storage::consensus_models::Block;
+    use tari_indexer_client::types::{GetBlockResponse, ListBlocksResponse};
+
+    use super::*;
+    use crate::storage::{
+        models::block::{NewBlock, NewBlockTransaction},
+        SubstateStoreWriteTransaction,
+    };
+
+    fn create_store() -> (SqlSubstateStore, tempfile::TempDir) {
+        let dir = tempfile::tempdir().unwrap();
+        let store = SqlSubstateStore::try_create_sqlite(dir.path().join("indexer.sqlite")).unwrap();
+        store.run_migrations().unwrap();
+        (store, dir)
+    }
+
+    fn genesis_block(epoch: u64) -> Block {
+        Block::genesis(
+            Network::LocalNet,
+            Epoch(epoch),
+            ShardGroup::new(0, 1),
+            FixedHash::zero(),
+            None,
+        )
+    }
+
+    fn block_transaction(block: &Block, n: u8, decision: &str) -> NewBlockTransaction {
+        NewBlockTransaction {
+            block_id: block.id().to_string(),
+            transaction_id: TransactionId::from([n; 32]).to_string(),
+            command: "LocalOnly".to_string(),
+            decision: decision.to_string(),
+            transaction_fee: i64::from(n),
+        }
+    }
+
+    fn save_block(store: &SqlSubstateStore, block: &Block, transactions: Vec<NewBlockTransaction>) {
+        store
+            .with_write_tx(|tx| tx.save_block(NewBlock::try_from(block).unwrap(), transactions))
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn it_returns_the_block_with_its_header_and_justify() {
+        let (store, _dir) = create_store();
+        let block = genesis_block(1);
+        save_block(&store, &block, vec![]);
+        let manager = BlockManager::new(store);
+
+        let response = manager.get_block(block.id()).await.unwrap().unwrap();
+        assert_eq!(response.block.block_id, *block.id());
+        assert_eq!(response.block.parent_id, *block.parent());
+        assert_eq!(response.block.epoch, Epoch(1));
+        assert_eq!(response.block.shard_group, ShardGroup::new(0, 1));
+        assert_eq!(response.block.proposed_by, *block.proposed_by());
+        assert_eq!(response.header.id(), block.id());
+        assert_eq!(response.justify.id(), block.justify().id());
+        assert!(response.commands.is_empty());
+
+        // The JSON-RPC response survives a round trip through the client types
+        let json = serde_json::to_value(&response).unwrap();
+        let response: GetBlockResponse = serde_json::from_value(json).unwrap();
+        assert_eq!(response.block.block_id, *block.id());
+
+        assert!(manager.get_block(&BlockId::from([9; 32])).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn it_lists_blocks_newest_first() {
+        let (store, _dir) = create_store();
+        for epoch in 1..=3 {
+            save_block(&store, &genesis_block(epoch), vec![]);
+        }
+        let manager = BlockManager::new(store);
+
+        let blocks = manager.list_blocks(None, None, 0, 10).await.unwrap();
+        let epochs = blocks.iter().map(|b| b.epoch.as_u64()).collect::<Vec<_>>();
+        assert_eq!(epochs, vec![3, 2, 1]);
+
+        let blocks = manager
+            .list_blocks(Some(Epoch(2)), Some(ShardGroup::new(0, 1)), 0, 10)
+            .await
+            .unwrap();
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].block_id, *genesis_block(2).id());
+
+        let json = serde_json::to_value(ListBlocksResponse { blocks }).unwrap();
+        let response: ListBlocksResponse = serde_json::from_value(json).unwrap();
+        assert_eq!(response.blocks[0].epoch, Epoch(2));
+    }
+
+    #[tokio::test]
+    async fn it_returns_the_transactions_of_scanned_blocks_only() {
+        let (store, _dir) = create_store();
+        let block = genesis_block(1);
+        save_block(&store, &block, vec![
+            block_transaction(&block, 1, "Commit"),
+            block_transaction(&block, 2, "Abort"),
+        ]);
+        let manager = BlockManager::new(store);
+
+        let transactions = manager.get_transactions_in_block(block.id()).await.unwrap().unwrap();
+        assert_eq!(transactions.len(), 2);
+        assert_eq!(transactions[0].transaction_id, TransactionId::from([1; 32]));
+        assert_eq!(transactions[0].decision, Decision::Commit);
+        assert!(transactions[1].decision.is_abort());
+        assert_eq!(transactions[1].transaction_fee, 2);
+
+        assert!(manager
+            .get_transactions_in_block(&BlockId::from([9; 32]))
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn it_returns_the_stats_of_the_epoch() {
+        let (store, _dir) = create_store();
+        let block = genesis_block(1);
+        save_block(&store, &block, vec![]);
+        save_block(&store, &genesis_block(2), vec![]);
+        let manager = BlockManager::new(store);
+
+        let stats = manager.get_epoch_stats(Epoch(1)).await.unwrap();
+        assert_eq!(stats.epoch, Epoch(1));
+        assert_eq!(stats.num_blocks, 1);
+        assert_eq!(stats.num_shard_groups, 1);
+        assert_eq!(stats.first_block_timestamp, Some(block.timestamp()));
+
+        let stats = manager.get_epoch_stats(Epoch(5)).await.unwrap();
+        assert_eq!(stats.num_blocks, 0);
+        assert_eq!(stats.first_block_timestamp, None);
+    }
+}
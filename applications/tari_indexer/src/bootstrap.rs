@@ -53,7 +53,7 @@ use tari_shutdown::ShutdownSignal;
 use tari_state_store_sqlite::SqliteStateStore;
 use tari_validator_node_rpc::client::TariValidatorNodeRpcClientFactory;
 
-use crate::{substate_storage_sqlite::sqlite_substate_store_factory::SqliteSubstateStore, ApplicationConfig};
+use crate::{config::DatabaseConfig, storage::SqlSubstateStore, ApplicationConfig};
 
 const _LOG_TARGET: &str = "tari_indexer::bootstrap";
 
@@ -120,7 +120,21 @@ pub async fn spawn_services(
     )?;
 
     // Connect to substate db
-    let substate_store = SqliteSubstateStore::try_create(config.indexer.state_db_path())?;
+    let substate_store = match &config.indexer.database {
+        DatabaseConfig::Sqlite => SqlSubstateStore::try_create_sqlite(config.indexer.state_db_path())?,
+        #[cfg(feature = "postgres")]
+        DatabaseConfig::Postgres { url } => SqlSubstateStore::try_create_postgres(url)?,
+        #[cfg(not(feature = "postgres"))]
+        DatabaseConfig::Postgres { .. } => {
+            return Err(anyhow::anyhow!(
+                "The PostgreSQL database backend requires the indexer to be built with the \"postgres\" feature"
+            ));
+        },
+    };
+    if !config.indexer.read_only {
+        substate_store.run_migrations()?;
+    }
+    let substate_store = substate_store.with_read_only(config.indexer.read_only);
 
     // Epoch manager
     let validator_node_client_factory = TariValidatorNodeRpcClientFactory::new(networking.clone());
@@ -184,7 +198,7 @@ pub struct Services {
     pub networking: NetworkingHandle<TariMessagingSpec>,
     pub epoch_manager: EpochManagerHandle<PeerAddress>,
     pub validator_node_client_factory: TariValidatorNodeRpcClientFactory,
-    pub substate_store: SqliteSubstateStore,
+    pub substate_store: SqlSubstateStore,
     pub template_manager: TemplateManager<PeerAddress>,
 }
 
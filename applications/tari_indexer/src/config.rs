@@ -102,6 +102,13 @@ pub struct IndexerConfig {
     pub burnt_utxo_sidechain_id: Option<RistrettoPublicKey>,
     /// The event filtering configuration
     pub event_filters: Vec<EventFilterConfig>,
+    /// Webhooks that matching events are delivered to
+    pub webhooks: Vec<WebhookConfig>,
+    /// The database that indexed data is stored in
+    pub database: DatabaseConfig,
+    /// If true, the indexer only serves API requests from a database that is populated by another indexer. No
+    /// migrations are run and the network is not scanned for events.
+    pub read_only: bool,
 }
 
 impl IndexerConfig {
@@ -142,6 +149,9 @@ impl Default for IndexerConfig {
             templates_sidechain_id: None,
             burnt_utxo_sidechain_id: None,
             event_filters: vec![],
+            webhooks: vec![],
+            database: DatabaseConfig::default(),
+            read_only: false,
         }
     }
 }
@@ -152,6 +162,16 @@ impl SubConfigPath for IndexerConfig {
     }
 }
 
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(tag = "backend", rename_all = "snake_case")]
+pub enum DatabaseConfig {
+    /// A SQLite database in the data directory
+    #[default]
+    Sqlite,
+    /// A PostgreSQL database, which can be shared by several indexers. Requires the `postgres` feature.
+    Postgres { url: String },
+}
+
 #[derive(Default, Debug, Serialize, Deserialize, Clone)]
 pub struct EventFilterConfig {
     pub topic: Option<String>,
@@ -159,3 +179,32 @@ pub struct EventFilterConfig {
     pub substate_id: Option<String>,
     pub template_address: Option<String>,
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WebhookConfig {
+    /// Unique name of the webhook, used to persist the delivery cursor across restarts
+    pub name: String,
+    /// The URL that matching events are POSTed to
+    pub url: Url,
+    /// If set, each request is signed with HMAC-SHA256 using this secret. The unix timestamp of the request is sent in
+    /// the `X-Tari-Timestamp` header and the hex signature of `<timestamp>.<body>` in the `X-Tari-Signature` header.
+    pub secret: Option<String>,
+    /// An event is delivered if it matches ANY of the filters. At least one filter is required, a filter without any
+    /// fields matches every event.
+    #[serde(default)]
+    pub filters: Vec<EventFilterConfig>,
+    /// How many times a failed delivery is retried before waiting for the next polling round
+    #[serde(default = "default_webhook_max_retries")]
+    pub max_retries: u32,
+    /// The delay before the first retry, which doubles after each failed attempt
+    #[serde(default = "default_webhook_initial_backoff", with = "serializers::seconds")]
+    pub initial_backoff: Duration,
+}
+
+fn default_webhook_max_retries() -> u32 {
+    5
+}
+
+fn default_webhook_initial_backoff() -> Duration {
+    Duration::from_secs(1)
+}
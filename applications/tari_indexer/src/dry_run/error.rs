@@ -23,7 +23,7 @@
 use tari_dan_app_utilities::transaction_executor::TransactionProcessorError;
 use tari_dan_common_types::{Epoch, SubstateAddress};
 use tari_dan_engine::state_store::StateStoreError;
-use tari_engine_types::substate::SubstateId;
+use tari_engine_types::{indexed_value::IndexedValueError, substate::SubstateId};
 use tari_epoch_manager::EpochManagerError;
 use tari_indexer_lib::{error::IndexerError, transaction_autofiller::TransactionAutofillerError};
 use tari_rpc_framework::RpcStatus;
@@ -35,6 +35,8 @@ pub enum DryRunTransactionProcessorError {
     TransactionAutofillerError(#[from] TransactionAutofillerError),
     #[error("Substate {id} v{version} is DOWN")]
     SubstateDowned { id: SubstateId, version: u32 },
+    #[error("Substate {id} does not exist")]
+    SubstateDoesNotExist { id: SubstateId },
     #[error("EpochManager error: {0}")]
     EpochManager(#[from] EpochManagerError),
     #[error("Rpc error: {0}")]
@@ -56,4 +58,6 @@ pub enum DryRunTransactionProcessorError {
     IndexerError(#[from] IndexerError),
     #[error("StateStore error: {0}")]
     StateStoreError(#[from] StateStoreError),
+    #[error("Indexed value error: {0}")]
+    IndexedValueError(#[from] IndexedValueError),
 }
@@ -20,7 +20,10 @@
 //  WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 //  USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 use log::info;
 use tari_dan_app_utilities::{
@@ -32,15 +35,18 @@ use tari_dan_engine::{fees::FeeTable, state_store::new_memory_store, transaction
 use tari_engine_types::{
     commit_result::ExecuteResult,
     instruction::Instruction,
+    instruction_result::InstructionResult,
     substate::{Substate, SubstateId},
     virtual_substate::{VirtualSubstate, VirtualSubstateId, VirtualSubstates},
 };
 use tari_epoch_manager::{base_layer::EpochManagerHandle, EpochManagerReader};
 use tari_indexer_lib::{
     substate_cache::SubstateCache,
+    substate_decoder::find_related_substates,
     substate_scanner::SubstateScanner,
     transaction_autofiller::TransactionAutofiller,
 };
+use tari_template_lib::{args::Arg, models::ComponentAddress};
 use tari_transaction::Transaction;
 use tari_validator_node_rpc::client::{
     SubstateResult,
@@ -53,6 +59,9 @@ use tokio::task;
 use crate::dry_run::error::DryRunTransactionProcessorError;
 
 const LOG_TARGET: &str = "tari::indexer::dry_run_transaction_processor";
+/// The number of levels of substates referenced by a component (e.g. vaults and their resources) that are fetched for
+/// a view call
+const MAX_VIEW_SUBSTATE_DEPTH: usize = 3;
 
 pub struct DryRunTransactionProcessor<TSubstateCache> {
     config: TransactionProcessorConfig,
@@ -89,10 +98,13 @@ where TSubstateCache: SubstateCache + 'static
         }
     }
 
+    /// Executes the transaction against the latest known state without submitting it. If `trace` is true, the result
+    /// includes an execution trace.
     pub async fn process_transaction(
         &self,
         transaction: Transaction,
         substate_requirements: Vec<SubstateRequirement>,
+        trace: bool,
     ) -> Result<ExecuteResult, DryRunTransactionProcessorError> {
         info!(target: LOG_TARGET, "process_transaction: {}", transaction.hash());
 
@@ -106,7 +118,7 @@ where TSubstateCache: SubstateCache + 'static
         let epoch = self.epoch_manager.current_epoch().await?;
         found_substates.extend(self.fetch_input_substates(&transaction, epoch).await?);
 
-        let payload_processor = self.build_payload_processor(&transaction);
+        let payload_processor = self.build_payload_processor(&transaction).with_tracing(trace);
 
         let virtual_substates = self.get_virtual_substates(&transaction, epoch).await?;
 
@@ -121,6 +133,72 @@ where TSubstateCache: SubstateCache + 'static
         Ok(exec_output.result)
     }
 
+    /// Calls a non-mutating method of a component against the latest committed state without a transaction
+    pub async fn call_view(
+        &self,
+        component_address: ComponentAddress,
+        method: String,
+        args: Vec<Arg>,
+    ) -> Result<InstructionResult, DryRunTransactionProcessorError> {
+        info!(target: LOG_TARGET, "call_view: {}.{}", component_address, method);
+
+        let epoch = self.epoch_manager.current_epoch().await?;
+
+        // Fetch the component and the substates it references
+        let mut substates = HashMap::new();
+        let mut to_fetch = HashSet::from([SubstateId::Component(component_address)]);
+        for _ in 0..MAX_VIEW_SUBSTATE_DEPTH {
+            if to_fetch.is_empty() {
+                break;
+            }
+            let mut related = HashSet::new();
+            for id in to_fetch {
+                let substate = self.fetch_latest_substate(&id).await?;
+                related.extend(find_related_substates(&substate)?);
+                substates.insert(id, substate);
+            }
+            to_fetch = related.into_iter().filter(|id| !substates.contains_key(id)).collect();
+        }
+
+        let mut state_store = new_memory_store();
+        state_store.set_many(substates)?;
+
+        let mut virtual_substates = VirtualSubstates::new();
+        virtual_substates.insert(
+            VirtualSubstateId::CurrentEpoch,
+            VirtualSubstate::CurrentEpoch(epoch.as_u64()),
+        );
+
+        let payload_processor = TariDanTransactionProcessor::new(
+            self.config.clone(),
+            self.template_manager.clone(),
+            FeeTable::zero_rated(),
+        );
+        let result = task::block_in_place(|| {
+            payload_processor.call_view(
+                &component_address,
+                &method,
+                args,
+                state_store.into_read_only(),
+                virtual_substates,
+            )
+        })?;
+
+        Ok(result)
+    }
+
+    async fn fetch_latest_substate(&self, id: &SubstateId) -> Result<Substate, DryRunTransactionProcessorError> {
+        match self.substate_scanner.get_substate(id, None).await? {
+            SubstateResult::Up { substate, .. } => Ok(substate),
+            SubstateResult::Down { id, version, .. } => {
+                Err(DryRunTransactionProcessorError::SubstateDowned { id, version })
+            },
+            SubstateResult::DoesNotExist => {
+                Err(DryRunTransactionProcessorError::SubstateDoesNotExist { id: id.clone() })
+            },
+        }
+    }
+
     fn build_payload_processor(
         &self,
         transaction: &Transaction,
@@ -133,6 +211,9 @@ where TSubstateCache: SubstateCache + 'static
                 per_byte_storage_cost: 1,
                 per_event_cost: 1,
                 per_log_cost: 1,
+                per_hash_cost: 1,
+                per_hash_block_cost: 1,
+                per_signature_verification_cost: 10,
             }
         } else {
             FeeTable::zero_rated()
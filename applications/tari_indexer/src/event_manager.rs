@@ -20,26 +20,23 @@
 //  WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 //  USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use std::{collections::BTreeMap, str::FromStr, sync::Arc};
+use std::{str::FromStr, sync::Arc};
 
 use log::*;
-use tari_crypto::tari_utilities::message_format::MessageFormat;
 use tari_dan_app_utilities::substate_file_cache::SubstateFileCache;
 use tari_dan_common_types::PeerAddress;
 use tari_engine_types::{events::Event, substate::SubstateId};
 use tari_epoch_manager::base_layer::EpochManagerHandle;
 use tari_indexer_lib::substate_scanner::SubstateScanner;
-use tari_template_lib::{
-    models::{Metadata, TemplateAddress},
-    Hash,
-};
+use tari_template_lib::{models::TemplateAddress, Hash};
 use tari_transaction::TransactionId;
 use tari_validator_node_rpc::client::TariValidatorNodeRpcClientFactory;
 
-use crate::substate_storage_sqlite::{
-    models::events::NewEvent,
-    sqlite_substate_store_factory::{
-        SqliteSubstateStore,
+use crate::{
+    event_payload::{decode_event_payload, encode_event_payload},
+    storage::{
+        models::{events, events::NewEvent},
+        SqlSubstateStore,
         SubstateStore,
         SubstateStoreReadTransaction,
         SubstateStoreWriteTransaction,
@@ -48,15 +45,26 @@ use crate::substate_storage_sqlite::{
 
 const LOG_TARGET: &str = "tari::indexer::event_manager";
 
-pub struct EventManager {
-    substate_store: SqliteSubstateStore,
+/// A stored event with its position in the database and the block it was committed in
+#[derive(Debug, Clone)]
+pub struct SequencedEvent {
+    pub sequence: u64,
+    /// The block id and height are unknown for events stored before they were tracked
+    pub block_id: Option<String>,
+    pub block_height: Option<u64>,
+    pub timestamp: u64,
+    pub event: Event,
+}
+
+pub struct EventManager<TStore = SqlSubstateStore> {
+    substate_store: TStore,
     substate_scanner:
         Arc<SubstateScanner<EpochManagerHandle<PeerAddress>, TariValidatorNodeRpcClientFactory, SubstateFileCache>>,
 }
 
-impl EventManager {
+impl<TStore: SubstateStore> EventManager<TStore> {
     pub fn new(
-        substate_store: SqliteSubstateStore,
+        substate_store: TStore,
         substate_scanner: Arc<
             SubstateScanner<EpochManagerHandle<PeerAddress>, TariValidatorNodeRpcClientFactory, SubstateFileCache>,
         >,
@@ -73,19 +81,22 @@ impl EventManager {
         template_address: TemplateAddress,
         tx_hash: TransactionId,
         topic: String,
-        payload: &Metadata,
+        payload: &tari_bor::Value,
         version: u64,
         timestamp: u64,
     ) -> Result<(), anyhow::Error> {
+        let payload = encode_event_payload(payload)?;
         self.substate_store.with_write_tx(|tx| {
             let new_event = NewEvent {
                 substate_id: Some(substate_id.to_string()),
                 template_address: template_address.to_string(),
                 tx_hash: tx_hash.to_string(),
                 topic,
-                payload: payload.to_json().expect("Failed to convert to JSON"),
+                payload,
                 version: version as i32,
                 timestamp: timestamp as i64,
+                block_id: None,
+                block_height: None,
             };
             tx.save_event(new_event)
         })?;
@@ -208,16 +219,41 @@ impl EventManager {
             .substate_store
             .with_read_tx(|tx| tx.get_events(substate_id, topic, offset, limit))?;
 
-        let mut events = vec![];
-        for row in rows {
-            let substate_id = row.substate_id.map(|str| SubstateId::from_str(&str)).transpose()?;
-            let template_address = Hash::from_hex(&row.template_address)?;
-            let tx_hash = Hash::from_hex(&row.tx_hash)?;
-            let topic = row.topic;
-            let payload = Metadata::from(serde_json::from_str::<BTreeMap<String, String>>(row.payload.as_str())?);
-            events.push(Event::new(substate_id, template_address, tx_hash, topic, payload));
-        }
+        rows.into_iter().map(Self::to_engine_event).collect()
+    }
 
-        Ok(events)
+    /// Returns the stored events after the given sequence number in the order they were stored, so consumers can resume
+    /// reading from the last sequence number they processed
+    pub async fn get_events_after_sequence(
+        &self,
+        after_sequence: u64,
+        topic: Option<String>,
+        substate_id: Option<SubstateId>,
+        limit: u32,
+    ) -> Result<Vec<SequencedEvent>, anyhow::Error> {
+        let rows = self
+            .substate_store
+            .with_read_tx(|tx| tx.get_events_after_sequence(after_sequence, substate_id, topic, limit))?;
+
+        rows.into_iter()
+            .map(|row| {
+                Ok(SequencedEvent {
+                    sequence: row.sequence as u64,
+                    block_id: row.block_id.clone(),
+                    block_height: row.block_height.map(|h| h as u64),
+                    timestamp: row.timestamp as u64,
+                    event: Self::to_engine_event(row)?,
+                })
+            })
+            .collect()
+    }
+
+    fn to_engine_event(row: events::Event) -> Result<Event, anyhow::Error> {
+        let substate_id = row.substate_id.map(|str| SubstateId::from_str(&str)).transpose()?;
+        let template_address = Hash::from_hex(&row.template_address)?;
+        let tx_hash = Hash::from_hex(&row.tx_hash)?;
+        let topic = row.topic;
+        let payload = decode_event_payload(&row.payload)?;
+        Ok(Event::new(substate_id, template_address, tx_hash, topic, payload))
     }
 }
@@ -0,0 +1,26 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use std::collections::BTreeMap;
+
+use tari_bor::json_encoding::{CborValueJsonDeserializeWrapper, CborValueJsonSerializeWrapper};
+
+/// Encodes a CBOR event payload as the JSON stored in the database
+pub fn encode_event_payload(payload: &tari_bor::Value) -> Result<String, anyhow::Error> {
+    Ok(serde_json::to_string(&CborValueJsonSerializeWrapper(payload))?)
+}
+
+/// Decodes a payload stored by `encode_event_payload`. Events stored before payloads were CBOR-encoded have a JSON
+/// object of strings as their payload, which is decoded into a CBOR map.
+pub fn decode_event_payload(json: &str) -> Result<tari_bor::Value, anyhow::Error> {
+    if let Ok(wrapper) = serde_json::from_str::<CborValueJsonDeserializeWrapper>(json) {
+        return Ok(wrapper.into_inner());
+    }
+    let payload = serde_json::from_str::<BTreeMap<String, String>>(json)?;
+    Ok(tari_bor::to_value(&payload)?)
+}
+
+/// Converts a CBOR event payload into the JSON returned by the APIs
+pub fn event_payload_to_json(payload: &tari_bor::Value) -> Result<serde_json::Value, anyhow::Error> {
+    Ok(serde_json::to_value(CborValueJsonSerializeWrapper(payload))?)
+}
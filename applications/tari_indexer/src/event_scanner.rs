@@ -20,40 +20,49 @@
 //  WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 //  USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use std::{collections::HashMap, str::FromStr};
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
 
 use anyhow::anyhow;
 use futures::StreamExt;
 use log::*;
 use tari_bor::decode;
-use tari_crypto::tari_utilities::message_format::MessageFormat;
-use tari_dan_common_types::{committee::Committee, Epoch, PeerAddress, ShardGroup};
+use tari_dan_common_types::{committee::Committee, Epoch, NodeHeight, PeerAddress, ShardGroup};
 use tari_dan_p2p::proto::rpc::{GetTransactionResultRequest, PayloadResultStatus, SyncBlocksRequest};
 use tari_dan_storage::consensus_models::{Block, BlockId, Decision, TransactionRecord};
 use tari_engine_types::{
     commit_result::{ExecuteResult, TransactionResult},
     events::Event,
+    indexed_value::IndexedWellKnownTypes,
     substate::{Substate, SubstateId, SubstateValue},
 };
 use tari_epoch_manager::{base_layer::EpochManagerHandle, EpochManagerReader};
 use tari_template_lib::models::{EntityId, TemplateAddress};
 use tari_transaction::{Transaction, TransactionId};
 use tari_validator_node_rpc::client::{TariValidatorNodeRpcClientFactory, ValidatorNodeClientFactory};
+use tokio::sync::broadcast;
 
 use crate::{
     config::EventFilterConfig,
     event_data::EventData,
-    substate_storage_sqlite::{
+    event_payload::encode_event_payload,
+    non_fungible_metadata::flatten_metadata,
+    storage::{
         models::{
+            account_transaction::{BalanceChangeKind, NewAccountBalanceChange, NewAccountTransaction},
+            block::{NewBlock, NewBlockTransaction},
             events::{NewEvent, NewScannedBlockId},
+            non_fungible_metadata::NewNonFungibleMetadataField,
+            resource_holder::{NewResourceHolder, ResourceHolder},
+            resource_supply::{NewResourceSupply, NewResourceVolume},
             substate::NewSubstate,
         },
-        sqlite_substate_store_factory::{
-            SqliteSubstateStore,
-            SubstateStore,
-            SubstateStoreReadTransaction,
-            SubstateStoreWriteTransaction,
-        },
+        SqlSubstateStore,
+        SubstateStore,
+        SubstateStoreReadTransaction,
+        SubstateStoreWriteTransaction,
     },
 };
 
@@ -67,6 +76,41 @@ pub struct EventFilter {
     pub template_address: Option<TemplateAddress>,
 }
 
+impl EventFilter {
+    pub fn matches(&self, event: &Event) -> bool {
+        let matches_topic = self.topic.as_ref().map_or(true, |t| *t == event.topic());
+        let matches_template = self
+            .template_address
+            .as_ref()
+            .map_or(true, |t| *t == event.template_address());
+
+        let matches_substate_id = match &self.substate_id {
+            Some(substate_id) => event.substate_id().map(|s| s == *substate_id).unwrap_or(false),
+            None => true,
+        };
+
+        let matches_entity_id = match &self.entity_id {
+            Some(entity_id) => event
+                .substate_id()
+                .map(|s| Self::entity_id_matches(&s, entity_id))
+                .unwrap_or(false),
+            None => true,
+        };
+
+        matches_topic && matches_template && matches_substate_id && matches_entity_id
+    }
+
+    fn entity_id_matches(substate_id: &SubstateId, entity_id: &EntityId) -> bool {
+        match substate_id {
+            SubstateId::Component(c) => c.entity_id() == *entity_id,
+            SubstateId::Resource(r) => r.as_entity_id() == *entity_id,
+            SubstateId::Vault(v) => v.entity_id() == *entity_id,
+            // TODO: should all types of substate addresses expose the entity id?
+            _ => false,
+        }
+    }
+}
+
 impl TryFrom<EventFilterConfig> for EventFilter {
     type Error = anyhow::Error;
 
@@ -91,27 +135,40 @@ impl TryFrom<EventFilterConfig> for EventFilter {
 struct TransactionMetadata {
     pub transaction_id: TransactionId,
     pub timestamp: u64,
+    /// The block in which the transaction was committed
+    pub block_id: BlockId,
+    pub block_height: NodeHeight,
 }
 
-pub struct EventScanner {
+#[derive(Debug, Default)]
+struct VaultChanges {
+    holders: Vec<NewResourceHolder>,
+    /// Components updated in the transaction that own vaults
+    owners: HashSet<String>,
+}
+
+pub struct EventScanner<TStore = SqlSubstateStore> {
     epoch_manager: EpochManagerHandle<PeerAddress>,
     client_factory: TariValidatorNodeRpcClientFactory,
-    substate_store: SqliteSubstateStore,
+    substate_store: TStore,
     event_filters: Vec<EventFilter>,
+    event_publisher: broadcast::Sender<Event>,
 }
 
-impl EventScanner {
+impl<TStore: SubstateStore> EventScanner<TStore> {
     pub fn new(
         epoch_manager: EpochManagerHandle<PeerAddress>,
         client_factory: TariValidatorNodeRpcClientFactory,
-        substate_store: SqliteSubstateStore,
+        substate_store: TStore,
         event_filters: Vec<EventFilter>,
+        event_publisher: broadcast::Sender<Event>,
     ) -> Self {
         Self {
             epoch_manager,
             client_factory,
             substate_store,
             event_filters,
+            event_publisher,
         }
     }
 
@@ -185,12 +242,34 @@ impl EventScanner {
 
             for transaction in transactions {
                 // fetch all the events in the transaction
-                let events = self.get_events_for_transaction(transaction.transaction_id).await?;
+                let (events, vault_changes, non_fungibles, supplies) =
+                    match self.get_transaction_result(transaction.transaction_id).await? {
+                        Some(result) => {
+                            let vault_changes = Self::extract_vault_changes(&result, transaction.timestamp);
+                            let non_fungibles = Self::extract_non_fungible_metadata(&result);
+                            let supplies = Self::extract_resource_supplies(&result, &transaction);
+                            (
+                                self.extract_events_from_transaction_result(result),
+                                vault_changes,
+                                non_fungibles,
+                                supplies,
+                            )
+                        },
+                        None => (vec![], VaultChanges::default(), vec![], vec![]),
+                    };
                 event_count += events.len();
 
+                // vault balances, account history and resource analytics are indexed regardless of the event filters
+                let withdrawals = self.store_vault_changes_in_db(&transaction, vault_changes)?;
+                self.store_resource_volumes_in_db(&transaction, supplies, withdrawals)?;
+                self.store_non_fungible_metadata_in_db(non_fungibles)?;
+
                 // only keep the events specified by the indexer filter
-                let filtered_events: Vec<EventData> =
-                    events.into_iter().filter(|ev| self.should_persist_event(ev)).collect();
+                let filtered_events: Vec<EventData> = events
+                    .iter()
+                    .filter(|ev| self.should_persist_event(ev))
+                    .cloned()
+                    .collect();
                 info!(
                     target: LOG_TARGET,
                     "Filtered events in epoch {}: {}",
@@ -198,6 +277,12 @@ impl EventScanner {
                     filtered_events.len()
                 );
                 self.store_events_in_db(&filtered_events, transaction).await?;
+
+                // Subscribers apply their own filters, so publish every scanned event
+                for data in events {
+                    // Ignore the error if there are no subscribers
+                    let _ignore = self.event_publisher.send(data.event);
+                }
             }
         }
 
@@ -211,50 +296,9 @@ impl EventScanner {
     }
 
     fn should_persist_event(&self, event_data: &EventData) -> bool {
-        for filter in &self.event_filters {
-            if Self::event_matches_filter(filter, &event_data.event) {
-                return true;
-            }
-        }
-
-        false
-    }
-
-    fn event_matches_filter(filter: &EventFilter, event: &Event) -> bool {
-        let matches_topic = filter.topic.as_ref().map_or(true, |t| *t == event.topic());
-        let matches_template = filter
-            .template_address
-            .as_ref()
-            .map_or(true, |t| *t == event.template_address());
-
-        let matches_substate_id = match &filter.substate_id {
-            Some(substate_id) => event.substate_id().map(|s| s == *substate_id).unwrap_or(false),
-            None => true,
-        };
-
-        let matches_entity_id = match &filter.entity_id {
-            Some(entity_id) => event
-                .substate_id()
-                .map(|s| Self::entity_id_matches(&s, entity_id))
-                .unwrap_or(false),
-            None => true,
-        };
-
-        if matches_topic && matches_template && matches_substate_id && matches_entity_id {
-            return true;
-        }
-
-        false
-    }
-
-    fn entity_id_matches(substate_id: &SubstateId, entity_id: &EntityId) -> bool {
-        match substate_id {
-            SubstateId::Component(c) => c.entity_id() == *entity_id,
-            SubstateId::Resource(r) => r.as_entity_id() == *entity_id,
-            SubstateId::Vault(v) => v.entity_id() == *entity_id,
-            // TODO: should all types of substate addresses expose the entity id?
-            _ => false,
-        }
+        self.event_filters
+            .iter()
+            .any(|filter| filter.matches(&event_data.event))
     }
 
     async fn store_events_in_db(
@@ -269,10 +313,12 @@ impl EventScanner {
                 template_address: data.event.template_address().to_string(),
                 tx_hash: data.event.tx_hash().to_string(),
                 topic: data.event.topic(),
-                payload: data.event.payload().to_json().expect("Failed to convert to JSON"),
+                payload: encode_event_payload(data.event.payload())?,
                 substate_id: data.event.substate_id().map(|s| s.to_string()),
                 version: 0_i32,
                 timestamp: transaction.timestamp as i64,
+                block_id: Some(transaction.block_id.to_string()),
+                block_height: Some(transaction.block_height.as_u64() as i64),
             };
 
             // TODO: properly avoid or handle duplicated events
@@ -322,6 +368,290 @@ impl EventScanner {
         Ok(())
     }
 
+    /// Updates the indexed vault balances and records the transaction in the history of every account it touched. The
+    /// balance change of each vault is the difference from the previously indexed balance. Returns the total amount
+    /// withdrawn from vaults per resource address.
+    fn store_vault_changes_in_db(
+        &self,
+        transaction: &TransactionMetadata,
+        vault_changes: VaultChanges,
+    ) -> Result<HashMap<String, i64>, anyhow::Error> {
+        let VaultChanges { holders, mut owners } = vault_changes;
+        if holders.is_empty() && owners.is_empty() {
+            return Ok(HashMap::new());
+        }
+        let transaction_id = transaction.transaction_id.to_string();
+
+        self.substate_store.with_write_tx(|tx| {
+            let mut balance_changes = HashMap::<_, Vec<_>>::new();
+            let mut withdrawals = HashMap::<_, i64>::new();
+            for holder in holders {
+                let previous = tx.get_resource_holder(&holder.vault_id)?;
+                if previous.as_ref().map_or(false, |p| p.version >= holder.version) {
+                    // This vault version was already indexed
+                    continue;
+                }
+                let owner = holder
+                    .owner
+                    .clone()
+                    .or_else(|| previous.as_ref().and_then(|p| p.owner.clone()));
+                let (kind, balance_change) = Self::vault_balance_change(previous.as_ref(), &holder);
+                if balance_change < 0 {
+                    *withdrawals.entry(holder.resource_address.clone()).or_default() -= balance_change;
+                }
+                if let Some(owner) = owner {
+                    // Unknown changes are recorded so that the history shows the vault was touched
+                    if balance_change != 0 || kind == BalanceChangeKind::Unknown {
+                        balance_changes
+                            .entry(owner.clone())
+                            .or_default()
+                            .push(NewAccountBalanceChange {
+                                account: owner.clone(),
+                                transaction_id: transaction_id.clone(),
+                                vault_id: holder.vault_id.clone(),
+                                resource_address: holder.resource_address.clone(),
+                                balance_change,
+                                kind: kind.as_str().to_string(),
+                            });
+                    }
+                    owners.insert(owner);
+                }
+
+                debug!(
+                    target: LOG_TARGET,
+                    "Saving resource holder: {:?}",
+                    holder
+                );
+                tx.set_resource_holder(holder)?;
+            }
+
+            for account in owners {
+                let changes = balance_changes.remove(&account).unwrap_or_default();
+                tx.save_account_transaction(
+                    NewAccountTransaction {
+                        account,
+                        transaction_id: transaction_id.clone(),
+                        timestamp: transaction.timestamp as i64,
+                    },
+                    changes,
+                )?;
+            }
+            Ok::<_, anyhow::Error>(withdrawals)
+        })
+    }
+
+    /// Returns how the balance of the vault changed from its previously indexed version. The previous balance of a
+    /// vault that existed before it was first indexed is not known, so its change is `Unknown` and zero.
+    fn vault_balance_change(previous: Option<&ResourceHolder>, holder: &NewResourceHolder) -> (BalanceChangeKind, i64) {
+        match previous {
+            Some(previous) => (BalanceChangeKind::Changed, holder.balance - previous.balance),
+            None if holder.version == 0 => (BalanceChangeKind::Created, holder.balance),
+            None => (BalanceChangeKind::Unknown, 0),
+        }
+    }
+
+    /// Records the supply of the resources updated in the transaction and the volume of every resource it moved. The
+    /// minted and burnt amounts are the change from the previously indexed total supply, the transferred amount is
+    /// what was withdrawn from vaults and not burnt.
+    fn store_resource_volumes_in_db(
+        &self,
+        transaction: &TransactionMetadata,
+        supplies: Vec<NewResourceSupply>,
+        mut withdrawals: HashMap<String, i64>,
+    ) -> Result<(), anyhow::Error> {
+        if supplies.is_empty() && withdrawals.is_empty() {
+            return Ok(());
+        }
+        let transaction_id = transaction.transaction_id.to_string();
+
+        self.substate_store.with_write_tx(|tx| {
+            // (minted, burnt) per resource address
+            let mut supply_changes = HashMap::<_, (i64, i64)>::new();
+            for supply in supplies {
+                let previous = tx.get_latest_resource_supply(&supply.resource_address)?;
+                if previous.as_ref().map_or(false, |p| p.version >= supply.version) {
+                    // This resource version was already indexed
+                    continue;
+                }
+                // The change is unknown for existing resources that were not indexed before
+                let supply_change = match previous {
+                    Some(p) => Some(supply.total_supply - p.total_supply),
+                    None if supply.version == 0 => Some(supply.total_supply),
+                    None => None,
+                };
+                if let Some(change) = supply_change.filter(|c| *c != 0) {
+                    let (minted, burnt) = supply_changes.entry(supply.resource_address.clone()).or_default();
+                    if change > 0 {
+                        *minted += change;
+                    } else {
+                        *burnt -= change;
+                    }
+                }
+
+                debug!(
+                    target: LOG_TARGET,
+                    "Saving resource supply: {:?}",
+                    supply
+                );
+                tx.save_resource_supply(supply)?;
+            }
+
+            let resource_addresses = supply_changes
+                .keys()
+                .chain(withdrawals.keys())
+                .cloned()
+                .collect::<HashSet<_>>();
+            for resource_address in resource_addresses {
+                let (minted, burnt) = supply_changes.get(&resource_address).copied().unwrap_or_default();
+                let withdrawn = withdrawals.remove(&resource_address).unwrap_or_default();
+                let transferred = (withdrawn - burnt).max(0);
+                if minted == 0 && burnt == 0 && transferred == 0 {
+                    continue;
+                }
+                tx.save_resource_volume(NewResourceVolume {
+                    resource_address,
+                    transaction_id: transaction_id.clone(),
+                    timestamp: transaction.timestamp as i64,
+                    minted,
+                    burnt,
+                    transferred,
+                })?;
+            }
+            Ok::<_, anyhow::Error>(())
+        })
+    }
+
+    fn store_non_fungible_metadata_in_db(
+        &self,
+        non_fungibles: Vec<(String, Vec<NewNonFungibleMetadataField>)>,
+    ) -> Result<(), anyhow::Error> {
+        if non_fungibles.is_empty() {
+            return Ok(());
+        }
+        self.substate_store.with_write_tx(|tx| {
+            for (nft_address, fields) in non_fungibles {
+                tx.set_non_fungible_metadata(&nft_address, fields)?;
+            }
+            Ok::<_, anyhow::Error>(())
+        })
+    }
+
+    /// Returns the flattened data and mutable data of the NFTs that were minted or updated in the transaction. Burnt
+    /// NFTs have no fields, which removes them from the metadata index.
+    fn extract_non_fungible_metadata(result: &ExecuteResult) -> Vec<(String, Vec<NewNonFungibleMetadataField>)> {
+        let TransactionResult::Accept(substate_diff) = &result.finalize.result else {
+            return vec![];
+        };
+
+        substate_diff
+            .up_iter()
+            .filter_map(|(substate_id, substate)| {
+                let SubstateValue::NonFungible(container) = substate.substate_value() else {
+                    return None;
+                };
+                let address = substate_id.as_non_fungible_address()?;
+                let nft_address = substate_id.to_string();
+                let fields = container
+                    .contents()
+                    .map(|nft| {
+                        [(false, nft.data()), (true, nft.mutable_data())]
+                            .into_iter()
+                            .flat_map(|(mutable, data)| {
+                                flatten_metadata(data)
+                                    .into_iter()
+                                    .map(move |(key, value)| (mutable, key, value))
+                            })
+                            .map(|(mutable, field_key, field_value)| NewNonFungibleMetadataField {
+                                nft_address: nft_address.clone(),
+                                resource_address: address.resource_address().to_string(),
+                                mutable,
+                                field_key,
+                                field_value,
+                                version: i64::from(substate.version()),
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                Some((nft_address, fields))
+            })
+            .collect()
+    }
+
+    /// Returns the vaults that were created or updated in the transaction. Only revealed balances are indexed. The
+    /// owner of a vault is only known if its owning component was also updated in the transaction, otherwise the
+    /// previously indexed owner is kept.
+    fn extract_vault_changes(result: &ExecuteResult, timestamp: u64) -> VaultChanges {
+        let TransactionResult::Accept(substate_diff) = &result.finalize.result else {
+            return VaultChanges::default();
+        };
+
+        let mut owners = HashMap::new();
+        for (substate_id, substate) in substate_diff.up_iter() {
+            if let SubstateValue::Component(component) = substate.substate_value() {
+                match IndexedWellKnownTypes::from_value(component.state()) {
+                    Ok(indexed) => {
+                        for vault_id in indexed.vault_ids() {
+                            owners.insert(SubstateId::from(*vault_id), substate_id.to_string());
+                        }
+                    },
+                    Err(e) => {
+                        warn!(
+                            target: LOG_TARGET,
+                            "Could not index component {} state: {}",
+                            substate_id,
+                            e
+                        );
+                    },
+                }
+            }
+        }
+
+        let holders = substate_diff
+            .up_iter()
+            .filter_map(|(substate_id, substate)| {
+                let SubstateValue::Vault(vault) = substate.substate_value() else {
+                    return None;
+                };
+                Some(NewResourceHolder {
+                    vault_id: substate_id.to_string(),
+                    resource_address: vault.resource_address().to_string(),
+                    owner: owners.get(substate_id).cloned(),
+                    balance: vault.balance().value(),
+                    version: i64::from(substate.version()),
+                    updated_at: timestamp as i64,
+                })
+            })
+            .collect();
+
+        VaultChanges {
+            holders,
+            owners: owners.into_values().collect(),
+        }
+    }
+
+    /// Returns the total supply of the resources that were created or updated in the transaction
+    fn extract_resource_supplies(result: &ExecuteResult, transaction: &TransactionMetadata) -> Vec<NewResourceSupply> {
+        let TransactionResult::Accept(substate_diff) = &result.finalize.result else {
+            return vec![];
+        };
+
+        substate_diff
+            .up_iter()
+            .filter_map(|(substate_id, substate)| {
+                let SubstateValue::Resource(resource) = substate.substate_value() else {
+                    return None;
+                };
+                Some(NewResourceSupply {
+                    resource_address: substate_id.to_string(),
+                    version: i64::from(substate.version()),
+                    total_supply: resource.total_supply().value(),
+                    transaction_id: transaction.transaction_id.to_string(),
+                    timestamp: transaction.timestamp as i64,
+                })
+            })
+            .collect()
+    }
+
     fn extract_template_address_from_substate(substate: &Substate) -> Option<TemplateAddress> {
         match substate.substate_value() {
             SubstateValue::Component(c) => Some(c.template_address),
@@ -341,7 +671,10 @@ impl EventScanner {
         Ok(pretty_json)
     }
 
-    async fn get_events_for_transaction(&self, transaction_id: TransactionId) -> Result<Vec<EventData>, anyhow::Error> {
+    async fn get_transaction_result(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<Option<ExecuteResult>, anyhow::Error> {
         let committee = self.get_all_vns().await?;
 
         for member in &committee {
@@ -349,13 +682,8 @@ impl EventScanner {
 
             match resp {
                 Ok(res) => {
-                    if let Some(execute_result) = res {
-                        let events = self.extract_events_from_transaction_result(execute_result);
-                        return Ok(events);
-                    } else {
-                        // The transaction is not successful, so we don't return any events
-                        return Ok(vec![]);
-                    }
+                    // If the transaction is not successful, there are no events or substate changes
+                    return Ok(res);
                 },
                 Err(e) => {
                     // We do nothing on a single VN failure, we only log it
@@ -373,7 +701,7 @@ impl EventScanner {
             target: LOG_TARGET,
             "We could not get transaction results from any of the vns",
         );
-        Ok(vec![])
+        Ok(None)
     }
 
     async fn get_execute_result_from_vn(
@@ -435,10 +763,12 @@ impl EventScanner {
     fn extract_transactions_from_blocks(&self, blocks: Vec<Block>) -> Vec<TransactionMetadata> {
         blocks
             .iter()
-            .flat_map(|b| b.all_committing_transactions_ids().map(|id| (id, b.timestamp())))
-            .map(|(transaction_id, timestamp)| TransactionMetadata {
+            .flat_map(|b| b.all_committing_transactions_ids().map(move |id| (id, b)))
+            .map(|(transaction_id, block)| TransactionMetadata {
                 transaction_id: *transaction_id,
-                timestamp,
+                timestamp: block.timestamp(),
+                block_id: *block.id(),
+                block_height: block.height(),
             })
             .collect()
     }
@@ -497,6 +827,9 @@ impl EventScanner {
                     // get the most recent block among all scanned blocks in the epoch
                     let last_block = blocks.iter().max_by_key(|b| (b.epoch(), b.height()));
 
+                    // Persist the blocks for the explorer API before moving the scan position past them
+                    self.store_blocks_in_db(&blocks)?;
+
                     if let Some(block) = last_block {
                         last_block_id = Some(*block.id());
                         // Store the latest scanned block id in the database for future scans
@@ -528,6 +861,16 @@ impl EventScanner {
         Ok(vec![])
     }
 
+    fn store_blocks_in_db(&self, blocks: &[Block]) -> Result<(), anyhow::Error> {
+        self.substate_store.with_write_tx(|tx| {
+            for block in blocks {
+                let transactions = NewBlockTransaction::all_from_block(block);
+                tx.save_block(NewBlock::try_from(block)?, transactions)?;
+            }
+            Ok(())
+        })
+    }
+
     fn save_scanned_block_id(
         &self,
         epoch: Epoch,
@@ -616,3 +959,60 @@ impl EventScanner {
         Ok(blocks)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_holder(balance: i64, version: i64) -> NewResourceHolder {
+        NewResourceHolder {
+            vault_id: "vault_1".to_string(),
+            resource_address: "resource_a".to_string(),
+            owner: None,
+            balance,
+            version,
+            updated_at: 0,
+        }
+    }
+
+    fn previous_holder(balance: i64) -> ResourceHolder {
+        ResourceHolder {
+            id: 1,
+            vault_id: "vault_1".to_string(),
+            resource_address: "resource_a".to_string(),
+            owner: None,
+            balance,
+            version: 4,
+            updated_at: 0,
+        }
+    }
+
+    #[test]
+    fn it_returns_the_difference_from_the_previous_version() {
+        let previous = previous_holder(100);
+        assert_eq!(
+            <EventScanner>::vault_balance_change(Some(&previous), &new_holder(60, 5)),
+            (BalanceChangeKind::Changed, -40)
+        );
+        assert_eq!(
+            <EventScanner>::vault_balance_change(Some(&previous), &new_holder(150, 5)),
+            (BalanceChangeKind::Changed, 50)
+        );
+    }
+
+    #[test]
+    fn it_returns_the_balance_of_a_created_vault() {
+        assert_eq!(
+            <EventScanner>::vault_balance_change(None, &new_holder(100, 0)),
+            (BalanceChangeKind::Created, 100)
+        );
+    }
+
+    #[test]
+    fn it_returns_unknown_if_the_previous_version_was_not_indexed() {
+        assert_eq!(
+            <EventScanner>::vault_balance_change(None, &new_holder(100, 3)),
+            (BalanceChangeKind::Unknown, 0)
+        );
+    }
+}
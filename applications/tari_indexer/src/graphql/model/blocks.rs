@@ -0,0 +1,305 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use async_graphql::{Context, Object, SimpleObject};
+use log::*;
+use serde::{Deserialize, Serialize};
+use tari_dan_common_types::{Epoch, ShardGroup};
+use tari_indexer_client::types::IndexedBlock;
+
+use crate::block_manager::{parse_block_id, BlockManager};
+
+const LOG_TARGET: &str = "tari::indexer::graphql::blocks";
+
+#[derive(SimpleObject, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Block {
+    pub block_id: String,
+    pub parent_id: String,
+    pub epoch: u64,
+    pub shard_group: String,
+    pub height: u64,
+    pub proposed_by: String,
+    pub timestamp: u64,
+    pub is_dummy: bool,
+    pub command_count: u64,
+    pub transaction_count: u64,
+    pub total_leader_fee: u64,
+}
+
+impl From<IndexedBlock> for Block {
+    fn from(block: IndexedBlock) -> Self {
+        Self {
+            block_id: block.block_id.to_string(),
+            parent_id: block.parent_id.to_string(),
+            epoch: block.epoch.as_u64(),
+            shard_group: block.shard_group.to_string(),
+            height: block.height.as_u64(),
+            proposed_by: block.proposed_by.to_string(),
+            timestamp: block.timestamp,
+            is_dummy: block.is_dummy,
+            command_count: block.command_count,
+            transaction_count: block.transaction_count,
+            total_leader_fee: block.total_leader_fee,
+        }
+    }
+}
+
+#[derive(SimpleObject, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockTransaction {
+    pub transaction_id: String,
+    pub command: String,
+    pub decision: String,
+    pub transaction_fee: u64,
+}
+
+#[derive(SimpleObject, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EpochStats {
+    pub epoch: u64,
+    pub num_blocks: u64,
+    pub num_dummy_blocks: u64,
+    pub num_shard_groups: u64,
+    pub num_commands: u64,
+    pub num_transactions: u64,
+    pub total_leader_fees: u64,
+    pub first_block_timestamp: Option<u64>,
+    pub last_block_timestamp: Option<u64>,
+}
+
+#[derive(Default)]
+pub struct BlockQuery;
+
+#[Object]
+impl BlockQuery {
+    pub async fn get_blocks(
+        &self,
+        ctx: &Context<'_>,
+        epoch: Option<u64>,
+        shard_group_start: Option<u32>,
+        shard_group_end: Option<u32>,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Block>, anyhow::Error> {
+        info!(
+            target: LOG_TARGET,
+            "Querying blocks. epoch: {:?}, shard_group: {:?}-{:?}, offset: {}, limit: {}",
+            epoch,
+            shard_group_start,
+            shard_group_end,
+            offset,
+            limit
+        );
+        let shard_group = match (shard_group_start, shard_group_end) {
+            (Some(start), Some(end)) if start <= end => Some(ShardGroup::new(start, end)),
+            (None, None) => None,
+            _ => {
+                return Err(anyhow!(
+                    "Both shard group start and end must be given, with start <= end"
+                ))
+            },
+        };
+        let block_manager = ctx.data_unchecked::<Arc<BlockManager>>();
+        let blocks = block_manager
+            .list_blocks(epoch.map(Epoch), shard_group, u64::from(offset), u64::from(limit))
+            .await?
+            .into_iter()
+            .map(Into::into)
+            .collect();
+
+        Ok(blocks)
+    }
+
+    pub async fn get_block(&self, ctx: &Context<'_>, block_id: String) -> Result<Option<Block>, anyhow::Error> {
+        info!(target: LOG_TARGET, "Querying block {}", block_id);
+        let block_manager = ctx.data_unchecked::<Arc<BlockManager>>();
+        let block_id = parse_block_id(&block_id)?;
+        let block = block_manager.get_block(&block_id).await?;
+        Ok(block.map(|b| b.block.into()))
+    }
+
+    pub async fn get_transactions_in_block(
+        &self,
+        ctx: &Context<'_>,
+        block_id: String,
+    ) -> Result<Option<Vec<BlockTransaction>>, anyhow::Error> {
+        info!(target: LOG_TARGET, "Querying transactions in block {}", block_id);
+        let block_manager = ctx.data_unchecked::<Arc<BlockManager>>();
+        let block_id = parse_block_id(&block_id)?;
+        let transactions = block_manager
+            .get_transactions_in_block(&block_id)
+            .await?
+            .map(|transactions| {
+                transactions
+                    .into_iter()
+                    .map(|t| BlockTransaction {
+                        transaction_id: t.transaction_id.to_string(),
+                        command: t.command,
+                        decision: t.decision.to_string(),
+                        transaction_fee: t.transaction_fee,
+                    })
+                    .collect()
+            });
+
+        Ok(transactions)
+    }
+
+    pub async fn get_epoch_stats(&self, ctx: &Context<'_>, epoch: u64) -> Result<EpochStats, anyhow::Error> {
+        info!(target: LOG_TARGET, "Querying stats of epoch {}", epoch);
+        let block_manager = ctx.data_unchecked::<Arc<BlockManager>>();
+        let stats = block_manager.get_epoch_stats(Epoch(epoch)).await?;
+        Ok(EpochStats {
+            epoch: stats.epoch.as_u64(),
+            num_blocks: stats.num_blocks,
+            num_dummy_blocks: stats.num_dummy_blocks,
+            num_shard_groups: stats.num_shard_groups,
+            num_commands: stats.num_commands,
+            num_transactions: stats.num_transactions,
+            total_leader_fees: stats.total_leader_fees,
+            first_block_timestamp: stats.first_block_timestamp,
+            last_block_timestamp: stats.last_block_timestamp,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_graphql::{EmptyMutation, EmptySubscription, Schema};
+    use serde_json::json;
+    use tari_common::configuration::Network;
+    use tari_common_types::types::FixedHash;
+    use tari_dan_storage::consensus_models;
+
+    use super::*;
+    use crate::storage::{
+        models::block::{NewBlock, NewBlockTransaction},
+        SqlSubstateStore,
+        SubstateStore,
+        SubstateStoreWriteTransaction,
+    };
+
+    type BlockSchema = Schema<BlockQuery, EmptyMutation, EmptySubscription>;
+
+    fn create_schema(blocks: &[consensus_models::Block]) -> (BlockSchema, tempfile::TempDir) {
+        let dir = tempfile::tempdir().unwrap();
+        let store = SqlSubstateStore::try_create_sqlite(dir.path().join("indexer.sqlite")).unwrap();
+        store.run_migrations().unwrap();
+        for block in blocks {
+            let transactions = vec![NewBlockTransaction {
+                block_id: block.id().to_string(),
+                transaction_id: "01".repeat(32),
+                command: "LocalOnly".to_string(),
+                decision: "Commit".to_string(),
+                transaction_fee: 5,
+            }];
+            store
+                .with_write_tx(|tx| tx.save_block(NewBlock::try_from(block).unwrap(), transactions))
+                .unwrap();
+        }
+
+        let schema = Schema::build(BlockQuery, EmptyMutation, EmptySubscription)
+            .data(Arc::new(BlockManager::new(store)))
+            .finish();
+        (schema, dir)
+    }
+
+    fn genesis_block(epoch: u64, shard_group: ShardGroup) -> consensus_models::Block {
+        consensus_models::Block::genesis(Network::LocalNet, Epoch(epoch), shard_group, FixedHash::zero(), None)
+    }
+
+    async fn execute(schema: &BlockSchema, query: &str) -> serde_json::Value {
+        let response = schema.execute(query).await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        response.data.into_json().unwrap()
+    }
+
+    #[tokio::test]
+    async fn it_lists_blocks_filtered_by_epoch_and_shard_group() {
+        let (schema, _dir) = create_schema(&[
+            genesis_block(1, ShardGroup::new(0, 1)),
+            genesis_block(1, ShardGroup::new(2, 3)),
+            genesis_block(2, ShardGroup::new(0, 1)),
+        ]);
+
+        let data = execute(&schema, "{ getBlocks(offset: 0, limit: 10) { epoch height } }").await;
+        assert_eq!(data["getBlocks"].as_array().unwrap().len(), 3);
+        assert_eq!(data["getBlocks"][0]["epoch"], json!(2));
+
+        let data = execute(
+            &schema,
+            "{ getBlocks(epoch: 1, shardGroupStart: 2, shardGroupEnd: 3, offset: 0, limit: 10) { shardGroup } }",
+        )
+        .await;
+        assert_eq!(data, json!({ "getBlocks": [{ "shardGroup": "ShardGroup(2-3)" }] }));
+    }
+
+    #[tokio::test]
+    async fn it_rejects_a_partial_shard_group() {
+        let (schema, _dir) = create_schema(&[]);
+        let response = schema
+            .execute("{ getBlocks(shardGroupStart: 2, offset: 0, limit: 10) { blockId } }")
+            .await;
+        assert_eq!(response.errors.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn it_returns_a_block_and_its_transactions() {
+        let block = genesis_block(1, ShardGroup::new(0, 1));
+        let (schema, _dir) = create_schema(&[block.clone()]);
+
+        let query = format!(
+            "{{ getBlock(blockId: \"{id}\") {{ blockId epoch }} getTransactionsInBlock(blockId: \"{id}\") {{ \
+             transactionId decision transactionFee }} }}",
+            id = block.id()
+        );
+        let data = execute(&schema, &query).await;
+        assert_eq!(
+            data,
+            json!({
+                "getBlock": { "blockId": block.id().to_string(), "epoch": 1 },
+                "getTransactionsInBlock": [
+                    { "transactionId": "01".repeat(32), "decision": "Commit", "transactionFee": 5 }
+                ],
+            })
+        );
+
+        let unknown = consensus_models::BlockId::from([9; 32]);
+        let query = format!(
+            "{{ getBlock(blockId: \"{id}\") {{ blockId }} getTransactionsInBlock(blockId: \"{id}\") {{ transactionId \
+             }} }}",
+            id = unknown
+        );
+        let data = execute(&schema, &query).await;
+        assert_eq!(data, json!({ "getBlock": null, "getTransactionsInBlock": null }));
+    }
+
+    #[tokio::test]
+    async fn it_returns_the_stats_of_an_epoch() {
+        let (schema, _dir) = create_schema(&[
+            genesis_block(1, ShardGroup::new(0, 1)),
+            genesis_block(1, ShardGroup::new(2, 3)),
+        ]);
+
+        let data = execute(
+            &schema,
+            "{ getEpochStats(epoch: 1) { epoch numBlocks numShardGroups numTransactions totalLeaderFees } }",
+        )
+        .await;
+        assert_eq!(
+            data,
+            json!({
+                "getEpochStats": {
+                    "epoch": 1,
+                    "numBlocks": 2,
+                    "numShardGroups": 2,
+                    "numTransactions": 0,
+                    "totalLeaderFees": 0,
+                }
+            })
+        );
+    }
+}
@@ -20,16 +20,24 @@
 //   WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 //   USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use std::{collections::BTreeMap, str::FromStr, sync::Arc};
+use std::{str::FromStr, sync::Arc};
 
-use async_graphql::{Context, EmptyMutation, EmptySubscription, Object, Schema, SimpleObject};
+use anyhow::anyhow;
+use async_graphql::{Context, Json, Object, SimpleObject, Subscription};
+use futures::{stream, Stream};
 use log::*;
 use serde::{Deserialize, Serialize};
 use tari_engine_types::substate::SubstateId;
-use tari_template_lib::Hash;
+use tari_template_lib::{models::TemplateAddress, Hash};
 use tari_transaction::TransactionId;
+use tokio::sync::{broadcast, broadcast::error::RecvError};
 
-use crate::event_manager::EventManager;
+use crate::{
+    event_manager::EventManager,
+    event_payload::{decode_event_payload, event_payload_to_json},
+    event_scanner::EventFilter,
+    non_fungible_metadata::flatten_metadata,
+};
 
 const LOG_TARGET: &str = "tari::indexer::graphql::events";
 
@@ -40,7 +48,8 @@ pub struct Event {
     pub template_address: [u8; 32],
     pub tx_hash: [u8; 32],
     pub topic: String,
-    pub payload: BTreeMap<String, String>,
+    /// The CBOR payload of the event encoded as JSON
+    pub payload: Json<serde_json::Value>,
 }
 
 impl Event {
@@ -50,13 +59,24 @@ impl Event {
             template_address: event.template_address().into_array(),
             tx_hash: event.tx_hash().into_array(),
             topic: event.topic(),
-            payload: event.into_payload().into_iter().collect(),
+            payload: Json(event_payload_to_json(event.payload())?),
         })
     }
 }
 
-pub(crate) type EventSchema = Schema<EventQuery, EmptyMutation, EmptySubscription>;
+/// An event with its cursor. Consumers resume reading by passing the last `sequence` they processed to
+/// `getEventsAfter`.
+#[derive(SimpleObject, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SequencedEvent {
+    pub sequence: u64,
+    pub block_id: Option<String>,
+    pub block_height: Option<u64>,
+    pub timestamp: u64,
+    pub event: Event,
+}
 
+#[derive(Default)]
 pub struct EventQuery;
 
 #[Object]
@@ -157,6 +177,38 @@ impl EventQuery {
         Ok(events)
     }
 
+    pub async fn get_events_after(
+        &self,
+        ctx: &Context<'_>,
+        after_sequence: Option<u64>,
+        topic: Option<String>,
+        substate_id: Option<String>,
+        limit: u32,
+    ) -> Result<Vec<SequencedEvent>, anyhow::Error> {
+        info!(
+            target: LOG_TARGET,
+            "Querying events after sequence {:?}. topic: {:?}, substate_id: {:?}, limit: {}", after_sequence, topic, substate_id, limit,
+        );
+        let substate_id = substate_id.map(|str| SubstateId::from_str(&str)).transpose()?;
+        let event_manager = ctx.data_unchecked::<Arc<EventManager>>();
+        let events = event_manager
+            .get_events_after_sequence(after_sequence.unwrap_or(0), topic, substate_id, limit)
+            .await?
+            .into_iter()
+            .map(|e| {
+                Ok(SequencedEvent {
+                    sequence: e.sequence,
+                    block_id: e.block_id,
+                    block_height: e.block_height,
+                    timestamp: e.timestamp,
+                    event: Event::from_engine_event(e.event)?,
+                })
+            })
+            .collect::<Result<Vec<_>, anyhow::Error>>()?;
+
+        Ok(events)
+    }
+
     pub async fn save_event(
         &self,
         ctx: &Context<'_>,
@@ -177,7 +229,7 @@ impl EventQuery {
         let template_address = Hash::from_str(&template_address)?;
         let tx_hash = TransactionId::from_hex(&tx_hash)?;
 
-        let payload = serde_json::from_str(&payload)?;
+        let payload = decode_event_payload(&payload)?;
         let event_manager = ctx.data_unchecked::<Arc<EventManager>>();
         event_manager.save_event_to_db(
             &substate_id,
@@ -194,7 +246,327 @@ impl EventQuery {
             template_address: template_address.into_array(),
             tx_hash: tx_hash.into_array(),
             topic,
-            payload: payload.into_iter().collect(),
+            payload: Json(event_payload_to_json(&payload)?),
         })
     }
 }
+
+pub struct EventSubscription;
+
+#[Subscription]
+impl EventSubscription {
+    /// Streams events as they are scanned from the network. All filters are optional and are combined with AND.
+    pub async fn events(
+        &self,
+        ctx: &Context<'_>,
+        topic: Option<String>,
+        template_address: Option<String>,
+        substate_id: Option<String>,
+        payload_key: Option<String>,
+        payload_value: Option<String>,
+    ) -> Result<impl Stream<Item = Event>, anyhow::Error> {
+        info!(
+            target: LOG_TARGET,
+            "Subscribing to events. topic: {:?}, template_address: {:?}, substate_id: {:?}, payload_key: {:?}, payload_value: {:?}",
+            topic, template_address, substate_id, payload_key, payload_value
+        );
+        if payload_value.is_some() && payload_key.is_none() {
+            return Err(anyhow!("payload_value requires payload_key"));
+        }
+        let filter = EventFilter {
+            topic,
+            entity_id: None,
+            substate_id: substate_id.map(|s| SubstateId::from_str(&s)).transpose()?,
+            template_address: template_address.map(|t| TemplateAddress::from_str(&t)).transpose()?,
+        };
+
+        let receiver = ctx
+            .data_unchecked::<broadcast::Sender<tari_engine_types::events::Event>>()
+            .subscribe();
+
+        let stream = stream::unfold(receiver, move |mut receiver| {
+            let filter = filter.clone();
+            let payload_key = payload_key.clone();
+            let payload_value = payload_value.clone();
+            async move {
+                loop {
+                    let event = match receiver.recv().await {
+                        Ok(event) => event,
+                        Err(RecvError::Lagged(n)) => {
+                            warn!(target: LOG_TARGET, "Event subscriber lagged, skipped {} event(s)", n);
+                            continue;
+                        },
+                        Err(RecvError::Closed) => return None,
+                    };
+                    if !filter.matches(&event) {
+                        continue;
+                    }
+                    // Match payload fields the same way as the event search, so nested keys and non-text values
+                    // can be filtered on
+                    if let Some(key) = &payload_key {
+                        match flatten_metadata(event.payload()).get(key) {
+                            Some(value) if payload_value.as_ref().map_or(true, |v| v == value) => {},
+                            _ => continue,
+                        }
+                    }
+                    match Event::from_engine_event(event) {
+                        Ok(event) => return Some((event, receiver)),
+                        Err(e) => warn!(target: LOG_TARGET, "Failed to convert event: {}", e),
+                    }
+                }
+            }
+        });
+
+        Ok(stream)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, time::Duration};
+
+    use async_graphql::{EmptyMutation, Response, Schema};
+    use futures::StreamExt;
+    use serde_json::json;
+    use tari_template_lib::models::ComponentAddress;
+
+    use super::*;
+
+    type EventSchema = Schema<EventQuery, EmptyMutation, EventSubscription>;
+
+    const TEMPLATE_A: [u8; 32] = [1; 32];
+    const TEMPLATE_B: [u8; 32] = [2; 32];
+
+    fn create_schema(capacity: usize) -> (EventSchema, broadcast::Sender<tari_engine_types::events::Event>) {
+        let (sender, _) = broadcast::channel(capacity);
+        let schema = Schema::build(EventQuery, EmptyMutation, EventSubscription)
+            .data(sender.clone())
+            .finish();
+        (schema, sender)
+    }
+
+    fn component(n: u8) -> SubstateId {
+        SubstateId::Component(ComponentAddress::from_array([n; 32]))
+    }
+
+    fn new_event(
+        topic: &str,
+        template: [u8; 32],
+        substate_id: Option<SubstateId>,
+        payload: &[(&str, &str)],
+    ) -> tari_engine_types::events::Event {
+        let payload = payload.iter().copied().collect::<BTreeMap<_, _>>();
+        tari_engine_types::events::Event::new(
+            substate_id,
+            TemplateAddress::from_array(template),
+            Hash::from_array([3; 32]),
+            topic.to_string(),
+            tari_bor::to_value(&payload).unwrap(),
+        )
+    }
+
+    /// Starts the subscription and waits until it is receiving from the publisher, so that no published event is
+    /// missed
+    async fn subscribe(
+        schema: &EventSchema,
+        sender: &broadcast::Sender<tari_engine_types::events::Event>,
+        query: &str,
+    ) -> impl Stream<Item = Response> + Unpin {
+        let mut stream = schema.execute_stream(query);
+        assert!(futures::poll!(stream.next()).is_pending());
+        assert_eq!(sender.receiver_count(), 1);
+        stream
+    }
+
+    async fn next_event(stream: &mut (impl Stream<Item = Response> + Unpin)) -> serde_json::Value {
+        let response = tokio::time::timeout(Duration::from_secs(5), stream.next())
+            .await
+            .expect("timed out waiting for an event")
+            .expect("subscription ended");
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let mut data = response.data.into_json().unwrap();
+        data["events"].take()
+    }
+
+    async fn next_topic(stream: &mut (impl Stream<Item = Response> + Unpin)) -> String {
+        next_event(stream).await["topic"].as_str().unwrap().to_string()
+    }
+
+    #[tokio::test]
+    async fn it_streams_published_events() {
+        let (schema, sender) = create_schema(10);
+        let mut stream = subscribe(&schema, &sender, "subscription { events { substateId topic payload } }").await;
+
+        let event = new_event("created", TEMPLATE_A, Some(component(1)), &[("owner", "alice")]);
+        let payload = event_payload_to_json(event.payload()).unwrap();
+        sender.send(event).unwrap();
+        assert_eq!(
+            next_event(&mut stream).await,
+            json!({
+                "substateId": component(1).to_string(),
+                "topic": "created",
+                "payload": payload,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn it_filters_by_topic() {
+        let (schema, sender) = create_schema(10);
+        let mut stream = subscribe(
+            &schema,
+            &sender,
+            r#"subscription { events(topic: "wanted") { topic } }"#,
+        )
+        .await;
+
+        sender.send(new_event("other", TEMPLATE_A, None, &[])).unwrap();
+        sender.send(new_event("wanted", TEMPLATE_A, None, &[])).unwrap();
+        assert_eq!(next_topic(&mut stream).await, "wanted");
+    }
+
+    #[tokio::test]
+    async fn it_filters_by_template_address() {
+        let (schema, sender) = create_schema(10);
+        let query = format!(
+            r#"subscription {{ events(templateAddress: "{}") {{ topic }} }}"#,
+            TemplateAddress::from_array(TEMPLATE_B)
+        );
+        let mut stream = subscribe(&schema, &sender, &query).await;
+
+        sender.send(new_event("from_a", TEMPLATE_A, None, &[])).unwrap();
+        sender.send(new_event("from_b", TEMPLATE_B, None, &[])).unwrap();
+        assert_eq!(next_topic(&mut stream).await, "from_b");
+    }
+
+    #[tokio::test]
+    async fn it_filters_by_substate_id() {
+        let (schema, sender) = create_schema(10);
+        let query = format!(
+            r#"subscription {{ events(substateId: "{}") {{ topic }} }}"#,
+            component(2)
+        );
+        let mut stream = subscribe(&schema, &sender, &query).await;
+
+        sender.send(new_event("no_substate", TEMPLATE_A, None, &[])).unwrap();
+        sender
+            .send(new_event("other_substate", TEMPLATE_A, Some(component(1)), &[]))
+            .unwrap();
+        sender
+            .send(new_event("wanted", TEMPLATE_A, Some(component(2)), &[]))
+            .unwrap();
+        assert_eq!(next_topic(&mut stream).await, "wanted");
+    }
+
+    #[tokio::test]
+    async fn it_filters_by_payload_key_and_value() {
+        let (schema, sender) = create_schema(10);
+        let mut stream = subscribe(
+            &schema,
+            &sender,
+            r#"subscription { events(payloadKey: "owner") { topic } }"#,
+        )
+        .await;
+        sender
+            .send(new_event("no_owner", TEMPLATE_A, None, &[("other", "x")]))
+            .unwrap();
+        sender
+            .send(new_event("with_owner", TEMPLATE_A, None, &[("owner", "bob")]))
+            .unwrap();
+        assert_eq!(next_topic(&mut stream).await, "with_owner");
+        drop(stream);
+
+        let mut stream = subscribe(
+            &schema,
+            &sender,
+            r#"subscription { events(payloadKey: "owner", payloadValue: "alice") { topic } }"#,
+        )
+        .await;
+        sender
+            .send(new_event("owned_by_bob", TEMPLATE_A, None, &[("owner", "bob")]))
+            .unwrap();
+        sender
+            .send(new_event("owned_by_alice", TEMPLATE_A, None, &[("owner", "alice")]))
+            .unwrap();
+        assert_eq!(next_topic(&mut stream).await, "owned_by_alice");
+    }
+
+    #[tokio::test]
+    async fn it_filters_by_nested_and_non_text_payload_fields() {
+        #[derive(Serialize)]
+        struct Transfer {
+            amount: u64,
+            to: Destination,
+        }
+        #[derive(Serialize)]
+        struct Destination {
+            owner: String,
+        }
+
+        let (schema, sender) = create_schema(10);
+        let transfer = |topic: &str, amount: u64, owner: &str| {
+            let payload = Transfer {
+                amount,
+                to: Destination {
+                    owner: owner.to_string(),
+                },
+            };
+            tari_engine_types::events::Event::new(
+                None,
+                TemplateAddress::from_array(TEMPLATE_A),
+                Hash::from_array([3; 32]),
+                topic.to_string(),
+                tari_bor::to_value(&payload).unwrap(),
+            )
+        };
+
+        let mut stream = subscribe(
+            &schema,
+            &sender,
+            r#"subscription { events(payloadKey: "amount", payloadValue: "123") { topic } }"#,
+        )
+        .await;
+        sender.send(transfer("other_amount", 5, "alice")).unwrap();
+        sender.send(transfer("wanted", 123, "alice")).unwrap();
+        assert_eq!(next_topic(&mut stream).await, "wanted");
+        drop(stream);
+
+        let mut stream = subscribe(
+            &schema,
+            &sender,
+            r#"subscription { events(payloadKey: "to.owner", payloadValue: "bob") { topic } }"#,
+        )
+        .await;
+        sender.send(transfer("to_alice", 1, "alice")).unwrap();
+        sender.send(transfer("to_bob", 1, "bob")).unwrap();
+        assert_eq!(next_topic(&mut stream).await, "to_bob");
+    }
+
+    #[tokio::test]
+    async fn it_rejects_a_payload_value_without_a_payload_key() {
+        let (schema, _sender) = create_schema(10);
+        let mut stream = schema.execute_stream(r#"subscription { events(payloadValue: "alice") { topic } }"#);
+
+        let response = stream.next().await.unwrap();
+        assert_eq!(response.errors.len(), 1);
+        assert_eq!(response.errors[0].message, "payload_value requires payload_key");
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn it_keeps_streaming_after_lagging_behind() {
+        let (schema, sender) = create_schema(2);
+        let mut stream = subscribe(&schema, &sender, "subscription { events { topic } }").await;
+
+        // Publish more events than the channel holds before the subscriber reads any of them
+        for topic in ["a", "b", "c", "d"] {
+            sender.send(new_event(topic, TEMPLATE_A, None, &[])).unwrap();
+        }
+
+        // The oldest events are skipped and the subscriber continues from the ones still in the channel
+        assert_eq!(next_topic(&mut stream).await, "c");
+        assert_eq!(next_topic(&mut stream).await, "d");
+        sender.send(new_event("e", TEMPLATE_A, None, &[])).unwrap();
+        assert_eq!(next_topic(&mut stream).await, "e");
+    }
+}
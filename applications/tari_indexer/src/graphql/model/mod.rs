@@ -20,4 +20,21 @@
 //   WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 //   USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use async_graphql::{EmptyMutation, MergedObject, Schema};
+
+use crate::graphql::model::{
+    blocks::BlockQuery,
+    events::EventQuery,
+    resources::ResourceQuery,
+    substates::SubstateQuery,
+};
+
+pub mod blocks;
 pub mod events;
+pub mod resources;
+pub mod substates;
+
+#[derive(MergedObject, Default)]
+pub struct IndexerQuery(BlockQuery, EventQuery, ResourceQuery, SubstateQuery);
+
+pub(crate) type IndexerSchema = Schema<IndexerQuery, EmptyMutation, events::EventSubscription>;
@@ -0,0 +1,304 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use std::{collections::BTreeMap, str::FromStr, sync::Arc};
+
+use async_graphql::{Context, InputObject, Object, SimpleObject};
+use log::*;
+use serde::{Deserialize, Serialize};
+use tari_indexer_client::types::{BalanceChangeKind, TransferDirection};
+use tari_template_lib::models::{ComponentAddress, ResourceAddress};
+
+use crate::substate_manager::SubstateManager;
+
+const LOG_TARGET: &str = "tari::indexer::graphql::resources";
+/// Supply and volume charts are bucketed by day unless requested otherwise
+const DEFAULT_BUCKET_SECONDS: u64 = 24 * 60 * 60;
+
+#[derive(SimpleObject, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceHolder {
+    /// The component that owns the vaults, or the vault itself if its owner is not known
+    pub holder: String,
+    pub balance: i64,
+    pub vault_count: u64,
+}
+
+#[derive(SimpleObject, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PortfolioBalance {
+    pub resource_address: String,
+    pub vault_id: String,
+    pub balance: i64,
+}
+
+#[derive(SimpleObject, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountTransaction {
+    pub transaction_id: String,
+    pub timestamp: u64,
+    pub balance_changes: Vec<AccountBalanceChange>,
+}
+
+#[derive(SimpleObject, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountBalanceChange {
+    pub resource_address: String,
+    pub vault_id: String,
+    /// One of "Changed", "Created" or "Unknown"
+    pub kind: String,
+    /// Positive for incoming and negative for outgoing amounts, zero if the kind is "Unknown"
+    pub amount: i64,
+}
+
+#[derive(SimpleObject, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NonFungibleMetadata {
+    pub address: String,
+    pub data: BTreeMap<String, String>,
+    pub mutable_data: BTreeMap<String, String>,
+}
+
+#[derive(SimpleObject, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceSupplyPoint {
+    pub bucket_start: u64,
+    pub total_supply: i64,
+}
+
+#[derive(SimpleObject, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceVolume {
+    pub bucket_start: u64,
+    pub minted: i64,
+    pub burnt: i64,
+    pub transferred: i64,
+    pub transaction_count: u64,
+}
+
+#[derive(InputObject, Clone, Debug)]
+pub struct MetadataField {
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(Default)]
+pub struct ResourceQuery;
+
+#[Object]
+impl ResourceQuery {
+    pub async fn get_top_holders(
+        &self,
+        ctx: &Context<'_>,
+        resource_address: String,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<ResourceHolder>, anyhow::Error> {
+        info!(
+            target: LOG_TARGET,
+            "Querying holders of resource {}. offset: {}, limit: {}", resource_address, offset, limit
+        );
+        let substate_manager = ctx.data_unchecked::<Arc<SubstateManager>>();
+        let resource_address = ResourceAddress::from_str(&resource_address)?;
+        let holders = substate_manager
+            .get_resource_holders(&resource_address, u64::from(offset), u64::from(limit))
+            .await?
+            .into_iter()
+            .map(|h| ResourceHolder {
+                holder: h.holder.to_string(),
+                balance: h.balance.value(),
+                vault_count: h.vault_count,
+            })
+            .collect();
+
+        Ok(holders)
+    }
+
+    pub async fn get_holder_count(&self, ctx: &Context<'_>, resource_address: String) -> Result<u64, anyhow::Error> {
+        let substate_manager = ctx.data_unchecked::<Arc<SubstateManager>>();
+        let resource_address = ResourceAddress::from_str(&resource_address)?;
+        substate_manager.count_resource_holders(&resource_address).await
+    }
+
+    pub async fn get_account_portfolio(
+        &self,
+        ctx: &Context<'_>,
+        account: String,
+    ) -> Result<Vec<PortfolioBalance>, anyhow::Error> {
+        info!(target: LOG_TARGET, "Querying portfolio of account {}", account);
+        let substate_manager = ctx.data_unchecked::<Arc<SubstateManager>>();
+        let account = ComponentAddress::from_str(&account)?;
+        let balances = substate_manager
+            .get_account_portfolio(&account)
+            .await?
+            .into_iter()
+            .map(|b| PortfolioBalance {
+                resource_address: b.resource_address.to_string(),
+                vault_id: b.vault_id.to_string(),
+                balance: b.balance.value(),
+            })
+            .collect();
+
+        Ok(balances)
+    }
+
+    pub async fn get_account_transactions(
+        &self,
+        ctx: &Context<'_>,
+        account: String,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<AccountTransaction>, anyhow::Error> {
+        info!(
+            target: LOG_TARGET,
+            "Querying transactions of account {}. offset: {}, limit: {}", account, offset, limit
+        );
+        let substate_manager = ctx.data_unchecked::<Arc<SubstateManager>>();
+        let account = ComponentAddress::from_str(&account)?;
+        let (transactions, _) = substate_manager
+            .get_account_transactions(&account, u64::from(offset), u64::from(limit))
+            .await?;
+        let transactions = transactions
+            .into_iter()
+            .map(|t| AccountTransaction {
+                transaction_id: t.transaction_id.to_string(),
+                timestamp: t.timestamp,
+                balance_changes: t
+                    .balance_changes
+                    .into_iter()
+                    .map(|c| AccountBalanceChange {
+                        resource_address: c.resource_address.to_string(),
+                        vault_id: c.vault_id.to_string(),
+                        kind: match c.kind {
+                            BalanceChangeKind::Changed => "Changed",
+                            BalanceChangeKind::Created => "Created",
+                            BalanceChangeKind::Unknown => "Unknown",
+                        }
+                        .to_string(),
+                        amount: match c.direction {
+                            TransferDirection::In => c.amount.value(),
+                            TransferDirection::Out => -c.amount.value(),
+                        },
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        Ok(transactions)
+    }
+
+    pub async fn search_non_fungibles(
+        &self,
+        ctx: &Context<'_>,
+        resource_address: Option<String>,
+        fields: Option<Vec<MetadataField>>,
+        name_prefix: Option<String>,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<NonFungibleMetadata>, anyhow::Error> {
+        info!(
+            target: LOG_TARGET,
+            "Searching non fungibles. resource: {:?}, fields: {:?}, name_prefix: {:?}", resource_address, fields, name_prefix
+        );
+        let substate_manager = ctx.data_unchecked::<Arc<SubstateManager>>();
+        let resource_address = resource_address.as_deref().map(ResourceAddress::from_str).transpose()?;
+        let fields = fields
+            .unwrap_or_default()
+            .into_iter()
+            .map(|f| (f.key, f.value))
+            .collect();
+        let non_fungibles = substate_manager
+            .search_non_fungibles(
+                resource_address.as_ref(),
+                fields,
+                name_prefix.as_deref(),
+                u64::from(offset),
+                u64::from(limit),
+            )
+            .await?
+            .into_iter()
+            .map(|nft| NonFungibleMetadata {
+                address: nft.address.to_string(),
+                data: nft.data,
+                mutable_data: nft.mutable_data,
+            })
+            .collect();
+
+        Ok(non_fungibles)
+    }
+
+    pub async fn get_resource_supply_history(
+        &self,
+        ctx: &Context<'_>,
+        resource_address: String,
+        bucket_seconds: Option<u64>,
+        from_timestamp: Option<u64>,
+        to_timestamp: Option<u64>,
+    ) -> Result<Vec<ResourceSupplyPoint>, anyhow::Error> {
+        info!(
+            target: LOG_TARGET,
+            "Querying supply history of resource {}. bucket_seconds: {:?}, from: {:?}, to: {:?}",
+            resource_address,
+            bucket_seconds,
+            from_timestamp,
+            to_timestamp
+        );
+        let substate_manager = ctx.data_unchecked::<Arc<SubstateManager>>();
+        let resource_address = ResourceAddress::from_str(&resource_address)?;
+        let history = substate_manager
+            .get_resource_supply_history(
+                &resource_address,
+                bucket_seconds.unwrap_or(DEFAULT_BUCKET_SECONDS),
+                from_timestamp.unwrap_or(0),
+                to_timestamp.unwrap_or(u64::MAX),
+            )
+            .await?
+            .into_iter()
+            .map(|b| ResourceSupplyPoint {
+                bucket_start: b.bucket_start,
+                total_supply: b.total_supply.value(),
+            })
+            .collect();
+
+        Ok(history)
+    }
+
+    pub async fn get_resource_volume(
+        &self,
+        ctx: &Context<'_>,
+        resource_address: String,
+        bucket_seconds: Option<u64>,
+        from_timestamp: Option<u64>,
+        to_timestamp: Option<u64>,
+    ) -> Result<Vec<ResourceVolume>, anyhow::Error> {
+        info!(
+            target: LOG_TARGET,
+            "Querying volume of resource {}. bucket_seconds: {:?}, from: {:?}, to: {:?}",
+            resource_address,
+            bucket_seconds,
+            from_timestamp,
+            to_timestamp
+        );
+        let substate_manager = ctx.data_unchecked::<Arc<SubstateManager>>();
+        let resource_address = ResourceAddress::from_str(&resource_address)?;
+        let volume = substate_manager
+            .get_resource_volume(
+                &resource_address,
+                bucket_seconds.unwrap_or(DEFAULT_BUCKET_SECONDS),
+                from_timestamp.unwrap_or(0),
+                to_timestamp.unwrap_or(u64::MAX),
+            )
+            .await?
+            .into_iter()
+            .map(|b| ResourceVolume {
+                bucket_start: b.bucket_start,
+                minted: b.minted.value(),
+                burnt: b.burnt.value(),
+                transferred: b.transferred.value(),
+                transaction_count: b.transaction_count,
+            })
+            .collect();
+
+        Ok(volume)
+    }
+}
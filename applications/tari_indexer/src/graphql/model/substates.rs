@@ -0,0 +1,166 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use std::{str::FromStr, sync::Arc};
+
+use async_graphql::{Context, Enum, InputObject, Object, SimpleObject};
+use log::*;
+use serde::{Deserialize, Serialize};
+use tari_dan_common_types::substate_type::SubstateType;
+use tari_indexer_client::types::{self, ListSubstatesRequest};
+use tari_template_lib::models::TemplateAddress;
+use tari_transaction::TransactionId;
+
+use crate::substate_manager::SubstateManager;
+
+const LOG_TARGET: &str = "tari::indexer::graphql::substates";
+
+#[derive(SimpleObject, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SubstateItem {
+    pub substate_id: String,
+    pub module_name: Option<String>,
+    pub version: u32,
+    pub template_address: Option<String>,
+    pub timestamp: u64,
+}
+
+#[derive(SimpleObject, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SubstatePage {
+    pub substates: Vec<SubstateItem>,
+    /// Pass as the `cursor` of the next query to fetch the next page
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Enum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SubstateKind {
+    #[default]
+    Component,
+    Resource,
+    Vault,
+    UnclaimedConfidentialOutput,
+    NonFungible,
+    TransactionReceipt,
+    FeeClaim,
+}
+
+impl From<SubstateKind> for SubstateType {
+    fn from(kind: SubstateKind) -> Self {
+        match kind {
+            SubstateKind::Component => SubstateType::Component,
+            SubstateKind::Resource => SubstateType::Resource,
+            SubstateKind::Vault => SubstateType::Vault,
+            SubstateKind::UnclaimedConfidentialOutput => SubstateType::UnclaimedConfidentialOutput,
+            SubstateKind::NonFungible => SubstateType::NonFungible,
+            SubstateKind::TransactionReceipt => SubstateType::TransactionReceipt,
+            SubstateKind::FeeClaim => SubstateType::FeeClaim,
+        }
+    }
+}
+
+#[derive(Enum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SubstateOrderBy {
+    #[default]
+    Indexed,
+    Version,
+    Timestamp,
+}
+
+impl From<SubstateOrderBy> for types::SubstateOrderBy {
+    fn from(order_by: SubstateOrderBy) -> Self {
+        match order_by {
+            SubstateOrderBy::Indexed => types::SubstateOrderBy::Indexed,
+            SubstateOrderBy::Version => types::SubstateOrderBy::Version,
+            SubstateOrderBy::Timestamp => types::SubstateOrderBy::Timestamp,
+        }
+    }
+}
+
+#[derive(Enum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
+
+impl From<SortOrder> for types::SortOrder {
+    fn from(order: SortOrder) -> Self {
+        match order {
+            SortOrder::Asc => types::SortOrder::Asc,
+            SortOrder::Desc => types::SortOrder::Desc,
+        }
+    }
+}
+
+#[derive(InputObject, Clone, Debug, Default)]
+pub struct SubstateFilter {
+    pub template_address: Option<String>,
+    pub substate_type: Option<SubstateKind>,
+    pub module_name: Option<String>,
+    pub transaction_id: Option<String>,
+    pub min_version: Option<u32>,
+    pub max_version: Option<u32>,
+    pub from_timestamp: Option<u64>,
+    pub to_timestamp: Option<u64>,
+}
+
+#[derive(Default)]
+pub struct SubstateQuery;
+
+#[Object]
+impl SubstateQuery {
+    pub async fn list_substates(
+        &self,
+        ctx: &Context<'_>,
+        filter: Option<SubstateFilter>,
+        order_by: Option<SubstateOrderBy>,
+        order: Option<SortOrder>,
+        cursor: Option<String>,
+        limit: Option<u64>,
+    ) -> Result<SubstatePage, anyhow::Error> {
+        let filter = filter.unwrap_or_default();
+        info!(
+            target: LOG_TARGET,
+            "Querying substates. filter: {:?}, cursor: {:?}, limit: {:?}", filter, cursor, limit
+        );
+        let request = ListSubstatesRequest {
+            filter_by_template: filter
+                .template_address
+                .as_deref()
+                .map(TemplateAddress::from_hex)
+                .transpose()?,
+            filter_by_type: filter.substate_type.map(Into::into),
+            filter_by_module_name: filter.module_name,
+            filter_by_transaction: filter
+                .transaction_id
+                .as_deref()
+                .map(TransactionId::from_hex)
+                .transpose()?,
+            min_version: filter.min_version,
+            max_version: filter.max_version,
+            from_timestamp: filter.from_timestamp,
+            to_timestamp: filter.to_timestamp,
+            order_by: order_by.unwrap_or_default().into(),
+            order: order.unwrap_or_default().into(),
+            cursor,
+            limit,
+            offset: None,
+        };
+
+        let substate_manager = ctx.data_unchecked::<Arc<SubstateManager>>();
+        let (substates, next_cursor) = substate_manager.list_substates(&request).await?;
+        let substates = substates
+            .into_iter()
+            .map(|s| SubstateItem {
+                substate_id: s.substate_id.to_string(),
+                module_name: s.module_name,
+                version: s.version,
+                template_address: s.template_address.map(|t| t.to_string()),
+                timestamp: s.timestamp,
+            })
+            .collect();
+
+        Ok(SubstatePage { substates, next_cursor })
+    }
+}
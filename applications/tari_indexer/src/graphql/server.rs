@@ -25,10 +25,9 @@ use std::{net::SocketAddr, sync::Arc};
 use async_graphql::{
     http::{playground_source, GraphQLPlaygroundConfig},
     EmptyMutation,
-    EmptySubscription,
     Schema,
 };
-use async_graphql_axum::{GraphQLRequest, GraphQLResponse};
+use async_graphql_axum::{GraphQLRequest, GraphQLResponse, GraphQLSubscription};
 use axum::{
     extract::Extension,
     http::StatusCode,
@@ -39,10 +38,13 @@ use axum::{
 };
 use log::*;
 use serde::Serialize;
+use tari_engine_types::events::Event;
+use tokio::sync::broadcast;
 use tower_http::cors::CorsLayer;
 
 use crate::{
-    graphql::model::events::{EventQuery, EventSchema},
+    block_manager::BlockManager,
+    graphql::model::{events::EventSubscription, IndexerQuery, IndexerSchema},
     substate_manager::SubstateManager,
     EventManager,
 };
@@ -53,13 +55,18 @@ pub async fn run_graphql(
     preferred_address: SocketAddr,
     substate_manager: Arc<SubstateManager>,
     event_manager: Arc<EventManager>,
+    block_manager: Arc<BlockManager>,
+    event_publisher: broadcast::Sender<Event>,
 ) -> Result<(), anyhow::Error> {
-    let schema = Schema::build(EventQuery, EmptyMutation, EmptySubscription)
+    let schema = Schema::build(IndexerQuery::default(), EmptyMutation, EventSubscription)
         .data(substate_manager)
         .data(event_manager)
+        .data(block_manager)
+        .data(event_publisher)
         .finish();
     let router = Router::new()
         .route("/", get(graphql_playground).post(graphql_handler))
+        .route_service("/ws", GraphQLSubscription::new(schema.clone()))
         .route("/health", get(health))
         .layer(CorsLayer::permissive())
         .layer(Extension(schema));
@@ -94,6 +101,9 @@ pub(crate) async fn graphql_playground() -> impl IntoResponse {
     ))
 }
 
-pub(crate) async fn graphql_handler(Extension(schema): Extension<EventSchema>, req: GraphQLRequest) -> GraphQLResponse {
+pub(crate) async fn graphql_handler(
+    Extension(schema): Extension<IndexerSchema>,
+    req: GraphQLRequest,
+) -> GraphQLResponse {
     schema.execute(req.into_inner()).await.into()
 }
@@ -48,12 +48,21 @@ use tari_indexer_client::types::{
     self,
     AddPeerRequest,
     AddPeerResponse,
+    CallViewRequest,
+    CallViewResponse,
     ConnectionDirection,
+    GetAccountPortfolioRequest,
+    GetAccountPortfolioResponse,
+    GetAccountTransactionsRequest,
+    GetAccountTransactionsResponse,
     GetAllVnsRequest,
     GetAllVnsResponse,
+    GetBlockRequest,
     GetCommsStatsResponse,
     GetConnectionsResponse,
     GetEpochManagerStatsResponse,
+    GetEpochStatsRequest,
+    GetEpochStatsResponse,
     GetIdentityResponse,
     GetNonFungibleCollectionsResponse,
     GetNonFungibleCountRequest,
@@ -62,20 +71,28 @@ use tari_indexer_client::types::{
     GetNonFungiblesResponse,
     GetRelatedTransactionsRequest,
     GetRelatedTransactionsResponse,
+    GetResourceHoldersRequest,
+    GetResourceHoldersResponse,
     GetSubstateRequest,
     GetSubstateResponse,
     GetTemplateDefinitionRequest,
     GetTemplateDefinitionResponse,
     GetTransactionResultRequest,
     GetTransactionResultResponse,
+    GetTransactionsInBlockRequest,
+    GetTransactionsInBlockResponse,
     IndexerTransactionFinalizedResult,
     InspectSubstateRequest,
     InspectSubstateResponse,
+    ListBlocksRequest,
+    ListBlocksResponse,
     ListSubstatesRequest,
     ListSubstatesResponse,
     ListTemplatesRequest,
     ListTemplatesResponse,
     NonFungibleSubstate,
+    SearchNonFungiblesRequest,
+    SearchNonFungiblesResponse,
     SubmitTransactionRequest,
     SubmitTransactionResponse,
     TemplateMetadata,
@@ -84,6 +101,7 @@ use tari_networking::{is_supported_multiaddr, NetworkingHandle, NetworkingServic
 use tari_validator_node_rpc::client::{SubstateResult, TariValidatorNodeRpcClientFactory, TransactionResultStatus};
 
 use crate::{
+    block_manager::BlockManager,
     bootstrap::Services,
     dry_run::processor::DryRunTransactionProcessor,
     json_rpc::error::internal_error,
@@ -92,6 +110,10 @@ use crate::{
 };
 
 const LOG_TARGET: &str = "tari::indexer::json_rpc::handlers";
+const DEFAULT_RESOURCE_HOLDERS_LIMIT: u64 = 20;
+const DEFAULT_ACCOUNT_TRANSACTIONS_LIMIT: u64 = 20;
+const DEFAULT_NON_FUNGIBLE_SEARCH_LIMIT: u64 = 20;
+const DEFAULT_BLOCKS_LIMIT: u64 = 20;
 
 pub struct JsonRpcHandlers {
     consensus_constants: BaseLayerConsensusConstants,
@@ -104,9 +126,11 @@ pub struct JsonRpcHandlers {
         TransactionManager<EpochManagerHandle<PeerAddress>, TariValidatorNodeRpcClientFactory, SubstateFileCache>,
     template_manager: TemplateManager<PeerAddress>,
     dry_run_transaction_processor: DryRunTransactionProcessor<SubstateFileCache>,
+    block_manager: Arc<BlockManager>,
 }
 
 impl JsonRpcHandlers {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         consensus_constants: BaseLayerConsensusConstants,
         services: &Services,
@@ -119,6 +143,7 @@ impl JsonRpcHandlers {
         >,
         template_manager: TemplateManager<PeerAddress>,
         dry_run_transaction_processor: DryRunTransactionProcessor<SubstateFileCache>,
+        block_manager: Arc<BlockManager>,
     ) -> Self {
         Self {
             consensus_constants,
@@ -130,6 +155,7 @@ impl JsonRpcHandlers {
             transaction_manager,
             template_manager,
             dry_run_transaction_processor,
+            block_manager,
         }
     }
 
@@ -263,23 +289,17 @@ impl JsonRpcHandlers {
 
     pub async fn list_substates(&self, value: JsonRpcExtractor) -> JrpcResult {
         let answer_id = value.get_answer_id();
-        let ListSubstatesRequest {
-            filter_by_template,
-            filter_by_type,
-            limit,
-            offset,
-        } = value.parse_params()?;
+        let request: ListSubstatesRequest = value.parse_params()?;
 
-        let substates = self
-            .substate_manager
-            .list_substates(filter_by_type, filter_by_template, limit, offset)
-            .await
-            .map_err(|e| {
-                warn!(target: LOG_TARGET, "Error getting substate: {}", e);
-                Self::internal_error(answer_id, format!("Error getting substate: {}", e))
-            })?;
+        let (substates, next_cursor) = self.substate_manager.list_substates(&request).await.map_err(|e| {
+            warn!(target: LOG_TARGET, "Error getting substate: {}", e);
+            Self::internal_error(answer_id, format!("Error getting substate: {}", e))
+        })?;
 
-        Ok(JsonRpcResponse::success(answer_id, ListSubstatesResponse { substates }))
+        Ok(JsonRpcResponse::success(answer_id, ListSubstatesResponse {
+            substates,
+            next_cursor,
+        }))
     }
 
     pub async fn get_substate(&self, value: JsonRpcExtractor) -> JrpcResult {
@@ -447,6 +467,168 @@ impl JsonRpcHandlers {
         }))
     }
 
+    pub async fn get_resource_holders(&self, value: JsonRpcExtractor) -> JrpcResult {
+        let answer_id = value.get_answer_id();
+        let request: GetResourceHoldersRequest = value.parse_params()?;
+
+        let holders = self
+            .substate_manager
+            .get_resource_holders(
+                &request.resource_address,
+                request.offset.unwrap_or(0),
+                request.limit.unwrap_or(DEFAULT_RESOURCE_HOLDERS_LIMIT),
+            )
+            .await
+            .map_err(|e| {
+                warn!(target: LOG_TARGET, "Error getting resource holders: {}", e);
+                Self::internal_error(answer_id, format!("Error getting resource holders: {}", e))
+            })?;
+        let total_holders = self
+            .substate_manager
+            .count_resource_holders(&request.resource_address)
+            .await
+            .map_err(|e| Self::internal_error(answer_id, e))?;
+
+        Ok(JsonRpcResponse::success(answer_id, GetResourceHoldersResponse {
+            holders,
+            total_holders,
+        }))
+    }
+
+    pub async fn get_account_portfolio(&self, value: JsonRpcExtractor) -> JrpcResult {
+        let answer_id = value.get_answer_id();
+        let request: GetAccountPortfolioRequest = value.parse_params()?;
+
+        let balances = self
+            .substate_manager
+            .get_account_portfolio(&request.account)
+            .await
+            .map_err(|e| {
+                warn!(target: LOG_TARGET, "Error getting account portfolio: {}", e);
+                Self::internal_error(answer_id, format!("Error getting account portfolio: {}", e))
+            })?;
+
+        Ok(JsonRpcResponse::success(answer_id, GetAccountPortfolioResponse {
+            balances,
+        }))
+    }
+
+    pub async fn get_account_transactions(&self, value: JsonRpcExtractor) -> JrpcResult {
+        let answer_id = value.get_answer_id();
+        let request: GetAccountTransactionsRequest = value.parse_params()?;
+
+        let (transactions, total) = self
+            .substate_manager
+            .get_account_transactions(
+                &request.account,
+                request.offset.unwrap_or(0),
+                request.limit.unwrap_or(DEFAULT_ACCOUNT_TRANSACTIONS_LIMIT),
+            )
+            .await
+            .map_err(|e| {
+                warn!(target: LOG_TARGET, "Error getting account transactions: {}", e);
+                Self::internal_error(answer_id, format!("Error getting account transactions: {}", e))
+            })?;
+
+        Ok(JsonRpcResponse::success(answer_id, GetAccountTransactionsResponse {
+            transactions,
+            total,
+        }))
+    }
+
+    pub async fn search_non_fungibles(&self, value: JsonRpcExtractor) -> JrpcResult {
+        let answer_id = value.get_answer_id();
+        let request: SearchNonFungiblesRequest = value.parse_params()?;
+
+        let non_fungibles = self
+            .substate_manager
+            .search_non_fungibles(
+                request.resource_address.as_ref(),
+                request.fields,
+                request.name_prefix.as_deref(),
+                request.offset.unwrap_or(0),
+                request.limit.unwrap_or(DEFAULT_NON_FUNGIBLE_SEARCH_LIMIT),
+            )
+            .await
+            .map_err(|e| {
+                warn!(target: LOG_TARGET, "Error searching non fungibles: {}", e);
+                Self::internal_error(answer_id, format!("Error searching non fungibles: {}", e))
+            })?;
+
+        Ok(JsonRpcResponse::success(answer_id, SearchNonFungiblesResponse {
+            non_fungibles,
+        }))
+    }
+
+    pub async fn list_blocks(&self, value: JsonRpcExtractor) -> JrpcResult {
+        let answer_id = value.get_answer_id();
+        let request: ListBlocksRequest = value.parse_params()?;
+
+        let blocks = self
+            .block_manager
+            .list_blocks(
+                request.epoch,
+                request.shard_group,
+                request.offset.unwrap_or(0),
+                request.limit.unwrap_or(DEFAULT_BLOCKS_LIMIT),
+            )
+            .await
+            .map_err(|e| {
+                warn!(target: LOG_TARGET, "Error listing blocks: {}", e);
+                Self::internal_error(answer_id, format!("Error listing blocks: {}", e))
+            })?;
+
+        Ok(JsonRpcResponse::success(answer_id, ListBlocksResponse { blocks }))
+    }
+
+    pub async fn get_block(&self, value: JsonRpcExtractor) -> JrpcResult {
+        let answer_id = value.get_answer_id();
+        let request: GetBlockRequest = value.parse_params()?;
+
+        let block = self
+            .block_manager
+            .get_block(&request.block_id)
+            .await
+            .map_err(|e| {
+                warn!(target: LOG_TARGET, "Error getting block: {}", e);
+                Self::internal_error(answer_id, format!("Error getting block: {}", e))
+            })?
+            .ok_or_else(|| Self::not_found(answer_id, format!("Block {} not found", request.block_id)))?;
+
+        Ok(JsonRpcResponse::success(answer_id, block))
+    }
+
+    pub async fn get_transactions_in_block(&self, value: JsonRpcExtractor) -> JrpcResult {
+        let answer_id = value.get_answer_id();
+        let request: GetTransactionsInBlockRequest = value.parse_params()?;
+
+        let transactions = self
+            .block_manager
+            .get_transactions_in_block(&request.block_id)
+            .await
+            .map_err(|e| {
+                warn!(target: LOG_TARGET, "Error getting transactions in block: {}", e);
+                Self::internal_error(answer_id, format!("Error getting transactions in block: {}", e))
+            })?
+            .ok_or_else(|| Self::not_found(answer_id, format!("Block {} not found", request.block_id)))?;
+
+        Ok(JsonRpcResponse::success(answer_id, GetTransactionsInBlockResponse {
+            transactions,
+        }))
+    }
+
+    pub async fn get_epoch_stats(&self, value: JsonRpcExtractor) -> JrpcResult {
+        let answer_id = value.get_answer_id();
+        let request: GetEpochStatsRequest = value.parse_params()?;
+
+        let stats = self.block_manager.get_epoch_stats(request.epoch).await.map_err(|e| {
+            warn!(target: LOG_TARGET, "Error getting epoch stats: {}", e);
+            Self::internal_error(answer_id, format!("Error getting epoch stats: {}", e))
+        })?;
+
+        Ok(JsonRpcResponse::success(answer_id, GetEpochStatsResponse { stats }))
+    }
+
     pub async fn get_non_fungibles(&self, value: JsonRpcExtractor) -> JrpcResult {
         let answer_id = value.get_answer_id();
         let request: GetNonFungiblesRequest = value.parse_params()?;
@@ -477,7 +659,7 @@ impl JsonRpcHandlers {
             let transaction_id = *request.transaction.id();
             let exec_result = self
                 .dry_run_transaction_processor
-                .process_transaction(request.transaction, request.required_substates)
+                .process_transaction(request.transaction, request.required_substates, request.trace)
                 .await
                 .map_err(|e| Self::internal_error(answer_id, e))?;
 
@@ -627,6 +809,27 @@ impl JsonRpcHandlers {
         }))
     }
 
+    pub async fn call_view(&self, value: JsonRpcExtractor) -> JrpcResult {
+        let answer_id = value.get_answer_id();
+        let CallViewRequest {
+            component_address,
+            method,
+            args,
+        } = value.parse_params()?;
+
+        let result = self
+            .dry_run_transaction_processor
+            .call_view(component_address, method, args)
+            .await
+            .map_err(|e| Self::error_response(answer_id, JsonRpcErrorReason::ApplicationError(400), e))?;
+        let json_result = json::to_value(result.indexed.value()).map_err(|e| Self::internal_error(answer_id, e))?;
+
+        Ok(JsonRpcResponse::success(answer_id, CallViewResponse {
+            result,
+            json_result,
+        }))
+    }
+
     pub async fn get_transaction_result(&self, value: JsonRpcExtractor) -> JrpcResult {
         let answer_id = value.get_answer_id();
         let request: GetTransactionResultRequest = value.parse_params()?;
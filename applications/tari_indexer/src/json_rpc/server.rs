@@ -77,7 +77,16 @@ async fn handler(Extension(handlers): Extension<Arc<JsonRpcHandlers>>, value: Js
         "get_non_fungible_collections" => handlers.get_non_fungible_collections(value).await,
         "get_non_fungible_count" => handlers.get_non_fungible_count(value).await,
         "get_non_fungibles" => handlers.get_non_fungibles(value).await,
+        "search_non_fungibles" => handlers.search_non_fungibles(value).await,
+        "get_resource_holders" => handlers.get_resource_holders(value).await,
+        "get_account_portfolio" => handlers.get_account_portfolio(value).await,
+        "get_account_transactions" => handlers.get_account_transactions(value).await,
+        "list_blocks" => handlers.list_blocks(value).await,
+        "get_block" => handlers.get_block(value).await,
+        "get_transactions_in_block" => handlers.get_transactions_in_block(value).await,
+        "get_epoch_stats" => handlers.get_epoch_stats(value).await,
         "submit_transaction" => handlers.submit_transaction(value).await,
+        "call_view" => handlers.call_view(value).await,
         "get_transaction_result" => handlers.get_transaction_result(value).await,
         "get_substate_transactions" => handlers.get_substate_transactions(value).await,
         "get_epoch_manager_stats" => handlers.get_epoch_manager_stats(value).await,
@@ -25,6 +25,7 @@ extern crate diesel;
 #[macro_use]
 extern crate diesel_migrations;
 
+mod block_manager;
 mod bootstrap;
 pub mod cli;
 pub mod config;
@@ -34,13 +35,16 @@ mod http_ui;
 
 mod event_data;
 mod event_manager;
+mod event_payload;
 mod event_scanner;
 mod json_rpc;
+mod non_fungible_metadata;
+pub mod storage;
 mod substate_manager;
-mod substate_storage_sqlite;
 mod transaction_manager;
+mod webhooks;
 
-use std::{fs, sync::Arc};
+use std::{collections::HashSet, fs, sync::Arc};
 
 use event_scanner::{EventFilter, EventScanner};
 use http_ui::server::run_http_ui_server;
@@ -60,9 +64,10 @@ use tari_epoch_manager::{EpochManagerEvent, EpochManagerReader};
 use tari_indexer_lib::substate_scanner::SubstateScanner;
 use tari_networking::NetworkingService;
 use tari_shutdown::ShutdownSignal;
-use tokio::{task, time};
+use tokio::{sync::broadcast, task, time};
 
 use crate::{
+    block_manager::BlockManager,
     bootstrap::{spawn_services, Services},
     config::ApplicationConfig,
     dry_run::processor::DryRunTransactionProcessor,
@@ -70,6 +75,7 @@ use crate::{
     graphql::server::run_graphql,
     json_rpc::{spawn_json_rpc, JsonRpcHandlers},
     transaction_manager::TransactionManager,
+    webhooks::WebhookSink,
 };
 
 const LOG_TARGET: &str = "tari::indexer::app";
@@ -125,6 +131,7 @@ pub async fn run_indexer(config: ApplicationConfig, mut shutdown_signal: Shutdow
         TransactionProcessorConfig::builder()
             .with_network(config.network)
             .with_template_binary_max_size_bytes(consensus_constants.template_binary_max_size_bytes)
+            .with_max_call_depth(consensus_constants.max_call_depth)
             .build(),
         services.epoch_manager.clone(),
         services.validator_node_client_factory.clone(),
@@ -132,6 +139,8 @@ pub async fn run_indexer(config: ApplicationConfig, mut shutdown_signal: Shutdow
         services.template_manager.clone(),
     );
 
+    let block_manager = Arc::new(BlockManager::new(services.substate_store.clone()));
+
     // Run the JSON-RPC API
     let jrpc_address = config.indexer.json_rpc_address;
     if let Some(jrpc_address) = jrpc_address {
@@ -149,6 +158,7 @@ pub async fn run_indexer(config: ApplicationConfig, mut shutdown_signal: Shutdow
             transaction_manager,
             services.template_manager.clone(),
             dry_run_transaction_processor,
+            block_manager.clone(),
         );
         let jrpc_address = spawn_json_rpc(jrpc_address, handlers)?;
         // Run the http ui
@@ -170,25 +180,61 @@ pub async fn run_indexer(config: ApplicationConfig, mut shutdown_signal: Shutdow
     ));
 
     // Run the event scanner
-    let event_filters: Vec<EventFilter> = config
+    let mut event_filters: Vec<EventFilter> = config
         .indexer
         .event_filters
         .into_iter()
         .map(TryInto::try_into)
         .collect::<Result<_, _>>()
         .map_err(|e| ExitError::new(ExitCode::ConfigError, format!("Invalid event filters: {}", e)))?;
+
+    // Webhooks are delivered from the database, so events matching any webhook must be persisted as well
+    let read_only = config.indexer.read_only;
+    let webhooks = if read_only && !config.indexer.webhooks.is_empty() {
+        warn!(
+            target: LOG_TARGET,
+            "Webhooks are not delivered by a read-only indexer, they should be configured on the indexer that scans the \
+             network"
+        );
+        vec![]
+    } else {
+        config.indexer.webhooks
+    };
+    let mut webhook_names = HashSet::new();
+    for webhook in webhooks {
+        if !webhook_names.insert(webhook.name.clone()) {
+            return Err(ExitError::new(
+                ExitCode::ConfigError,
+                format!("Duplicate webhook name '{}'", webhook.name),
+            ));
+        }
+        let name = webhook.name.clone();
+        let sink = WebhookSink::new(webhook, services.substate_store.clone())
+            .map_err(|e| ExitError::new(ExitCode::ConfigError, format!("Invalid webhook '{}': {}", name, e)))?;
+        event_filters.extend(sink.filters().iter().cloned());
+        task::spawn(sink.run(config.indexer.dan_layer_scanning_internal, shutdown_signal.clone()));
+    }
+
+    let (event_publisher, _) = broadcast::channel(1000);
     let event_scanner = EventScanner::new(
         services.epoch_manager.clone(),
         services.validator_node_client_factory.clone(),
         services.substate_store.clone(),
         event_filters,
+        event_publisher.clone(),
     );
 
     // Run the GraphQL API
     let graphql_address = config.indexer.graphql_address;
     if let Some(address) = graphql_address {
         info!(target: LOG_TARGET, "🌐 Started GraphQL server on {}", address);
-        task::spawn(run_graphql(address, substate_manager.clone(), event_manager.clone()));
+        task::spawn(run_graphql(
+            address,
+            substate_manager.clone(),
+            event_manager.clone(),
+            block_manager.clone(),
+            event_publisher,
+        ));
     }
 
     // Create pid to allow watchers to know that the process has started
@@ -197,8 +243,8 @@ pub async fn run_indexer(config: ApplicationConfig, mut shutdown_signal: Shutdow
 
     loop {
         tokio::select! {
-            // keep scanning the dan layer for new events
-            _ = time::sleep(config.indexer.dan_layer_scanning_internal) => {
+            // keep scanning the dan layer for new events, unless another indexer populates the database
+            _ = time::sleep(config.indexer.dan_layer_scanning_internal), if !read_only => {
                 match event_scanner.scan_events().await {
                     Ok(0) => {},
                     Ok(cnt) => info!(target: LOG_TARGET, "Scanned {} events(s) successfully", cnt),
@@ -0,0 +1,69 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use std::collections::BTreeMap;
+
+use tari_bor::Value;
+use tari_crypto::tari_utilities::hex::to_hex;
+
+/// Nested values deeper than this are not indexed
+const MAX_DEPTH: usize = 8;
+/// The maximum number of fields indexed for a single value
+const MAX_FIELDS: usize = 128;
+
+/// Flattens decoded NFT data or event payloads into searchable key/value pairs. Map entries are keyed by their path
+/// joined with `.` (e.g. `traits.colour`) and array items by their index (e.g. `tags.0`). A scalar value that is not in
+/// a map is keyed by the empty string.
+pub fn flatten_metadata(value: &Value) -> BTreeMap<String, String> {
+    let mut fields = BTreeMap::new();
+    flatten_into(&mut fields, String::new(), value, 0);
+    fields
+}
+
+fn flatten_into(fields: &mut BTreeMap<String, String>, path: String, value: &Value, depth: usize) {
+    if depth > MAX_DEPTH || fields.len() >= MAX_FIELDS {
+        return;
+    }
+
+    match value {
+        Value::Map(entries) => {
+            for (key, value) in entries {
+                let Some(key) = scalar_to_string(key) else {
+                    continue;
+                };
+                flatten_into(fields, join_path(&path, &key), value, depth + 1);
+            }
+        },
+        Value::Array(items) => {
+            for (i, value) in items.iter().enumerate() {
+                flatten_into(fields, join_path(&path, &i.to_string()), value, depth + 1);
+            }
+        },
+        Value::Tag(_, value) => flatten_into(fields, path, value, depth),
+        value => {
+            if let Some(value) = scalar_to_string(value) {
+                fields.insert(path, value);
+            }
+        },
+    }
+}
+
+fn join_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+fn scalar_to_string(value: &Value) -> Option<String> {
+    match value {
+        Value::Text(s) => Some(s.clone()),
+        Value::Integer(i) => Some(i128::from(*i).to_string()),
+        Value::Float(f) => Some(f.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        Value::Bytes(b) => Some(to_hex(b)),
+        Value::Tag(_, value) => scalar_to_string(value),
+        _ => None,
+    }
+}
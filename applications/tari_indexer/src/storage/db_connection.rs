@@ -0,0 +1,166 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use std::time::Duration;
+
+#[cfg(feature = "postgres")]
+use diesel::PgConnection;
+use diesel::{
+    connection::SimpleConnection,
+    r2d2::{ConnectionManager, CustomizeConnection, Pool, PooledConnection},
+    sql_query,
+    RunQueryDsl,
+    SqliteConnection,
+};
+use tari_dan_storage::StorageError;
+
+/// How long SQLite waits for a lock held by another pooled connection before failing with SQLITE_BUSY
+const SQLITE_BUSY_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A pool of connections to one of the database backends supported by the indexer
+#[derive(Clone)]
+pub enum DbPool {
+    Sqlite(Pool<ConnectionManager<SqliteConnection>>),
+    #[cfg(feature = "postgres")]
+    Postgres(Pool<ConnectionManager<PgConnection>>),
+}
+
+impl DbPool {
+    pub fn sqlite(database_url: &str) -> Result<Self, StorageError> {
+        let pool = Pool::builder()
+            .connection_customizer(Box::new(SqliteConnectionCustomizer))
+            .build(ConnectionManager::new(database_url))
+            .map_err(|e| StorageError::ConnectionError { reason: e.to_string() })?;
+        Ok(Self::Sqlite(pool))
+    }
+
+    #[cfg(feature = "postgres")]
+    pub fn postgres(database_url: &str) -> Result<Self, StorageError> {
+        let pool = Pool::builder()
+            .build(ConnectionManager::new(database_url))
+            .map_err(|e| StorageError::ConnectionError { reason: e.to_string() })?;
+        Ok(Self::Postgres(pool))
+    }
+
+    /// Checks a connection out of the pool, waiting for one to become available if necessary
+    pub fn get(&self) -> Result<DbConnection, StorageError> {
+        let connection = match self {
+            Self::Sqlite(pool) => pool.get().map(DbConnection::Sqlite),
+            #[cfg(feature = "postgres")]
+            Self::Postgres(pool) => pool.get().map(DbConnection::Postgres),
+        };
+        connection.map_err(|e| StorageError::ConnectionError { reason: e.to_string() })
+    }
+}
+
+/// Applies the per-connection SQLite settings to every connection opened by the pool
+#[derive(Debug)]
+struct SqliteConnectionCustomizer;
+
+impl CustomizeConnection<SqliteConnection, diesel::r2d2::Error> for SqliteConnectionCustomizer {
+    fn on_acquire(&self, conn: &mut SqliteConnection) -> Result<(), diesel::r2d2::Error> {
+        // WAL allows readers to proceed while another connection holds the write lock
+        conn.batch_execute(&format!(
+            "PRAGMA journal_mode = WAL; PRAGMA busy_timeout = {}; PRAGMA foreign_keys = ON;",
+            SQLITE_BUSY_TIMEOUT.as_millis()
+        ))
+        .map_err(diesel::r2d2::Error::QueryError)
+    }
+}
+
+/// A connection checked out of a [DbPool], returned to the pool on drop
+pub enum DbConnection {
+    Sqlite(PooledConnection<ConnectionManager<SqliteConnection>>),
+    #[cfg(feature = "postgres")]
+    Postgres(PooledConnection<ConnectionManager<PgConnection>>),
+}
+
+/// Evaluates `$body` with `$conn` bound to the concrete connection of the backend. The body is expanded once per
+/// backend, so queries are type-checked against each of them.
+macro_rules! with_connection {
+    ($connection:expr, |$conn:ident| $body:expr) => {
+        match $connection {
+            $crate::storage::db_connection::DbConnection::Sqlite(pooled) => {
+                let $conn: &mut ::diesel::SqliteConnection = pooled;
+                $body
+            },
+            #[cfg(feature = "postgres")]
+            $crate::storage::db_connection::DbConnection::Postgres(pooled) => {
+                let $conn: &mut ::diesel::PgConnection = pooled;
+                $body
+            },
+        }
+    };
+}
+
+pub(crate) use with_connection;
+
+/// A database transaction that is rolled back on drop unless it has been committed
+pub struct DbTransaction {
+    connection: DbConnection,
+    is_done: bool,
+}
+
+impl DbTransaction {
+    /// Begins a read transaction. SQLite only takes the database lock once the transaction first writes.
+    pub fn begin_read(connection: DbConnection) -> Result<Self, StorageError> {
+        Self::begin(connection, "BEGIN TRANSACTION")
+    }
+
+    /// Begins a write transaction. On SQLite the write lock is taken up front, so that concurrent writers wait for
+    /// each other (up to the busy timeout) instead of failing when a read lock cannot be upgraded.
+    pub fn begin_write(connection: DbConnection) -> Result<Self, StorageError> {
+        let sql = match connection {
+            DbConnection::Sqlite(_) => "BEGIN IMMEDIATE TRANSACTION",
+            #[cfg(feature = "postgres")]
+            DbConnection::Postgres(_) => "BEGIN TRANSACTION",
+        };
+        Self::begin(connection, sql)
+    }
+
+    fn begin(connection: DbConnection, sql: &str) -> Result<Self, StorageError> {
+        let mut this = Self {
+            connection,
+            is_done: false,
+        };
+        this.execute_sql(sql)?;
+        Ok(this)
+    }
+
+    pub fn connection(&mut self) -> &mut DbConnection {
+        &mut self.connection
+    }
+
+    pub fn commit(mut self) -> Result<(), StorageError> {
+        self.execute_sql("COMMIT")?;
+        self.is_done = true;
+        Ok(())
+    }
+
+    pub fn rollback(mut self) -> Result<(), StorageError> {
+        self.rollback_inner()
+    }
+
+    fn execute_sql(&mut self, sql: &str) -> Result<(), StorageError> {
+        with_connection!(self.connection(), |conn| sql_query(sql).execute(conn)).map_err(|e| {
+            StorageError::QueryError {
+                reason: format!("execute sql '{}': {}", sql, e),
+            }
+        })?;
+        Ok(())
+    }
+
+    fn rollback_inner(&mut self) -> Result<(), StorageError> {
+        self.execute_sql("ROLLBACK")?;
+        self.is_done = true;
+        Ok(())
+    }
+}
+
+impl Drop for DbTransaction {
+    fn drop(&mut self) {
+        if !self.is_done {
+            let _ignore = self.rollback_inner();
+        }
+    }
+}
@@ -0,0 +1,10 @@
+-- The id of the last event delivered to each configured webhook
+create table webhook_cursors
+(
+    id            integer not NULL primary key AUTOINCREMENT,
+    name          text    not NULL,
+    last_event_id integer not NULL,
+    updated_at    bigint  not NULL
+);
+
+create unique index webhook_cursors_unique_name on webhook_cursors (name);
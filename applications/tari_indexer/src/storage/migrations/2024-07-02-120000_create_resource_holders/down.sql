@@ -0,0 +1 @@
+drop table resource_holders;
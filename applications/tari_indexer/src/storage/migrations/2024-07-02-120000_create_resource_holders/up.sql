@@ -0,0 +1,18 @@
+-- Latest revealed balance of every scanned vault, used to answer resource holder and portfolio queries
+create table resource_holders
+(
+    id               integer not NULL primary key AUTOINCREMENT,
+    vault_id         text    not NULL,
+    resource_address text    not NULL,
+    -- The component that owns the vault, if it has been seen
+    owner            text    NULL,
+    balance          bigint  not NULL,
+    version          bigint  not NULL,
+    updated_at       bigint  not NULL
+);
+
+create unique index resource_holders_unique_vault on resource_holders (vault_id);
+
+-- DB indexes for faster holder and portfolio queries
+create index resource_holders_resource_balance on resource_holders (resource_address, balance);
+create index resource_holders_owner on resource_holders (owner);
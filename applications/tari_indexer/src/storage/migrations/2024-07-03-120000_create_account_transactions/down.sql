@@ -0,0 +1,2 @@
+drop table account_balance_changes;
+drop table account_transactions;
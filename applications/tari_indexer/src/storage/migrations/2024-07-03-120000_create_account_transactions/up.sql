@@ -0,0 +1,25 @@
+-- Transactions that touched a component that owns vaults (e.g. an account) or any of its vaults
+create table account_transactions
+(
+    id             integer not NULL primary key AUTOINCREMENT,
+    account        text    not NULL,
+    transaction_id text    not NULL,
+    timestamp      bigint  not NULL
+);
+
+create unique index account_transactions_unique_account_tx on account_transactions (account, transaction_id);
+create index account_transactions_account_timestamp on account_transactions (account, timestamp);
+
+-- Vault balance changes of an account in a transaction, derived from the substate diff
+create table account_balance_changes
+(
+    id               integer not NULL primary key AUTOINCREMENT,
+    account          text    not NULL,
+    transaction_id   text    not NULL,
+    vault_id         text    not NULL,
+    resource_address text    not NULL,
+    balance_change   bigint  not NULL
+);
+
+create unique index account_balance_changes_unique_tx_vault on account_balance_changes (transaction_id, vault_id);
+create index account_balance_changes_account_tx on account_balance_changes (account, transaction_id);
@@ -0,0 +1 @@
+drop table non_fungible_metadata;
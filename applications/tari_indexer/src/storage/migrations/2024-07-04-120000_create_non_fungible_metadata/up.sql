@@ -0,0 +1,17 @@
+-- Flattened key/value fields of the immutable and mutable data of each indexed NFT
+create table non_fungible_metadata
+(
+    id               integer not NULL primary key AUTOINCREMENT,
+    nft_address      text    not NULL,
+    resource_address text    not NULL,
+    mutable          boolean not NULL,
+    field_key        text    not NULL,
+    field_value      text    not NULL,
+    version          bigint  not NULL
+);
+
+create unique index non_fungible_metadata_unique_field on non_fungible_metadata (nft_address, mutable, field_key);
+
+-- DB indexes for faster searches within a collection and across collections
+create index non_fungible_metadata_resource_field on non_fungible_metadata (resource_address, field_key, field_value);
+create index non_fungible_metadata_field on non_fungible_metadata (field_key, field_value);
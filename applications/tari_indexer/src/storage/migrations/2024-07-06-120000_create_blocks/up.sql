@@ -0,0 +1,37 @@
+-- Blocks scanned from every committee, used to answer block explorer queries
+create table blocks
+(
+    id                integer not NULL primary key AUTOINCREMENT,
+    block_id          text    not NULL,
+    parent_id         text    not NULL,
+    epoch             bigint  not NULL,
+    shard_group       integer not NULL,
+    height            bigint  not NULL,
+    proposed_by       text    not NULL,
+    timestamp         bigint  not NULL,
+    is_dummy          boolean not NULL,
+    command_count     integer not NULL,
+    transaction_count integer not NULL,
+    total_leader_fee  bigint  not NULL,
+    -- JSON encoded block header, justify QC and commands
+    header            text    not NULL,
+    justify           text    not NULL,
+    commands          text    not NULL
+);
+
+create unique index blocks_unique_block_id on blocks (block_id);
+create index blocks_epoch_shard_group_height on blocks (epoch, shard_group, height);
+
+-- The transaction commands of each scanned block
+create table block_transactions
+(
+    id              integer not NULL primary key AUTOINCREMENT,
+    block_id        text    not NULL,
+    transaction_id  text    not NULL,
+    command         text    not NULL,
+    decision        text    not NULL,
+    transaction_fee bigint  not NULL
+);
+
+create unique index block_transactions_unique_block_tx on block_transactions (block_id, transaction_id);
+create index block_transactions_transaction_id on block_transactions (transaction_id);
@@ -0,0 +1,2 @@
+drop table resource_volumes;
+drop table resource_supply_history;
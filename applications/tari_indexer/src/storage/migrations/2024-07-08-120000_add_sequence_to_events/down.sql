@@ -0,0 +1,7 @@
+drop index events_unique_sequence;
+alter table events
+    drop column block_height;
+alter table events
+    drop column block_id;
+alter table events
+    drop column sequence;
@@ -0,0 +1,16 @@
+-- Monotonic sequence number of each event, used by consumers as a cursor to resume reading events.
+-- Existing events are numbered from 1 in insertion order, the same on SQLite and PostgreSQL, and new events continue
+-- from the last number.
+alter table events
+    add column sequence bigint not NULL default 0;
+update events
+set sequence = numbered.sequence
+from (select id, row_number() over (order by id) as sequence from events) as numbered
+where events.id = numbered.id;
+create unique index events_unique_sequence on events (sequence);
+
+-- The block in which the transaction that emitted the event was committed, unknown for events indexed before
+alter table events
+    add column block_id text NULL;
+alter table events
+    add column block_height bigint NULL;
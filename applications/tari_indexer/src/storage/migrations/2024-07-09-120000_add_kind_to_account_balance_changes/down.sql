@@ -0,0 +1,2 @@
+alter table account_balance_changes
+    drop column kind;
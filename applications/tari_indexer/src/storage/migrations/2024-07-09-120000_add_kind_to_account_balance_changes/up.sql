@@ -0,0 +1,5 @@
+-- How the balance change was derived: 'changed' is the difference from the previously indexed balance, 'created' is the
+-- initial balance of a new vault and 'unknown' marks a vault that existed before it was first indexed, so its change
+-- could not be derived. Changes indexed before were all derived from a previous balance.
+alter table account_balance_changes
+    add column kind text not NULL default 'changed';
@@ -0,0 +1,10 @@
+drop table non_fungible_metadata;
+drop table account_balance_changes;
+drop table account_transactions;
+drop table resource_holders;
+drop table webhook_cursors;
+drop table scanned_block_ids;
+drop table event_payloads;
+drop table events;
+drop table non_fungible_indexes;
+drop table substates;
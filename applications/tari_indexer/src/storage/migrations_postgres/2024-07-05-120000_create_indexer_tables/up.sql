@@ -0,0 +1,144 @@
+-- The PostgreSQL schema is equivalent to the result of all the SQLite migrations
+
+create table substates
+(
+    id               serial  not NULL primary key,
+    address          text    not NULL,
+    version          bigint  not NULL,
+    data             text    not NULL,
+    tx_hash          text    not NULL,
+    -- Template address for component substates
+    template_address text    NULL,
+    -- Name of the template module for components
+    module_name      text    NULL,
+    timestamp        bigint  not NULL
+);
+
+create unique index uniq_substates_address on substates (address);
+
+-- all the indexes in NFT resources
+create table non_fungible_indexes
+(
+    id                   serial  not NULL primary key,
+    resource_address     text    not NULL,
+    idx                  integer not NULL,
+    non_fungible_address text    not NULL,
+    FOREIGN KEY (resource_address) REFERENCES substates (address),
+    FOREIGN KEY (non_fungible_address) REFERENCES substates (address)
+);
+
+-- A list can only have one single item at any specific position
+create unique index uniq_nft_indexes on non_fungible_indexes (resource_address, idx);
+
+-- Event data
+create table events
+(
+    id               serial  not NULL primary key,
+    template_address text    not NULL,
+    tx_hash          text    not NULL,
+    topic            text    not NULL,
+    payload          text    not NULL,
+    version          integer not NULL,
+    substate_id      text    NULL,
+    timestamp        bigint  not NULL
+);
+
+-- DB index for faster collection scan queries
+create index events_indexer on events (template_address, tx_hash);
+
+-- All key-value pairs in event payloads
+create table event_payloads
+(
+    id            serial  not NULL primary key,
+    payload_key   text    not NULL,
+    payload_value text    not NULL,
+    event_id      integer not NULL,
+    FOREIGN KEY (event_id) REFERENCES events (id)
+);
+
+-- A payload key should be unique by event
+create unique index unique_event_payload_keys on event_payloads (event_id, payload_key);
+
+-- Index for faster scan queries by key and value
+create index event_payloads_key_value_index on event_payloads (payload_key, payload_value);
+
+-- Latest scanned blocks, separately by committee (epoch + shard)
+create table scanned_block_ids
+(
+    id            serial  not NULL primary key,
+    epoch         bigint  not NULL,
+    shard_group   integer not NULL,
+    last_block_id bytea   not NULL
+);
+
+-- There should only be one last scanned block by committee (epoch + shard)
+create unique index scanned_block_ids_unique_committee on scanned_block_ids (epoch, shard_group);
+
+-- The id of the last event delivered to each configured webhook
+create table webhook_cursors
+(
+    id            serial  not NULL primary key,
+    name          text    not NULL,
+    last_event_id integer not NULL,
+    updated_at    bigint  not NULL
+);
+
+create unique index webhook_cursors_unique_name on webhook_cursors (name);
+
+-- Latest revealed balance of every scanned vault
+create table resource_holders
+(
+    id               serial  not NULL primary key,
+    vault_id         text    not NULL,
+    resource_address text    not NULL,
+    owner            text    NULL,
+    balance          bigint  not NULL,
+    version          bigint  not NULL,
+    updated_at       bigint  not NULL
+);
+
+create unique index resource_holders_unique_vault on resource_holders (vault_id);
+create index resource_holders_resource_balance on resource_holders (resource_address, balance);
+create index resource_holders_owner on resource_holders (owner);
+
+-- Transactions that touched a component that owns vaults (e.g. an account) or any of its vaults
+create table account_transactions
+(
+    id             serial  not NULL primary key,
+    account        text    not NULL,
+    transaction_id text    not NULL,
+    timestamp      bigint  not NULL
+);
+
+create unique index account_transactions_unique_account_tx on account_transactions (account, transaction_id);
+create index account_transactions_account_timestamp on account_transactions (account, timestamp);
+
+-- Vault balance changes of an account in a transaction
+create table account_balance_changes
+(
+    id               serial  not NULL primary key,
+    account          text    not NULL,
+    transaction_id   text    not NULL,
+    vault_id         text    not NULL,
+    resource_address text    not NULL,
+    balance_change   bigint  not NULL
+);
+
+create unique index account_balance_changes_unique_tx_vault on account_balance_changes (transaction_id, vault_id);
+create index account_balance_changes_account_tx on account_balance_changes (account, transaction_id);
+
+-- Flattened key/value fields of the immutable and mutable data of each indexed NFT
+create table non_fungible_metadata
+(
+    id               serial  not NULL primary key,
+    nft_address      text    not NULL,
+    resource_address text    not NULL,
+    mutable          boolean not NULL,
+    field_key        text    not NULL,
+    field_value      text    not NULL,
+    version          bigint  not NULL
+);
+
+create unique index non_fungible_metadata_unique_field on non_fungible_metadata (nft_address, mutable, field_key);
+create index non_fungible_metadata_resource_field on non_fungible_metadata (resource_address, field_key, field_value);
+create index non_fungible_metadata_field on non_fungible_metadata (field_key, field_value);
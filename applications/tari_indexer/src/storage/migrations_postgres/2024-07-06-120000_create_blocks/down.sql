@@ -0,0 +1,2 @@
+drop table block_transactions;
+drop table blocks;
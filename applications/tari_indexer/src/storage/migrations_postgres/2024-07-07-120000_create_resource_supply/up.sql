@@ -0,0 +1,28 @@
+-- The total supply of a resource at every indexed version of its substate
+create table resource_supply_history
+(
+    id               serial  not NULL primary key,
+    resource_address text    not NULL,
+    version          bigint  not NULL,
+    total_supply     bigint  not NULL,
+    transaction_id   text    not NULL,
+    timestamp        bigint  not NULL
+);
+
+create unique index resource_supply_history_unique_resource_version on resource_supply_history (resource_address, version);
+create index resource_supply_history_resource_timestamp on resource_supply_history (resource_address, timestamp);
+
+-- Minted, burnt and transferred amounts of a resource in a transaction, derived from the substate diff
+create table resource_volumes
+(
+    id               serial  not NULL primary key,
+    resource_address text    not NULL,
+    transaction_id   text    not NULL,
+    timestamp        bigint  not NULL,
+    minted           bigint  not NULL,
+    burnt            bigint  not NULL,
+    transferred      bigint  not NULL
+);
+
+create unique index resource_volumes_unique_resource_tx on resource_volumes (resource_address, transaction_id);
+create index resource_volumes_resource_timestamp on resource_volumes (resource_address, timestamp);
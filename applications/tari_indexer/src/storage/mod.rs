@@ -20,6 +20,11 @@
 //  WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 //  USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+pub mod db_connection;
 pub mod models;
 mod schema;
-pub mod sqlite_substate_store_factory;
+mod sql_substate_store;
+mod substate_store;
+
+pub use sql_substate_store::*;
+pub use substate_store::*;
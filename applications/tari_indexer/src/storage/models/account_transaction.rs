@@ -0,0 +1,83 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use std::str::FromStr;
+
+use anyhow::anyhow;
+
+use crate::storage::schema::*;
+
+#[derive(Debug, Clone, Identifiable, Queryable)]
+#[diesel(table_name = account_transactions)]
+pub struct AccountTransaction {
+    pub id: i32,
+    pub account: String,
+    pub transaction_id: String,
+    pub timestamp: i64,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = account_transactions)]
+pub struct NewAccountTransaction {
+    pub account: String,
+    pub transaction_id: String,
+    pub timestamp: i64,
+}
+
+#[derive(Debug, Clone, Identifiable, Queryable)]
+#[diesel(table_name = account_balance_changes)]
+pub struct AccountBalanceChange {
+    pub id: i32,
+    pub account: String,
+    pub transaction_id: String,
+    pub vault_id: String,
+    pub resource_address: String,
+    pub balance_change: i64,
+    pub kind: String,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = account_balance_changes)]
+pub struct NewAccountBalanceChange {
+    pub account: String,
+    pub transaction_id: String,
+    pub vault_id: String,
+    pub resource_address: String,
+    /// Zero if the kind is `Unknown`
+    pub balance_change: i64,
+    pub kind: String,
+}
+
+/// How the balance change of a vault in a transaction was derived
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BalanceChangeKind {
+    /// The difference from the previously indexed balance of the vault
+    Changed,
+    /// The initial balance of a vault created in the transaction
+    Created,
+    /// The vault existed before it was first indexed, so the change is not known
+    Unknown,
+}
+
+impl BalanceChangeKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Changed => "changed",
+            Self::Created => "created",
+            Self::Unknown => "unknown",
+        }
+    }
+}
+
+impl FromStr for BalanceChangeKind {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "changed" => Ok(Self::Changed),
+            "created" => Ok(Self::Created),
+            "unknown" => Ok(Self::Unknown),
+            _ => Err(anyhow!("Invalid balance change kind '{}'", s)),
+        }
+    }
+}
@@ -0,0 +1,150 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use diesel::sql_types::{BigInt, Nullable};
+use tari_crypto::tari_utilities::hex::Hex;
+use tari_dan_storage::consensus_models;
+
+use crate::storage::schema::*;
+
+#[derive(Debug, Clone, Identifiable, Queryable)]
+#[diesel(table_name = blocks)]
+pub struct Block {
+    pub id: i32,
+    pub block_id: String,
+    pub parent_id: String,
+    pub epoch: i64,
+    pub shard_group: i32,
+    pub height: i64,
+    pub proposed_by: String,
+    pub timestamp: i64,
+    pub is_dummy: bool,
+    pub command_count: i32,
+    pub transaction_count: i32,
+    pub total_leader_fee: i64,
+    pub header: String,
+    pub justify: String,
+    pub commands: String,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = blocks)]
+pub struct NewBlock {
+    pub block_id: String,
+    pub parent_id: String,
+    pub epoch: i64,
+    pub shard_group: i32,
+    pub height: i64,
+    pub proposed_by: String,
+    pub timestamp: i64,
+    pub is_dummy: bool,
+    pub command_count: i32,
+    pub transaction_count: i32,
+    pub total_leader_fee: i64,
+    pub header: String,
+    pub justify: String,
+    pub commands: String,
+}
+
+impl TryFrom<&consensus_models::Block> for NewBlock {
+    type Error = serde_json::Error;
+
+    fn try_from(block: &consensus_models::Block) -> Result<Self, Self::Error> {
+        Ok(Self {
+            block_id: block.id().to_string(),
+            parent_id: block.parent().to_string(),
+            epoch: block.epoch().as_u64() as i64,
+            shard_group: block.shard_group().encode_as_u32() as i32,
+            height: block.height().as_u64() as i64,
+            proposed_by: block.proposed_by().to_hex(),
+            timestamp: block.timestamp() as i64,
+            is_dummy: block.is_dummy(),
+            command_count: block.command_count() as i32,
+            transaction_count: block.commands().iter().filter(|c| c.transaction().is_some()).count() as i32,
+            total_leader_fee: block.total_leader_fee() as i64,
+            header: serde_json::to_string(block.header())?,
+            justify: serde_json::to_string(block.justify())?,
+            commands: serde_json::to_string(block.commands())?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Identifiable, Queryable)]
+#[diesel(table_name = block_transactions)]
+pub struct BlockTransaction {
+    pub id: i32,
+    pub block_id: String,
+    pub transaction_id: String,
+    pub command: String,
+    pub decision: String,
+    pub transaction_fee: i64,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = block_transactions)]
+pub struct NewBlockTransaction {
+    pub block_id: String,
+    pub transaction_id: String,
+    pub command: String,
+    pub decision: String,
+    pub transaction_fee: i64,
+}
+
+impl NewBlockTransaction {
+    /// Returns a row for each transaction command in the block
+    pub fn all_from_block(block: &consensus_models::Block) -> Vec<Self> {
+        block
+            .commands()
+            .iter()
+            .filter_map(|command| {
+                let atom = command.transaction()?;
+                Some(Self {
+                    block_id: block.id().to_string(),
+                    transaction_id: atom.id().to_string(),
+                    command: command_name(command).to_string(),
+                    decision: atom.decision.to_string(),
+                    transaction_fee: atom.transaction_fee as i64,
+                })
+            })
+            .collect()
+    }
+}
+
+fn command_name(command: &consensus_models::Command) -> &'static str {
+    use consensus_models::Command;
+    match command {
+        Command::LocalOnly(_) => "LocalOnly",
+        Command::Prepare(_) => "Prepare",
+        Command::LocalPrepare(_) => "LocalPrepare",
+        Command::AllPrepare(_) => "AllPrepare",
+        Command::SomePrepare(_) => "SomePrepare",
+        Command::LocalAccept(_) => "LocalAccept",
+        Command::AllAccept(_) => "AllAccept",
+        Command::SomeAccept(_) => "SomeAccept",
+        Command::ForeignProposal(_) => "ForeignProposal",
+        Command::MintConfidentialOutput(_) => "MintConfidentialOutput",
+        Command::EvictNode(_) => "EvictNode",
+        Command::EndEpoch => "EndEpoch",
+    }
+}
+
+/// Aggregates of the blocks of an epoch. Sums are cast because PostgreSQL sums big integers as numerics.
+#[derive(Debug, QueryableByName)]
+pub struct EpochStatsRow {
+    #[diesel(sql_type = BigInt)]
+    pub num_blocks: i64,
+    #[diesel(sql_type = BigInt)]
+    pub num_dummy_blocks: i64,
+    #[diesel(sql_type = BigInt)]
+    pub num_shard_groups: i64,
+    #[diesel(sql_type = BigInt)]
+    pub num_commands: i64,
+    #[diesel(sql_type = BigInt)]
+    pub num_transactions: i64,
+    #[diesel(sql_type = BigInt)]
+    pub total_leader_fees: i64,
+    #[diesel(sql_type = Nullable<BigInt>)]
+    pub first_block_timestamp: Option<i64>,
+    #[diesel(sql_type = Nullable<BigInt>)]
+    pub last_block_timestamp: Option<i64>,
+}
@@ -23,12 +23,16 @@
 
 use std::{convert::TryFrom, str::FromStr};
 
+use async_graphql::Json;
 use diesel::sql_types::{Integer, Nullable, Text};
 use serde::{Deserialize, Serialize};
 use tari_engine_types::substate::SubstateId;
 use tari_template_lib::Hash;
 
-use crate::substate_storage_sqlite::schema::*;
+use crate::{
+    event_payload::{decode_event_payload, event_payload_to_json},
+    storage::schema::*,
+};
 
 #[derive(Debug, Identifiable, Queryable)]
 #[diesel(table_name = events)]
@@ -41,6 +45,23 @@ pub struct Event {
     pub version: i32,
     pub substate_id: Option<String>,
     pub timestamp: i64,
+    /// Monotonic position of the event in the database, assigned on insertion
+    pub sequence: i64,
+    pub block_id: Option<String>,
+    pub block_height: Option<i64>,
+}
+
+impl From<Event> for EventData {
+    fn from(event: Event) -> Self {
+        Self {
+            template_address: event.template_address,
+            tx_hash: event.tx_hash,
+            topic: event.topic,
+            payload: event.payload,
+            version: event.version,
+            substate_id: event.substate_id,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Insertable, AsChangeset)]
@@ -54,6 +75,9 @@ pub struct NewEvent {
     pub version: i32,
     pub substate_id: Option<String>,
     pub timestamp: i64,
+    /// The block in which the transaction that emitted the event was committed, if known
+    pub block_id: Option<String>,
+    pub block_height: Option<i64>,
 }
 
 #[derive(Debug, Clone, Insertable, AsChangeset)]
@@ -91,13 +115,13 @@ impl TryFrom<EventData> for crate::graphql::model::events::Event {
 
         let tx_hash = Hash::from_hex(&event_data.tx_hash)?.into_array();
 
-        let payload = serde_json::from_str(event_data.payload.as_str())?;
+        let payload = event_payload_to_json(&decode_event_payload(&event_data.payload)?)?;
 
         Ok(Self {
             substate_id,
             template_address,
             tx_hash,
-            payload,
+            payload: Json(payload),
             topic: event_data.topic,
         })
     }
@@ -114,7 +138,7 @@ impl TryFrom<EventData> for tari_engine_types::events::Event {
             .transpose()?;
         let template_address = Hash::from_hex(&event_data.template_address)?;
         let tx_hash = Hash::from_hex(&event_data.tx_hash)?;
-        let payload = serde_json::from_str(event_data.payload.as_str())?;
+        let payload = decode_event_payload(&event_data.payload)?;
 
         Ok(Self::new(
             substate_id,
@@ -144,3 +168,13 @@ pub struct NewScannedBlockId {
     pub shard_group: i32,
     pub last_block_id: Vec<u8>,
 }
+
+// To keep track of the last event delivered to each webhook
+
+#[derive(Debug, Clone, Insertable, AsChangeset)]
+#[diesel(table_name = webhook_cursors)]
+pub struct NewWebhookCursor {
+    pub name: String,
+    pub last_event_id: i32,
+    pub updated_at: i64,
+}
@@ -20,6 +20,11 @@
 //  WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 //  USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+pub mod account_transaction;
+pub mod block;
 pub mod events;
 pub mod non_fungible_index;
+pub mod non_fungible_metadata;
+pub mod resource_holder;
+pub mod resource_supply;
 pub mod substate;
@@ -25,7 +25,7 @@ use std::convert::TryFrom;
 
 use diesel::sql_types::{Integer, Text};
 
-use crate::{substate_manager::NonFungibleResponse, substate_storage_sqlite::schema::*};
+use crate::{storage::schema::*, substate_manager::NonFungibleResponse};
 #[derive(Debug, Identifiable, Queryable)]
 #[diesel(table_name = non_fungible_indexes)]
 pub struct NonFungibleIndex {
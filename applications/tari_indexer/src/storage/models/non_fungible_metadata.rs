@@ -0,0 +1,35 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use diesel::sql_types::Text;
+
+use crate::storage::schema::*;
+
+#[derive(Debug, Clone, Identifiable, Queryable)]
+#[diesel(table_name = non_fungible_metadata)]
+pub struct NonFungibleMetadataField {
+    pub id: i32,
+    pub nft_address: String,
+    pub resource_address: String,
+    pub mutable: bool,
+    pub field_key: String,
+    pub field_value: String,
+    pub version: i64,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = non_fungible_metadata)]
+pub struct NewNonFungibleMetadataField {
+    pub nft_address: String,
+    pub resource_address: String,
+    pub mutable: bool,
+    pub field_key: String,
+    pub field_value: String,
+    pub version: i64,
+}
+
+#[derive(Debug, QueryableByName)]
+pub struct NftAddressRow {
+    #[diesel(sql_type = Text)]
+    pub nft_address: String,
+}
@@ -0,0 +1,49 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use diesel::sql_types::{BigInt, Text};
+
+use crate::storage::schema::*;
+
+#[derive(Debug, Clone, Identifiable, Queryable)]
+#[diesel(table_name = resource_holders)]
+pub struct ResourceHolder {
+    pub id: i32,
+    pub vault_id: String,
+    pub resource_address: String,
+    pub owner: Option<String>,
+    pub balance: i64,
+    pub version: i64,
+    pub updated_at: i64,
+}
+
+/// The owner is not overwritten when updating an existing vault if it is `None`
+#[derive(Debug, Clone, Insertable, AsChangeset)]
+#[diesel(table_name = resource_holders)]
+pub struct NewResourceHolder {
+    pub vault_id: String,
+    pub resource_address: String,
+    pub owner: Option<String>,
+    pub balance: i64,
+    pub version: i64,
+    pub updated_at: i64,
+}
+
+/// The total balance of a resource held by an owner, or by a single vault if its owner is unknown
+#[derive(Debug, QueryableByName)]
+pub struct ResourceHolderBalance {
+    /// The owner, or the vault if its owner is unknown
+    #[diesel(sql_type = Text)]
+    pub holder: String,
+    #[diesel(sql_type = BigInt)]
+    pub total_balance: i64,
+    /// The number of the holder's vaults with a positive balance
+    #[diesel(sql_type = BigInt)]
+    pub vault_count: i64,
+}
+
+#[derive(Debug, QueryableByName)]
+pub struct HolderCount {
+    #[diesel(sql_type = BigInt)]
+    pub count: i64,
+}
@@ -0,0 +1,53 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use diesel::sql_types::BigInt;
+
+use crate::storage::schema::*;
+
+#[derive(Debug, Clone, Identifiable, Queryable)]
+#[diesel(table_name = resource_supply_history)]
+pub struct ResourceSupply {
+    pub id: i32,
+    pub resource_address: String,
+    pub version: i64,
+    pub total_supply: i64,
+    pub transaction_id: String,
+    pub timestamp: i64,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = resource_supply_history)]
+pub struct NewResourceSupply {
+    pub resource_address: String,
+    pub version: i64,
+    pub total_supply: i64,
+    pub transaction_id: String,
+    pub timestamp: i64,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = resource_volumes)]
+pub struct NewResourceVolume {
+    pub resource_address: String,
+    pub transaction_id: String,
+    pub timestamp: i64,
+    pub minted: i64,
+    pub burnt: i64,
+    pub transferred: i64,
+}
+
+/// The aggregated volume of a resource in the time bucket starting at `bucket_start`
+#[derive(Debug, Clone, QueryableByName)]
+pub struct ResourceVolumeBucket {
+    #[diesel(sql_type = BigInt)]
+    pub bucket_start: i64,
+    #[diesel(sql_type = BigInt)]
+    pub minted: i64,
+    #[diesel(sql_type = BigInt)]
+    pub burnt: i64,
+    #[diesel(sql_type = BigInt)]
+    pub transferred: i64,
+    #[diesel(sql_type = BigInt)]
+    pub transaction_count: i64,
+}
@@ -26,8 +26,8 @@ use std::convert::{TryFrom, TryInto};
 use tari_transaction::TransactionId;
 
 use crate::{
+    storage::{models::substate::Substate as SubstateRow, schema::*},
     substate_manager::SubstateResponse,
-    substate_storage_sqlite::{models::substate::Substate as SubstateRow, schema::*},
 };
 
 #[derive(Debug, Identifiable, Queryable)]
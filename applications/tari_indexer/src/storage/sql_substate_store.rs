@@ -0,0 +1,1509 @@
+//  Copyright 2022. The Tari Project
+//
+//  Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+//  following conditions are met:
+//
+//  1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+//  disclaimer.
+//
+//  2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+//  following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+//  3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+//  products derived from this software without specific prior written permission.
+//
+//  THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+//  INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+//  DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+//  SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+//  SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+//  WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+//  USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    fs::create_dir_all,
+    ops::{Deref, DerefMut},
+    path::PathBuf,
+    str::FromStr,
+};
+
+use diesel::{
+    dsl::count,
+    prelude::*,
+    sql_query,
+    sql_types::{BigInt, Integer, Nullable, Text},
+};
+use diesel_migrations::{EmbeddedMigrations, MigrationHarness};
+use log::*;
+use tari_crypto::tari_utilities::hex::to_hex;
+use tari_dan_common_types::{substate_type::SubstateType, Epoch, ShardGroup};
+use tari_dan_storage::{consensus_models::BlockId, StorageError};
+use tari_engine_types::substate::SubstateId;
+use tari_indexer_client::types::{ListSubstateItem, ListSubstatesRequest, SortOrder, SubstateOrderBy};
+use tari_template_lib::models::TemplateAddress;
+use tari_transaction::TransactionId;
+
+use super::{
+    db_connection::{with_connection, DbConnection, DbPool, DbTransaction},
+    models::{
+        account_transaction::{
+            AccountBalanceChange,
+            AccountTransaction,
+            NewAccountBalanceChange,
+            NewAccountTransaction,
+        },
+        block::{Block, BlockTransaction, EpochStatsRow, NewBlock, NewBlockTransaction},
+        events::{EventData, NewEvent, NewScannedBlockId, NewWebhookCursor},
+        non_fungible_index::{IndexedNftSubstate, NewNonFungibleIndex},
+        non_fungible_metadata::{NewNonFungibleMetadataField, NftAddressRow, NonFungibleMetadataField},
+        resource_holder::{HolderCount, NewResourceHolder, ResourceHolder, ResourceHolderBalance},
+        resource_supply::{NewResourceSupply, NewResourceVolume, ResourceSupply, ResourceVolumeBucket},
+    },
+    SubstateStore,
+    SubstateStoreReadTransaction,
+    SubstateStoreWriteTransaction,
+};
+use crate::{
+    event_payload::decode_event_payload,
+    non_fungible_metadata::flatten_metadata,
+    storage::models::{
+        events::{Event, NewEventPayloadField, ScannedBlockId},
+        substate::{NewSubstate, Substate},
+    },
+};
+
+const LOG_TARGET: &str = "tari::indexer::storage";
+/// The metadata field searched by NFT name prefix queries
+const NFT_NAME_FIELD_KEY: &str = "name";
+
+#[derive(Clone)]
+pub struct SqlSubstateStore {
+    pool: DbPool,
+    read_only: bool,
+}
+
+impl SqlSubstateStore {
+    pub fn try_create_sqlite(path: PathBuf) -> Result<Self, StorageError> {
+        create_dir_all(path.parent().unwrap()).map_err(|_| StorageError::FileSystemPathDoesNotExist)?;
+
+        let database_url = path.to_str().expect("database_url utf-8 error").to_string();
+        Ok(Self::new(DbPool::sqlite(&database_url)?))
+    }
+
+    #[cfg(feature = "postgres")]
+    pub fn try_create_postgres(database_url: &str) -> Result<Self, StorageError> {
+        Ok(Self::new(DbPool::postgres(database_url)?))
+    }
+
+    fn new(pool: DbPool) -> Self {
+        Self { pool, read_only: false }
+    }
+
+    /// Rejects write transactions, for API replicas that share a database populated by another indexer
+    pub fn with_read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    pub fn run_migrations(&self) -> Result<(), StorageError> {
+        const SQLITE_MIGRATIONS: EmbeddedMigrations = embed_migrations!("./src/storage/migrations");
+        #[cfg(feature = "postgres")]
+        const POSTGRES_MIGRATIONS: EmbeddedMigrations = embed_migrations!("./src/storage/migrations_postgres");
+
+        let mut connection = self.pool.get()?;
+        let result = match &mut connection {
+            DbConnection::Sqlite(conn) => conn.run_pending_migrations(SQLITE_MIGRATIONS).map(|_| ()),
+            #[cfg(feature = "postgres")]
+            DbConnection::Postgres(conn) => conn.run_pending_migrations(POSTGRES_MIGRATIONS).map(|_| ()),
+        };
+        result.map_err(|e| StorageError::MigrationError { reason: e.to_string() })
+    }
+
+    pub fn find_by_address(address: String, conn: &mut DbConnection) -> Result<Option<Substate>, StorageError> {
+        use crate::storage::schema::substates;
+
+        let substate_option = with_connection!(conn, |conn| substates::table
+            .filter(substates::address.eq(address))
+            .first(conn))
+        .optional()
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("find_by_address: {}", e),
+        })?;
+
+        Ok(substate_option)
+    }
+}
+
+impl SubstateStore for SqlSubstateStore {
+    type ReadTransaction<'a> = SqlSubstateStoreReadTransaction;
+    type WriteTransaction<'a> = SqlSubstateStoreWriteTransaction;
+
+    fn create_read_tx(&self) -> Result<Self::ReadTransaction<'_>, StorageError> {
+        let tx = DbTransaction::begin_read(self.pool.get()?)?;
+        Ok(SqlSubstateStoreReadTransaction::new(tx))
+    }
+
+    fn create_write_tx(&self) -> Result<Self::WriteTransaction<'_>, StorageError> {
+        if self.read_only {
+            return Err(StorageError::General {
+                details: "The substate store is read-only".to_string(),
+            });
+        }
+        let tx = DbTransaction::begin_write(self.pool.get()?)?;
+        Ok(SqlSubstateStoreWriteTransaction::new(tx))
+    }
+}
+
+pub struct SqlSubstateStoreReadTransaction {
+    transaction: DbTransaction,
+}
+
+impl SqlSubstateStoreReadTransaction {
+    fn new(transaction: DbTransaction) -> Self {
+        Self { transaction }
+    }
+
+    fn connection(&mut self) -> &mut DbConnection {
+        self.transaction.connection()
+    }
+}
+
+impl SubstateStoreReadTransaction for SqlSubstateStoreReadTransaction {
+    fn list_substates(
+        &mut self,
+        request: &ListSubstatesRequest,
+    ) -> Result<(Vec<ListSubstateItem>, Option<String>), StorageError> {
+        use crate::storage::schema::substates;
+
+        let cursor = request.cursor.as_deref().map(parse_substate_cursor).transpose()?;
+        // The boxed query is bound to a single backend, so it is built separately for each of them
+        let mut substates: Vec<Substate> = with_connection!(self.connection(), |conn| {
+            let mut query = substates::table.into_boxed();
+
+            if let Some(template_address) = request.filter_by_template {
+                query = query.filter(substates::template_address.eq(template_address.to_string()));
+            }
+
+            if let Some(substate_type) = request.filter_by_type {
+                let address_like = match substate_type {
+                    SubstateType::NonFungible => format!("resource_% {}_%", substate_type.as_prefix_str()),
+                    _ => format!("{}_%", substate_type.as_prefix_str()),
+                };
+                query = query.filter(substates::address.like(address_like));
+            }
+
+            if let Some(module_name) = &request.filter_by_module_name {
+                query = query.filter(substates::module_name.eq(module_name.clone()));
+            }
+            if let Some(transaction_id) = request.filter_by_transaction {
+                query = query.filter(substates::tx_hash.eq(transaction_id.to_string()));
+            }
+            if let Some(min_version) = request.min_version {
+                query = query.filter(substates::version.ge(i64::from(min_version)));
+            }
+            if let Some(max_version) = request.max_version {
+                query = query.filter(substates::version.le(i64::from(max_version)));
+            }
+            if let Some(from_timestamp) = request.from_timestamp {
+                query = query.filter(substates::timestamp.ge(from_timestamp as i64));
+            }
+            if let Some(to_timestamp) = request.to_timestamp {
+                query = query.filter(substates::timestamp.le(to_timestamp as i64));
+            }
+
+            // Keyset pagination: the id breaks ties so that the order is stable
+            macro_rules! order_by_key {
+                ($column:expr) => {
+                    match request.order {
+                        SortOrder::Asc => {
+                            if let Some((key, id)) = cursor {
+                                query = query.filter(
+                                    $column
+                                        .gt(key)
+                                        .or($column.eq(key).and(substates::id.gt(id))),
+                                );
+                            }
+                            query = query
+                                .order_by($column.asc())
+                                .then_order_by(substates::id.asc());
+                        },
+                        SortOrder::Desc => {
+                            if let Some((key, id)) = cursor {
+                                query = query.filter(
+                                    $column
+                                        .lt(key)
+                                        .or($column.eq(key).and(substates::id.lt(id))),
+                                );
+                            }
+                            query = query
+                                .order_by($column.desc())
+                                .then_order_by(substates::id.desc());
+                        },
+                    }
+                };
+            }
+            match request.order_by {
+                SubstateOrderBy::Indexed => match request.order {
+                    SortOrder::Asc => {
+                        if let Some((_, id)) = cursor {
+                            query = query.filter(substates::id.gt(id));
+                        }
+                        query = query.order_by(substates::id.asc());
+                    },
+                    SortOrder::Desc => {
+                        if let Some((_, id)) = cursor {
+                            query = query.filter(substates::id.lt(id));
+                        }
+                        query = query.order_by(substates::id.desc());
+                    },
+                },
+                SubstateOrderBy::Version => order_by_key!(substates::version),
+                SubstateOrderBy::Timestamp => order_by_key!(substates::timestamp),
+            }
+
+            if let Some(limit) = request.limit {
+                // Fetch one more than requested to know if there is a next page
+                query = query.limit(limit as i64 + 1);
+            }
+            if let Some(offset) = request.offset {
+                query = query.offset(offset as i64);
+            }
+
+            query.get_results(conn)
+        })
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("list_substates: {}", e),
+        })?;
+
+        let next_cursor = match request.limit {
+            Some(limit) if substates.len() as u64 > limit => {
+                substates.truncate(limit as usize);
+                substates.last().map(|s| {
+                    let key = match request.order_by {
+                        SubstateOrderBy::Indexed => i64::from(s.id),
+                        SubstateOrderBy::Version => s.version,
+                        SubstateOrderBy::Timestamp => s.timestamp,
+                    };
+                    format!("{}:{}", key, s.id)
+                })
+            },
+            _ => None,
+        };
+
+        let items = substates
+            .into_iter()
+            .map(|s| {
+                let substate_id = SubstateId::from_str(&s.address)?;
+                let version = u32::try_from(s.version)?;
+                let template_address = s.template_address.map(|h| TemplateAddress::from_hex(&h)).transpose()?;
+                let timestamp = u64::try_from(s.timestamp)?;
+                Ok(ListSubstateItem {
+                    substate_id,
+                    module_name: s.module_name,
+                    version,
+                    template_address,
+                    timestamp,
+                })
+            })
+            .collect::<Result<Vec<ListSubstateItem>, anyhow::Error>>()
+            .map_err(|e| StorageError::QueryError {
+                reason: format!("list_substates: invalid substate items: {}", e),
+            })?;
+
+        Ok((items, next_cursor))
+    }
+
+    fn get_substate(&mut self, address: &SubstateId) -> Result<Option<Substate>, StorageError> {
+        use crate::storage::schema::substates;
+
+        let substate = with_connection!(self.connection(), |conn| substates::table
+            .filter(substates::address.eq(address.to_string()))
+            .first(conn))
+        .optional()
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("get_substate: {}", e),
+        })?;
+
+        Ok(substate)
+    }
+
+    fn get_latest_version_for_substate(&mut self, address: &SubstateId) -> Result<Option<i64>, StorageError> {
+        use crate::storage::schema::substates;
+
+        let version = with_connection!(self.connection(), |conn| substates::table
+            .filter(substates::address.eq(address.to_string()))
+            .select(diesel::dsl::max(substates::version))
+            .get_result(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("get_latest_version_for_substate: {}", e),
+        })?;
+
+        Ok(version)
+    }
+
+    fn get_all_addresses(&mut self) -> Result<Vec<(String, i64)>, StorageError> {
+        use crate::storage::schema::substates;
+
+        let addresses = with_connection!(self.connection(), |conn| substates::table
+            .select((substates::address, substates::version))
+            .get_results(conn))
+        .optional()
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("get_all_addresses: {}", e),
+        })?;
+
+        match addresses {
+            Some(address_vec) => Ok(address_vec),
+            None => Ok(vec![]),
+        }
+    }
+
+    fn get_all_substates(&mut self) -> Result<Vec<Substate>, StorageError> {
+        use crate::storage::schema::substates;
+
+        let substates = with_connection!(self.connection(), |conn| substates::table.get_results(conn))
+            .optional()
+            .map_err(|e| StorageError::QueryError {
+                reason: format!("get_all_substates: {}", e),
+            })?;
+
+        match substates {
+            Some(substates_vec) => Ok(substates_vec),
+            None => Ok(vec![]),
+        }
+    }
+
+    fn get_non_fungible_collections(&mut self) -> Result<Vec<(String, i64)>, StorageError> {
+        use crate::storage::schema::non_fungible_indexes as nfts;
+
+        let collections = with_connection!(self.connection(), |conn| nfts::table
+            .group_by(nfts::resource_address)
+            .select((nfts::resource_address, count(nfts::id)))
+            .get_results(conn))
+        .optional()
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("get_all_addresses: {}", e),
+        })?;
+
+        match collections {
+            Some(collections_vec) => Ok(collections_vec),
+            None => Ok(vec![]),
+        }
+    }
+
+    fn get_non_fungible_count(&mut self, resource_address: String) -> Result<i64, StorageError> {
+        use crate::storage::schema::non_fungible_indexes;
+
+        let count = with_connection!(self.connection(), |conn| non_fungible_indexes::table
+            .filter(non_fungible_indexes::resource_address.eq(resource_address))
+            .count()
+            .get_result::<i64>(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("get_non_fungible_count: {}", e),
+        })?;
+
+        Ok(count)
+    }
+
+    fn get_non_fungible_latest_index(&mut self, resource_address: String) -> Result<Option<i32>, StorageError> {
+        use crate::storage::schema::non_fungible_indexes;
+
+        let latest_index = with_connection!(self.connection(), |conn| non_fungible_indexes::table
+            .filter(non_fungible_indexes::resource_address.eq(resource_address))
+            .select(diesel::dsl::max(non_fungible_indexes::idx))
+            .get_result(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("get_non_fungible_latest_index: {}", e),
+        })?;
+
+        Ok(latest_index)
+    }
+
+    fn get_non_fungibles(
+        &mut self,
+        resource_address: String,
+        start_idx: i32,
+        end_idx: i32,
+    ) -> Result<Vec<IndexedNftSubstate>, StorageError> {
+        let res = with_connection!(self.connection(), |conn| sql_query(
+            "SELECT s.address, CAST(s.version AS INTEGER) AS version, s.data, n.idx FROM substates s INNER JOIN \
+             non_fungible_indexes n ON s.address = n.non_fungible_address WHERE n.resource_address = $1 AND n.idx \
+             BETWEEN $2 AND $3 ORDER BY n.idx ASC",
+        )
+        .bind::<Text, _>(resource_address)
+        .bind::<Integer, _>(start_idx)
+        .bind::<Integer, _>(end_idx)
+        .get_results::<IndexedNftSubstate>(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("get_non_fungibles: {}", e),
+        })?;
+
+        Ok(res)
+    }
+
+    fn search_non_fungibles(
+        &mut self,
+        resource_address: Option<&str>,
+        fields: &[(String, String)],
+        name_prefix: Option<&str>,
+        offset: u64,
+        limit: u64,
+    ) -> Result<Vec<String>, StorageError> {
+        // Numbered placeholders are understood by both SQLite and PostgreSQL
+        let mut num_params = 0;
+        let mut next_param = || {
+            num_params += 1;
+            format!("${}", num_params)
+        };
+        let mut sql = String::from("SELECT DISTINCT m.nft_address FROM non_fungible_metadata m WHERE 1 = 1");
+        if resource_address.is_some() {
+            sql.push_str(&format!(" AND m.resource_address = {}", next_param()));
+        }
+        for _ in fields {
+            sql.push_str(&format!(
+                " AND m.nft_address IN (SELECT nft_address FROM non_fungible_metadata WHERE field_key = {} AND \
+                 field_value = {})",
+                next_param(),
+                next_param()
+            ));
+        }
+        if name_prefix.is_some() {
+            sql.push_str(&format!(
+                " AND m.nft_address IN (SELECT nft_address FROM non_fungible_metadata WHERE field_key = {} AND \
+                 LOWER(field_value) LIKE LOWER({}) ESCAPE '\\')",
+                next_param(),
+                next_param()
+            ));
+        }
+        sql.push_str(&format!(
+            " ORDER BY m.nft_address LIMIT {} OFFSET {}",
+            next_param(),
+            next_param()
+        ));
+
+        // The boxed query is bound to a single backend, so the binds are added separately for each of them
+        let rows = with_connection!(self.connection(), |conn| {
+            let mut query = sql_query(sql).into_boxed();
+            if let Some(resource_address) = resource_address {
+                query = query.bind::<Text, _>(resource_address.to_string());
+            }
+            for (key, value) in fields {
+                query = query.bind::<Text, _>(key.clone()).bind::<Text, _>(value.clone());
+            }
+            if let Some(prefix) = name_prefix {
+                let pattern = format!("{}%", escape_like_pattern(prefix));
+                query = query.bind::<Text, _>(NFT_NAME_FIELD_KEY).bind::<Text, _>(pattern);
+            }
+            query
+                .bind::<BigInt, _>(limit as i64)
+                .bind::<BigInt, _>(offset as i64)
+                .load::<NftAddressRow>(conn)
+        })
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("search_non_fungibles: {}", e),
+        })?;
+
+        Ok(rows.into_iter().map(|r| r.nft_address).collect())
+    }
+
+    fn get_non_fungible_metadata(
+        &mut self,
+        nft_addresses: &[String],
+    ) -> Result<Vec<NonFungibleMetadataField>, StorageError> {
+        use crate::storage::schema::non_fungible_metadata;
+
+        let fields = with_connection!(self.connection(), |conn| non_fungible_metadata::table
+            .filter(non_fungible_metadata::nft_address.eq_any(nft_addresses))
+            .order_by(non_fungible_metadata::field_key.asc())
+            .get_results(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("get_non_fungible_metadata: {}", e),
+        })?;
+
+        Ok(fields)
+    }
+
+    fn get_resource_holders(
+        &mut self,
+        resource_address: &str,
+        offset: u64,
+        limit: u64,
+    ) -> Result<Vec<ResourceHolderBalance>, StorageError> {
+        // Postgres sums BIGINTs into a NUMERIC, so the total is cast back
+        let holders = with_connection!(self.connection(), |conn| sql_query(
+            "SELECT COALESCE(owner, vault_id) AS holder, CAST(SUM(balance) AS BIGINT) AS total_balance, COUNT(*) AS \
+             vault_count FROM resource_holders WHERE resource_address = $1 AND balance > 0 GROUP BY COALESCE(owner, \
+             vault_id) ORDER BY total_balance DESC, holder ASC LIMIT $2 OFFSET $3",
+        )
+        .bind::<Text, _>(resource_address)
+        .bind::<BigInt, _>(limit as i64)
+        .bind::<BigInt, _>(offset as i64)
+        .get_results::<ResourceHolderBalance>(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("get_resource_holders: {}", e),
+        })?;
+
+        Ok(holders)
+    }
+
+    fn count_resource_holders(&mut self, resource_address: &str) -> Result<u64, StorageError> {
+        // Counts the same groups as get_resource_holders
+        let res = with_connection!(self.connection(), |conn| sql_query(
+            "SELECT COUNT(DISTINCT COALESCE(owner, vault_id)) AS count FROM resource_holders WHERE resource_address = \
+             $1 AND balance > 0",
+        )
+        .bind::<Text, _>(resource_address)
+        .get_result::<HolderCount>(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("count_resource_holders: {}", e),
+        })?;
+
+        Ok(res.count as u64)
+    }
+
+    fn get_resource_holders_by_owner(&mut self, owner: &str) -> Result<Vec<ResourceHolder>, StorageError> {
+        use crate::storage::schema::resource_holders;
+
+        let holders = with_connection!(self.connection(), |conn| resource_holders::table
+            .filter(resource_holders::owner.eq(owner))
+            .order_by(resource_holders::resource_address.asc())
+            .get_results(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("get_resource_holders_by_owner: {}", e),
+        })?;
+
+        Ok(holders)
+    }
+
+    fn get_resource_holder(&mut self, vault_id: &str) -> Result<Option<ResourceHolder>, StorageError> {
+        use crate::storage::schema::resource_holders;
+
+        let holder = with_connection!(self.connection(), |conn| resource_holders::table
+            .filter(resource_holders::vault_id.eq(vault_id))
+            .first(conn))
+        .optional()
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("get_resource_holder: {}", e),
+        })?;
+
+        Ok(holder)
+    }
+
+    fn get_account_transactions(
+        &mut self,
+        account: &str,
+        offset: u64,
+        limit: u64,
+    ) -> Result<Vec<AccountTransaction>, StorageError> {
+        use crate::storage::schema::account_transactions;
+
+        let transactions = with_connection!(self.connection(), |conn| account_transactions::table
+            .filter(account_transactions::account.eq(account))
+            .order_by(account_transactions::timestamp.desc())
+            .then_order_by(account_transactions::id.desc())
+            .offset(offset as i64)
+            .limit(limit as i64)
+            .get_results(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("get_account_transactions: {}", e),
+        })?;
+
+        Ok(transactions)
+    }
+
+    fn count_account_transactions(&mut self, account: &str) -> Result<u64, StorageError> {
+        use crate::storage::schema::account_transactions;
+
+        let count: i64 = with_connection!(self.connection(), |conn| account_transactions::table
+            .filter(account_transactions::account.eq(account))
+            .count()
+            .get_result(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("count_account_transactions: {}", e),
+        })?;
+
+        Ok(count as u64)
+    }
+
+    fn get_account_balance_changes(
+        &mut self,
+        account: &str,
+        transaction_ids: &[String],
+    ) -> Result<Vec<AccountBalanceChange>, StorageError> {
+        use crate::storage::schema::account_balance_changes;
+
+        let changes = with_connection!(self.connection(), |conn| account_balance_changes::table
+            .filter(account_balance_changes::account.eq(account))
+            .filter(account_balance_changes::transaction_id.eq_any(transaction_ids))
+            .order_by(account_balance_changes::id.asc())
+            .get_results(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("get_account_balance_changes: {}", e),
+        })?;
+
+        Ok(changes)
+    }
+
+    fn get_latest_resource_supply(&mut self, resource_address: &str) -> Result<Option<ResourceSupply>, StorageError> {
+        use crate::storage::schema::resource_supply_history;
+
+        let supply = with_connection!(self.connection(), |conn| resource_supply_history::table
+            .filter(resource_supply_history::resource_address.eq(resource_address))
+            .order_by(resource_supply_history::version.desc())
+            .first(conn))
+        .optional()
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("get_latest_resource_supply: {}", e),
+        })?;
+
+        Ok(supply)
+    }
+
+    fn get_resource_supply_history(
+        &mut self,
+        resource_address: &str,
+        from_timestamp: u64,
+        to_timestamp: u64,
+    ) -> Result<Vec<ResourceSupply>, StorageError> {
+        use crate::storage::schema::resource_supply_history;
+
+        let history = with_connection!(self.connection(), |conn| resource_supply_history::table
+            .filter(resource_supply_history::resource_address.eq(resource_address))
+            .filter(resource_supply_history::timestamp.ge(from_timestamp as i64))
+            .filter(resource_supply_history::timestamp.lt(to_timestamp as i64))
+            .order_by(resource_supply_history::version.asc())
+            .get_results(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("get_resource_supply_history: {}", e),
+        })?;
+
+        Ok(history)
+    }
+
+    fn get_resource_volume_buckets(
+        &mut self,
+        resource_address: &str,
+        bucket_seconds: u64,
+        from_timestamp: u64,
+        to_timestamp: u64,
+    ) -> Result<Vec<ResourceVolumeBucket>, StorageError> {
+        let buckets = with_connection!(self.connection(), |conn| sql_query(
+            "SELECT CAST((timestamp / $1) * $1 AS BIGINT) AS bucket_start, CAST(SUM(minted) AS BIGINT) AS minted, \
+             CAST(SUM(burnt) AS BIGINT) AS burnt, CAST(SUM(transferred) AS BIGINT) AS transferred, COUNT(*) AS \
+             transaction_count FROM resource_volumes WHERE resource_address = $2 AND timestamp >= $3 AND timestamp < \
+             $4 GROUP BY bucket_start ORDER BY bucket_start ASC",
+        )
+        .bind::<BigInt, _>(bucket_seconds as i64)
+        .bind::<Text, _>(resource_address)
+        .bind::<BigInt, _>(from_timestamp as i64)
+        .bind::<BigInt, _>(to_timestamp as i64)
+        .get_results::<ResourceVolumeBucket>(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("get_resource_volume_buckets: {}", e),
+        })?;
+
+        Ok(buckets)
+    }
+
+    fn list_blocks(
+        &mut self,
+        epoch: Option<Epoch>,
+        shard_group: Option<ShardGroup>,
+        offset: u64,
+        limit: u64,
+    ) -> Result<Vec<Block>, StorageError> {
+        use crate::storage::schema::blocks;
+
+        let blocks = with_connection!(self.connection(), |conn| {
+            let mut query = blocks::table.into_boxed();
+            if let Some(epoch) = epoch {
+                query = query.filter(blocks::epoch.eq(epoch.as_u64() as i64));
+            }
+            if let Some(shard_group) = shard_group {
+                query = query.filter(blocks::shard_group.eq(shard_group.encode_as_u32() as i32));
+            }
+            query
+                .order_by(blocks::epoch.desc())
+                .then_order_by(blocks::height.desc())
+                .then_order_by(blocks::id.desc())
+                .offset(offset as i64)
+                .limit(limit as i64)
+                .get_results(conn)
+        })
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("list_blocks: {}", e),
+        })?;
+
+        Ok(blocks)
+    }
+
+    fn get_block(&mut self, block_id: &BlockId) -> Result<Option<Block>, StorageError> {
+        use crate::storage::schema::blocks;
+
+        let block = with_connection!(self.connection(), |conn| blocks::table
+            .filter(blocks::block_id.eq(block_id.to_string()))
+            .first(conn))
+        .optional()
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("get_block: {}", e),
+        })?;
+
+        Ok(block)
+    }
+
+    fn get_block_transactions(&mut self, block_id: &BlockId) -> Result<Vec<BlockTransaction>, StorageError> {
+        use crate::storage::schema::block_transactions;
+
+        let transactions = with_connection!(self.connection(), |conn| block_transactions::table
+            .filter(block_transactions::block_id.eq(block_id.to_string()))
+            .order_by(block_transactions::id.asc())
+            .get_results(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("get_block_transactions: {}", e),
+        })?;
+
+        Ok(transactions)
+    }
+
+    fn get_epoch_stats(&mut self, epoch: Epoch) -> Result<EpochStatsRow, StorageError> {
+        let stats = with_connection!(self.connection(), |conn| sql_query(
+            "SELECT COUNT(*) AS num_blocks, CAST(COALESCE(SUM(CASE WHEN is_dummy THEN 1 ELSE 0 END), 0) AS BIGINT) AS \
+             num_dummy_blocks, COUNT(DISTINCT shard_group) AS num_shard_groups, CAST(COALESCE(SUM(command_count), 0) \
+             AS BIGINT) AS num_commands, CAST(COALESCE(SUM(transaction_count), 0) AS BIGINT) AS num_transactions, \
+             CAST(COALESCE(SUM(total_leader_fee), 0) AS BIGINT) AS total_leader_fees, MIN(timestamp) AS \
+             first_block_timestamp, MAX(timestamp) AS last_block_timestamp FROM blocks WHERE epoch = $1",
+        )
+        .bind::<BigInt, _>(epoch.as_u64() as i64)
+        .get_result::<EpochStatsRow>(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("get_epoch_stats: {}", e),
+        })?;
+
+        Ok(stats)
+    }
+
+    fn get_events_for_transaction(&mut self, tx_id: TransactionId) -> Result<Vec<EventData>, StorageError> {
+        info!(
+            target: LOG_TARGET,
+            "Querying substate scanner database: get_events_for_transaction with tx_hash = {}", tx_id
+        );
+        let res = with_connection!(self.connection(), |conn| sql_query(
+            "SELECT substate_id, template_address, tx_hash, topic, payload, version FROM events WHERE tx_hash = $1",
+        )
+        .bind::<Text, _>(tx_id.to_string())
+        .get_results::<EventData>(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("get_events_for_transaction: {}", e),
+        })?;
+
+        Ok(res)
+    }
+
+    fn get_stored_versions_of_events(
+        &mut self,
+        substate_id: &SubstateId,
+        start_version: u32,
+    ) -> Result<Vec<u32>, StorageError> {
+        info!(
+            target: LOG_TARGET,
+            "Querying substate scanner database: get_stored_versions_of_events with substate_id = {} and \
+             start_version = {}",
+             substate_id,
+            start_version
+        );
+        use crate::storage::schema::events;
+        let res: Vec<i32> = with_connection!(self.connection(), |conn| events::table
+            .filter(
+                events::substate_id
+                    .eq(&substate_id.to_string())
+                    .and(events::version.gt(start_version as i32)),
+            )
+            .select(events::version)
+            .get_results(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("get_last_version_of_events: {}", e),
+        })?;
+
+        // for our purposes, a non-existing version in the db, means we have
+        // to scan the network from res = 0
+        Ok(res.into_iter().map(|v| v as u32).collect::<Vec<_>>())
+    }
+
+    fn get_events_by_version(
+        &mut self,
+        substate_id: &SubstateId,
+        version: u32,
+    ) -> Result<Vec<EventData>, StorageError> {
+        info!(
+            target: LOG_TARGET,
+            "Querying substate scanner database: get_events_by_version with substate_id = {} and version = {}",
+            substate_id,
+            version
+        );
+        let res = with_connection!(self.connection(), |conn| sql_query(
+            "SELECT substate_id, template_address, tx_hash, topic, payload FROM events WHERE substate_id = $1 AND \
+             version = $2",
+        )
+        .bind::<Nullable<Text>, _>(Some(substate_id.to_string()))
+        .bind::<Integer, _>(version as i32)
+        .get_results::<EventData>(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("get_events_by_version: {}", e),
+        })?;
+
+        Ok(res)
+    }
+
+    fn get_events_by_payload(
+        &mut self,
+        payload_key: String,
+        payload_value: String,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<EventData>, StorageError> {
+        info!(
+            target: LOG_TARGET,
+            "Querying substate scanner database: get_events_by_payload with payload_key = {} and payload_value = {}",
+            payload_key,
+            payload_value
+        );
+        let res = with_connection!(self.connection(), |conn| sql_query(
+            "SELECT substate_id, template_address, tx_hash, topic, payload, version FROM events e INNER JOIN \
+             event_payloads p ON p.event_id = e.id WHERE p.payload_key = $1 AND p.payload_value = $2 LIMIT $3 OFFSET \
+             $4",
+        )
+        .bind::<Text, _>(payload_key)
+        .bind::<Text, _>(payload_value)
+        .bind::<Integer, _>(limit as i32)
+        .bind::<Integer, _>(offset as i32)
+        .get_results::<EventData>(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("get_events_by_payload: {}", e),
+        })?;
+
+        Ok(res)
+    }
+
+    fn get_all_events(&mut self, substate_id: &SubstateId) -> Result<Vec<EventData>, StorageError> {
+        let res = with_connection!(self.connection(), |conn| sql_query(
+            "SELECT substate_id, template_address, tx_hash, topic, payload, version FROM events WHERE substate_id = $1",
+        )
+        .bind::<Text, _>(substate_id.to_string())
+        .get_results::<EventData>(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("get_all_events: {}", e),
+        })?;
+        Ok(res)
+    }
+
+    fn get_events(
+        &mut self,
+        substate_id_filter: Option<SubstateId>,
+        topic_filter: Option<String>,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Event>, StorageError> {
+        // TODO: allow to query by payload as well, unifying all event methods into one
+        info!(
+            target: LOG_TARGET,
+            "Querying substate scanner database: get_events with substate_id_filter = {:?} and \
+            topic_filter = {:?}",
+            substate_id_filter,
+            topic_filter
+        );
+        use crate::storage::schema::events;
+
+        let events = with_connection!(self.connection(), |conn| {
+            let mut query = events::table.into_boxed();
+
+            if let Some(substate_id) = substate_id_filter {
+                query = query.filter(events::substate_id.eq(substate_id.to_string()));
+            }
+
+            if let Some(topic) = topic_filter {
+                query = query.filter(events::topic.eq(topic));
+            }
+
+            query = query.order_by(events::sequence.asc()).offset(offset.into());
+            if limit > 0 {
+                query = query.limit(limit.into());
+            }
+
+            query.get_results::<Event>(conn)
+        })
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("get_events: {}", e),
+        })?;
+
+        Ok(events)
+    }
+
+    fn event_exists(&mut self, value: NewEvent) -> Result<bool, StorageError> {
+        use crate::storage::schema::events;
+
+        let count = with_connection!(self.connection(), |conn| events::table
+            .filter(
+                events::substate_id
+                    .eq(value.substate_id)
+                    .and(events::template_address.eq(value.template_address))
+                    .and(events::topic.eq(value.topic))
+                    .and(events::version.eq(value.version))
+                    .and(events::payload.eq(value.payload))
+                    .and(events::tx_hash.eq(value.tx_hash)),
+            )
+            .count()
+            .get_result::<i64>(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("event_exists: {}", e),
+        })?;
+        let exists = count > 0;
+
+        Ok(exists)
+    }
+
+    fn get_events_after_id(&mut self, after_id: i32, limit: u32) -> Result<Vec<Event>, StorageError> {
+        use crate::storage::schema::events;
+
+        let events = with_connection!(self.connection(), |conn| events::table
+            .filter(events::id.gt(after_id))
+            .order_by(events::id.asc())
+            .limit(i64::from(limit))
+            .get_results::<Event>(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("get_events_after_id: {}", e),
+        })?;
+
+        Ok(events)
+    }
+
+    fn get_events_after_sequence(
+        &mut self,
+        after_sequence: u64,
+        substate_id_filter: Option<SubstateId>,
+        topic_filter: Option<String>,
+        limit: u32,
+    ) -> Result<Vec<Event>, StorageError> {
+        use crate::storage::schema::events;
+
+        let events = with_connection!(self.connection(), |conn| {
+            let mut query = events::table
+                .filter(events::sequence.gt(after_sequence as i64))
+                .into_boxed();
+
+            if let Some(substate_id) = substate_id_filter {
+                query = query.filter(events::substate_id.eq(substate_id.to_string()));
+            }
+
+            if let Some(topic) = topic_filter {
+                query = query.filter(events::topic.eq(topic));
+            }
+
+            query
+                .order_by(events::sequence.asc())
+                .limit(i64::from(limit))
+                .get_results::<Event>(conn)
+        })
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("get_events_after_sequence: {}", e),
+        })?;
+
+        Ok(events)
+    }
+
+    fn get_latest_event_id(&mut self) -> Result<Option<i32>, StorageError> {
+        use crate::storage::schema::events;
+
+        let id = with_connection!(self.connection(), |conn| events::table
+            .select(diesel::dsl::max(events::id))
+            .first(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("get_latest_event_id: {}", e),
+        })?;
+
+        Ok(id)
+    }
+
+    fn get_webhook_cursor(&mut self, name: &str) -> Result<Option<i32>, StorageError> {
+        use crate::storage::schema::webhook_cursors;
+
+        let last_event_id = with_connection!(self.connection(), |conn| webhook_cursors::table
+            .select(webhook_cursors::last_event_id)
+            .filter(webhook_cursors::name.eq(name))
+            .first(conn))
+        .optional()
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("get_webhook_cursor: {}", e),
+        })?;
+
+        Ok(last_event_id)
+    }
+
+    fn get_oldest_scanned_epoch(&mut self) -> Result<Option<Epoch>, StorageError> {
+        use crate::storage::schema::scanned_block_ids;
+
+        let res: Option<i64> = with_connection!(self.connection(), |conn| scanned_block_ids::table
+            .select(diesel::dsl::min(scanned_block_ids::epoch))
+            .first(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("get_oldest_scanned_epoch: {}", e),
+        })?;
+
+        let oldest_epoch = res
+            .map(|r| {
+                let epoch_as_u64 = r.try_into().map_err(|_| StorageError::InvalidIntegerCast)?;
+                Ok::<Epoch, StorageError>(Epoch(epoch_as_u64))
+            })
+            .transpose()?;
+
+        Ok(oldest_epoch)
+    }
+
+    fn get_last_scanned_block_id(
+        &mut self,
+        epoch: Epoch,
+        shard_group: ShardGroup,
+    ) -> Result<Option<BlockId>, StorageError> {
+        use crate::storage::schema::scanned_block_ids;
+
+        let row: Option<ScannedBlockId> = with_connection!(self.connection(), |conn| scanned_block_ids::table
+            .filter(
+                scanned_block_ids::epoch
+                    .eq(epoch.0 as i64)
+                    .and(scanned_block_ids::shard_group.eq(shard_group.encode_as_u32() as i32)),
+            )
+            .first(conn))
+        .optional()
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("get_last_scanned_block_id: {}", e),
+        })?;
+
+        let block_id_option = row.map(|r| BlockId::try_from(r.last_block_id)).transpose()?;
+
+        Ok(block_id_option)
+    }
+}
+
+pub struct SqlSubstateStoreWriteTransaction {
+    /// None indicates if the transaction has been explicitly committed/rolled back
+    transaction: Option<SqlSubstateStoreReadTransaction>,
+}
+
+impl SqlSubstateStoreWriteTransaction {
+    pub fn new(transaction: DbTransaction) -> Self {
+        Self {
+            transaction: Some(SqlSubstateStoreReadTransaction::new(transaction)),
+        }
+    }
+
+    pub fn connection(&mut self) -> &mut DbConnection {
+        self.transaction.as_mut().unwrap().connection()
+    }
+}
+
+impl SubstateStoreWriteTransaction for SqlSubstateStoreWriteTransaction {
+    fn commit(mut self) -> Result<(), StorageError> {
+        self.transaction.take().unwrap().transaction.commit()?;
+        Ok(())
+    }
+
+    fn rollback(mut self) -> Result<(), StorageError> {
+        self.transaction.take().unwrap().transaction.rollback()?;
+        Ok(())
+    }
+
+    fn set_substate(&mut self, new_substate: NewSubstate) -> Result<(), StorageError> {
+        use crate::storage::schema::substates;
+
+        let address = &new_substate.address;
+        let current_substate = SqlSubstateStore::find_by_address(address.clone(), self.connection())?;
+
+        match current_substate {
+            Some(_) => {
+                with_connection!(self.connection(), |conn| diesel::update(substates::table)
+                    .set(&new_substate)
+                    .filter(substates::address.eq(address))
+                    .execute(conn))
+                .map_err(|e| StorageError::QueryError {
+                    reason: format!("Update leaf node: {}", e),
+                })?;
+                info!(
+                    target: LOG_TARGET,
+                    "Updated substate {} version to {}", address, new_substate.version
+                );
+            },
+            None => {
+                with_connection!(self.connection(), |conn| diesel::insert_into(substates::table)
+                    .values(&new_substate)
+                    .execute(conn))
+                .map_err(|e| StorageError::QueryError {
+                    reason: format!("Update substate error: {}", e),
+                })?;
+                info!(
+                    target: LOG_TARGET,
+                    "Added new substate {} with version {}", address, new_substate.version
+                );
+            },
+        };
+
+        Ok(())
+    }
+
+    fn delete_substate(&mut self, address: String) -> Result<(), StorageError> {
+        use crate::storage::schema::substates;
+
+        with_connection!(self.connection(), |conn| diesel::delete(substates::table)
+            .filter(substates::address.eq(address))
+            .execute(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("delete substate error: {}", e),
+        })?;
+
+        Ok(())
+    }
+
+    fn clear_substates(&mut self) -> Result<(), StorageError> {
+        use crate::storage::schema::substates;
+
+        with_connection!(self.connection(), |conn| diesel::delete(substates::table).execute(conn)).map_err(|e| {
+            StorageError::QueryError {
+                reason: format!("clear_substates error: {}", e),
+            }
+        })?;
+
+        Ok(())
+    }
+
+    fn add_non_fungible_index(&mut self, new_nft_index: NewNonFungibleIndex) -> Result<(), StorageError> {
+        use crate::storage::schema::non_fungible_indexes;
+
+        with_connection!(self.connection(), |conn| diesel::insert_into(
+            non_fungible_indexes::table
+        )
+        .values(&new_nft_index)
+        .on_conflict_do_nothing()
+        .execute(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("add_non_fungible_index error: {}", e),
+        })?;
+
+        info!(
+            target: LOG_TARGET,
+            "Added new NFT index for resource {} with index {}", new_nft_index.resource_address, new_nft_index.idx
+        );
+
+        Ok(())
+    }
+
+    fn save_event(&mut self, new_event: NewEvent) -> Result<(), StorageError> {
+        use crate::storage::schema::{event_payloads, events};
+
+        // Sequence numbers must be committed in order, or a consumer resuming after a sequence number could skip an
+        // event that is committed later. SQLite write transactions already hold the database write lock, PostgreSQL
+        // ones lock the table until they end.
+        #[cfg(feature = "postgres")]
+        if let DbConnection::Postgres(conn) = self.connection() {
+            sql_query("LOCK TABLE events IN EXCLUSIVE MODE")
+                .execute(&mut **conn)
+                .map_err(|e| StorageError::QueryError {
+                    reason: format!("save_event: {}", e),
+                })?;
+        }
+        let last_sequence: Option<i64> = with_connection!(self.connection(), |conn| events::table
+            .select(diesel::dsl::max(events::sequence))
+            .first(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("save_event: {}", e),
+        })?;
+        let sequence = last_sequence.unwrap_or(0) + 1;
+
+        // Save the event into the database
+        let event_row: Event = with_connection!(self.connection(), |conn| diesel::insert_into(events::table)
+            .values((&new_event, events::sequence.eq(sequence)))
+            .get_result::<Event>(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("save_event: {}", e),
+        })?;
+
+        // Save all the key-value pairs of the payload to be able to query them later
+        let payload = decode_event_payload(&new_event.payload).map_err(|e| StorageError::QueryError {
+            reason: format!("save_event: {}", e),
+        })?;
+        let new_payload_fields = flatten_metadata(&payload)
+            .into_iter()
+            .map(|(key, value)| NewEventPayloadField {
+                payload_key: key,
+                payload_value: value,
+                event_id: event_row.id,
+            })
+            .collect::<Vec<_>>();
+        // diesel fails if we try to pass all the new rows in a single insert
+        // so the workaround is to loop over them
+        // TODO: make diesel work with a single insert instruction instead of looping
+        for field in new_payload_fields {
+            with_connection!(self.connection(), |conn| diesel::insert_into(event_payloads::table)
+                .values(&field)
+                .execute(conn))
+            .map_err(|e| StorageError::QueryError {
+                reason: format!("save_event: {}", e),
+            })?;
+        }
+        debug!(
+            target: LOG_TARGET,
+            "Added new event to the database with substate_id = {:?}, template_address = {} and for transaction \
+             hash = {}, version = {}",
+            new_event.substate_id,
+            new_event.template_address,
+            new_event.tx_hash,
+            new_event.version,
+        );
+
+        Ok(())
+    }
+
+    fn save_scanned_block_id(&mut self, new: NewScannedBlockId) -> Result<(), StorageError> {
+        use crate::storage::schema::scanned_block_ids;
+
+        with_connection!(self.connection(), |conn| diesel::insert_into(scanned_block_ids::table)
+            .values(&new)
+            .on_conflict((scanned_block_ids::epoch, scanned_block_ids::shard_group))
+            .do_update()
+            .set(new.clone())
+            .execute(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("save_scanned_block_id error: {}", e),
+        })?;
+
+        debug!(
+            target: LOG_TARGET,
+            "Added new scanned block id {} for epoch {} and shard {:?}", to_hex(&new.last_block_id), new.epoch, new.shard_group
+        );
+
+        Ok(())
+    }
+
+    fn delete_scanned_epochs_older_than(&mut self, epoch: Epoch) -> Result<(), StorageError> {
+        use crate::storage::schema::scanned_block_ids;
+
+        with_connection!(self.connection(), |conn| diesel::delete(scanned_block_ids::table)
+            .filter(scanned_block_ids::epoch.lt(epoch.as_u64() as i64))
+            .execute(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("delete_scanned_epochs_older_than: {}", e),
+        })?;
+
+        Ok(())
+    }
+
+    fn set_webhook_cursor(&mut self, cursor: NewWebhookCursor) -> Result<(), StorageError> {
+        use crate::storage::schema::webhook_cursors;
+
+        with_connection!(self.connection(), |conn| diesel::insert_into(webhook_cursors::table)
+            .values(&cursor)
+            .on_conflict(webhook_cursors::name)
+            .do_update()
+            .set(cursor.clone())
+            .execute(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("set_webhook_cursor: {}", e),
+        })?;
+
+        Ok(())
+    }
+
+    fn set_resource_holder(&mut self, holder: NewResourceHolder) -> Result<(), StorageError> {
+        use crate::storage::schema::resource_holders;
+
+        let stored_version: Option<i64> = with_connection!(self.connection(), |conn| resource_holders::table
+            .select(resource_holders::version)
+            .filter(resource_holders::vault_id.eq(&holder.vault_id))
+            .first(conn))
+        .optional()
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("set_resource_holder: {}", e),
+        })?;
+        if stored_version.map_or(false, |v| v >= holder.version) {
+            debug!(
+                target: LOG_TARGET,
+                "Ignoring version {} of vault {}, version {} is already stored",
+                holder.version,
+                holder.vault_id,
+                stored_version.unwrap_or_default()
+            );
+            return Ok(());
+        }
+
+        with_connection!(self.connection(), |conn| diesel::insert_into(resource_holders::table)
+            .values(&holder)
+            .on_conflict(resource_holders::vault_id)
+            .do_update()
+            .set(holder.clone())
+            .execute(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("set_resource_holder: {}", e),
+        })?;
+
+        Ok(())
+    }
+
+    fn save_block(&mut self, block: NewBlock, transactions: Vec<NewBlockTransaction>) -> Result<(), StorageError> {
+        use crate::storage::schema::{block_transactions, blocks};
+
+        let num_inserted = with_connection!(self.connection(), |conn| diesel::insert_into(blocks::table)
+            .values(&block)
+            .on_conflict_do_nothing()
+            .execute(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("save_block: {}", e),
+        })?;
+        if num_inserted == 0 {
+            return Ok(());
+        }
+
+        for transaction in transactions {
+            with_connection!(self.connection(), |conn| diesel::insert_into(block_transactions::table)
+                .values(&transaction)
+                .on_conflict_do_nothing()
+                .execute(conn))
+            .map_err(|e| StorageError::QueryError {
+                reason: format!("save_block: {}", e),
+            })?;
+        }
+
+        debug!(
+            target: LOG_TARGET,
+            "Added block {} (epoch={}, height={}) with {} transaction(s)", block.block_id, block.epoch, block.height, block.transaction_count
+        );
+
+        Ok(())
+    }
+
+    fn save_resource_supply(&mut self, supply: NewResourceSupply) -> Result<(), StorageError> {
+        use crate::storage::schema::resource_supply_history;
+
+        with_connection!(self.connection(), |conn| diesel::insert_into(
+            resource_supply_history::table
+        )
+        .values(&supply)
+        .on_conflict_do_nothing()
+        .execute(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("save_resource_supply: {}", e),
+        })?;
+
+        Ok(())
+    }
+
+    fn save_resource_volume(&mut self, volume: NewResourceVolume) -> Result<(), StorageError> {
+        use crate::storage::schema::resource_volumes;
+
+        with_connection!(self.connection(), |conn| diesel::insert_into(resource_volumes::table)
+            .values(&volume)
+            .on_conflict_do_nothing()
+            .execute(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("save_resource_volume: {}", e),
+        })?;
+
+        Ok(())
+    }
+
+    fn set_non_fungible_metadata(
+        &mut self,
+        nft_address: &str,
+        fields: Vec<NewNonFungibleMetadataField>,
+    ) -> Result<(), StorageError> {
+        use crate::storage::schema::non_fungible_metadata;
+
+        with_connection!(self.connection(), |conn| diesel::delete(non_fungible_metadata::table)
+            .filter(non_fungible_metadata::nft_address.eq(nft_address))
+            .execute(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("set_non_fungible_metadata: {}", e),
+        })?;
+
+        // diesel fails if we try to pass all the new rows in a single insert
+        for field in fields {
+            with_connection!(self.connection(), |conn| diesel::insert_into(
+                non_fungible_metadata::table
+            )
+            .values(&field)
+            .execute(conn))
+            .map_err(|e| StorageError::QueryError {
+                reason: format!("set_non_fungible_metadata: {}", e),
+            })?;
+        }
+
+        Ok(())
+    }
+
+    fn save_account_transaction(
+        &mut self,
+        transaction: NewAccountTransaction,
+        balance_changes: Vec<NewAccountBalanceChange>,
+    ) -> Result<(), StorageError> {
+        use crate::storage::schema::{account_balance_changes, account_transactions};
+
+        with_connection!(self.connection(), |conn| diesel::insert_into(
+            account_transactions::table
+        )
+        .values(&transaction)
+        .on_conflict_do_nothing()
+        .execute(conn))
+        .map_err(|e| StorageError::QueryError {
+            reason: format!("save_account_transaction: {}", e),
+        })?;
+
+        for change in balance_changes {
+            with_connection!(self.connection(), |conn| diesel::insert_into(
+                account_balance_changes::table
+            )
+            .values(&change)
+            .on_conflict_do_nothing()
+            .execute(conn))
+            .map_err(|e| StorageError::QueryError {
+                reason: format!("save_account_transaction: {}", e),
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Deref for SqlSubstateStoreWriteTransaction {
+    type Target = SqlSubstateStoreReadTransaction;
+
+    fn deref(&self) -> &Self::Target {
+        self.transaction.as_ref().unwrap()
+    }
+}
+
+impl DerefMut for SqlSubstateStoreWriteTransaction {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.transaction.as_mut().unwrap()
+    }
+}
+
+impl Drop for SqlSubstateStoreWriteTransaction {
+    fn drop(&mut self) {
+        if self.transaction.is_some() {
+            warn!(
+                target: LOG_TARGET,
+                "Substate store write transaction was not committed/rolled back"
+            );
+        }
+    }
+}
+
+fn escape_like_pattern(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_")
+}
+
+/// Parses a `<sort key>:<id>` substate list cursor
+fn parse_substate_cursor(cursor: &str) -> Result<(i64, i32), StorageError> {
+    cursor
+        .split_once(':')
+        .and_then(|(key, id)| Some((key.parse().ok()?, id.parse().ok()?)))
+        .ok_or_else(|| StorageError::QueryError {
+            reason: format!("list_substates: invalid cursor '{}'", cursor),
+        })
+}
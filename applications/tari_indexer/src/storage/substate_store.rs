@@ -0,0 +1,242 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use std::ops::DerefMut;
+
+use tari_dan_common_types::{Epoch, ShardGroup};
+use tari_dan_storage::{consensus_models::BlockId, StorageError};
+use tari_engine_types::substate::SubstateId;
+use tari_indexer_client::types::{ListSubstateItem, ListSubstatesRequest};
+use tari_transaction::TransactionId;
+use thiserror::Error;
+
+use crate::storage::models::{
+    account_transaction::{AccountBalanceChange, AccountTransaction, NewAccountBalanceChange, NewAccountTransaction},
+    block::{Block, BlockTransaction, EpochStatsRow, NewBlock, NewBlockTransaction},
+    events::{Event, EventData, NewEvent, NewScannedBlockId, NewWebhookCursor},
+    non_fungible_index::{IndexedNftSubstate, NewNonFungibleIndex},
+    non_fungible_metadata::{NewNonFungibleMetadataField, NonFungibleMetadataField},
+    resource_holder::{NewResourceHolder, ResourceHolder, ResourceHolderBalance},
+    resource_supply::{NewResourceSupply, NewResourceVolume, ResourceSupply, ResourceVolumeBucket},
+    substate::{NewSubstate, Substate},
+};
+
+const LOG_TARGET: &str = "tari::indexer::storage";
+
+/// The indexer's storage. The indexer services depend on this trait rather than on a particular database backend.
+pub trait SubstateStore {
+    type ReadTransaction<'a>: SubstateStoreReadTransaction
+    where Self: 'a;
+    type WriteTransaction<'a>: SubstateStoreWriteTransaction + DerefMut<Target = Self::ReadTransaction<'a>>
+    where Self: 'a;
+
+    fn create_read_tx(&self) -> Result<Self::ReadTransaction<'_>, StorageError>;
+    fn create_write_tx(&self) -> Result<Self::WriteTransaction<'_>, StorageError>;
+
+    fn with_write_tx<F: FnOnce(&mut Self::WriteTransaction<'_>) -> Result<R, E>, R, E>(&self, f: F) -> Result<R, E>
+    where E: From<StorageError> {
+        let mut tx = self.create_write_tx()?;
+        match f(&mut tx) {
+            Ok(r) => {
+                tx.commit()?;
+                Ok(r)
+            },
+            Err(e) => {
+                if let Err(err) = tx.rollback() {
+                    log::error!(target: LOG_TARGET, "Failed to rollback transaction: {}", err);
+                }
+                Err(e)
+            },
+        }
+    }
+
+    fn with_read_tx<F: FnOnce(&mut Self::ReadTransaction<'_>) -> Result<R, E>, R, E>(&self, f: F) -> Result<R, E>
+    where E: From<StorageError> {
+        let mut tx = self.create_read_tx()?;
+        let ret = f(&mut tx)?;
+        Ok(ret)
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum StoreError {
+    #[error("Storage error: {details}")]
+    StorageError { details: String },
+}
+
+impl From<StorageError> for StoreError {
+    fn from(err: StorageError) -> Self {
+        Self::StorageError {
+            details: err.to_string(),
+        }
+    }
+}
+
+// TODO: remove the allow dead_code attributes as these become used.
+pub trait SubstateStoreReadTransaction {
+    /// Returns the substates matching the request and the cursor of the next page, if there are more results
+    fn list_substates(
+        &mut self,
+        request: &ListSubstatesRequest,
+    ) -> Result<(Vec<ListSubstateItem>, Option<String>), StorageError>;
+    fn get_substate(&mut self, address: &SubstateId) -> Result<Option<Substate>, StorageError>;
+    #[allow(dead_code)]
+    fn get_latest_version_for_substate(&mut self, address: &SubstateId) -> Result<Option<i64>, StorageError>;
+    #[allow(dead_code)]
+    fn get_all_addresses(&mut self) -> Result<Vec<(String, i64)>, StorageError>;
+    #[allow(dead_code)]
+    fn get_all_substates(&mut self) -> Result<Vec<Substate>, StorageError>;
+    fn get_non_fungible_collections(&mut self) -> Result<Vec<(String, i64)>, StorageError>;
+    fn get_non_fungible_count(&mut self, resource_address: String) -> Result<i64, StorageError>;
+    #[allow(dead_code)]
+    fn get_non_fungible_latest_index(&mut self, resource_address: String) -> Result<Option<i32>, StorageError>;
+    #[allow(dead_code)]
+    fn get_non_fungibles(
+        &mut self,
+        resource_address: String,
+        start_idx: i32,
+        end_idx: i32,
+    ) -> Result<Vec<IndexedNftSubstate>, StorageError>;
+    /// Returns the addresses of the NFTs that have all of the given metadata fields and, if given, a name starting with
+    /// `name_prefix`. Results are ordered by address.
+    fn search_non_fungibles(
+        &mut self,
+        resource_address: Option<&str>,
+        fields: &[(String, String)],
+        name_prefix: Option<&str>,
+        offset: u64,
+        limit: u64,
+    ) -> Result<Vec<String>, StorageError>;
+    fn get_non_fungible_metadata(
+        &mut self,
+        nft_addresses: &[String],
+    ) -> Result<Vec<NonFungibleMetadataField>, StorageError>;
+    /// Returns the owners (or vaults, if the owner is unknown) holding a positive balance of the resource with the
+    /// total balance of their vaults, largest balance first
+    fn get_resource_holders(
+        &mut self,
+        resource_address: &str,
+        offset: u64,
+        limit: u64,
+    ) -> Result<Vec<ResourceHolderBalance>, StorageError>;
+    /// Counts the distinct owners (or vaults, if the owner is unknown) holding a positive balance of the resource
+    fn count_resource_holders(&mut self, resource_address: &str) -> Result<u64, StorageError>;
+    fn get_resource_holders_by_owner(&mut self, owner: &str) -> Result<Vec<ResourceHolder>, StorageError>;
+    fn get_resource_holder(&mut self, vault_id: &str) -> Result<Option<ResourceHolder>, StorageError>;
+    /// Returns the transactions that touched the account, newest first
+    fn get_account_transactions(
+        &mut self,
+        account: &str,
+        offset: u64,
+        limit: u64,
+    ) -> Result<Vec<AccountTransaction>, StorageError>;
+    fn count_account_transactions(&mut self, account: &str) -> Result<u64, StorageError>;
+    fn get_account_balance_changes(
+        &mut self,
+        account: &str,
+        transaction_ids: &[String],
+    ) -> Result<Vec<AccountBalanceChange>, StorageError>;
+    fn get_latest_resource_supply(&mut self, resource_address: &str) -> Result<Option<ResourceSupply>, StorageError>;
+    fn get_resource_supply_history(
+        &mut self,
+        resource_address: &str,
+        from_timestamp: u64,
+        to_timestamp: u64,
+    ) -> Result<Vec<ResourceSupply>, StorageError>;
+    fn get_resource_volume_buckets(
+        &mut self,
+        resource_address: &str,
+        bucket_seconds: u64,
+        from_timestamp: u64,
+        to_timestamp: u64,
+    ) -> Result<Vec<ResourceVolumeBucket>, StorageError>;
+    /// Returns the scanned blocks, newest first
+    fn list_blocks(
+        &mut self,
+        epoch: Option<Epoch>,
+        shard_group: Option<ShardGroup>,
+        offset: u64,
+        limit: u64,
+    ) -> Result<Vec<Block>, StorageError>;
+    fn get_block(&mut self, block_id: &BlockId) -> Result<Option<Block>, StorageError>;
+    fn get_block_transactions(&mut self, block_id: &BlockId) -> Result<Vec<BlockTransaction>, StorageError>;
+    fn get_epoch_stats(&mut self, epoch: Epoch) -> Result<EpochStatsRow, StorageError>;
+    fn get_events_for_transaction(&mut self, tx_id: TransactionId) -> Result<Vec<EventData>, StorageError>;
+    fn get_stored_versions_of_events(
+        &mut self,
+        substate_id: &SubstateId,
+        start_version: u32,
+    ) -> Result<Vec<u32>, StorageError>;
+    #[allow(dead_code)]
+    fn get_events_by_version(&mut self, substate_id: &SubstateId, version: u32)
+        -> Result<Vec<EventData>, StorageError>;
+    fn get_all_events(&mut self, substate_id: &SubstateId) -> Result<Vec<EventData>, StorageError>;
+    fn get_events_by_payload(
+        &mut self,
+        payload_key: String,
+        payload_value: String,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<EventData>, StorageError>;
+    fn get_events(
+        &mut self,
+        substate_id_filter: Option<SubstateId>,
+        topic_filter: Option<String>,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Event>, StorageError>;
+    fn event_exists(&mut self, event: NewEvent) -> Result<bool, StorageError>;
+    /// Returns up to `limit` stored events with an id greater than `after_id`, in insertion order
+    fn get_events_after_id(&mut self, after_id: i32, limit: u32) -> Result<Vec<Event>, StorageError>;
+    /// Returns the events with a sequence number greater than `after_sequence`, in sequence order
+    fn get_events_after_sequence(
+        &mut self,
+        after_sequence: u64,
+        substate_id_filter: Option<SubstateId>,
+        topic_filter: Option<String>,
+        limit: u32,
+    ) -> Result<Vec<Event>, StorageError>;
+    fn get_latest_event_id(&mut self) -> Result<Option<i32>, StorageError>;
+    fn get_webhook_cursor(&mut self, name: &str) -> Result<Option<i32>, StorageError>;
+    fn get_oldest_scanned_epoch(&mut self) -> Result<Option<Epoch>, StorageError>;
+    fn get_last_scanned_block_id(
+        &mut self,
+        epoch: Epoch,
+        shard_group: ShardGroup,
+    ) -> Result<Option<BlockId>, StorageError>;
+}
+
+// TODO: remove the allow dead_code attributes as these become used.
+pub trait SubstateStoreWriteTransaction {
+    fn commit(self) -> Result<(), StorageError>;
+    fn rollback(self) -> Result<(), StorageError>;
+    fn set_substate(&mut self, new_substate: NewSubstate) -> Result<(), StorageError>;
+    #[allow(dead_code)]
+    fn delete_substate(&mut self, address: String) -> Result<(), StorageError>;
+    #[allow(dead_code)]
+    fn clear_substates(&mut self) -> Result<(), StorageError>;
+    #[allow(dead_code)]
+    fn add_non_fungible_index(&mut self, new_nft_index: NewNonFungibleIndex) -> Result<(), StorageError>;
+    fn save_event(&mut self, new_event: NewEvent) -> Result<(), StorageError>;
+    fn save_scanned_block_id(&mut self, new_scanned_block_id: NewScannedBlockId) -> Result<(), StorageError>;
+    fn delete_scanned_epochs_older_than(&mut self, epoch: Epoch) -> Result<(), StorageError>;
+    fn set_webhook_cursor(&mut self, cursor: NewWebhookCursor) -> Result<(), StorageError>;
+    /// Inserts or updates the indexed vault. Versions that are not newer than the stored version are ignored.
+    fn set_resource_holder(&mut self, holder: NewResourceHolder) -> Result<(), StorageError>;
+    /// Records the block and its transactions. Blocks that were already recorded are ignored.
+    fn save_block(&mut self, block: NewBlock, transactions: Vec<NewBlockTransaction>) -> Result<(), StorageError>;
+    fn save_resource_supply(&mut self, supply: NewResourceSupply) -> Result<(), StorageError>;
+    fn save_resource_volume(&mut self, volume: NewResourceVolume) -> Result<(), StorageError>;
+    /// Replaces all indexed metadata fields of the NFT
+    fn set_non_fungible_metadata(
+        &mut self,
+        nft_address: &str,
+        fields: Vec<NewNonFungibleMetadataField>,
+    ) -> Result<(), StorageError>;
+    /// Records the account transaction and its balance changes. Transactions that were already recorded are ignored.
+    fn save_account_transaction(
+        &mut self,
+        transaction: NewAccountTransaction,
+        balance_changes: Vec<NewAccountBalanceChange>,
+    ) -> Result<(), StorageError>;
+}
@@ -20,22 +20,39 @@
 //  WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 //  USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use std::{convert::TryInto, sync::Arc};
+use std::{
+    collections::{BTreeMap, HashMap},
+    convert::TryInto,
+    str::FromStr,
+    sync::Arc,
+};
 
+use anyhow::anyhow;
 use serde::{Deserialize, Serialize};
 use tari_common_types::types::FixedHash;
 use tari_dan_app_utilities::substate_file_cache::SubstateFileCache;
-use tari_dan_common_types::{substate_type::SubstateType, PeerAddress};
+use tari_dan_common_types::PeerAddress;
 use tari_engine_types::substate::{Substate, SubstateId};
 use tari_epoch_manager::base_layer::EpochManagerHandle;
-use tari_indexer_client::types::ListSubstateItem;
+use tari_indexer_client::types::{
+    AccountBalanceChange,
+    AccountTransaction,
+    BalanceChangeKind,
+    ListSubstateItem,
+    ListSubstatesRequest,
+    NonFungibleMetadata,
+    PortfolioBalance,
+    ResourceHolder,
+    TransferDirection,
+};
 use tari_indexer_lib::{substate_scanner::SubstateScanner, NonFungibleSubstate};
-use tari_template_lib::models::TemplateAddress;
+use tari_template_lib::models::{Amount, ComponentAddress, ResourceAddress};
 use tari_transaction::TransactionId;
 use tari_validator_node_rpc::client::{SubstateResult, TariValidatorNodeRpcClientFactory};
 
-use crate::substate_storage_sqlite::sqlite_substate_store_factory::{
-    SqliteSubstateStore,
+use crate::storage::{
+    models::{account_transaction, resource_holder, resource_supply},
+    SqlSubstateStore,
     SubstateStore,
     SubstateStoreReadTransaction,
 };
@@ -71,18 +88,35 @@ pub struct EventResponse {
     pub created_by_transaction: FixedHash,
 }
 
-pub struct SubstateManager {
+/// The total supply of a resource at the end of a time bucket
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResourceSupplyBucket {
+    pub bucket_start: u64,
+    pub total_supply: Amount,
+}
+
+/// The minted, burnt and transferred amounts of a resource in a time bucket
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResourceVolumeBucket {
+    pub bucket_start: u64,
+    pub minted: Amount,
+    pub burnt: Amount,
+    pub transferred: Amount,
+    pub transaction_count: u64,
+}
+
+pub struct SubstateManager<TStore = SqlSubstateStore> {
     substate_scanner:
         Arc<SubstateScanner<EpochManagerHandle<PeerAddress>, TariValidatorNodeRpcClientFactory, SubstateFileCache>>,
-    substate_store: SqliteSubstateStore,
+    substate_store: TStore,
 }
 
-impl SubstateManager {
+impl<TStore: SubstateStore> SubstateManager<TStore> {
     pub fn new(
         dan_layer_scanner: Arc<
             SubstateScanner<EpochManagerHandle<PeerAddress>, TariValidatorNodeRpcClientFactory, SubstateFileCache>,
         >,
-        substate_store: SqliteSubstateStore,
+        substate_store: TStore,
     ) -> Self {
         Self {
             substate_scanner: dan_layer_scanner,
@@ -90,16 +124,14 @@ impl SubstateManager {
         }
     }
 
+    /// Returns the indexed substates matching the request and the cursor of the next page, if any
     pub async fn list_substates(
         &self,
-        filter_by_type: Option<SubstateType>,
-        filter_by_template: Option<TemplateAddress>,
-        limit: Option<u64>,
-        offset: Option<u64>,
-    ) -> Result<Vec<ListSubstateItem>, anyhow::Error> {
+        request: &ListSubstatesRequest,
+    ) -> Result<(Vec<ListSubstateItem>, Option<String>), anyhow::Error> {
         let mut tx = self.substate_store.create_read_tx()?;
-        let substates = tx.list_substates(filter_by_type, filter_by_template, limit, offset)?;
-        Ok(substates)
+        let page = tx.list_substates(request)?;
+        Ok(page)
     }
 
     pub async fn get_substate(
@@ -196,4 +228,278 @@ impl SubstateManager {
 
         Ok(non_fungibles)
     }
+
+    /// Searches the indexed NFT metadata. `fields` must all match exactly.
+    pub async fn search_non_fungibles(
+        &self,
+        resource_address: Option<&ResourceAddress>,
+        fields: BTreeMap<String, String>,
+        name_prefix: Option<&str>,
+        offset: u64,
+        limit: u64,
+    ) -> Result<Vec<NonFungibleMetadata>, anyhow::Error> {
+        let fields = fields.into_iter().collect::<Vec<_>>();
+        let mut tx = self.substate_store.create_read_tx()?;
+        let addresses = tx.search_non_fungibles(
+            resource_address.map(|r| r.to_string()).as_deref(),
+            &fields,
+            name_prefix,
+            offset,
+            limit,
+        )?;
+
+        let mut metadata = addresses
+            .iter()
+            .map(|address| {
+                Ok((address.clone(), NonFungibleMetadata {
+                    address: SubstateId::from_str(address)?,
+                    data: BTreeMap::new(),
+                    mutable_data: BTreeMap::new(),
+                }))
+            })
+            .collect::<Result<HashMap<_, _>, anyhow::Error>>()?;
+        for field in tx.get_non_fungible_metadata(&addresses)? {
+            if let Some(nft) = metadata.get_mut(&field.nft_address) {
+                if field.mutable {
+                    nft.mutable_data.insert(field.field_key, field.field_value);
+                } else {
+                    nft.data.insert(field.field_key, field.field_value);
+                }
+            }
+        }
+
+        // Keep the search result order
+        Ok(addresses.iter().filter_map(|a| metadata.remove(a)).collect())
+    }
+
+    pub async fn get_resource_holders(
+        &self,
+        resource_address: &ResourceAddress,
+        offset: u64,
+        limit: u64,
+    ) -> Result<Vec<ResourceHolder>, anyhow::Error> {
+        let mut tx = self.substate_store.create_read_tx()?;
+        let rows = tx.get_resource_holders(&resource_address.to_string(), offset, limit)?;
+        rows.into_iter().map(Self::to_resource_holder).collect()
+    }
+
+    pub async fn count_resource_holders(&self, resource_address: &ResourceAddress) -> Result<u64, anyhow::Error> {
+        let mut tx = self.substate_store.create_read_tx()?;
+        let count = tx.count_resource_holders(&resource_address.to_string())?;
+        Ok(count)
+    }
+
+    /// Returns the indexed balance of every vault owned by the account, including empty vaults
+    pub async fn get_account_portfolio(
+        &self,
+        account: &ComponentAddress,
+    ) -> Result<Vec<PortfolioBalance>, anyhow::Error> {
+        let owner = SubstateId::from(*account).to_string();
+        let mut tx = self.substate_store.create_read_tx()?;
+        let rows = tx.get_resource_holders_by_owner(&owner)?;
+        rows.into_iter().map(Self::to_portfolio_balance).collect()
+    }
+
+    pub async fn get_account_transactions(
+        &self,
+        account: &ComponentAddress,
+        offset: u64,
+        limit: u64,
+    ) -> Result<(Vec<AccountTransaction>, u64), anyhow::Error> {
+        let account = SubstateId::from(*account).to_string();
+        let mut tx = self.substate_store.create_read_tx()?;
+        let total = tx.count_account_transactions(&account)?;
+        let rows = tx.get_account_transactions(&account, offset, limit)?;
+        let transaction_ids = rows.iter().map(|r| r.transaction_id.clone()).collect::<Vec<_>>();
+
+        let mut changes_by_tx = HashMap::<_, Vec<_>>::new();
+        for change in tx.get_account_balance_changes(&account, &transaction_ids)? {
+            changes_by_tx
+                .entry(change.transaction_id.clone())
+                .or_default()
+                .push(Self::to_account_balance_change(change)?);
+        }
+
+        let transactions = rows
+            .into_iter()
+            .map(|row| {
+                Ok(AccountTransaction {
+                    transaction_id: TransactionId::from_hex(&row.transaction_id)?,
+                    timestamp: row.timestamp as u64,
+                    balance_changes: changes_by_tx.remove(&row.transaction_id).unwrap_or_default(),
+                })
+            })
+            .collect::<Result<_, anyhow::Error>>()?;
+
+        Ok((transactions, total))
+    }
+
+    /// Returns the total supply of the resource at the end of every time bucket in which it changed. Timestamps are
+    /// in seconds and `to_timestamp` is exclusive.
+    pub async fn get_resource_supply_history(
+        &self,
+        resource_address: &ResourceAddress,
+        bucket_seconds: u64,
+        from_timestamp: u64,
+        to_timestamp: u64,
+    ) -> Result<Vec<ResourceSupplyBucket>, anyhow::Error> {
+        if bucket_seconds == 0 {
+            return Err(anyhow!("The bucket size must be greater than zero"));
+        }
+        let mut tx = self.substate_store.create_read_tx()?;
+        let rows = tx.get_resource_supply_history(
+            &resource_address.to_string(),
+            from_timestamp,
+            to_timestamp.min(i64::MAX as u64),
+        )?;
+
+        Ok(Self::to_resource_supply_buckets(rows, bucket_seconds))
+    }
+
+    /// Returns the supply at the end of each bucket from supply rows ordered by version
+    fn to_resource_supply_buckets(
+        rows: Vec<resource_supply::ResourceSupply>,
+        bucket_seconds: u64,
+    ) -> Vec<ResourceSupplyBucket> {
+        // The last row of each bucket holds the supply at its end
+        let mut buckets = BTreeMap::new();
+        for row in rows {
+            let bucket_start = (row.timestamp as u64 / bucket_seconds) * bucket_seconds;
+            buckets.insert(bucket_start, Amount::new(row.total_supply));
+        }
+
+        buckets
+            .into_iter()
+            .map(|(bucket_start, total_supply)| ResourceSupplyBucket {
+                bucket_start,
+                total_supply,
+            })
+            .collect()
+    }
+
+    /// Returns the volume of the resource in every time bucket in which it was minted, burnt or transferred.
+    /// Timestamps are in seconds and `to_timestamp` is exclusive.
+    pub async fn get_resource_volume(
+        &self,
+        resource_address: &ResourceAddress,
+        bucket_seconds: u64,
+        from_timestamp: u64,
+        to_timestamp: u64,
+    ) -> Result<Vec<ResourceVolumeBucket>, anyhow::Error> {
+        if bucket_seconds == 0 {
+            return Err(anyhow!("The bucket size must be greater than zero"));
+        }
+        let mut tx = self.substate_store.create_read_tx()?;
+        let rows = tx.get_resource_volume_buckets(
+            &resource_address.to_string(),
+            bucket_seconds,
+            from_timestamp,
+            to_timestamp.min(i64::MAX as u64),
+        )?;
+
+        Ok(rows.into_iter().map(Self::to_resource_volume_bucket).collect())
+    }
+
+    fn to_resource_volume_bucket(row: resource_supply::ResourceVolumeBucket) -> ResourceVolumeBucket {
+        ResourceVolumeBucket {
+            bucket_start: row.bucket_start as u64,
+            minted: Amount::new(row.minted),
+            burnt: Amount::new(row.burnt),
+            transferred: Amount::new(row.transferred),
+            transaction_count: row.transaction_count as u64,
+        }
+    }
+
+    fn to_account_balance_change(
+        row: account_transaction::AccountBalanceChange,
+    ) -> Result<AccountBalanceChange, anyhow::Error> {
+        let direction = if row.balance_change < 0 {
+            TransferDirection::Out
+        } else {
+            TransferDirection::In
+        };
+        let kind = match account_transaction::BalanceChangeKind::from_str(&row.kind)? {
+            account_transaction::BalanceChangeKind::Changed => BalanceChangeKind::Changed,
+            account_transaction::BalanceChangeKind::Created => BalanceChangeKind::Created,
+            account_transaction::BalanceChangeKind::Unknown => BalanceChangeKind::Unknown,
+        };
+        Ok(AccountBalanceChange {
+            resource_address: ResourceAddress::from_str(&row.resource_address)?,
+            vault_id: SubstateId::from_str(&row.vault_id)?,
+            kind,
+            direction,
+            amount: Amount::new(row.balance_change.abs()),
+        })
+    }
+
+    fn to_resource_holder(row: resource_holder::ResourceHolderBalance) -> Result<ResourceHolder, anyhow::Error> {
+        Ok(ResourceHolder {
+            holder: SubstateId::from_str(&row.holder)?,
+            balance: Amount::new(row.total_balance),
+            vault_count: row.vault_count as u64,
+        })
+    }
+
+    fn to_portfolio_balance(row: resource_holder::ResourceHolder) -> Result<PortfolioBalance, anyhow::Error> {
+        Ok(PortfolioBalance {
+            resource_address: ResourceAddress::from_str(&row.resource_address)?,
+            vault_id: SubstateId::from_str(&row.vault_id)?,
+            balance: Amount::new(row.balance),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn supply(version: i64, total_supply: i64, timestamp: i64) -> resource_supply::ResourceSupply {
+        resource_supply::ResourceSupply {
+            id: version as i32,
+            resource_address: "resource_a".to_string(),
+            version,
+            total_supply,
+            transaction_id: "00".repeat(32),
+            timestamp,
+        }
+    }
+
+    #[test]
+    fn it_returns_the_supply_at_the_end_of_each_bucket() {
+        let rows = vec![
+            supply(0, 100, 5),
+            supply(1, 150, 9),
+            supply(2, 120, 25),
+            supply(3, 200, 60),
+            supply(4, 180, 61),
+        ];
+
+        let buckets = <SubstateManager>::to_resource_supply_buckets(rows, 10)
+            .into_iter()
+            .map(|b| (b.bucket_start, b.total_supply.value()))
+            .collect::<Vec<_>>();
+        // Buckets without changes are omitted
+        assert_eq!(buckets, vec![(0, 150), (20, 120), (60, 180)]);
+    }
+
+    #[test]
+    fn it_returns_no_supply_buckets_without_rows() {
+        assert!(<SubstateManager>::to_resource_supply_buckets(vec![], 10).is_empty());
+    }
+
+    #[test]
+    fn it_converts_volume_buckets() {
+        let bucket = <SubstateManager>::to_resource_volume_bucket(resource_supply::ResourceVolumeBucket {
+            bucket_start: 3_600,
+            minted: 100,
+            burnt: 20,
+            transferred: 50,
+            transaction_count: 3,
+        });
+        assert_eq!(bucket.bucket_start, 3_600);
+        assert_eq!(bucket.minted, Amount::new(100));
+        assert_eq!(bucket.burnt, Amount::new(20));
+        assert_eq!(bucket.transferred, Amount::new(50));
+        assert_eq!(bucket.transaction_count, 3);
+    }
 }
@@ -0,0 +1,507 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use std::time::Duration;
+
+use anyhow::anyhow;
+use hmac::{Hmac, Mac};
+use log::*;
+use serde::Serialize;
+use sha2::Sha256;
+use tari_crypto::tari_utilities::hex::to_hex;
+use tari_engine_types::events::Event;
+use tari_shutdown::ShutdownSignal;
+use tokio::time;
+
+use crate::{
+    config::WebhookConfig,
+    event_scanner::EventFilter,
+    storage::{
+        models::events::{EventData, NewWebhookCursor},
+        SqlSubstateStore,
+        SubstateStore,
+        SubstateStoreReadTransaction,
+        SubstateStoreWriteTransaction,
+    },
+};
+
+const LOG_TARGET: &str = "tari::indexer::webhooks";
+
+/// The maximum number of stored events read per delivery round
+const DELIVERY_BATCH_SIZE: u32 = 100;
+const SIGNATURE_HEADER: &str = "X-Tari-Signature";
+const TIMESTAMP_HEADER: &str = "X-Tari-Timestamp";
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Delivers stored events that match a webhook's filters to its URL. Delivery is ordered and at-least-once: the id of
+/// the last delivered event is persisted and only advanced once the receiver has responded with a success status.
+pub struct WebhookSink<TStore = SqlSubstateStore> {
+    config: WebhookConfig,
+    filters: Vec<EventFilter>,
+    substate_store: TStore,
+    client: reqwest::Client,
+}
+
+impl<TStore: SubstateStore> WebhookSink<TStore> {
+    pub fn new(config: WebhookConfig, substate_store: TStore) -> Result<Self, anyhow::Error> {
+        let filters = Self::filters_for(&config)?;
+        Ok(Self {
+            config,
+            filters,
+            substate_store,
+            client: reqwest::Client::new(),
+        })
+    }
+
+    pub fn filters(&self) -> &[EventFilter] {
+        &self.filters
+    }
+
+    /// At least one filter is required, because every event that matches a webhook is also persisted. A filter without
+    /// any fields matches every event.
+    fn filters_for(config: &WebhookConfig) -> Result<Vec<EventFilter>, anyhow::Error> {
+        if config.filters.is_empty() {
+            return Err(anyhow!(
+                "at least one filter is required, add an empty filter to receive every event"
+            ));
+        }
+        config.filters.iter().cloned().map(TryInto::try_into).collect()
+    }
+
+    pub async fn run(self, poll_interval: Duration, mut shutdown: ShutdownSignal) {
+        info!(
+            target: LOG_TARGET,
+            "Starting webhook '{}' delivering to {}", self.config.name, self.config.url
+        );
+        loop {
+            tokio::select! {
+                _ = time::sleep(poll_interval) => {
+                    match self.deliver_pending().await {
+                        Ok(0) => {},
+                        Ok(count) => info!(target: LOG_TARGET, "Webhook '{}' delivered {} event(s)", self.config.name, count),
+                        Err(e) => error!(target: LOG_TARGET, "Webhook '{}' delivery failed: {}", self.config.name, e),
+                    }
+                },
+                _ = shutdown.wait() => break,
+            }
+        }
+    }
+
+    /// Delivers all matching events stored since the last delivered event, returning the number of events delivered.
+    async fn deliver_pending(&self) -> Result<usize, anyhow::Error> {
+        let mut cursor = self.load_cursor()?;
+        let mut delivered = 0;
+
+        loop {
+            let events = self
+                .substate_store
+                .with_read_tx(|tx| tx.get_events_after_id(cursor, DELIVERY_BATCH_SIZE))?;
+            if events.is_empty() {
+                return Ok(delivered);
+            }
+
+            for row in events {
+                let event_id = row.id;
+                let timestamp = row.timestamp;
+                let event = match Event::try_from(EventData::from(row)) {
+                    Ok(event) => event,
+                    Err(e) => {
+                        // Retrying cannot fix a row that fails to decode, so skip it rather than block the webhook
+                        error!(
+                            target: LOG_TARGET,
+                            "Webhook '{}' skipped event {} that could not be decoded: {}", self.config.name, event_id, e
+                        );
+                        cursor = event_id;
+                        continue;
+                    },
+                };
+                if self.filters.iter().any(|f| f.matches(&event)) {
+                    self.deliver_with_retries(event_id, timestamp, &event).await?;
+                    self.save_cursor(event_id)?;
+                    delivered += 1;
+                }
+                cursor = event_id;
+            }
+            // Skip past the events in this batch that did not match
+            self.save_cursor(cursor)?;
+        }
+    }
+
+    fn load_cursor(&self) -> Result<i32, anyhow::Error> {
+        self.substate_store.with_write_tx(|tx| {
+            if let Some(cursor) = tx.get_webhook_cursor(&self.config.name)? {
+                return Ok(cursor);
+            }
+            // A new webhook only receives events stored after it was added
+            let cursor = tx.get_latest_event_id()?.unwrap_or(0);
+            tx.set_webhook_cursor(self.new_cursor(cursor))?;
+            Ok(cursor)
+        })
+    }
+
+    fn save_cursor(&self, last_event_id: i32) -> Result<(), anyhow::Error> {
+        self.substate_store
+            .with_write_tx(|tx| tx.set_webhook_cursor(self.new_cursor(last_event_id)))?;
+        Ok(())
+    }
+
+    fn new_cursor(&self, last_event_id: i32) -> NewWebhookCursor {
+        NewWebhookCursor {
+            name: self.config.name.clone(),
+            last_event_id,
+            updated_at: unix_timestamp(),
+        }
+    }
+
+    async fn deliver_with_retries(&self, event_id: i32, timestamp: i64, event: &Event) -> Result<(), anyhow::Error> {
+        let body = serde_json::to_vec(&WebhookRequest {
+            webhook: &self.config.name,
+            event_id,
+            timestamp,
+            event: WebhookEvent::from(event),
+        })?;
+
+        let mut backoff = self.config.initial_backoff;
+        let mut attempt = 0;
+        loop {
+            match self.post(&body).await {
+                Ok(()) => return Ok(()),
+                Err(e) if attempt < self.config.max_retries => {
+                    attempt += 1;
+                    warn!(
+                        target: LOG_TARGET,
+                        "Webhook '{}' failed to deliver event {} (attempt {}/{}): {}. Retrying in {:.2?}",
+                        self.config.name,
+                        event_id,
+                        attempt,
+                        self.config.max_retries,
+                        e,
+                        backoff
+                    );
+                    time::sleep(backoff).await;
+                    backoff *= 2;
+                },
+                Err(e) => {
+                    return Err(anyhow!(
+                        "Giving up delivering event {} after {} attempt(s), will retry next round: {}",
+                        event_id,
+                        attempt + 1,
+                        e
+                    ))
+                },
+            }
+        }
+    }
+
+    async fn post(&self, body: &[u8]) -> Result<(), anyhow::Error> {
+        let mut request = self
+            .client
+            .post(self.config.url.clone())
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(body.to_vec());
+        if let Some(secret) = &self.config.secret {
+            let timestamp = unix_timestamp();
+            request = request
+                .header(TIMESTAMP_HEADER, timestamp)
+                .header(SIGNATURE_HEADER, sign_payload(secret, timestamp, body));
+        }
+
+        let response = request.send().await?;
+        if !response.status().is_success() {
+            return Err(anyhow!("Webhook responded with status {}", response.status()));
+        }
+        Ok(())
+    }
+}
+
+/// Returns the hex encoded HMAC-SHA256 of `<timestamp>.<body>`. Signing the timestamp lets receivers reject replayed
+/// requests.
+pub fn sign_payload(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    to_hex(&mac.finalize().into_bytes())
+}
+
+fn unix_timestamp() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default()
+}
+
+#[derive(Serialize)]
+struct WebhookRequest<'a> {
+    webhook: &'a str,
+    event_id: i32,
+    timestamp: i64,
+    event: WebhookEvent,
+}
+
+#[derive(Serialize)]
+struct WebhookEvent {
+    substate_id: Option<String>,
+    template_address: String,
+    tx_hash: String,
+    topic: String,
+    #[serde(with = "tari_engine_types::serde_with::cbor_value")]
+    payload: tari_bor::Value,
+}
+
+impl From<&Event> for WebhookEvent {
+    fn from(event: &Event) -> Self {
+        Self {
+            substate_id: event.substate_id().map(|s| s.to_string()),
+            template_address: event.template_address().to_string(),
+            tx_hash: event.tx_hash().to_string(),
+            topic: event.topic(),
+            payload: event.payload().clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use axum::{
+        body::Bytes,
+        extract::State,
+        http::{HeaderMap, StatusCode},
+        routing::post,
+        Router,
+    };
+    use tari_template_lib::Hash;
+    use url::Url;
+
+    use super::*;
+    use crate::{config::EventFilterConfig, storage::models::events::NewEvent};
+
+    const SECRET: &str = "secret";
+    const TEMPLATE_ADDRESS: &str = "1111111111111111111111111111111111111111111111111111111111111111";
+
+    struct Receiver {
+        failures_remaining: usize,
+        requests: Vec<(HeaderMap, Bytes)>,
+    }
+
+    async fn receive(State(receiver): State<Arc<Mutex<Receiver>>>, headers: HeaderMap, body: Bytes) -> StatusCode {
+        let mut receiver = receiver.lock().unwrap();
+        if receiver.failures_remaining > 0 {
+            receiver.failures_remaining -= 1;
+            return StatusCode::INTERNAL_SERVER_ERROR;
+        }
+        receiver.requests.push((headers, body));
+        StatusCode::OK
+    }
+
+    /// Starts a webhook receiver that fails the first `failures` requests
+    fn spawn_receiver(failures: usize) -> (Url, Arc<Mutex<Receiver>>) {
+        let receiver = Arc::new(Mutex::new(Receiver {
+            failures_remaining: failures,
+            requests: vec![],
+        }));
+        let app = Router::new().route("/", post(receive)).with_state(receiver.clone());
+        let server = axum::Server::bind(&"127.0.0.1:0".parse().unwrap()).serve(app.into_make_service());
+        let url = format!("http://{}/", server.local_addr()).parse().unwrap();
+        tokio::spawn(server);
+        (url, receiver)
+    }
+
+    fn create_store() -> (SqlSubstateStore, tempfile::TempDir) {
+        let dir = tempfile::tempdir().unwrap();
+        let store = SqlSubstateStore::try_create_sqlite(dir.path().join("indexer.sqlite")).unwrap();
+        store.run_migrations().unwrap();
+        (store, dir)
+    }
+
+    fn topic_filter(topic: &str) -> EventFilterConfig {
+        EventFilterConfig {
+            topic: Some(topic.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn create_sink(url: Url, store: &SqlSubstateStore, filters: Vec<EventFilterConfig>) -> WebhookSink {
+        let config = WebhookConfig {
+            name: "test".to_string(),
+            url,
+            secret: Some(SECRET.to_string()),
+            filters,
+            max_retries: 2,
+            initial_backoff: Duration::from_millis(1),
+        };
+        let sink = WebhookSink::new(config, store.clone()).unwrap();
+        // Start delivering from the current end of the event table
+        sink.load_cursor().unwrap();
+        sink
+    }
+
+    /// Stores an event and returns its id
+    fn save_event(store: &SqlSubstateStore, topic: &str, template_address: &str) -> i32 {
+        store
+            .with_write_tx(|tx| {
+                tx.save_event(NewEvent {
+                    template_address: template_address.to_string(),
+                    tx_hash: "22".repeat(32),
+                    topic: topic.to_string(),
+                    payload: "{}".to_string(),
+                    version: 0,
+                    substate_id: None,
+                    timestamp: 1,
+                    block_id: None,
+                    block_height: None,
+                })?;
+                tx.get_latest_event_id()
+            })
+            .unwrap()
+            .unwrap()
+    }
+
+    fn get_cursor(store: &SqlSubstateStore) -> Option<i32> {
+        store.with_read_tx(|tx| tx.get_webhook_cursor("test")).unwrap()
+    }
+
+    fn delivered_topics(receiver: &Mutex<Receiver>) -> Vec<String> {
+        receiver
+            .lock()
+            .unwrap()
+            .requests
+            .iter()
+            .map(|(_, body)| {
+                let body: serde_json::Value = serde_json::from_slice(body).unwrap();
+                body["event"]["topic"].as_str().unwrap().to_string()
+            })
+            .collect()
+    }
+
+    mod filters {
+        use super::*;
+
+        #[test]
+        fn it_requires_at_least_one_filter() {
+            let (store, _dir) = create_store();
+            let config = WebhookConfig {
+                name: "test".to_string(),
+                url: "http://127.0.0.1/".parse().unwrap(),
+                secret: None,
+                filters: vec![],
+                max_retries: 0,
+                initial_backoff: Duration::from_secs(1),
+            };
+            assert!(WebhookSink::new(config, store).is_err());
+        }
+
+        #[test]
+        fn an_empty_filter_matches_every_event() {
+            let filter = EventFilter::try_from(EventFilterConfig::default()).unwrap();
+            let event = Event::new(
+                None,
+                Hash::from_hex(TEMPLATE_ADDRESS).unwrap(),
+                Hash::default(),
+                "anything".to_string(),
+                tari_bor::Value::Null,
+            );
+            assert!(filter.matches(&event));
+        }
+
+        #[tokio::test]
+        async fn it_only_delivers_matching_events() {
+            let (url, receiver) = spawn_receiver(0);
+            let (store, _dir) = create_store();
+            let sink = create_sink(url, &store, vec![topic_filter("sale"), topic_filter("burn")]);
+
+            save_event(&store, "sale", TEMPLATE_ADDRESS);
+            save_event(&store, "mint", TEMPLATE_ADDRESS);
+            let last_id = save_event(&store, "burn", TEMPLATE_ADDRESS);
+
+            assert_eq!(sink.deliver_pending().await.unwrap(), 2);
+            assert_eq!(delivered_topics(&receiver), vec!["sale", "burn"]);
+            assert_eq!(get_cursor(&store), Some(last_id));
+        }
+    }
+
+    mod signature {
+        use super::*;
+
+        #[test]
+        fn it_signs_the_timestamp_and_the_body() {
+            let mut mac = HmacSha256::new_from_slice(SECRET.as_bytes()).unwrap();
+            mac.update(b"1700000000.{\"a\":1}");
+            let expected = to_hex(&mac.finalize().into_bytes());
+
+            assert_eq!(sign_payload(SECRET, 1_700_000_000, b"{\"a\":1}"), expected);
+            assert_ne!(sign_payload(SECRET, 1_700_000_001, b"{\"a\":1}"), expected);
+            assert_ne!(sign_payload("other", 1_700_000_000, b"{\"a\":1}"), expected);
+        }
+
+        #[tokio::test]
+        async fn it_sends_a_verifiable_signature() {
+            let (url, receiver) = spawn_receiver(0);
+            let (store, _dir) = create_store();
+            let sink = create_sink(url, &store, vec![topic_filter("sale")]);
+            save_event(&store, "sale", TEMPLATE_ADDRESS);
+
+            sink.deliver_pending().await.unwrap();
+
+            let receiver = receiver.lock().unwrap();
+            let (headers, body) = &receiver.requests[0];
+            let timestamp: i64 = headers[TIMESTAMP_HEADER].to_str().unwrap().parse().unwrap();
+            assert!((timestamp - unix_timestamp()).abs() < 60);
+            assert_eq!(
+                headers[SIGNATURE_HEADER].to_str().unwrap(),
+                sign_payload(SECRET, timestamp, body)
+            );
+        }
+    }
+
+    mod delivery {
+        use super::*;
+
+        #[tokio::test]
+        async fn it_retries_failed_deliveries() {
+            // Fails fewer times than the retry limit
+            let (url, receiver) = spawn_receiver(2);
+            let (store, _dir) = create_store();
+            let sink = create_sink(url, &store, vec![topic_filter("sale")]);
+            let event_id = save_event(&store, "sale", TEMPLATE_ADDRESS);
+
+            assert_eq!(sink.deliver_pending().await.unwrap(), 1);
+            assert_eq!(delivered_topics(&receiver), vec!["sale"]);
+            assert_eq!(get_cursor(&store), Some(event_id));
+        }
+
+        #[tokio::test]
+        async fn it_does_not_advance_the_cursor_past_an_undelivered_event() {
+            let (url, receiver) = spawn_receiver(10);
+            let (store, _dir) = create_store();
+            let sink = create_sink(url, &store, vec![topic_filter("sale")]);
+            let initial_cursor = get_cursor(&store);
+            save_event(&store, "sale", TEMPLATE_ADDRESS);
+            let second_id = save_event(&store, "sale", TEMPLATE_ADDRESS);
+
+            assert!(sink.deliver_pending().await.is_err());
+            assert_eq!(get_cursor(&store), initial_cursor);
+
+            // The next round delivers both events once the receiver recovers
+            receiver.lock().unwrap().failures_remaining = 0;
+            assert_eq!(sink.deliver_pending().await.unwrap(), 2);
+            assert_eq!(get_cursor(&store), Some(second_id));
+        }
+
+        #[tokio::test]
+        async fn it_skips_events_that_cannot_be_decoded() {
+            let (url, receiver) = spawn_receiver(0);
+            let (store, _dir) = create_store();
+            let sink = create_sink(url, &store, vec![EventFilterConfig::default()]);
+            save_event(&store, "poison", "not a template address");
+            let last_id = save_event(&store, "sale", TEMPLATE_ADDRESS);
+
+            assert_eq!(sink.deliver_pending().await.unwrap(), 1);
+            assert_eq!(delivered_topics(&receiver), vec!["sale"]);
+            assert_eq!(get_cursor(&store), Some(last_id));
+        }
+    }
+}
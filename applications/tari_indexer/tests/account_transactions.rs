@@ -0,0 +1,115 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+mod support;
+
+use std::str::FromStr;
+
+use tari_dan_storage::StorageError;
+use tari_indexer::storage::{
+    models::account_transaction::{BalanceChangeKind, NewAccountBalanceChange, NewAccountTransaction},
+    SubstateStore,
+    SubstateStoreReadTransaction,
+    SubstateStoreWriteTransaction,
+};
+
+use crate::support::TestStore;
+
+const ACCOUNT: &str = "account_a";
+
+fn balance_change(
+    transaction_id: &str,
+    vault_id: &str,
+    kind: BalanceChangeKind,
+    change: i64,
+) -> NewAccountBalanceChange {
+    NewAccountBalanceChange {
+        account: ACCOUNT.to_string(),
+        transaction_id: transaction_id.to_string(),
+        vault_id: vault_id.to_string(),
+        resource_address: "resource_a".to_string(),
+        balance_change: change,
+        kind: kind.as_str().to_string(),
+    }
+}
+
+fn save_transaction(store: &TestStore, transaction_id: &str, timestamp: i64, changes: Vec<NewAccountBalanceChange>) {
+    store
+        .with_write_tx(|tx| {
+            tx.save_account_transaction(
+                NewAccountTransaction {
+                    account: ACCOUNT.to_string(),
+                    transaction_id: transaction_id.to_string(),
+                    timestamp,
+                },
+                changes,
+            )
+        })
+        .unwrap();
+}
+
+#[test]
+fn it_stores_the_kind_of_each_balance_change() {
+    let store = TestStore::new();
+    save_transaction(&store, "tx_1", 1, vec![
+        balance_change("tx_1", "vault_1", BalanceChangeKind::Created, 100),
+        balance_change("tx_1", "vault_2", BalanceChangeKind::Unknown, 0),
+        balance_change("tx_1", "vault_3", BalanceChangeKind::Changed, -40),
+    ]);
+
+    let changes = store
+        .with_read_tx(|tx| tx.get_account_balance_changes(ACCOUNT, &["tx_1".to_string()]))
+        .unwrap();
+    let changes = changes
+        .iter()
+        .map(|c| {
+            (
+                c.vault_id.as_str(),
+                BalanceChangeKind::from_str(&c.kind).unwrap(),
+                c.balance_change,
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(changes, vec![
+        ("vault_1", BalanceChangeKind::Created, 100),
+        ("vault_2", BalanceChangeKind::Unknown, 0),
+        ("vault_3", BalanceChangeKind::Changed, -40),
+    ]);
+}
+
+#[test]
+fn it_returns_the_changes_of_the_requested_transactions() {
+    let store = TestStore::new();
+    save_transaction(&store, "tx_1", 1, vec![balance_change(
+        "tx_1",
+        "vault_1",
+        BalanceChangeKind::Created,
+        100,
+    )]);
+    save_transaction(&store, "tx_2", 2, vec![balance_change(
+        "tx_2",
+        "vault_1",
+        BalanceChangeKind::Changed,
+        -25,
+    )]);
+
+    let (count, transactions, changes) = store
+        .with_read_tx(|tx| {
+            let count = tx.count_account_transactions(ACCOUNT)?;
+            let transactions = tx.get_account_transactions(ACCOUNT, 0, 10)?;
+            let changes = tx.get_account_balance_changes(ACCOUNT, &["tx_2".to_string()])?;
+            Ok::<_, StorageError>((count, transactions, changes))
+        })
+        .unwrap();
+    assert_eq!(count, 2);
+    // Newest first
+    let transaction_ids = transactions
+        .iter()
+        .map(|t| t.transaction_id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(transaction_ids, vec!["tx_2", "tx_1"]);
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].transaction_id, "tx_2");
+    assert_eq!(changes[0].kind, BalanceChangeKind::Changed.as_str());
+    assert_eq!(changes[0].balance_change, -25);
+}
@@ -0,0 +1,218 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+mod support;
+
+use tari_dan_common_types::{Epoch, ShardGroup};
+use tari_dan_storage::consensus_models::BlockId;
+use tari_indexer::storage::{
+    models::block::{NewBlock, NewBlockTransaction},
+    SubstateStore,
+    SubstateStoreReadTransaction,
+    SubstateStoreWriteTransaction,
+};
+
+use crate::support::TestStore;
+
+fn block_id(n: u8) -> BlockId {
+    BlockId::from([n; 32])
+}
+
+fn shard_group(start: u32, end: u32) -> ShardGroup {
+    ShardGroup::new(start, end)
+}
+
+fn new_block(n: u8, epoch: u64, shard_group: ShardGroup, height: i64) -> NewBlock {
+    NewBlock {
+        block_id: block_id(n).to_string(),
+        parent_id: block_id(n.wrapping_sub(1)).to_string(),
+        epoch: epoch as i64,
+        shard_group: shard_group.encode_as_u32() as i32,
+        height,
+        proposed_by: "00".repeat(32),
+        timestamp: 1_000 + i64::from(n),
+        is_dummy: false,
+        command_count: 2,
+        transaction_count: 1,
+        total_leader_fee: 10,
+        header: "{}".to_string(),
+        justify: "{}".to_string(),
+        commands: "[]".to_string(),
+    }
+}
+
+fn new_transaction(block: u8, n: u8) -> NewBlockTransaction {
+    NewBlockTransaction {
+        block_id: block_id(block).to_string(),
+        transaction_id: format!("{:02x}", n).repeat(32),
+        command: "LocalOnly".to_string(),
+        decision: "Commit".to_string(),
+        transaction_fee: i64::from(n),
+    }
+}
+
+fn save_block(store: &TestStore, block: NewBlock, transactions: Vec<NewBlockTransaction>) {
+    store.with_write_tx(|tx| tx.save_block(block, transactions)).unwrap();
+}
+
+/// Epoch 1 has heights 1 and 2 in shard group 0-1 and height 1 in shard group 2-3, epoch 2 has one block
+fn seeded_store() -> TestStore {
+    let store = TestStore::new();
+    save_block(&store, new_block(1, 1, shard_group(0, 1), 1), vec![new_transaction(
+        1, 1,
+    )]);
+    save_block(&store, new_block(2, 1, shard_group(0, 1), 2), vec![]);
+    save_block(&store, new_block(3, 1, shard_group(2, 3), 1), vec![]);
+    save_block(&store, new_block(4, 2, shard_group(0, 1), 3), vec![]);
+    store
+}
+
+fn list_block_ids(
+    store: &TestStore,
+    epoch: Option<Epoch>,
+    shard_group: Option<ShardGroup>,
+    offset: u64,
+    limit: u64,
+) -> Vec<String> {
+    store
+        .with_read_tx(|tx| tx.list_blocks(epoch, shard_group, offset, limit))
+        .unwrap()
+        .into_iter()
+        .map(|b| b.block_id)
+        .collect()
+}
+
+mod save_block {
+    use super::*;
+
+    #[test]
+    fn it_stores_the_block_and_its_transactions() {
+        let store = TestStore::new();
+        save_block(&store, new_block(1, 1, shard_group(0, 1), 1), vec![
+            new_transaction(1, 1),
+            new_transaction(1, 2),
+        ]);
+
+        let block = store.with_read_tx(|tx| tx.get_block(&block_id(1))).unwrap().unwrap();
+        assert_eq!(block.epoch, 1);
+        assert_eq!(block.height, 1);
+        assert_eq!(block.shard_group, shard_group(0, 1).encode_as_u32() as i32);
+        assert_eq!(block.parent_id, block_id(0).to_string());
+
+        let transactions = store
+            .with_read_tx(|tx| tx.get_block_transactions(&block_id(1)))
+            .unwrap();
+        let fees = transactions.iter().map(|t| t.transaction_fee).collect::<Vec<_>>();
+        assert_eq!(fees, vec![1, 2]);
+        assert!(transactions.iter().all(|t| t.block_id == block_id(1).to_string()));
+    }
+
+    #[test]
+    fn it_ignores_blocks_that_were_already_saved() {
+        let store = TestStore::new();
+        save_block(&store, new_block(1, 1, shard_group(0, 1), 1), vec![new_transaction(
+            1, 1,
+        )]);
+
+        let mut rescanned = new_block(1, 1, shard_group(0, 1), 1);
+        rescanned.total_leader_fee = 99;
+        save_block(&store, rescanned, vec![new_transaction(1, 1), new_transaction(1, 2)]);
+
+        let block = store.with_read_tx(|tx| tx.get_block(&block_id(1))).unwrap().unwrap();
+        assert_eq!(block.total_leader_fee, 10);
+        let transactions = store
+            .with_read_tx(|tx| tx.get_block_transactions(&block_id(1)))
+            .unwrap();
+        assert_eq!(transactions.len(), 1);
+    }
+
+    #[test]
+    fn it_returns_none_for_unknown_blocks() {
+        let store = seeded_store();
+        assert!(store.with_read_tx(|tx| tx.get_block(&block_id(9))).unwrap().is_none());
+        assert!(store
+            .with_read_tx(|tx| tx.get_block_transactions(&block_id(9)))
+            .unwrap()
+            .is_empty());
+    }
+}
+
+mod list_blocks {
+    use super::*;
+
+    #[test]
+    fn it_lists_the_newest_blocks_first() {
+        let store = seeded_store();
+        assert_eq!(list_block_ids(&store, None, None, 0, 10), vec![
+            block_id(4).to_string(),
+            block_id(2).to_string(),
+            block_id(3).to_string(),
+            block_id(1).to_string(),
+        ]);
+    }
+
+    #[test]
+    fn it_filters_by_epoch_and_shard_group() {
+        let store = seeded_store();
+        assert_eq!(list_block_ids(&store, Some(Epoch(1)), None, 0, 10).len(), 3);
+        assert_eq!(
+            list_block_ids(&store, Some(Epoch(1)), Some(shard_group(2, 3)), 0, 10),
+            vec![block_id(3).to_string()]
+        );
+        assert_eq!(list_block_ids(&store, None, Some(shard_group(0, 1)), 0, 10).len(), 3);
+        assert!(list_block_ids(&store, Some(Epoch(3)), None, 0, 10).is_empty());
+    }
+
+    #[test]
+    fn it_pages_with_offset_and_limit() {
+        let store = seeded_store();
+        assert_eq!(list_block_ids(&store, None, None, 0, 2), vec![
+            block_id(4).to_string(),
+            block_id(2).to_string(),
+        ]);
+        assert_eq!(list_block_ids(&store, None, None, 2, 2), vec![
+            block_id(3).to_string(),
+            block_id(1).to_string(),
+        ]);
+        assert!(list_block_ids(&store, None, None, 4, 2).is_empty());
+    }
+}
+
+mod get_epoch_stats {
+    use super::*;
+
+    #[test]
+    fn it_aggregates_the_blocks_of_the_epoch() {
+        let store = seeded_store();
+        let mut dummy = new_block(5, 1, shard_group(2, 3), 2);
+        dummy.is_dummy = true;
+        dummy.command_count = 0;
+        dummy.transaction_count = 0;
+        dummy.total_leader_fee = 0;
+        save_block(&store, dummy, vec![]);
+
+        let stats = store.with_read_tx(|tx| tx.get_epoch_stats(Epoch(1))).unwrap();
+        assert_eq!(stats.num_blocks, 4);
+        assert_eq!(stats.num_dummy_blocks, 1);
+        assert_eq!(stats.num_shard_groups, 2);
+        assert_eq!(stats.num_commands, 6);
+        assert_eq!(stats.num_transactions, 3);
+        assert_eq!(stats.total_leader_fees, 30);
+        assert_eq!(stats.first_block_timestamp, Some(1_001));
+        assert_eq!(stats.last_block_timestamp, Some(1_005));
+    }
+
+    #[test]
+    fn it_returns_zeroes_for_an_epoch_without_blocks() {
+        let store = seeded_store();
+        let stats = store.with_read_tx(|tx| tx.get_epoch_stats(Epoch(3))).unwrap();
+        assert_eq!(stats.num_blocks, 0);
+        assert_eq!(stats.num_dummy_blocks, 0);
+        assert_eq!(stats.num_shard_groups, 0);
+        assert_eq!(stats.num_commands, 0);
+        assert_eq!(stats.num_transactions, 0);
+        assert_eq!(stats.total_leader_fees, 0);
+        assert_eq!(stats.first_block_timestamp, None);
+        assert_eq!(stats.last_block_timestamp, None);
+    }
+}
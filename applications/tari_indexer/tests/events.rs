@@ -0,0 +1,151 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+mod support;
+
+use std::thread;
+
+use tari_dan_storage::StorageError;
+use tari_indexer::storage::{
+    models::events::NewEvent,
+    SubstateStore,
+    SubstateStoreReadTransaction,
+    SubstateStoreWriteTransaction,
+};
+
+use crate::support::TestStore;
+
+const TEMPLATE_ADDRESS: &str = "1111111111111111111111111111111111111111111111111111111111111111";
+
+fn new_event(topic: &str) -> NewEvent {
+    NewEvent {
+        template_address: TEMPLATE_ADDRESS.to_string(),
+        tx_hash: "22".repeat(32),
+        topic: topic.to_string(),
+        payload: "{}".to_string(),
+        version: 0,
+        substate_id: None,
+        timestamp: 1,
+        block_id: None,
+        block_height: None,
+    }
+}
+
+fn save_events(store: &TestStore, topics: &[&str]) {
+    store
+        .with_write_tx(|tx| topics.iter().try_for_each(|topic| tx.save_event(new_event(topic))))
+        .unwrap();
+}
+
+/// Returns the (sequence, topic) of the events after `after_sequence`
+fn events_after(store: &TestStore, after_sequence: u64, topic: Option<&str>, limit: u32) -> Vec<(i64, String)> {
+    store
+        .with_read_tx(|tx| tx.get_events_after_sequence(after_sequence, None, topic.map(ToString::to_string), limit))
+        .unwrap()
+        .into_iter()
+        .map(|e| (e.sequence, e.topic))
+        .collect()
+}
+
+fn sequences(events: &[(i64, String)]) -> Vec<i64> {
+    events.iter().map(|(sequence, _)| *sequence).collect()
+}
+
+#[test]
+fn it_numbers_events_in_the_order_they_are_saved() {
+    let store = TestStore::new();
+    save_events(&store, &["a", "b"]);
+    save_events(&store, &["c"]);
+    save_events(&store, &["d", "e"]);
+
+    let events = events_after(&store, 0, None, 10);
+    assert_eq!(sequences(&events), vec![1, 2, 3, 4, 5]);
+    let topics = events.iter().map(|(_, topic)| topic.as_str()).collect::<Vec<_>>();
+    assert_eq!(topics, vec!["a", "b", "c", "d", "e"]);
+}
+
+#[test]
+fn it_resumes_after_a_sequence_without_skipping_or_repeating_events() {
+    let store = TestStore::new();
+    save_events(&store, &["a", "b", "a", "b", "a"]);
+
+    let mut cursor = 0;
+    let mut seen = vec![];
+    loop {
+        let page = events_after(&store, cursor, None, 2);
+        let Some((last, _)) = page.last() else {
+            break;
+        };
+        cursor = *last as u64;
+        seen.extend(sequences(&page));
+    }
+    assert_eq!(seen, vec![1, 2, 3, 4, 5]);
+
+    // Filtered reads keep the sequence order
+    assert_eq!(sequences(&events_after(&store, 1, Some("a"), 10)), vec![3, 5]);
+}
+
+#[test]
+fn it_leaves_no_gaps_for_rolled_back_events() {
+    let store = TestStore::new();
+    save_events(&store, &["a"]);
+    let result = store.with_write_tx(|tx| {
+        tx.save_event(new_event("rolled back"))?;
+        Err::<(), _>(StorageError::General {
+            details: "abort".to_string(),
+        })
+    });
+    assert!(result.is_err());
+    save_events(&store, &["b"]);
+
+    // The number of the rolled back event is given to the next event, so consumers see no gaps
+    assert_eq!(events_after(&store, 0, None, 10), vec![
+        (1, "a".to_string()),
+        (2, "b".to_string())
+    ]);
+}
+
+#[test]
+fn it_assigns_unique_contiguous_sequences_to_concurrent_writers() {
+    const NUM_WRITERS: usize = 4;
+    const EVENTS_PER_WRITER: usize = 10;
+
+    let store = TestStore::new();
+    thread::scope(|s| {
+        for _ in 0..NUM_WRITERS {
+            s.spawn(|| {
+                for _ in 0..EVENTS_PER_WRITER {
+                    save_events(&store, &["concurrent"]);
+                }
+            });
+        }
+    });
+
+    let events = events_after(&store, 0, None, 100);
+    let expected = (1..=(NUM_WRITERS * EVENTS_PER_WRITER) as i64).collect::<Vec<_>>();
+    assert_eq!(sequences(&events), expected);
+}
+
+#[test]
+fn it_backfills_existing_events_in_insertion_order() {
+    // Events indexed before sequence numbers were added, with a gap in the ids
+    let store = TestStore::seeded_before_migration(
+        "2024-07-08-120000",
+        &format!(
+            "insert into events (template_address, tx_hash, topic, payload, version, substate_id, timestamp) values \
+             ('{t}', 'tx_1', 'first', '{{}}', 0, NULL, 30), ('{t}', 'tx_2', 'removed', '{{}}', 0, NULL, 20), ('{t}', \
+             'tx_3', 'second', '{{}}', 0, NULL, 10); delete from events where topic = 'removed';",
+            t = TEMPLATE_ADDRESS
+        ),
+    );
+
+    // Both backends number the existing events from 1 by insertion order, regardless of their timestamps
+    assert_eq!(events_after(&store, 0, None, 10), vec![
+        (1, "first".to_string()),
+        (2, "second".to_string())
+    ]);
+
+    // New events continue from the backfilled sequence
+    save_events(&store, &["third"]);
+    assert_eq!(events_after(&store, 2, None, 10), vec![(3, "third".to_string())]);
+}
@@ -0,0 +1,245 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+mod support;
+
+use tari_dan_common_types::substate_type::SubstateType;
+use tari_indexer::storage::{
+    models::substate::NewSubstate,
+    SubstateStore,
+    SubstateStoreReadTransaction,
+    SubstateStoreWriteTransaction,
+};
+use tari_indexer_client::types::{ListSubstateItem, ListSubstatesRequest, SortOrder, SubstateOrderBy};
+use tari_template_lib::models::TemplateAddress;
+use tari_transaction::TransactionId;
+
+use crate::support::TestStore;
+
+fn template_address() -> TemplateAddress {
+    TemplateAddress::from_array([1; 32])
+}
+
+fn transaction_id(n: u8) -> TransactionId {
+    TransactionId::from([n; 32])
+}
+
+fn component(n: u64, version: i64, timestamp: i64) -> NewSubstate {
+    NewSubstate {
+        address: format!("component_{:064x}", n),
+        version,
+        data: "{}".to_string(),
+        tx_hash: transaction_id(n as u8).to_string(),
+        template_address: Some(template_address().to_string()),
+        module_name: Some("Counter".to_string()),
+        timestamp,
+    }
+}
+
+fn resource(n: u64) -> NewSubstate {
+    NewSubstate {
+        address: format!("resource_{:064x}", n),
+        version: 0,
+        data: "{}".to_string(),
+        tx_hash: transaction_id(n as u8).to_string(),
+        template_address: None,
+        module_name: None,
+        timestamp: 1_000,
+    }
+}
+
+/// Five components with versions 4, 3, 2, 1, 0 and timestamps 1000, 1000, 1002, 1003, 1004 in the order they are
+/// indexed, and two resources
+fn seeded_store() -> TestStore {
+    let store = TestStore::new();
+    store
+        .with_write_tx(|tx| {
+            tx.set_substate(component(1, 4, 1_000))?;
+            tx.set_substate(component(2, 3, 1_000))?;
+            tx.set_substate(component(3, 2, 1_002))?;
+            tx.set_substate(component(4, 1, 1_003))?;
+            tx.set_substate(component(5, 0, 1_004))?;
+            tx.set_substate(resource(6))?;
+            tx.set_substate(resource(7))
+        })
+        .unwrap();
+    store
+}
+
+fn list(store: &TestStore, request: &ListSubstatesRequest) -> (Vec<ListSubstateItem>, Option<String>) {
+    store.with_read_tx(|tx| tx.list_substates(request)).unwrap()
+}
+
+fn versions(items: &[ListSubstateItem]) -> Vec<u32> {
+    items.iter().map(|s| s.version).collect()
+}
+
+/// Follows the cursors from the first page and returns every page
+fn all_pages(store: &TestStore, mut request: ListSubstatesRequest) -> Vec<Vec<ListSubstateItem>> {
+    let mut pages = vec![];
+    loop {
+        let (items, next_cursor) = list(store, &request);
+        pages.push(items);
+        match next_cursor {
+            Some(cursor) => request.cursor = Some(cursor),
+            None => return pages,
+        }
+    }
+}
+
+mod filters {
+    use super::*;
+
+    #[test]
+    fn it_filters_by_type() {
+        let store = seeded_store();
+        let (items, _) = list(&store, &ListSubstatesRequest {
+            filter_by_type: Some(SubstateType::Resource),
+            ..Default::default()
+        });
+        assert_eq!(items.len(), 2);
+        assert!(items.iter().all(|s| s.substate_id.is_resource()));
+    }
+
+    #[test]
+    fn it_filters_by_template_and_module_name() {
+        let store = seeded_store();
+        let (items, _) = list(&store, &ListSubstatesRequest {
+            filter_by_template: Some(template_address()),
+            ..Default::default()
+        });
+        assert_eq!(items.len(), 5);
+
+        let (items, _) = list(&store, &ListSubstatesRequest {
+            filter_by_module_name: Some("Counter".to_string()),
+            ..Default::default()
+        });
+        assert_eq!(items.len(), 5);
+
+        let (items, _) = list(&store, &ListSubstatesRequest {
+            filter_by_module_name: Some("Other".to_string()),
+            ..Default::default()
+        });
+        assert!(items.is_empty());
+    }
+
+    #[test]
+    fn it_filters_by_transaction() {
+        let store = seeded_store();
+        let (items, _) = list(&store, &ListSubstatesRequest {
+            filter_by_transaction: Some(transaction_id(3)),
+            ..Default::default()
+        });
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].substate_id.to_string(), format!("component_{:064x}", 3));
+    }
+
+    #[test]
+    fn it_filters_by_inclusive_version_and_timestamp_ranges() {
+        let store = seeded_store();
+        let (items, _) = list(&store, &ListSubstatesRequest {
+            filter_by_type: Some(SubstateType::Component),
+            min_version: Some(1),
+            max_version: Some(3),
+            ..Default::default()
+        });
+        assert_eq!(versions(&items), vec![3, 2, 1]);
+
+        let (items, _) = list(&store, &ListSubstatesRequest {
+            filter_by_type: Some(SubstateType::Component),
+            from_timestamp: Some(1_002),
+            to_timestamp: Some(1_003),
+            ..Default::default()
+        });
+        assert_eq!(versions(&items), vec![2, 1]);
+    }
+}
+
+mod pagination {
+    use super::*;
+
+    #[test]
+    fn it_returns_no_cursor_without_a_limit_or_on_the_last_page() {
+        let store = seeded_store();
+        let (items, next_cursor) = list(&store, &ListSubstatesRequest::default());
+        assert_eq!(items.len(), 7);
+        assert_eq!(next_cursor, None);
+
+        // Exactly one full page
+        let (items, next_cursor) = list(&store, &ListSubstatesRequest {
+            filter_by_type: Some(SubstateType::Component),
+            limit: Some(5),
+            ..Default::default()
+        });
+        assert_eq!(items.len(), 5);
+        assert_eq!(next_cursor, None);
+    }
+
+    #[test]
+    fn it_pages_through_every_filtered_substate_once() {
+        let store = seeded_store();
+        for order_by in [
+            SubstateOrderBy::Indexed,
+            SubstateOrderBy::Version,
+            SubstateOrderBy::Timestamp,
+        ] {
+            for order in [SortOrder::Asc, SortOrder::Desc] {
+                let pages = all_pages(&store, ListSubstatesRequest {
+                    filter_by_type: Some(SubstateType::Component),
+                    order_by,
+                    order,
+                    limit: Some(2),
+                    ..Default::default()
+                });
+                let page_sizes = pages.iter().map(Vec::len).collect::<Vec<_>>();
+                assert_eq!(page_sizes, vec![2, 2, 1], "{:?} {:?}", order_by, order);
+
+                let mut ids = pages
+                    .iter()
+                    .flatten()
+                    .map(|s| s.substate_id.to_string())
+                    .collect::<Vec<_>>();
+                ids.sort();
+                ids.dedup();
+                assert_eq!(ids.len(), 5, "{:?} {:?}", order_by, order);
+            }
+        }
+    }
+
+    #[test]
+    fn it_orders_by_the_key_and_breaks_ties_by_indexed_order() {
+        let store = seeded_store();
+        let pages = all_pages(&store, ListSubstatesRequest {
+            filter_by_type: Some(SubstateType::Component),
+            order_by: SubstateOrderBy::Version,
+            order: SortOrder::Asc,
+            limit: Some(2),
+            ..Default::default()
+        });
+        assert_eq!(versions(&pages.concat()), vec![0, 1, 2, 3, 4]);
+
+        // The first two components share a timestamp and the page boundary falls between them
+        let pages = all_pages(&store, ListSubstatesRequest {
+            filter_by_type: Some(SubstateType::Component),
+            order_by: SubstateOrderBy::Timestamp,
+            order: SortOrder::Desc,
+            limit: Some(4),
+            ..Default::default()
+        });
+        let timestamps = pages.concat().iter().map(|s| s.timestamp).collect::<Vec<_>>();
+        assert_eq!(timestamps, vec![1_004, 1_003, 1_002, 1_000, 1_000]);
+        assert_eq!(versions(&pages.concat()), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn it_rejects_an_invalid_cursor() {
+        let store = seeded_store();
+        let result = store.with_read_tx(|tx| {
+            tx.list_substates(&ListSubstatesRequest {
+                cursor: Some("not a cursor".to_string()),
+                ..Default::default()
+            })
+        });
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,232 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+mod support;
+
+use tari_indexer::storage::{
+    models::non_fungible_metadata::NewNonFungibleMetadataField,
+    SubstateStore,
+    SubstateStoreReadTransaction,
+    SubstateStoreWriteTransaction,
+};
+
+use crate::support::TestStore;
+
+const COLLECTION_A: &str = "resource_a";
+const COLLECTION_B: &str = "resource_b";
+
+fn field(
+    nft_address: &str,
+    resource_address: &str,
+    mutable: bool,
+    key: &str,
+    value: &str,
+) -> NewNonFungibleMetadataField {
+    NewNonFungibleMetadataField {
+        nft_address: nft_address.to_string(),
+        resource_address: resource_address.to_string(),
+        mutable,
+        field_key: key.to_string(),
+        field_value: value.to_string(),
+        version: 0,
+    }
+}
+
+fn set_metadata(store: &TestStore, nft_address: &str, fields: Vec<NewNonFungibleMetadataField>) {
+    store
+        .with_write_tx(|tx| tx.set_non_fungible_metadata(nft_address, fields))
+        .unwrap();
+}
+
+/// Three NFTs in collection A and one in collection B
+fn seeded_store() -> TestStore {
+    let store = TestStore::new();
+    set_metadata(&store, "nft_1", vec![
+        field("nft_1", COLLECTION_A, false, "name", "Dragon"),
+        field("nft_1", COLLECTION_A, false, "rarity", "rare"),
+        field("nft_1", COLLECTION_A, true, "level", "3"),
+    ]);
+    set_metadata(&store, "nft_2", vec![
+        field("nft_2", COLLECTION_A, false, "name", "drake"),
+        field("nft_2", COLLECTION_A, false, "rarity", "common"),
+        field("nft_2", COLLECTION_A, true, "level", "3"),
+    ]);
+    set_metadata(&store, "nft_3", vec![
+        field("nft_3", COLLECTION_A, false, "name", "100% Dragon"),
+        field("nft_3", COLLECTION_A, false, "rarity", "rare"),
+    ]);
+    set_metadata(&store, "nft_4", vec![
+        field("nft_4", COLLECTION_B, false, "name", "Dragon"),
+        field("nft_4", COLLECTION_B, false, "rarity", "rare"),
+    ]);
+    store
+}
+
+fn search(
+    store: &TestStore,
+    resource_address: Option<&str>,
+    fields: &[(&str, &str)],
+    name_prefix: Option<&str>,
+    offset: u64,
+    limit: u64,
+) -> Vec<String> {
+    let fields = fields
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect::<Vec<_>>();
+    store
+        .with_read_tx(|tx| tx.search_non_fungibles(resource_address, &fields, name_prefix, offset, limit))
+        .unwrap()
+}
+
+mod search_non_fungibles {
+    use super::*;
+
+    #[test]
+    fn it_filters_by_collection_and_every_field() {
+        let store = seeded_store();
+        assert_eq!(search(&store, None, &[("rarity", "rare")], None, 0, 10), vec![
+            "nft_1", "nft_3", "nft_4"
+        ]);
+        assert_eq!(
+            search(&store, Some(COLLECTION_A), &[("rarity", "rare")], None, 0, 10),
+            vec!["nft_1", "nft_3"]
+        );
+        // Mutable and immutable fields are matched alike and all fields must match
+        assert_eq!(
+            search(
+                &store,
+                Some(COLLECTION_A),
+                &[("rarity", "rare"), ("level", "3")],
+                None,
+                0,
+                10
+            ),
+            vec!["nft_1"]
+        );
+        assert!(search(&store, Some(COLLECTION_B), &[("level", "3")], None, 0, 10).is_empty());
+    }
+
+    #[test]
+    fn it_matches_a_case_insensitive_name_prefix() {
+        let store = seeded_store();
+        assert_eq!(search(&store, Some(COLLECTION_A), &[], Some("dra"), 0, 10), vec![
+            "nft_1", "nft_2"
+        ]);
+        assert_eq!(search(&store, None, &[], Some("DRAGON"), 0, 10), vec!["nft_1", "nft_4"]);
+    }
+
+    #[test]
+    fn it_matches_like_wildcards_in_the_name_prefix_literally() {
+        let store = seeded_store();
+        assert_eq!(search(&store, None, &[], Some("100%"), 0, 10), vec!["nft_3"]);
+        assert!(search(&store, None, &[], Some("%"), 0, 10).is_empty());
+        assert!(search(&store, None, &[], Some("_ragon"), 0, 10).is_empty());
+    }
+
+    #[test]
+    fn it_pages_in_address_order() {
+        let store = seeded_store();
+        assert_eq!(search(&store, None, &[], None, 0, 2), vec!["nft_1", "nft_2"]);
+        assert_eq!(search(&store, None, &[], None, 2, 2), vec!["nft_3", "nft_4"]);
+        assert!(search(&store, None, &[], None, 4, 2).is_empty());
+    }
+}
+
+mod set_non_fungible_metadata {
+    use super::*;
+
+    #[test]
+    fn it_replaces_all_fields_of_the_nft() {
+        let store = seeded_store();
+        set_metadata(&store, "nft_1", vec![field(
+            "nft_1",
+            COLLECTION_A,
+            false,
+            "name",
+            "Wyvern",
+        )]);
+
+        let fields = store
+            .with_read_tx(|tx| tx.get_non_fungible_metadata(&["nft_1".to_string()]))
+            .unwrap();
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].field_value, "Wyvern");
+        assert!(search(&store, None, &[("rarity", "rare")], None, 0, 10)
+            .iter()
+            .all(|a| a != "nft_1"));
+
+        // Other NFTs are untouched
+        let fields = store
+            .with_read_tx(|tx| tx.get_non_fungible_metadata(&["nft_2".to_string()]))
+            .unwrap();
+        assert_eq!(fields.len(), 3);
+    }
+
+    #[test]
+    fn it_allows_the_same_key_in_the_mutable_and_immutable_data() {
+        let store = TestStore::new();
+        set_metadata(&store, "nft_1", vec![
+            field("nft_1", COLLECTION_A, false, "level", "1"),
+            field("nft_1", COLLECTION_A, true, "level", "2"),
+        ]);
+
+        let fields = store
+            .with_read_tx(|tx| tx.get_non_fungible_metadata(&["nft_1".to_string()]))
+            .unwrap();
+        assert_eq!(fields.len(), 2);
+    }
+
+    #[test]
+    fn it_rejects_duplicate_fields_and_keeps_the_previous_fields() {
+        let store = seeded_store();
+        let result = store.with_write_tx(|tx| {
+            tx.set_non_fungible_metadata("nft_1", vec![
+                field("nft_1", COLLECTION_A, false, "name", "Wyvern"),
+                field("nft_1", COLLECTION_A, false, "name", "Wyvern"),
+            ])
+        });
+        assert!(result.is_err());
+
+        let fields = store
+            .with_read_tx(|tx| tx.get_non_fungible_metadata(&["nft_1".to_string()]))
+            .unwrap();
+        assert_eq!(fields.len(), 3);
+    }
+}
+
+mod get_non_fungible_metadata {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_fields_of_the_requested_nfts_ordered_by_key() {
+        let store = seeded_store();
+        let fields = store
+            .with_read_tx(|tx| tx.get_non_fungible_metadata(&["nft_2".to_string(), "nft_4".to_string()]))
+            .unwrap();
+
+        let keys = fields.iter().map(|f| f.field_key.as_str()).collect::<Vec<_>>();
+        assert_eq!(keys, vec!["level", "name", "name", "rarity", "rarity"]);
+        assert!(fields
+            .iter()
+            .all(|f| f.nft_address == "nft_2" || f.nft_address == "nft_4"));
+        let level = fields.iter().find(|f| f.field_key == "level").unwrap();
+        assert!(level.mutable);
+        assert_eq!(level.resource_address, COLLECTION_A);
+    }
+}
+
+mod migrations {
+    use super::*;
+
+    #[test]
+    fn it_does_not_reapply_migrations() {
+        let store = seeded_store();
+        store.run_migrations().unwrap();
+
+        let fields = store
+            .with_read_tx(|tx| tx.get_non_fungible_metadata(&["nft_1".to_string()]))
+            .unwrap();
+        assert_eq!(fields.len(), 3);
+    }
+}
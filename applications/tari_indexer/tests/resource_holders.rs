@@ -0,0 +1,152 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+mod support;
+
+use tari_indexer::storage::{
+    models::resource_holder::NewResourceHolder,
+    SubstateStore,
+    SubstateStoreReadTransaction,
+    SubstateStoreWriteTransaction,
+};
+
+use crate::support::TestStore;
+
+const RESOURCE: &str = "resource_a";
+
+fn holder(vault_id: &str, owner: Option<&str>, balance: i64, version: i64) -> NewResourceHolder {
+    NewResourceHolder {
+        vault_id: vault_id.to_string(),
+        resource_address: RESOURCE.to_string(),
+        owner: owner.map(ToString::to_string),
+        balance,
+        version,
+        updated_at: version,
+    }
+}
+
+fn set_holders(store: &TestStore, holders: Vec<NewResourceHolder>) {
+    store
+        .with_write_tx(|tx| holders.into_iter().try_for_each(|h| tx.set_resource_holder(h)))
+        .unwrap();
+}
+
+mod set_resource_holder {
+    use super::*;
+
+    #[test]
+    fn it_only_applies_newer_versions() {
+        let store = TestStore::new();
+        set_holders(&store, vec![holder("vault_1", Some("owner_a"), 100, 2)]);
+
+        // Older and equal versions are ignored
+        set_holders(&store, vec![
+            holder("vault_1", Some("owner_b"), 50, 1),
+            holder("vault_1", Some("owner_b"), 60, 2),
+        ]);
+        let stored = store
+            .with_read_tx(|tx| tx.get_resource_holder("vault_1"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.balance, 100);
+        assert_eq!(stored.version, 2);
+        assert_eq!(stored.owner.as_deref(), Some("owner_a"));
+
+        set_holders(&store, vec![holder("vault_1", Some("owner_b"), 70, 3)]);
+        let stored = store
+            .with_read_tx(|tx| tx.get_resource_holder("vault_1"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.balance, 70);
+        assert_eq!(stored.version, 3);
+        assert_eq!(stored.owner.as_deref(), Some("owner_b"));
+    }
+
+    #[test]
+    fn it_keeps_the_owner_if_the_new_version_has_none() {
+        let store = TestStore::new();
+        set_holders(&store, vec![holder("vault_1", Some("owner_a"), 100, 0)]);
+        set_holders(&store, vec![holder("vault_1", None, 80, 1)]);
+
+        let stored = store
+            .with_read_tx(|tx| tx.get_resource_holder("vault_1"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.balance, 80);
+        assert_eq!(stored.owner.as_deref(), Some("owner_a"));
+    }
+}
+
+mod get_resource_holders {
+    use super::*;
+
+    fn setup() -> TestStore {
+        let store = TestStore::new();
+        set_holders(&store, vec![
+            holder("vault_a1", Some("owner_a"), 10, 0),
+            holder("vault_a2", Some("owner_a"), 5, 0),
+            holder("vault_unowned", None, 12, 0),
+            holder("vault_b1", Some("owner_b"), 3, 0),
+            holder("vault_b2", Some("owner_b"), 0, 0),
+            holder("vault_c1", Some("owner_c"), 0, 0),
+            NewResourceHolder {
+                resource_address: "resource_other".to_string(),
+                ..holder("vault_other", Some("owner_c"), 1_000, 0)
+            },
+        ]);
+        store
+    }
+
+    #[test]
+    fn it_aggregates_balances_by_owner() {
+        let store = setup();
+
+        let holders = store
+            .with_read_tx(|tx| tx.get_resource_holders(RESOURCE, 0, 10))
+            .unwrap();
+        let holders = holders
+            .iter()
+            .map(|h| (h.holder.as_str(), h.total_balance, h.vault_count))
+            .collect::<Vec<_>>();
+        // Empty vaults and owners without a positive balance are not holders
+        assert_eq!(holders, vec![
+            ("owner_a", 15, 2),
+            ("vault_unowned", 12, 1),
+            ("owner_b", 3, 1)
+        ]);
+    }
+
+    #[test]
+    fn it_paginates_the_aggregated_holders() {
+        let store = setup();
+
+        let holders = store
+            .with_read_tx(|tx| tx.get_resource_holders(RESOURCE, 1, 1))
+            .unwrap();
+        assert_eq!(holders.len(), 1);
+        assert_eq!(holders[0].holder, "vault_unowned");
+
+        let holders = store
+            .with_read_tx(|tx| tx.get_resource_holders(RESOURCE, 3, 10))
+            .unwrap();
+        assert!(holders.is_empty());
+    }
+
+    #[test]
+    fn it_counts_the_same_holders() {
+        let store = setup();
+
+        let count = store.with_read_tx(|tx| tx.count_resource_holders(RESOURCE)).unwrap();
+        let holders = store
+            .with_read_tx(|tx| tx.get_resource_holders(RESOURCE, 0, 100))
+            .unwrap();
+        assert_eq!(count, 3);
+        assert_eq!(count, holders.len() as u64);
+        assert_eq!(
+            store
+                .with_read_tx(|tx| tx.count_resource_holders("resource_none"))
+                .unwrap(),
+            0
+        );
+    }
+}
@@ -0,0 +1,173 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+mod support;
+
+use tari_indexer::storage::{
+    models::resource_supply::{NewResourceSupply, NewResourceVolume},
+    SubstateStore,
+    SubstateStoreReadTransaction,
+    SubstateStoreWriteTransaction,
+};
+
+use crate::support::TestStore;
+
+const RESOURCE: &str = "resource_a";
+const OTHER_RESOURCE: &str = "resource_b";
+
+fn supply(resource_address: &str, version: i64, total_supply: i64, timestamp: i64) -> NewResourceSupply {
+    NewResourceSupply {
+        resource_address: resource_address.to_string(),
+        version,
+        total_supply,
+        transaction_id: format!("{:064x}", version),
+        timestamp,
+    }
+}
+
+fn volume(
+    resource_address: &str,
+    transaction: u64,
+    timestamp: i64,
+    minted: i64,
+    burnt: i64,
+    transferred: i64,
+) -> NewResourceVolume {
+    NewResourceVolume {
+        resource_address: resource_address.to_string(),
+        transaction_id: format!("{:064x}", transaction),
+        timestamp,
+        minted,
+        burnt,
+        transferred,
+    }
+}
+
+fn save_supplies(store: &TestStore, supplies: Vec<NewResourceSupply>) {
+    store
+        .with_write_tx(|tx| supplies.into_iter().try_for_each(|s| tx.save_resource_supply(s)))
+        .unwrap();
+}
+
+fn save_volumes(store: &TestStore, volumes: Vec<NewResourceVolume>) {
+    store
+        .with_write_tx(|tx| volumes.into_iter().try_for_each(|v| tx.save_resource_volume(v)))
+        .unwrap();
+}
+
+mod resource_supply {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_latest_version_of_the_supply() {
+        let store = TestStore::new();
+        save_supplies(&store, vec![
+            supply(RESOURCE, 0, 100, 10),
+            supply(RESOURCE, 2, 80, 30),
+            supply(RESOURCE, 1, 150, 20),
+            supply(OTHER_RESOURCE, 5, 1, 40),
+        ]);
+
+        let latest = store
+            .with_read_tx(|tx| tx.get_latest_resource_supply(RESOURCE))
+            .unwrap()
+            .unwrap();
+        assert_eq!(latest.version, 2);
+        assert_eq!(latest.total_supply, 80);
+
+        assert!(store
+            .with_read_tx(|tx| tx.get_latest_resource_supply("resource_unknown"))
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn it_ignores_versions_that_were_already_saved() {
+        let store = TestStore::new();
+        save_supplies(&store, vec![supply(RESOURCE, 0, 100, 10)]);
+        save_supplies(&store, vec![supply(RESOURCE, 0, 999, 10)]);
+
+        let history = store
+            .with_read_tx(|tx| tx.get_resource_supply_history(RESOURCE, 0, 100))
+            .unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].total_supply, 100);
+    }
+
+    #[test]
+    fn it_returns_the_history_in_the_time_range_ordered_by_version() {
+        let store = TestStore::new();
+        save_supplies(&store, vec![
+            supply(RESOURCE, 0, 100, 10),
+            supply(RESOURCE, 1, 150, 20),
+            supply(RESOURCE, 2, 80, 30),
+            supply(RESOURCE, 3, 90, 40),
+            supply(OTHER_RESOURCE, 0, 1, 20),
+        ]);
+
+        // The start is inclusive and the end is exclusive
+        let history = store
+            .with_read_tx(|tx| tx.get_resource_supply_history(RESOURCE, 20, 40))
+            .unwrap();
+        let versions = history.iter().map(|s| s.version).collect::<Vec<_>>();
+        assert_eq!(versions, vec![1, 2]);
+        assert!(history.iter().all(|s| s.resource_address == RESOURCE));
+    }
+}
+
+mod resource_volume_buckets {
+    use super::*;
+
+    #[test]
+    fn it_aggregates_the_volume_of_each_bucket() {
+        let store = TestStore::new();
+        save_volumes(&store, vec![
+            volume(RESOURCE, 1, 0, 100, 0, 0),
+            volume(RESOURCE, 2, 59, 0, 10, 25),
+            volume(RESOURCE, 3, 60, 0, 0, 40),
+            volume(RESOURCE, 4, 185, 5, 0, 0),
+            volume(OTHER_RESOURCE, 5, 30, 1_000, 0, 0),
+        ]);
+
+        let buckets = store
+            .with_read_tx(|tx| tx.get_resource_volume_buckets(RESOURCE, 60, 0, 1_000))
+            .unwrap();
+        let buckets = buckets
+            .iter()
+            .map(|b| (b.bucket_start, b.minted, b.burnt, b.transferred, b.transaction_count))
+            .collect::<Vec<_>>();
+        // Buckets without volume are omitted
+        assert_eq!(buckets, vec![(0, 100, 10, 25, 2), (60, 0, 0, 40, 1), (180, 5, 0, 0, 1)]);
+    }
+
+    #[test]
+    fn it_only_includes_volumes_in_the_time_range() {
+        let store = TestStore::new();
+        save_volumes(&store, vec![
+            volume(RESOURCE, 1, 59, 100, 0, 0),
+            volume(RESOURCE, 2, 60, 7, 0, 0),
+            volume(RESOURCE, 3, 120, 9, 0, 0),
+        ]);
+
+        let buckets = store
+            .with_read_tx(|tx| tx.get_resource_volume_buckets(RESOURCE, 60, 60, 120))
+            .unwrap();
+        assert_eq!(buckets.len(), 1);
+        assert_eq!(buckets[0].bucket_start, 60);
+        assert_eq!(buckets[0].minted, 7);
+    }
+
+    #[test]
+    fn it_records_the_volume_of_a_transaction_once() {
+        let store = TestStore::new();
+        save_volumes(&store, vec![volume(RESOURCE, 1, 0, 100, 0, 0)]);
+        save_volumes(&store, vec![volume(RESOURCE, 1, 0, 100, 0, 0)]);
+
+        let buckets = store
+            .with_read_tx(|tx| tx.get_resource_volume_buckets(RESOURCE, 60, 0, 60))
+            .unwrap();
+        assert_eq!(buckets.len(), 1);
+        assert_eq!(buckets[0].minted, 100);
+        assert_eq!(buckets[0].transaction_count, 1);
+    }
+}
@@ -0,0 +1,138 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+mod support;
+
+use tari_dan_storage::StorageError;
+use tari_engine_types::substate::SubstateId;
+use tari_indexer::storage::{
+    models::{events::NewWebhookCursor, substate::NewSubstate},
+    SubstateStore,
+    SubstateStoreReadTransaction,
+    SubstateStoreWriteTransaction,
+};
+
+use crate::support::TestStore;
+
+const COMPONENT: &str = "component_0000000000000000000000000000000000000000000000000000000000000001";
+
+fn new_substate(version: i64) -> NewSubstate {
+    NewSubstate {
+        address: COMPONENT.to_string(),
+        version,
+        data: "{}".to_string(),
+        tx_hash: "00".repeat(32),
+        template_address: None,
+        module_name: None,
+        timestamp: 1_000 + version,
+    }
+}
+
+fn component_id() -> SubstateId {
+    COMPONENT.parse().unwrap()
+}
+
+#[test]
+fn it_inserts_and_updates_substates() {
+    let store = TestStore::new();
+
+    store.with_write_tx(|tx| tx.set_substate(new_substate(0))).unwrap();
+    let substate = store
+        .with_read_tx(|tx| tx.get_substate(&component_id()))
+        .unwrap()
+        .unwrap();
+    assert_eq!(substate.version, 0);
+
+    store.with_write_tx(|tx| tx.set_substate(new_substate(1))).unwrap();
+    let substate = store
+        .with_read_tx(|tx| tx.get_substate(&component_id()))
+        .unwrap()
+        .unwrap();
+    assert_eq!(substate.version, 1);
+    assert_eq!(substate.timestamp, 1_001);
+
+    let substates = store.with_read_tx(|tx| tx.get_all_substates()).unwrap();
+    assert_eq!(substates.len(), 1);
+}
+
+#[test]
+fn it_rolls_back_failed_write_transactions() {
+    let store = TestStore::new();
+
+    let result = store.with_write_tx(|tx| {
+        tx.set_substate(new_substate(0))?;
+        Err::<(), _>(StorageError::General {
+            details: "test".to_string(),
+        })
+    });
+    assert!(result.is_err());
+
+    let substate = store.with_read_tx(|tx| tx.get_substate(&component_id())).unwrap();
+    assert!(substate.is_none());
+}
+
+#[test]
+fn it_rolls_back_dropped_write_transactions() {
+    let store = TestStore::new();
+
+    let mut tx = store.create_write_tx().unwrap();
+    tx.set_substate(new_substate(0)).unwrap();
+    drop(tx);
+
+    let substate = store.with_read_tx(|tx| tx.get_substate(&component_id())).unwrap();
+    assert!(substate.is_none());
+}
+
+#[test]
+fn it_allows_concurrent_read_and_write_transactions() {
+    let store = TestStore::new();
+    store.with_write_tx(|tx| tx.set_substate(new_substate(0))).unwrap();
+
+    let mut read_tx = store.create_read_tx().unwrap();
+    assert_eq!(read_tx.get_substate(&component_id()).unwrap().unwrap().version, 0);
+
+    // Each transaction has its own pooled connection, so a write can commit while the read is still open
+    std::thread::scope(|s| {
+        s.spawn(|| store.with_write_tx(|tx| tx.set_substate(new_substate(1))).unwrap())
+            .join()
+            .unwrap();
+    });
+    drop(read_tx);
+
+    let substate = store
+        .with_read_tx(|tx| tx.get_substate(&component_id()))
+        .unwrap()
+        .unwrap();
+    assert_eq!(substate.version, 1);
+}
+
+#[test]
+fn it_rejects_writes_to_a_read_only_store() {
+    let store = TestStore::new();
+    store.with_write_tx(|tx| tx.set_substate(new_substate(0))).unwrap();
+
+    let replica = store.read_only();
+    assert!(replica.create_write_tx().is_err());
+
+    let substate = replica
+        .with_read_tx(|tx| tx.get_substate(&component_id()))
+        .unwrap()
+        .unwrap();
+    assert_eq!(substate.version, 0);
+}
+
+#[test]
+fn it_upserts_webhook_cursors() {
+    let store = TestStore::new();
+    let cursor = |last_event_id| NewWebhookCursor {
+        name: "hook".to_string(),
+        last_event_id,
+        updated_at: 0,
+    };
+
+    assert_eq!(store.with_read_tx(|tx| tx.get_webhook_cursor("hook")).unwrap(), None);
+    store.with_write_tx(|tx| tx.set_webhook_cursor(cursor(5))).unwrap();
+    store.with_write_tx(|tx| tx.set_webhook_cursor(cursor(9))).unwrap();
+    assert_eq!(store.with_read_tx(|tx| tx.get_webhook_cursor("hook")).unwrap(), Some(9));
+    assert_eq!(store.with_read_tx(|tx| tx.get_webhook_cursor("other")).unwrap(), None);
+}
@@ -0,0 +1,165 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+// Each test file uses a different subset of the helpers
+#![allow(dead_code)]
+
+use std::ops::Deref;
+
+use diesel::{backend::Backend, connection::SimpleConnection, Connection, SqliteConnection};
+use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+use tari_indexer::storage::SqlSubstateStore;
+
+/// A migrated substate store backed by a database that is removed when the store is dropped.
+///
+/// When the indexer is built with the `postgres` feature and `TARI_INDEXER_TEST_POSTGRES_URL` is set to the URL of a
+/// PostgreSQL server, each test creates its own database on that server. Otherwise each test uses a SQLite database in
+/// a temporary directory.
+pub struct TestStore {
+    // Declared first so that the pooled connections are closed before the database is removed
+    store: SqlSubstateStore,
+    _database: TestDatabase,
+}
+
+impl TestStore {
+    pub fn new() -> Self {
+        #[cfg(feature = "postgres")]
+        if let Ok(url) = std::env::var(postgres::TEST_POSTGRES_URL_VAR) {
+            let database = postgres::TestDatabase::create(&url);
+            let store = SqlSubstateStore::try_create_postgres(database.url()).unwrap();
+            return Self::migrated(store, TestDatabase::Postgres(database));
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let store = SqlSubstateStore::try_create_sqlite(dir.path().join("indexer.sqlite")).unwrap();
+        Self::migrated(store, TestDatabase::Sqlite(dir))
+    }
+
+    /// Returns a store whose database was migrated up to, but not including, the migration starting with
+    /// `before_migration` and seeded with `seed_sql`, before the remaining migrations were run
+    pub fn seeded_before_migration(before_migration: &str, seed_sql: &str) -> Self {
+        #[cfg(feature = "postgres")]
+        if let Ok(url) = std::env::var(postgres::TEST_POSTGRES_URL_VAR) {
+            const POSTGRES_MIGRATIONS: EmbeddedMigrations = embed_migrations!("src/storage/migrations_postgres");
+            let database = postgres::TestDatabase::create(&url);
+            let mut conn = diesel::PgConnection::establish(database.url()).unwrap();
+            migrate_before(&mut conn, POSTGRES_MIGRATIONS, before_migration);
+            conn.batch_execute(seed_sql).unwrap();
+            drop(conn);
+            let store = SqlSubstateStore::try_create_postgres(database.url()).unwrap();
+            return Self::migrated(store, TestDatabase::Postgres(database));
+        }
+
+        const SQLITE_MIGRATIONS: EmbeddedMigrations = embed_migrations!("src/storage/migrations");
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("indexer.sqlite");
+        let mut conn = SqliteConnection::establish(path.to_str().unwrap()).unwrap();
+        migrate_before(&mut conn, SQLITE_MIGRATIONS, before_migration);
+        conn.batch_execute(seed_sql).unwrap();
+        drop(conn);
+        let store = SqlSubstateStore::try_create_sqlite(path).unwrap();
+        Self::migrated(store, TestDatabase::Sqlite(dir))
+    }
+
+    fn migrated(store: SqlSubstateStore, database: TestDatabase) -> Self {
+        store.run_migrations().unwrap();
+        Self {
+            store,
+            _database: database,
+        }
+    }
+
+    /// Returns a read-only handle to the same database, as used by API replicas
+    pub fn read_only(&self) -> SqlSubstateStore {
+        self.store.clone().with_read_only(true)
+    }
+}
+
+impl Deref for TestStore {
+    type Target = SqlSubstateStore;
+
+    fn deref(&self) -> &Self::Target {
+        &self.store
+    }
+}
+
+/// Runs the pending migrations that come before the migration starting with `before_migration`
+fn migrate_before<DB: Backend>(
+    conn: &mut impl MigrationHarness<DB>,
+    migrations: EmbeddedMigrations,
+    before_migration: &str,
+) {
+    let pending = conn.pending_migrations(migrations).unwrap();
+    let position = pending
+        .iter()
+        .position(|m| m.name().to_string().starts_with(before_migration))
+        .unwrap_or_else(|| panic!("Migration {} not found", before_migration));
+    for migration in &pending[..position] {
+        conn.run_migration(migration.as_ref()).unwrap();
+    }
+}
+
+enum TestDatabase {
+    Sqlite(tempfile::TempDir),
+    #[cfg(feature = "postgres")]
+    Postgres(postgres::TestDatabase),
+}
+
+#[cfg(feature = "postgres")]
+mod postgres {
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        time::{SystemTime, UNIX_EPOCH},
+    };
+
+    use diesel::{sql_query, Connection, PgConnection, RunQueryDsl};
+    use url::Url;
+
+    pub const TEST_POSTGRES_URL_VAR: &str = "TARI_INDEXER_TEST_POSTGRES_URL";
+
+    static NEXT_DATABASE: AtomicUsize = AtomicUsize::new(0);
+
+    /// A database created on the test server for a single test
+    pub struct TestDatabase {
+        server_url: String,
+        name: String,
+        url: String,
+    }
+
+    impl TestDatabase {
+        pub fn create(server_url: &str) -> Self {
+            let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().subsec_nanos();
+            let name = format!(
+                "indexer_test_{}_{}_{}",
+                std::process::id(),
+                nanos,
+                NEXT_DATABASE.fetch_add(1, Ordering::Relaxed)
+            );
+            let mut conn = PgConnection::establish(server_url).unwrap();
+            sql_query(format!("CREATE DATABASE {}", name))
+                .execute(&mut conn)
+                .unwrap();
+
+            let mut url = Url::parse(server_url).unwrap();
+            url.set_path(&name);
+            Self {
+                server_url: server_url.to_string(),
+                name,
+                url: url.to_string(),
+            }
+        }
+
+        pub fn url(&self) -> &str {
+            &self.url
+        }
+    }
+
+    impl Drop for TestDatabase {
+        fn drop(&mut self) {
+            if let Ok(mut conn) = PgConnection::establish(&self.server_url) {
+                let _ignore =
+                    sql_query(format!("DROP DATABASE IF EXISTS {} WITH (FORCE)", self.name)).execute(&mut conn);
+            }
+        }
+    }
+}
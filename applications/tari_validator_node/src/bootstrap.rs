@@ -265,6 +265,9 @@ pub async fn spawn_services(
         per_byte_storage_cost: 1,
         per_event_cost: 1,
         per_log_cost: 1,
+        per_hash_cost: 1,
+        per_hash_block_cost: 1,
+        per_signature_verification_cost: 10,
     };
 
     let (tx_hotstuff_events, _) = broadcast::channel(100);
@@ -300,6 +303,7 @@ pub async fn spawn_services(
         TransactionProcessorConfig::builder()
             .with_network(config.network)
             .with_template_binary_max_size_bytes(consensus_constants.template_binary_max_size_bytes)
+            .with_max_call_depth(consensus_constants.max_call_depth)
             .build(),
         template_manager.clone(),
         fee_table,
@@ -572,6 +576,7 @@ where
             owner_rule: OwnerRule::None,
             access_rules: ComponentAccessRules::allow_all(),
             entity_id: EntityId::default(),
+            reentrancy_guard: false,
             body: ComponentBody {
                 state: cbor!({"vault" => XTR_FAUCET_VAULT_ADDRESS}).unwrap(),
             },
@@ -20,6 +20,9 @@
 //  WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 //  USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use std::collections::HashSet;
+
+use indexmap::IndexMap;
 use log::info;
 use tari_dan_app_utilities::{
     substate_file_cache::SubstateFileCache,
@@ -29,10 +32,18 @@ use tari_dan_app_utilities::{
 use tari_dan_common_types::PeerAddress;
 use tari_dan_engine::state_store::{new_memory_store, StateStoreError};
 use tari_dan_storage::StorageError;
-use tari_engine_types::commit_result::ExecuteResult;
+use tari_engine_types::{
+    commit_result::ExecuteResult,
+    indexed_value::IndexedValueError,
+    instruction_result::InstructionResult,
+    substate::SubstateId,
+    virtual_substate::{VirtualSubstate, VirtualSubstateId, VirtualSubstates},
+};
 use tari_epoch_manager::{base_layer::EpochManagerHandle, EpochManagerError, EpochManagerReader};
+use tari_indexer_lib::substate_decoder::find_related_substates;
 use tari_rpc_framework::RpcStatus;
 use tari_state_store_sqlite::SqliteStateStore;
+use tari_template_lib::{args::Arg, models::ComponentAddress};
 use tari_transaction::Transaction;
 use tari_validator_node_client::ValidatorNodeClientError;
 use tari_validator_node_rpc::client::TariValidatorNodeRpcClientFactory;
@@ -46,6 +57,9 @@ use crate::{
 };
 
 const LOG_TARGET: &str = "tari::dan::validator_node::dry_run_transaction_processor";
+/// The number of levels of substates referenced by a component (e.g. vaults and their resources) that are resolved for
+/// a view call
+const MAX_VIEW_SUBSTATE_DEPTH: usize = 3;
 
 #[derive(Error, Debug)]
 pub enum DryRunTransactionProcessorError {
@@ -67,6 +81,8 @@ pub enum DryRunTransactionProcessorError {
     VirtualSubstateError(#[from] VirtualSubstateError),
     #[error("Execution thread failed: {0}")]
     ExecutionThreadFailed(#[from] task::JoinError),
+    #[error("Indexed value error: {0}")]
+    IndexedValueError(#[from] IndexedValueError),
 }
 
 #[derive(Clone, Debug)]
@@ -99,9 +115,12 @@ impl DryRunTransactionProcessor {
         }
     }
 
+    /// Executes the transaction against the current state without committing it. If `trace` is true, the result
+    /// includes an execution trace.
     pub async fn process_transaction(
         &self,
         transaction: Transaction,
+        trace: bool,
     ) -> Result<ExecuteResult, DryRunTransactionProcessorError> {
         // Resolve all local and foreign substates
         let mut temp_state_store = new_memory_store();
@@ -123,7 +142,7 @@ impl DryRunTransactionProcessor {
         temp_state_store.set_many(foreign_inputs)?;
 
         // execute the payload in the WASM engine and return the result
-        let processor = self.payload_processor.clone();
+        let processor = self.payload_processor.clone().with_tracing(trace);
         let exec_output = task::spawn_blocking(move || {
             processor.execute(transaction, temp_state_store.into_read_only(), virtual_substates)
         })
@@ -135,4 +154,59 @@ impl DryRunTransactionProcessor {
 
         Ok(result)
     }
+
+    /// Calls a non-mutating method of a component against the current committed state without a transaction
+    pub async fn call_view(
+        &self,
+        component_address: ComponentAddress,
+        method: String,
+        args: Vec<Arg>,
+    ) -> Result<InstructionResult, DryRunTransactionProcessorError> {
+        let current_epoch = self.epoch_manager.current_epoch().await?;
+
+        // Resolve the component and the substates it references
+        let mut substates = IndexMap::new();
+        let mut to_resolve = vec![SubstateId::Component(component_address)];
+        for _ in 0..MAX_VIEW_SUBSTATE_DEPTH {
+            if to_resolve.is_empty() {
+                break;
+            }
+            let resolved = self.substate_resolver.resolve_latest_substates(&to_resolve).await?;
+            let related = resolved
+                .values()
+                .map(find_related_substates)
+                .collect::<Result<Vec<_>, _>>()?;
+            substates.extend(resolved);
+            to_resolve = related
+                .into_iter()
+                .flatten()
+                .filter(|id| !substates.contains_key(id))
+                .collect::<HashSet<_>>()
+                .into_iter()
+                .collect();
+        }
+
+        let mut state_store = new_memory_store();
+        state_store.set_many(substates)?;
+
+        let mut virtual_substates = VirtualSubstates::new();
+        virtual_substates.insert(
+            VirtualSubstateId::CurrentEpoch,
+            VirtualSubstate::CurrentEpoch(current_epoch.as_u64()),
+        );
+
+        let processor = self.payload_processor.clone();
+        let result = task::spawn_blocking(move || {
+            processor.call_view(
+                &component_address,
+                &method,
+                args,
+                state_store.into_read_only(),
+                virtual_substates,
+            )
+        })
+        .await??;
+
+        Ok(result)
+    }
 }
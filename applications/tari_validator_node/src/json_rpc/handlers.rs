@@ -46,6 +46,8 @@ use tari_validator_node_client::types::{
     self,
     AddPeerRequest,
     AddPeerResponse,
+    CallViewRequest,
+    CallViewResponse,
     ConnectionDirection,
     DryRunTransactionFinalizeResult,
     GetAllVnsRequest,
@@ -160,6 +162,7 @@ impl JsonRpcHandlers {
         let SubmitTransactionRequest {
             transaction,
             is_dry_run,
+            trace,
         } = value.parse_params()?;
         debug!(
             target: LOG_TARGET,
@@ -184,7 +187,7 @@ impl JsonRpcHandlers {
         if is_dry_run {
             let result = self
                 .dry_run_transaction_processor
-                .process_transaction(transaction)
+                .process_transaction(transaction, trace)
                 .await;
             match result {
                 Ok(exec_result) => {
@@ -194,6 +197,7 @@ impl JsonRpcHandlers {
                             decision: QuorumDecision::Accept,
                             fee_breakdown: Some(exec_result.finalize.fee_receipt.to_cost_breakdown()),
                             finalize: exec_result.finalize,
+                            trace: exec_result.trace,
                         }),
                     };
 
@@ -225,6 +229,32 @@ impl JsonRpcHandlers {
         }
     }
 
+    pub async fn call_view(&self, value: JsonRpcExtractor) -> JrpcResult {
+        let answer_id = value.get_answer_id();
+        let CallViewRequest {
+            component_address,
+            method,
+            args,
+        } = value.parse_params()?;
+
+        let result = self
+            .dry_run_transaction_processor
+            .call_view(component_address, method, args)
+            .await
+            .map_err(|e| {
+                JsonRpcResponse::error(
+                    answer_id,
+                    JsonRpcError::new(JsonRpcErrorReason::ApplicationError(1), e.to_string(), json!(null)),
+                )
+            })?;
+        let json_result = json::to_value(result.indexed.value()).map_err(internal_error(answer_id))?;
+
+        Ok(JsonRpcResponse::success(answer_id, CallViewResponse {
+            result,
+            json_result,
+        }))
+    }
+
     pub async fn get_state(&self, value: JsonRpcExtractor) -> JrpcResult {
         let answer_id = value.get_answer_id();
         let request: GetStateRequest = value.parse_params()?;
@@ -67,6 +67,7 @@ async fn handler(Extension(handlers): Extension<Arc<JsonRpcHandlers>>, value: Js
         // Transaction
         // "get_transaction_status" => handlers.get_transaction_status(value).await,
         "submit_transaction" => handlers.submit_transaction(value).await,
+        "call_view" => handlers.call_view(value).await,
         "get_recent_transactions" => handlers.get_recent_transactions(value).await,
         "get_transaction" => handlers.get_transaction(value).await,
         "get_transaction_result" => handlers.get_transaction_result(value).await,
@@ -56,6 +56,33 @@ where
         }
     }
 
+    /// Resolves the latest version of the given substates. Substates that are not found (or are DOWN) locally are
+    /// requested from the committee responsible for them.
+    pub async fn resolve_latest_substates<'a, I: IntoIterator<Item = &'a SubstateId>>(
+        &self,
+        substate_ids: I,
+    ) -> Result<IndexMap<SubstateId, Substate>, SubstateResolverError> {
+        let (found_local_substates, missing_substate_ids) = self
+            .store
+            .with_read_tx(|tx| SubstateRecord::get_any_max_version(tx, substate_ids))?;
+
+        let mut substates = IndexMap::with_capacity(found_local_substates.len());
+        let mut foreign = missing_substate_ids
+            .into_iter()
+            .map(|id| SubstateRequirement::unversioned(id.clone()))
+            .collect::<HashSet<_>>();
+        for substate in found_local_substates {
+            if substate.is_destroyed() {
+                foreign.insert(SubstateRequirement::unversioned(substate.substate_id.clone()));
+                continue;
+            }
+            substates.insert(substate.substate_id.clone(), substate.into_substate());
+        }
+
+        substates.extend(self.resolve_remote_substates(&foreign).await?);
+        Ok(substates)
+    }
+
     fn resolve_local_substates(&self, transaction: &Transaction) -> Result<ResolvedSubstates, SubstateResolverError> {
         let mut substates = IndexMap::new();
         let inputs = transaction.all_inputs_substate_ids_iter();
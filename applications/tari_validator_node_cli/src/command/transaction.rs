@@ -245,6 +245,7 @@ pub async fn submit_transaction(
     let request = SubmitTransactionRequest {
         transaction,
         is_dry_run: common.dry_run,
+        trace: false,
     };
 
     let mut resp = client.submit_transaction(request).await?;
@@ -282,6 +283,7 @@ pub async fn submit_transaction(
             },
             fee_breakdown: Some(result.finalize.fee_receipt.to_cost_breakdown()),
             finalize: result.finalize,
+            trace: result.trace,
         });
     }
 
@@ -484,6 +486,9 @@ fn summarize_finalize_result(finalize: &FinalizeResult) {
             Type::Other { ref name } if name == "Bucket" => {
                 println!("{}: {}", name, result.decode::<BucketId>().unwrap());
             },
+            ty @ (Type::Option(_) | Type::Map(_, _)) => {
+                println!("{}: {}", ty, serde_json::to_string(&result.indexed).unwrap());
+            },
             Type::Other { ref name } => {
                 println!("{}: {}", name, serde_json::to_string(&result.indexed).unwrap());
             },
@@ -566,7 +571,7 @@ fn display_vec<W: fmt::Write>(writer: &mut W, ty: &Type, result: &InstructionRes
                 display_slice(&result.decode::<Vec<NonFungibleId>>().unwrap())
             )?;
         },
-        Type::Other { .. } => {
+        Type::Option(_) | Type::Map(_, _) | Type::Other { .. } => {
             write!(writer, "{}", serde_json::to_string(&result.indexed).unwrap())?;
         },
     }
@@ -4,10 +4,12 @@
 export * from "./types/AbortReason";
 export * from "./types/AccessRule";
 export * from "./types/Account";
+export * from "./types/AdditionalOutputStatement";
 export * from "./types/Amount";
 export * from "./types/Arg";
 export * from "./types/ArgDef";
 export * from "./types/AuthHook";
+export * from "./types/BalanceChange";
 export * from "./types/Block";
 export * from "./types/BlockHeader";
 export * from "./types/BucketId";
@@ -26,17 +28,20 @@ export * from "./types/ConfidentialOutputStatement";
 export * from "./types/ConfidentialStatement";
 export * from "./types/ConfidentialTransferInputSelection";
 export * from "./types/ConfidentialWithdrawProof";
+export * from "./types/Contact";
 export * from "./types/Decision";
 export * from "./types/ElgamalVerifiableBalance";
 export * from "./types/EntityId";
 export * from "./types/Epoch";
 export * from "./types/Era";
 export * from "./types/Event";
+export * from "./types/EventDef";
 export * from "./types/EvictNodeAtom";
 export * from "./types/Evidence";
 export * from "./types/EvidenceInputLockData";
 export * from "./types/ExecuteResult";
 export * from "./types/ExecutedTransaction";
+export * from "./types/ExecutionTrace";
 export * from "./types/ExtraData";
 export * from "./types/FeeBreakdown";
 export * from "./types/FeeClaim";
@@ -44,6 +49,8 @@ export * from "./types/FeeClaimAddress";
 export * from "./types/FeeCostBreakdown";
 export * from "./types/FeeReceipt";
 export * from "./types/FeeSource";
+export * from "./types/FieldDef";
+export * from "./types/FieldsDef";
 export * from "./types/FinalizeResult";
 export * from "./types/ForeignProposalAtom";
 export * from "./types/FunctionDef";
@@ -101,8 +108,11 @@ export * from "./types/SubstateType";
 export * from "./types/SubstateValue";
 export * from "./types/TemplateDef";
 export * from "./types/TemplateDefV1";
+export * from "./types/TraceEntry";
+export * from "./types/TraceEvent";
 export * from "./types/Transaction";
 export * from "./types/TransactionAtom";
+export * from "./types/TransactionDirection";
 export * from "./types/TransactionPoolRecord";
 export * from "./types/TransactionPoolStage";
 export * from "./types/TransactionReceipt";
@@ -113,12 +123,15 @@ export * from "./types/TransactionSignature";
 export * from "./types/TransactionStatus";
 export * from "./types/TransactionV1";
 export * from "./types/Type";
+export * from "./types/TypeDef";
+export * from "./types/TypeDefKind";
 export * from "./types/UnclaimedConfidentialOutput";
 export * from "./types/UnclaimedConfidentialOutputAddress";
 export * from "./types/UnsealedTransactionV1";
 export * from "./types/UnsignedTransaction";
 export * from "./types/UnsignedTransactionV1";
 export * from "./types/ValidatorSignature";
+export * from "./types/VariantDef";
 export * from "./types/Vault";
 export * from "./types/VaultId";
 export * from "./types/VersionedSubstateId";
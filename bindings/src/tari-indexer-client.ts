@@ -35,3 +35,33 @@ export * from "./types/tari-indexer-client/GetNonFungibleCollectionsResponse";
 export * from "./types/tari-indexer-client/IndexerSubmitTransactionResponse";
 export * from "./types/tari-indexer-client/IndexerConnectionDirection";
 export * from "./types/tari-indexer-client/ListSubstateItem";
+export * from "./types/tari-indexer-client/IndexerCallViewRequest";
+export * from "./types/tari-indexer-client/IndexerCallViewResponse";
+export * from "./types/tari-indexer-client/AccountBalanceChange";
+export * from "./types/tari-indexer-client/AccountTransaction";
+export * from "./types/tari-indexer-client/BalanceChangeKind";
+export * from "./types/tari-indexer-client/BlockTransaction";
+export * from "./types/tari-indexer-client/EpochStats";
+export * from "./types/tari-indexer-client/GetAccountPortfolioRequest";
+export * from "./types/tari-indexer-client/GetAccountPortfolioResponse";
+export * from "./types/tari-indexer-client/GetAccountTransactionsRequest";
+export * from "./types/tari-indexer-client/GetAccountTransactionsResponse";
+export * from "./types/tari-indexer-client/GetBlockRequest";
+export * from "./types/tari-indexer-client/GetBlockResponse";
+export * from "./types/tari-indexer-client/GetEpochStatsRequest";
+export * from "./types/tari-indexer-client/GetEpochStatsResponse";
+export * from "./types/tari-indexer-client/GetResourceHoldersRequest";
+export * from "./types/tari-indexer-client/GetResourceHoldersResponse";
+export * from "./types/tari-indexer-client/GetTransactionsInBlockRequest";
+export * from "./types/tari-indexer-client/GetTransactionsInBlockResponse";
+export * from "./types/tari-indexer-client/IndexedBlock";
+export * from "./types/tari-indexer-client/ListBlocksRequest";
+export * from "./types/tari-indexer-client/ListBlocksResponse";
+export * from "./types/tari-indexer-client/NonFungibleMetadata";
+export * from "./types/tari-indexer-client/PortfolioBalance";
+export * from "./types/tari-indexer-client/ResourceHolder";
+export * from "./types/tari-indexer-client/SearchNonFungiblesRequest";
+export * from "./types/tari-indexer-client/SearchNonFungiblesResponse";
+export * from "./types/tari-indexer-client/SortOrder";
+export * from "./types/tari-indexer-client/SubstateOrderBy";
+export * from "./types/tari-indexer-client/TransferDirection";
@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Amount } from "./Amount";
+import type { ResourceAddress } from "./ResourceAddress";
+import type { SubstateId } from "./SubstateId";
+
+export interface BalanceChange {
+  transaction_id: string;
+  vault_address: SubstateId;
+  resource_address: ResourceAddress;
+  balance_before: Amount | null;
+  balance_after: Amount;
+  timestamp: string;
+}
@@ -4,4 +4,5 @@ import type { AccessRule } from "./AccessRule";
 export interface ComponentAccessRules {
   method_access: Record<string, AccessRule>;
   default: AccessRule;
+  upgrade_template: AccessRule;
 }
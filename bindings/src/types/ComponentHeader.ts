@@ -11,5 +11,6 @@ export interface ComponentHeader {
   owner_rule: OwnerRule;
   access_rules: ComponentAccessRules;
   entity_id: EntityId;
+  reentrancy_guard: boolean;
   body: ComponentBody;
 }
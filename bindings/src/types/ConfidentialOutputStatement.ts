@@ -1,4 +1,5 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AdditionalOutputStatement } from "./AdditionalOutputStatement";
 import type { Amount } from "./Amount";
 import type { ConfidentialStatement } from "./ConfidentialStatement";
 
@@ -8,4 +9,5 @@ export interface ConfidentialOutputStatement {
   range_proof: Array<number>;
   output_revealed_amount: Amount;
   change_revealed_amount: Amount;
+  additional_outputs: Array<AdditionalOutputStatement>;
 }
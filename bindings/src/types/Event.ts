@@ -1,5 +1,4 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
-import type { Metadata } from "./Metadata";
 import type { SubstateId } from "./SubstateId";
 
 export interface Event {
@@ -7,5 +6,5 @@ export interface Event {
   template_address: string;
   tx_hash: string;
   topic: string;
-  payload: Metadata;
+  payload: any;
 }
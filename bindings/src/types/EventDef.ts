@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Type } from "./Type";
+
+export interface EventDef {
+  topic: string;
+  payload_type: Type;
+}
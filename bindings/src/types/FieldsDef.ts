@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FieldDef } from "./FieldDef";
+import type { Type } from "./Type";
+
+export type FieldsDef = { Named: Array<FieldDef> } | { Unnamed: Array<Type> } | "Unit";
@@ -1,8 +1,12 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EventDef } from "./EventDef";
 import type { FunctionDef } from "./FunctionDef";
+import type { TypeDef } from "./TypeDef";
 
 export interface TemplateDefV1 {
   template_name: string;
   tari_version: string;
   functions: Array<FunctionDef>;
+  events: Array<EventDef>;
+  types: Array<TypeDef>;
 }
@@ -0,0 +1,28 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Amount } from "./Amount";
+import type { BucketId } from "./BucketId";
+import type { ComponentAddress } from "./ComponentAddress";
+import type { FeeSource } from "./FeeSource";
+import type { LockFlag } from "./LockFlag";
+import type { ProofId } from "./ProofId";
+import type { ResourceAddress } from "./ResourceAddress";
+import type { SubstateId } from "./SubstateId";
+
+export type TraceEvent =
+  | { RuntimeCall: { function: string } }
+  | { Action: { action: string } }
+  | {
+      CallFramePushed: {
+        template_address: string;
+        module_name: string;
+        component_address: ComponentAddress | null;
+      };
+    }
+  | "CallFramePopped"
+  | { SubstateLocked: { substate_id: SubstateId; lock_flag: LockFlag } }
+  | { SubstateUnlocked: { substate_id: SubstateId } }
+  | { BucketCreated: { bucket_id: BucketId; resource_address: ResourceAddress; amount: Amount } }
+  | { BucketTaken: { bucket_id: BucketId } }
+  | { ProofCreated: { proof_id: ProofId; resource_address: ResourceAddress } }
+  | { ProofDropped: { proof_id: ProofId } }
+  | { FeeCharged: { source: FeeSource; amount: number } };
@@ -16,4 +16,6 @@ export type Type =
   | "String"
   | { Vec: Type }
   | { Tuple: Array<Type> }
+  | { Option: Type }
+  | { Map: [Type, Type] }
   | { Other: { name: string } };
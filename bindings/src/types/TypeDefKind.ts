@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FieldsDef } from "./FieldsDef";
+import type { VariantDef } from "./VariantDef";
+
+export type TypeDefKind = { Struct: { fields: FieldsDef } } | { Enum: { variants: Array<VariantDef> } } | "Engine" | "External";
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Amount } from "../Amount";
+import type { BalanceChangeKind } from "./BalanceChangeKind";
+import type { ResourceAddress } from "../ResourceAddress";
+import type { SubstateId } from "../SubstateId";
+import type { TransferDirection } from "./TransferDirection";
+
+export interface AccountBalanceChange {
+  resource_address: ResourceAddress;
+  vault_id: SubstateId;
+  kind: BalanceChangeKind;
+  direction: TransferDirection;
+  amount: Amount;
+}
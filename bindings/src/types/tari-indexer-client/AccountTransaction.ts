@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AccountBalanceChange } from "./AccountBalanceChange";
+
+export interface AccountTransaction {
+  transaction_id: string;
+  timestamp: number;
+  balance_changes: Array<AccountBalanceChange>;
+}
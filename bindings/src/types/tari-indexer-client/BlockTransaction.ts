@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Decision } from "../Decision";
+
+export interface BlockTransaction {
+  transaction_id: string;
+  command: string;
+  decision: Decision;
+  transaction_fee: number;
+}
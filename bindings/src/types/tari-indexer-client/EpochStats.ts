@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Epoch } from "../Epoch";
+
+export interface EpochStats {
+  epoch: Epoch;
+  num_blocks: number;
+  num_dummy_blocks: number;
+  num_shard_groups: number;
+  num_commands: number;
+  num_transactions: number;
+  total_leader_fees: number;
+  first_block_timestamp: number | null;
+  last_block_timestamp: number | null;
+}
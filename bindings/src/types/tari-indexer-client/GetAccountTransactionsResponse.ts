@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AccountTransaction } from "./AccountTransaction";
+
+export interface GetAccountTransactionsResponse {
+  transactions: Array<AccountTransaction>;
+  total: number;
+}
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BlockHeader } from "../BlockHeader";
+import type { Command } from "../Command";
+import type { IndexedBlock } from "./IndexedBlock";
+import type { QuorumCertificate } from "../QuorumCertificate";
+
+export interface GetBlockResponse {
+  block: IndexedBlock;
+  header: BlockHeader;
+  justify: QuorumCertificate;
+  commands: Array<Command>;
+}
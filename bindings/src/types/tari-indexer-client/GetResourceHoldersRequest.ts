@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ResourceAddress } from "../ResourceAddress";
+
+export interface GetResourceHoldersRequest {
+  resource_address: ResourceAddress;
+  limit: number | null;
+  offset: number | null;
+}
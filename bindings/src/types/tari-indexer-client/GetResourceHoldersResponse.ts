@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ResourceHolder } from "./ResourceHolder";
+
+export interface GetResourceHoldersResponse {
+  holders: Array<ResourceHolder>;
+  total_holders: number;
+}
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Epoch } from "../Epoch";
+import type { NodeHeight } from "../NodeHeight";
+import type { ShardGroup } from "../ShardGroup";
+
+export interface IndexedBlock {
+  block_id: string;
+  parent_id: string;
+  epoch: Epoch;
+  shard_group: ShardGroup;
+  height: NodeHeight;
+  proposed_by: string;
+  timestamp: number;
+  is_dummy: boolean;
+  command_count: number;
+  transaction_count: number;
+  total_leader_fee: number;
+}
@@ -6,4 +6,5 @@ export interface IndexerSubmitTransactionRequest {
   transaction: Transaction;
   required_substates: Array<SubstateRequirement>;
   is_dry_run: boolean;
+  trace: boolean;
 }
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Epoch } from "../Epoch";
+import type { ShardGroup } from "../ShardGroup";
+
+export interface ListBlocksRequest {
+  epoch: Epoch | null;
+  shard_group: ShardGroup | null;
+  limit: number | null;
+  offset: number | null;
+}
@@ -1,9 +1,20 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SortOrder } from "./SortOrder";
+import type { SubstateOrderBy } from "./SubstateOrderBy";
 import type { SubstateType } from "../SubstateType";
 
 export interface ListSubstatesRequest {
   filter_by_template: string | null;
   filter_by_type: SubstateType | null;
+  filter_by_module_name: string | null;
+  filter_by_transaction: string | null;
+  min_version: number | null;
+  max_version: number | null;
+  from_timestamp: number | null;
+  to_timestamp: number | null;
+  order_by: SubstateOrderBy;
+  order: SortOrder;
+  cursor: string | null;
   limit: bigint | null;
   offset: bigint | null;
 }
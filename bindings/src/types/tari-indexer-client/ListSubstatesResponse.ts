@@ -3,4 +3,5 @@ import type { ListSubstateItem } from "./ListSubstateItem";
 
 export interface ListSubstatesResponse {
   substates: Array<ListSubstateItem>;
+  next_cursor: string | null;
 }
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SubstateId } from "../SubstateId";
+
+export interface NonFungibleMetadata {
+  address: SubstateId;
+  data: Record<string, string>;
+  mutable_data: Record<string, string>;
+}
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Amount } from "../Amount";
+import type { ResourceAddress } from "../ResourceAddress";
+import type { SubstateId } from "../SubstateId";
+
+export interface PortfolioBalance {
+  resource_address: ResourceAddress;
+  vault_id: SubstateId;
+  balance: Amount;
+}
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Amount } from "../Amount";
+import type { SubstateId } from "../SubstateId";
+
+export interface ResourceHolder {
+  holder: SubstateId;
+  balance: Amount;
+  vault_count: number;
+}
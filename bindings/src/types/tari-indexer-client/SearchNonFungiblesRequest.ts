@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ResourceAddress } from "../ResourceAddress";
+
+export interface SearchNonFungiblesRequest {
+  resource_address: ResourceAddress | null;
+  fields: Record<string, string>;
+  name_prefix: string | null;
+  limit: number | null;
+  offset: number | null;
+}
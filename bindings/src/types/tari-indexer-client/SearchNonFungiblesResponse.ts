@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { NonFungibleMetadata } from "./NonFungibleMetadata";
+
+export interface SearchNonFungiblesResponse {
+  non_fungibles: Array<NonFungibleMetadata>;
+}
@@ -1,4 +1,5 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ExecutionTrace } from "../ExecutionTrace";
 import type { FeeCostBreakdown } from "../FeeCostBreakdown";
 import type { FinalizeResult } from "../FinalizeResult";
 import type { QuorumDecision } from "../QuorumDecision";
@@ -7,4 +8,5 @@ export interface DryRunTransactionFinalizeResult {
   decision: QuorumDecision;
   finalize: FinalizeResult;
   fee_breakdown: FeeCostBreakdown | null;
+  trace: ExecutionTrace | null;
 }
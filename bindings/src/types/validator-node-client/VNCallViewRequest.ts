@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Arg } from "../Arg";
+import type { ComponentAddress } from "../ComponentAddress";
+
+export interface VNCallViewRequest {
+  component_address: ComponentAddress;
+  method: string;
+  args: Array<Arg>;
+}
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Amount } from "../Amount";
+import type { ComponentAddressOrName } from "./ComponentAddressOrName";
+import type { TransferRecipient } from "./TransferRecipient";
+
+export interface AccountsBatchTransferRequest {
+  account: ComponentAddressOrName | null;
+  recipients: Array<TransferRecipient>;
+  max_fee: Amount | null;
+  proof_from_badge_resource: string | null;
+  dry_run: boolean;
+}
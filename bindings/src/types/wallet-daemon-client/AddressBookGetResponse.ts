@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ComponentAddress } from "../ComponentAddress";
+import type { Contact } from "../Contact";
+
+export interface AddressBookGetResponse {
+  contact: Contact;
+  account_address: ComponentAddress;
+}
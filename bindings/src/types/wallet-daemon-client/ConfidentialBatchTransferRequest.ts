@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Amount } from "../Amount";
+import type { ComponentAddressOrName } from "./ComponentAddressOrName";
+import type { ConfidentialTransferInputSelection } from "../ConfidentialTransferInputSelection";
+import type { ConfidentialTransferRecipient } from "./ConfidentialTransferRecipient";
+import type { ResourceAddress } from "../ResourceAddress";
+
+export interface ConfidentialBatchTransferRequest {
+  account: ComponentAddressOrName | null;
+  input_selection: ConfidentialTransferInputSelection;
+  resource_address: ResourceAddress;
+  recipients: Array<ConfidentialTransferRecipient>;
+  max_fee: Amount | null;
+  output_to_revealed: boolean;
+  proof_from_badge_resource: string | null;
+  dry_run: boolean;
+}
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Amount } from "../Amount";
+import type { FinalizeResult } from "../FinalizeResult";
+import type { TransferRecipientResult } from "./TransferRecipientResult";
+
+export interface ConfidentialBatchTransferResponse {
+  transaction_id: string;
+  fee: Amount;
+  recipients: Array<TransferRecipientResult>;
+  result: FinalizeResult;
+}
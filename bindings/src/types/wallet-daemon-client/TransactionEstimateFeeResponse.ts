@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Amount } from "../Amount";
+import type { FeeReceipt } from "../FeeReceipt";
+
+export interface TransactionEstimateFeeResponse {
+  fee_receipt: FeeReceipt;
+  total_fees_charged: Amount;
+  recommended_max_fee: Amount;
+}
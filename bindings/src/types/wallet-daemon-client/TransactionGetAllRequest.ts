@@ -1,8 +1,15 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { ComponentAddress } from "../ComponentAddress";
+import type { TransactionDirection } from "../TransactionDirection";
 import type { TransactionStatus } from "../TransactionStatus";
 
 export interface TransactionGetAllRequest {
   status: TransactionStatus | null;
   component: ComponentAddress | null;
+  resource_address: string | null;
+  direction: TransactionDirection | null;
+  from_date: string | null;
+  to_date: string | null;
+  offset: number;
+  limit: number | null;
 }
@@ -5,4 +5,5 @@ import type { TransactionStatus } from "../TransactionStatus";
 
 export interface TransactionGetAllResponse {
   transactions: Array<[Transaction, FinalizeResult | null, TransactionStatus, string]>;
+  total: number;
 }
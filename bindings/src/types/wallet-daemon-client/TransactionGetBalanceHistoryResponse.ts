@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BalanceChange } from "../BalanceChange";
+import type { ComponentAddress } from "../ComponentAddress";
+
+export interface TransactionGetBalanceHistoryResponse {
+  account_address: ComponentAddress;
+  changes: Array<BalanceChange>;
+}
@@ -8,4 +8,5 @@ export interface TransactionSubmitDryRunRequest {
   autofill_inputs: Array<SubstateRequirement>;
   detect_inputs: boolean;
   proof_ids: Array<number>;
+  trace: boolean;
 }
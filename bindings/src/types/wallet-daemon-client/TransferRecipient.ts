@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Amount } from "../Amount";
+import type { ResourceAddress } from "../ResourceAddress";
+
+export interface TransferRecipient {
+  destination_public_key: string;
+  resource_address: ResourceAddress;
+  amount: Amount;
+}
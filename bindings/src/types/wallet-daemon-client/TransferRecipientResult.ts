@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Amount } from "../Amount";
+import type { ComponentAddress } from "../ComponentAddress";
+import type { ResourceAddress } from "../ResourceAddress";
+
+export interface TransferRecipientResult {
+  destination_public_key: string;
+  destination_account: ComponentAddress;
+  resource_address: ResourceAddress;
+  amount: Amount;
+  account_created: boolean;
+}
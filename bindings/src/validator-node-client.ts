@@ -61,3 +61,5 @@ export * from "./types/validator-node-client/VNGetIdentityResponse";
 export * from "./types/validator-node-client/GetSubstatesByTransactionRequest";
 export * from "./types/validator-node-client/GetNetworkCommitteeResponse";
 export * from "./types/validator-node-client/VNGetValidatorFeesRequest";
+export * from "./types/validator-node-client/VNCallViewRequest";
+export * from "./types/validator-node-client/VNCallViewResponse";
@@ -92,3 +92,22 @@ export * from "./types/wallet-daemon-client/AccountsGetBalancesRequest";
 export * from "./types/wallet-daemon-client/AccountInfo";
 export * from "./types/wallet-daemon-client/SubstatesListRequest";
 export * from "./types/wallet-daemon-client/ComponentAddressOrName";
+export * from "./types/wallet-daemon-client/AccountsBatchTransferRequest";
+export * from "./types/wallet-daemon-client/AccountsBatchTransferResponse";
+export * from "./types/wallet-daemon-client/AddressBookAddRequest";
+export * from "./types/wallet-daemon-client/AddressBookAddResponse";
+export * from "./types/wallet-daemon-client/AddressBookGetRequest";
+export * from "./types/wallet-daemon-client/AddressBookGetResponse";
+export * from "./types/wallet-daemon-client/AddressBookListRequest";
+export * from "./types/wallet-daemon-client/AddressBookListResponse";
+export * from "./types/wallet-daemon-client/AddressBookRemoveRequest";
+export * from "./types/wallet-daemon-client/AddressBookRemoveResponse";
+export * from "./types/wallet-daemon-client/ConfidentialBatchTransferRequest";
+export * from "./types/wallet-daemon-client/ConfidentialBatchTransferResponse";
+export * from "./types/wallet-daemon-client/ConfidentialTransferRecipient";
+export * from "./types/wallet-daemon-client/TransactionEstimateFeeRequest";
+export * from "./types/wallet-daemon-client/TransactionEstimateFeeResponse";
+export * from "./types/wallet-daemon-client/TransactionGetBalanceHistoryRequest";
+export * from "./types/wallet-daemon-client/TransactionGetBalanceHistoryResponse";
+export * from "./types/wallet-daemon-client/TransferRecipient";
+export * from "./types/wallet-daemon-client/TransferRecipientResult";
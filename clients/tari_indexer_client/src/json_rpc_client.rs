@@ -31,17 +31,35 @@ use crate::{
     types::{
         AddPeerRequest,
         AddPeerResponse,
+        CallViewRequest,
+        CallViewResponse,
+        GetAccountPortfolioRequest,
+        GetAccountPortfolioResponse,
+        GetAccountTransactionsRequest,
+        GetAccountTransactionsResponse,
+        GetBlockRequest,
+        GetBlockResponse,
         GetEpochManagerStatsResponse,
+        GetEpochStatsRequest,
+        GetEpochStatsResponse,
         GetNonFungiblesRequest,
         GetNonFungiblesResponse,
+        GetResourceHoldersRequest,
+        GetResourceHoldersResponse,
         GetSubstateRequest,
         GetSubstateResponse,
         GetTemplateDefinitionRequest,
         GetTemplateDefinitionResponse,
         GetTransactionResultRequest,
         GetTransactionResultResponse,
+        GetTransactionsInBlockRequest,
+        GetTransactionsInBlockResponse,
+        ListBlocksRequest,
+        ListBlocksResponse,
         ListSubstatesRequest,
         ListSubstatesResponse,
+        SearchNonFungiblesRequest,
+        SearchNonFungiblesResponse,
         SubmitTransactionRequest,
         SubmitTransactionResponse,
     },
@@ -114,6 +132,10 @@ impl IndexerJsonRpcClient {
         self.send_request("submit_transaction", req).await
     }
 
+    pub async fn call_view(&mut self, req: CallViewRequest) -> Result<CallViewResponse, IndexerClientError> {
+        self.send_request("call_view", req).await
+    }
+
     pub async fn get_transaction_result(
         &mut self,
         req: GetTransactionResultRequest,
@@ -135,6 +157,56 @@ impl IndexerJsonRpcClient {
         self.send_request("get_non_fungibles", req).await
     }
 
+    pub async fn get_resource_holders(
+        &mut self,
+        req: GetResourceHoldersRequest,
+    ) -> Result<GetResourceHoldersResponse, IndexerClientError> {
+        self.send_request("get_resource_holders", req).await
+    }
+
+    pub async fn get_account_portfolio(
+        &mut self,
+        req: GetAccountPortfolioRequest,
+    ) -> Result<GetAccountPortfolioResponse, IndexerClientError> {
+        self.send_request("get_account_portfolio", req).await
+    }
+
+    pub async fn get_account_transactions(
+        &mut self,
+        req: GetAccountTransactionsRequest,
+    ) -> Result<GetAccountTransactionsResponse, IndexerClientError> {
+        self.send_request("get_account_transactions", req).await
+    }
+
+    pub async fn search_non_fungibles(
+        &mut self,
+        req: SearchNonFungiblesRequest,
+    ) -> Result<SearchNonFungiblesResponse, IndexerClientError> {
+        self.send_request("search_non_fungibles", req).await
+    }
+
+    pub async fn list_blocks(&mut self, req: ListBlocksRequest) -> Result<ListBlocksResponse, IndexerClientError> {
+        self.send_request("list_blocks", req).await
+    }
+
+    pub async fn get_block(&mut self, req: GetBlockRequest) -> Result<GetBlockResponse, IndexerClientError> {
+        self.send_request("get_block", req).await
+    }
+
+    pub async fn get_transactions_in_block(
+        &mut self,
+        req: GetTransactionsInBlockRequest,
+    ) -> Result<GetTransactionsInBlockResponse, IndexerClientError> {
+        self.send_request("get_transactions_in_block", req).await
+    }
+
+    pub async fn get_epoch_stats(
+        &mut self,
+        req: GetEpochStatsRequest,
+    ) -> Result<GetEpochStatsResponse, IndexerClientError> {
+        self.send_request("get_epoch_stats", req).await
+    }
+
     pub async fn get_epoch_manager_stats(&mut self) -> Result<GetEpochManagerStatsResponse, IndexerClientError> {
         self.send_request("get_epoch_manager_stats", ()).await
     }
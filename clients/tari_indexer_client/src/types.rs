@@ -1,27 +1,30 @@
 //   Copyright 2023 The Tari Project
 //   SPDX-License-Identifier: BSD-3-Clause
 
-use std::{sync::Arc, time::Duration};
+use std::{collections::BTreeMap, sync::Arc, time::Duration};
 
 use multiaddr::Multiaddr;
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use tari_base_node_client::types::BaseLayerValidatorNode;
 use tari_common_types::types::{FixedHash, PublicKey};
-use tari_dan_common_types::{substate_type::SubstateType, Epoch, SubstateRequirement};
-use tari_dan_storage::consensus_models::Decision;
+use tari_dan_common_types::{substate_type::SubstateType, Epoch, NodeHeight, ShardGroup, SubstateRequirement};
+use tari_dan_storage::consensus_models::{BlockHeader, BlockId, Command, Decision, QuorumCertificate};
 use tari_engine_types::{
     commit_result::ExecuteResult,
+    instruction_result::InstructionResult,
     serde_with as serde_tools,
     substate::{Substate, SubstateId},
+    template_models::{Amount, ComponentAddress, ResourceAddress},
     TemplateAddress,
 };
 use tari_template_abi::TemplateDef;
+use tari_template_lib::args::Arg;
 use tari_transaction::{Transaction, TransactionId};
 #[cfg(feature = "ts")]
 use ts_rs::TS;
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 #[cfg_attr(
     feature = "ts",
     derive(TS),
@@ -32,10 +35,61 @@ pub struct ListSubstatesRequest {
     #[cfg_attr(feature = "ts", ts(type = "string | null"))]
     pub filter_by_template: Option<TemplateAddress>,
     pub filter_by_type: Option<SubstateType>,
+    #[serde(default)]
+    pub filter_by_module_name: Option<String>,
+    /// Only return substates whose indexed version was created by this transaction
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(type = "string | null"))]
+    pub filter_by_transaction: Option<TransactionId>,
+    #[serde(default)]
+    pub min_version: Option<u32>,
+    #[serde(default)]
+    pub max_version: Option<u32>,
+    /// Inclusive lower bound of the substate timestamp, in seconds since the unix epoch
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(type = "number | null"))]
+    pub from_timestamp: Option<u64>,
+    /// Inclusive upper bound of the substate timestamp, in seconds since the unix epoch
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(type = "number | null"))]
+    pub to_timestamp: Option<u64>,
+    #[serde(default)]
+    pub order_by: SubstateOrderBy,
+    #[serde(default)]
+    pub order: SortOrder,
+    /// The `next_cursor` of a previous response. The cursor is only valid with the same filters and ordering.
+    #[serde(default)]
+    pub cursor: Option<String>,
     pub limit: Option<u64>,
     pub offset: Option<u64>,
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub enum SubstateOrderBy {
+    /// The order in which the substates were first indexed
+    #[default]
+    Indexed,
+    Version,
+    Timestamp,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub enum SortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[cfg_attr(
     feature = "ts",
@@ -44,6 +98,9 @@ pub struct ListSubstatesRequest {
 )]
 pub struct ListSubstatesResponse {
     pub substates: Vec<ListSubstateItem>,
+    /// Pass this as the `cursor` of the next request to fetch the next page. `None` if there are no more results.
+    #[serde(default)]
+    pub next_cursor: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -137,6 +194,9 @@ pub struct SubmitTransactionRequest {
     #[serde(default)]
     pub required_substates: Vec<SubstateRequirement>,
     pub is_dry_run: bool,
+    /// Include an execution trace in the dry run result. Ignored if `is_dry_run` is false.
+    #[serde(default)]
+    pub trace: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -155,6 +215,39 @@ pub struct SubmitTransactionResponse {
     pub result: IndexerTransactionFinalizedResult,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(
+        export,
+        export_to = "../../bindings/src/types/tari-indexer-client/",
+        rename = "IndexerCallViewRequest"
+    )
+)]
+pub struct CallViewRequest {
+    pub component_address: ComponentAddress,
+    pub method: String,
+    #[serde(default)]
+    pub args: Vec<Arg>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(
+        export,
+        export_to = "../../bindings/src/types/tari-indexer-client/",
+        rename = "IndexerCallViewResponse"
+    )
+)]
+pub struct CallViewResponse {
+    pub result: InstructionResult,
+    #[cfg_attr(feature = "ts", ts(type = "any"))]
+    pub json_result: JsonValue,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(
     feature = "ts",
@@ -511,3 +604,373 @@ pub struct GetTemplateDefinitionResponse {
     pub name: String,
     pub definition: TemplateDef,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct GetResourceHoldersRequest {
+    pub resource_address: ResourceAddress,
+    #[cfg_attr(feature = "ts", ts(type = "number | null"))]
+    pub limit: Option<u64>,
+    #[cfg_attr(feature = "ts", ts(type = "number | null"))]
+    pub offset: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct GetResourceHoldersResponse {
+    /// Owners holding the resource, largest total balance first. Vaults with an unknown owner are listed individually.
+    pub holders: Vec<ResourceHolder>,
+    /// The number of distinct owners holding a positive balance of the resource
+    #[cfg_attr(feature = "ts", ts(type = "number"))]
+    pub total_holders: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct ResourceHolder {
+    /// The component that owns the vaults, or the vault itself if its owner is not known
+    pub holder: SubstateId,
+    /// The total balance of the holder's vaults
+    pub balance: Amount,
+    /// The number of the holder's vaults with a positive balance
+    #[cfg_attr(feature = "ts", ts(type = "number"))]
+    pub vault_count: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct GetAccountPortfolioRequest {
+    pub account: ComponentAddress,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct GetAccountPortfolioResponse {
+    pub balances: Vec<PortfolioBalance>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct PortfolioBalance {
+    pub resource_address: ResourceAddress,
+    pub vault_id: SubstateId,
+    /// The revealed balance of the vault
+    pub balance: Amount,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct GetAccountTransactionsRequest {
+    pub account: ComponentAddress,
+    #[cfg_attr(feature = "ts", ts(type = "number | null"))]
+    pub limit: Option<u64>,
+    #[cfg_attr(feature = "ts", ts(type = "number | null"))]
+    pub offset: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct GetAccountTransactionsResponse {
+    /// Transactions that touched the account or any of its vaults, newest first
+    pub transactions: Vec<AccountTransaction>,
+    #[cfg_attr(feature = "ts", ts(type = "number"))]
+    pub total: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct AccountTransaction {
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    pub transaction_id: TransactionId,
+    #[cfg_attr(feature = "ts", ts(type = "number"))]
+    pub timestamp: u64,
+    /// The revealed balance changes of the account's vaults. Empty if the transaction did not change a balance.
+    pub balance_changes: Vec<AccountBalanceChange>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct AccountBalanceChange {
+    pub resource_address: ResourceAddress,
+    pub vault_id: SubstateId,
+    pub kind: BalanceChangeKind,
+    pub direction: TransferDirection,
+    /// The absolute amount by which the balance changed, zero if the change is unknown
+    pub amount: Amount,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub enum BalanceChangeKind {
+    /// The difference from the previously indexed balance of the vault
+    Changed,
+    /// The initial balance of a vault created in the transaction
+    Created,
+    /// The vault existed before the indexer first saw it, so the change is not known
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub enum TransferDirection {
+    In,
+    Out,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct SearchNonFungiblesRequest {
+    /// Only search within this collection
+    #[serde(default)]
+    pub resource_address: Option<ResourceAddress>,
+    /// Metadata fields that must all match exactly. Nested fields are keyed by their path joined with `.`
+    #[serde(default)]
+    pub fields: BTreeMap<String, String>,
+    /// Case-insensitive prefix of the `name` metadata field
+    #[serde(default)]
+    pub name_prefix: Option<String>,
+    #[cfg_attr(feature = "ts", ts(type = "number | null"))]
+    pub limit: Option<u64>,
+    #[cfg_attr(feature = "ts", ts(type = "number | null"))]
+    pub offset: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct SearchNonFungiblesResponse {
+    pub non_fungibles: Vec<NonFungibleMetadata>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct NonFungibleMetadata {
+    pub address: SubstateId,
+    /// The flattened immutable data of the NFT
+    pub data: BTreeMap<String, String>,
+    /// The flattened mutable data of the NFT
+    pub mutable_data: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct ListBlocksRequest {
+    #[serde(default)]
+    pub epoch: Option<Epoch>,
+    #[serde(default)]
+    pub shard_group: Option<ShardGroup>,
+    #[cfg_attr(feature = "ts", ts(type = "number | null"))]
+    pub limit: Option<u64>,
+    #[cfg_attr(feature = "ts", ts(type = "number | null"))]
+    pub offset: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct ListBlocksResponse {
+    /// Newest blocks first
+    pub blocks: Vec<IndexedBlock>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct IndexedBlock {
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    pub block_id: BlockId,
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    pub parent_id: BlockId,
+    pub epoch: Epoch,
+    pub shard_group: ShardGroup,
+    pub height: NodeHeight,
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    pub proposed_by: PublicKey,
+    #[cfg_attr(feature = "ts", ts(type = "number"))]
+    pub timestamp: u64,
+    pub is_dummy: bool,
+    #[cfg_attr(feature = "ts", ts(type = "number"))]
+    pub command_count: u64,
+    #[cfg_attr(feature = "ts", ts(type = "number"))]
+    pub transaction_count: u64,
+    #[cfg_attr(feature = "ts", ts(type = "number"))]
+    pub total_leader_fee: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct GetBlockRequest {
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    pub block_id: BlockId,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct GetBlockResponse {
+    pub block: IndexedBlock,
+    pub header: BlockHeader,
+    /// The quorum certificate that justifies the parent of the block
+    pub justify: QuorumCertificate,
+    pub commands: Vec<Command>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct GetTransactionsInBlockRequest {
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    pub block_id: BlockId,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct GetTransactionsInBlockResponse {
+    pub transactions: Vec<BlockTransaction>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct BlockTransaction {
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    pub transaction_id: TransactionId,
+    /// The consensus command that includes the transaction in the block, e.g. `LocalPrepare` or `AllAccept`
+    pub command: String,
+    pub decision: Decision,
+    #[cfg_attr(feature = "ts", ts(type = "number"))]
+    pub transaction_fee: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct GetEpochStatsRequest {
+    pub epoch: Epoch,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct GetEpochStatsResponse {
+    pub stats: EpochStats,
+}
+
+/// Statistics of the blocks scanned by the indexer in an epoch, across all shard groups
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct EpochStats {
+    pub epoch: Epoch,
+    #[cfg_attr(feature = "ts", ts(type = "number"))]
+    pub num_blocks: u64,
+    #[cfg_attr(feature = "ts", ts(type = "number"))]
+    pub num_dummy_blocks: u64,
+    #[cfg_attr(feature = "ts", ts(type = "number"))]
+    pub num_shard_groups: u64,
+    #[cfg_attr(feature = "ts", ts(type = "number"))]
+    pub num_commands: u64,
+    #[cfg_attr(feature = "ts", ts(type = "number"))]
+    pub num_transactions: u64,
+    #[cfg_attr(feature = "ts", ts(type = "number"))]
+    pub total_leader_fees: u64,
+    #[cfg_attr(feature = "ts", ts(type = "number | null"))]
+    pub first_block_timestamp: Option<u64>,
+    #[cfg_attr(feature = "ts", ts(type = "number | null"))]
+    pub last_block_timestamp: Option<u64>,
+}
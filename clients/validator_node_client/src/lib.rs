@@ -145,6 +145,10 @@ impl ValidatorNodeClient {
         self.send_request("submit_transaction", request).await
     }
 
+    pub async fn call_view(&mut self, request: CallViewRequest) -> Result<CallViewResponse, ValidatorNodeClientError> {
+        self.send_request("call_view", request).await
+    }
+
     pub async fn add_peer(&mut self, request: AddPeerRequest) -> Result<AddPeerResponse, ValidatorNodeClientError> {
         self.send_request("add_peer", request).await
     }
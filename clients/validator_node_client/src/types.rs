@@ -49,11 +49,15 @@ use tari_dan_storage::{
 };
 use tari_engine_types::{
     commit_result::{ExecuteResult, FinalizeResult},
+    execution_trace::ExecutionTrace,
     fees::FeeCostBreakdown,
+    instruction_result::InstructionResult,
     serde_with,
     substate::{SubstateId, SubstateValue},
+    template_models::ComponentAddress,
     TemplateAddress,
 };
+use tari_template_lib::args::Arg;
 use tari_transaction::{Transaction, TransactionId};
 #[cfg(feature = "ts")]
 use ts_rs::TS;
@@ -196,6 +200,9 @@ pub struct TemplateMetadata {
 pub struct SubmitTransactionRequest {
     pub transaction: Transaction,
     pub is_dry_run: bool,
+    /// Include an execution trace in the dry run result. Ignored if `is_dry_run` is false.
+    #[serde(default)]
+    pub trace: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -225,6 +232,41 @@ pub struct DryRunTransactionFinalizeResult {
     pub decision: QuorumDecision,
     pub finalize: FinalizeResult,
     pub fee_breakdown: Option<FeeCostBreakdown>,
+    #[serde(default)]
+    pub trace: Option<ExecutionTrace>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(
+        export,
+        export_to = "../../bindings/src/types/validator-node-client/",
+        rename = "VNCallViewRequest"
+    )
+)]
+pub struct CallViewRequest {
+    pub component_address: ComponentAddress,
+    pub method: String,
+    #[serde(default)]
+    pub args: Vec<Arg>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(
+        export,
+        export_to = "../../bindings/src/types/validator-node-client/",
+        rename = "VNCallViewResponse"
+    )
+)]
+pub struct CallViewResponse {
+    pub result: InstructionResult,
+    #[cfg_attr(feature = "ts", ts(type = "any"))]
+    pub json_result: serde_json::Value,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -79,6 +79,8 @@ use crate::{
         AccountGetResponse,
         AccountSetDefaultRequest,
         AccountSetDefaultResponse,
+        AccountsBatchTransferRequest,
+        AccountsBatchTransferResponse,
         AccountsCreateRequest,
         AccountsCreateResponse,
         AccountsGetBalancesRequest,
@@ -87,12 +89,22 @@ use crate::{
         AccountsInvokeResponse,
         AccountsListRequest,
         AccountsListResponse,
+        AddressBookAddRequest,
+        AddressBookAddResponse,
+        AddressBookGetRequest,
+        AddressBookGetResponse,
+        AddressBookListRequest,
+        AddressBookListResponse,
+        AddressBookRemoveRequest,
+        AddressBookRemoveResponse,
         AuthGetAllJwtRequest,
         AuthGetAllJwtResponse,
         AuthRevokeTokenRequest,
         AuthRevokeTokenResponse,
         ClaimValidatorFeesRequest,
         ClaimValidatorFeesResponse,
+        ConfidentialBatchTransferRequest,
+        ConfidentialBatchTransferResponse,
         ConfidentialCreateOutputProofRequest,
         ConfidentialCreateOutputProofResponse,
         ConfidentialTransferRequest,
@@ -112,6 +124,12 @@ use crate::{
         PublishTemplateResponse,
         RevealFundsRequest,
         RevealFundsResponse,
+        TransactionEstimateFeeRequest,
+        TransactionEstimateFeeResponse,
+        TransactionGetAllRequest,
+        TransactionGetAllResponse,
+        TransactionGetBalanceHistoryRequest,
+        TransactionGetBalanceHistoryResponse,
         TransactionGetRequest,
         TransactionGetResponse,
         TransactionGetResultRequest,
@@ -263,6 +281,21 @@ impl WalletDaemonClient {
         self.send_request("transactions.get_result", request.borrow()).await
     }
 
+    pub async fn list_transactions<T: Borrow<TransactionGetAllRequest>>(
+        &mut self,
+        request: T,
+    ) -> Result<TransactionGetAllResponse, WalletDaemonClientError> {
+        self.send_request("transactions.get_all", request.borrow()).await
+    }
+
+    pub async fn get_balance_history<T: Borrow<TransactionGetBalanceHistoryRequest>>(
+        &mut self,
+        request: T,
+    ) -> Result<TransactionGetBalanceHistoryResponse, WalletDaemonClientError> {
+        self.send_request("transactions.get_balance_history", request.borrow())
+            .await
+    }
+
     pub async fn wait_transaction_result<T: Borrow<TransactionWaitResultRequest>>(
         &mut self,
         request: T,
@@ -284,6 +317,13 @@ impl WalletDaemonClient {
         self.send_request("transactions.submit_dry_run", request.borrow()).await
     }
 
+    pub async fn estimate_transaction_fee<T: Borrow<TransactionEstimateFeeRequest>>(
+        &mut self,
+        request: T,
+    ) -> Result<TransactionEstimateFeeResponse, WalletDaemonClientError> {
+        self.send_request("transactions.estimate_fee", request.borrow()).await
+    }
+
     pub async fn create_account<T: Borrow<AccountsCreateRequest>>(
         &mut self,
         request: T,
@@ -349,6 +389,38 @@ impl WalletDaemonClient {
             .await
     }
 
+    pub async fn address_book_add<T: Borrow<AddressBookAddRequest>>(
+        &mut self,
+        req: T,
+    ) -> Result<AddressBookAddResponse, WalletDaemonClientError> {
+        self.send_request("address_book.add", req.borrow()).await
+    }
+
+    pub async fn address_book_get<T: Into<String>>(
+        &mut self,
+        name: T,
+    ) -> Result<AddressBookGetResponse, WalletDaemonClientError> {
+        self.send_request("address_book.get", &AddressBookGetRequest { name: name.into() })
+            .await
+    }
+
+    pub async fn address_book_list(
+        &mut self,
+        offset: u64,
+        limit: u64,
+    ) -> Result<AddressBookListResponse, WalletDaemonClientError> {
+        self.send_request("address_book.list", &AddressBookListRequest { offset, limit })
+            .await
+    }
+
+    pub async fn address_book_remove<T: Into<String>>(
+        &mut self,
+        name: T,
+    ) -> Result<AddressBookRemoveResponse, WalletDaemonClientError> {
+        self.send_request("address_book.remove", &AddressBookRemoveRequest { name: name.into() })
+            .await
+    }
+
     pub async fn accounts_transfer<T: Borrow<AccountsTransferRequest>>(
         &mut self,
         req: T,
@@ -363,6 +435,21 @@ impl WalletDaemonClient {
         self.send_request("accounts.confidential_transfer", req.borrow()).await
     }
 
+    pub async fn accounts_batch_transfer<T: Borrow<AccountsBatchTransferRequest>>(
+        &mut self,
+        req: T,
+    ) -> Result<AccountsBatchTransferResponse, WalletDaemonClientError> {
+        self.send_request("accounts.batch_transfer", req.borrow()).await
+    }
+
+    pub async fn accounts_confidential_batch_transfer<T: Borrow<ConfidentialBatchTransferRequest>>(
+        &mut self,
+        req: T,
+    ) -> Result<ConfidentialBatchTransferResponse, WalletDaemonClientError> {
+        self.send_request("accounts.confidential_batch_transfer", req.borrow())
+            .await
+    }
+
     pub async fn claim_burn<T: Borrow<ClaimBurnRequest>>(
         &mut self,
         req: T,
@@ -32,10 +32,19 @@ use tari_common_types::types::PublicKey;
 use tari_dan_common_types::{substate_type::SubstateType, Epoch, SubstateAddress, SubstateRequirement};
 use tari_dan_wallet_sdk::{
     apis::{confidential_transfer::ConfidentialTransferInputSelection, jwt::Claims, key_manager},
-    models::{Account, ConfidentialProofId, NonFungibleToken, TransactionStatus},
+    models::{
+        Account,
+        BalanceChange,
+        ConfidentialProofId,
+        Contact,
+        NonFungibleToken,
+        TransactionDirection,
+        TransactionStatus,
+    },
 };
 use tari_engine_types::{
     commit_result::{ExecuteResult, FinalizeResult},
+    fees::FeeReceipt,
     instruction::Instruction,
     instruction_result::InstructionResult,
     serde_with,
@@ -141,6 +150,9 @@ pub struct TransactionSubmitDryRunRequest {
     pub detect_inputs: bool,
     #[cfg_attr(feature = "ts", ts(type = "Array<number>"))]
     pub proof_ids: Vec<ConfidentialProofId>,
+    /// Include an execution trace in the result
+    #[serde(default)]
+    pub trace: bool,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -157,6 +169,37 @@ pub struct TransactionSubmitDryRunResponse {
     pub json_result: Vec<serde_json::Value>,
 }
 
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/wallet-daemon-client/")
+)]
+pub struct TransactionEstimateFeeRequest {
+    pub transaction: UnsignedTransaction,
+    #[cfg_attr(feature = "ts", ts(type = "number | null"))]
+    pub signing_key_index: Option<u64>,
+    pub autofill_inputs: Vec<SubstateRequirement>,
+    pub detect_inputs: bool,
+    /// Percentage added to the fees charged to produce the recommended max fee. Defaults to 20%.
+    #[cfg_attr(feature = "ts", ts(type = "number | null"))]
+    pub fee_margin_percent: Option<u64>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/wallet-daemon-client/")
+)]
+pub struct TransactionEstimateFeeResponse {
+    /// The fee receipt of the dry run, including the cost breakdown by fee source
+    pub fee_receipt: FeeReceipt,
+    pub total_fees_charged: Amount,
+    /// The total fees charged plus the safety margin
+    pub recommended_max_fee: Amount,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[cfg_attr(
     feature = "ts",
@@ -222,6 +265,22 @@ pub struct TransactionGetResponse {
 pub struct TransactionGetAllRequest {
     pub status: Option<TransactionStatus>,
     pub component: Option<ComponentAddress>,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(type = "string | null"))]
+    pub resource_address: Option<ResourceAddress>,
+    /// Direction relative to `component`. Requires `component` to be set.
+    #[serde(default)]
+    pub direction: Option<TransactionDirection>,
+    #[serde(default)]
+    pub from_date: Option<NaiveDateTime>,
+    #[serde(default)]
+    pub to_date: Option<NaiveDateTime>,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(type = "number"))]
+    pub offset: u64,
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(type = "number | null"))]
+    pub limit: Option<u64>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -232,6 +291,33 @@ pub struct TransactionGetAllRequest {
 )]
 pub struct TransactionGetAllResponse {
     pub transactions: Vec<(Transaction, Option<FinalizeResult>, TransactionStatus, NaiveDateTime)>,
+    /// The total number of transactions matching the filter, ignoring offset and limit
+    #[cfg_attr(feature = "ts", ts(type = "number"))]
+    pub total: u64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/wallet-daemon-client/")
+)]
+pub struct TransactionGetBalanceHistoryRequest {
+    #[serde(default, deserialize_with = "opt_string_or_struct")]
+    pub account: Option<ComponentAddressOrName>,
+    #[cfg_attr(feature = "ts", ts(type = "string | null"))]
+    pub resource_address: Option<ResourceAddress>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/wallet-daemon-client/")
+)]
+pub struct TransactionGetBalanceHistoryResponse {
+    pub account_address: ComponentAddress,
+    pub changes: Vec<BalanceChange>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -629,6 +715,66 @@ pub struct AccountsTransferResponse {
     pub result: FinalizeResult,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/wallet-daemon-client/")
+)]
+pub struct TransferRecipient {
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    pub destination_public_key: PublicKey,
+    pub resource_address: ResourceAddress,
+    pub amount: Amount,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/wallet-daemon-client/")
+)]
+pub struct TransferRecipientResult {
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    pub destination_public_key: PublicKey,
+    pub destination_account: ComponentAddress,
+    pub resource_address: ResourceAddress,
+    pub amount: Amount,
+    /// True if the destination account did not exist and was created by the transaction
+    pub account_created: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/wallet-daemon-client/")
+)]
+pub struct AccountsBatchTransferRequest {
+    #[serde(deserialize_with = "opt_string_or_struct")]
+    pub account: Option<ComponentAddressOrName>,
+    pub recipients: Vec<TransferRecipient>,
+    pub max_fee: Option<Amount>,
+    #[cfg_attr(feature = "ts", ts(type = "string | null"))]
+    pub proof_from_badge_resource: Option<ResourceAddress>,
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/wallet-daemon-client/")
+)]
+pub struct AccountsBatchTransferResponse {
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    pub transaction_id: TransactionId,
+    pub fee: Amount,
+    pub fee_refunded: Amount,
+    pub recipients: Vec<TransferRecipientResult>,
+    pub result: FinalizeResult,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[cfg_attr(
     feature = "ts",
@@ -743,6 +889,51 @@ pub struct ConfidentialTransferResponse {
     pub result: FinalizeResult,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/wallet-daemon-client/")
+)]
+pub struct ConfidentialTransferRecipient {
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    pub destination_public_key: PublicKey,
+    pub amount: Amount,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/wallet-daemon-client/")
+)]
+pub struct ConfidentialBatchTransferRequest {
+    #[serde(deserialize_with = "opt_string_or_struct")]
+    pub account: Option<ComponentAddressOrName>,
+    pub input_selection: ConfidentialTransferInputSelection,
+    pub resource_address: ResourceAddress,
+    pub recipients: Vec<ConfidentialTransferRecipient>,
+    pub max_fee: Option<Amount>,
+    pub output_to_revealed: bool,
+    #[cfg_attr(feature = "ts", ts(type = "string | null"))]
+    pub proof_from_badge_resource: Option<ResourceAddress>,
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/wallet-daemon-client/")
+)]
+pub struct ConfidentialBatchTransferResponse {
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    pub transaction_id: TransactionId,
+    pub fee: Amount,
+    pub recipients: Vec<TransferRecipientResult>,
+    pub result: FinalizeResult,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[cfg_attr(
     feature = "ts",
@@ -1227,3 +1418,94 @@ pub struct TemplatesGetRequest {
 pub struct TemplatesGetResponse {
     pub template_definition: TemplateDef,
 }
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/wallet-daemon-client/")
+)]
+pub struct AddressBookAddRequest {
+    pub name: String,
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    pub public_key: PublicKey,
+    #[serde(default)]
+    pub memo: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/wallet-daemon-client/")
+)]
+pub struct AddressBookAddResponse {
+    pub contact: Contact,
+    pub account_address: ComponentAddress,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/wallet-daemon-client/")
+)]
+pub struct AddressBookGetRequest {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/wallet-daemon-client/")
+)]
+pub struct AddressBookGetResponse {
+    pub contact: Contact,
+    pub account_address: ComponentAddress,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/wallet-daemon-client/")
+)]
+pub struct AddressBookListRequest {
+    #[cfg_attr(feature = "ts", ts(type = "number"))]
+    pub offset: u64,
+    #[cfg_attr(feature = "ts", ts(type = "number"))]
+    pub limit: u64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/wallet-daemon-client/")
+)]
+pub struct AddressBookListResponse {
+    pub contacts: Vec<Contact>,
+    #[cfg_attr(feature = "ts", ts(type = "number"))]
+    pub total: u64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/wallet-daemon-client/")
+)]
+pub struct AddressBookRemoveRequest {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/wallet-daemon-client/")
+)]
+pub struct AddressBookRemoveResponse {
+    pub contact: Contact,
+}
@@ -48,6 +48,8 @@ pub struct ConsensusConstants {
     pub epochs_per_era: Epoch,
     /// Maximum size in bytes for a template WASM binary.
     pub template_binary_max_size_bytes: usize,
+    /// Maximum depth of nested template calls (including cross-template calls) within a single instruction.
+    pub max_call_depth: usize,
 }
 
 impl ConsensusConstants {
@@ -66,6 +68,7 @@ impl ConsensusConstants {
             fee_exhaust_divisor: 20, // 5%
             epochs_per_era: Epoch(10),
             template_binary_max_size_bytes: 1000 * 1000 * 5, // 5 MB
+            max_call_depth: 10,
         }
     }
 }
@@ -261,6 +261,7 @@ fn new_substate_value(seed: u8) -> SubstateValue {
         owner_rule: Default::default(),
         access_rules: Default::default(),
         entity_id: [seed; EntityId::LENGTH].into(),
+        reentrancy_guard: false,
         body: ComponentBody {
             state: tari_bor::Value::Null,
         },
@@ -540,6 +540,7 @@ impl TestBuilder {
                     fee_exhaust_divisor: 20,
                     epochs_per_era: Epoch(10),
                     template_binary_max_size_bytes: 1000 * 1000 * 5,
+                    max_call_depth: 10,
                 },
             },
         }
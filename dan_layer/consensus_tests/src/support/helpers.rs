@@ -68,6 +68,7 @@ pub fn make_test_component(entity_id: EntityId) -> SubstateValue {
         owner_rule: Default::default(),
         access_rules: Default::default(),
         entity_id,
+        reentrancy_guard: false,
         body: ComponentBody {
             state: tari_bor::Value::Null,
         },
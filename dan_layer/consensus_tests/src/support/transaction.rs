@@ -65,6 +65,7 @@ pub fn create_execution_result_for_transaction(
                                 .as_component_address()
                                 .unwrap()
                                 .entity_id(),
+                            reentrancy_guard: false,
                             body: ComponentBody { state },
                         }),
                     );
@@ -124,6 +125,7 @@ pub fn create_execution_result_for_transaction(
             },
         ),
         execution_time: Duration::from_secs(0),
+        trace: None,
     };
 
     result
@@ -7,7 +7,7 @@ use tari_bor::encode_into_std_writer;
 use tari_engine_types::fees::FeeSource;
 
 use super::FeeTable;
-use crate::runtime::{RuntimeModule, RuntimeModuleError, StateTracker};
+use crate::runtime::{CryptoOperation, RuntimeModule, RuntimeModuleError, StateTracker};
 
 pub struct FeeModule {
     initial_cost: u64,
@@ -34,6 +34,15 @@ impl RuntimeModule for FeeModule {
         Ok(())
     }
 
+    fn on_crypto_operation(&self, track: &StateTracker, operation: CryptoOperation) -> Result<(), RuntimeModuleError> {
+        let cost = match operation {
+            CryptoOperation::Hash { num_bytes } => self.fee_table.hash_cost(num_bytes),
+            CryptoOperation::VerifySignature { message_len } => self.fee_table.signature_verification_cost(message_len),
+        };
+        track.add_fee_charge(FeeSource::Crypto, cost);
+        Ok(())
+    }
+
     fn on_before_finalize(&self, track: &StateTracker) -> Result<(), RuntimeModuleError> {
         let total_storage = track.with_substates_to_persist(|changes| {
             let mut counter = ByteCounter::new();
@@ -1,12 +1,18 @@
 //   Copyright 2023 The Tari Project
 //   SPDX-License-Identifier: BSD-3-Clause
 
+/// The number of input bytes that are charged as one block by native hashing and signature verification
+pub const HASH_BLOCK_SIZE: usize = 64;
+
 #[derive(Debug, Clone)]
 pub struct FeeTable {
     pub per_module_call_cost: u64,
     pub per_byte_storage_cost: u64,
     pub per_event_cost: u64,
     pub per_log_cost: u64,
+    pub per_hash_cost: u64,
+    pub per_hash_block_cost: u64,
+    pub per_signature_verification_cost: u64,
 }
 
 impl FeeTable {
@@ -16,6 +22,9 @@ impl FeeTable {
             per_byte_storage_cost: 0,
             per_event_cost: 0,
             per_log_cost: 0,
+            per_hash_cost: 0,
+            per_hash_block_cost: 0,
+            per_signature_verification_cost: 0,
         }
     }
 
@@ -34,4 +43,30 @@ impl FeeTable {
     pub fn per_log_cost(&self) -> u64 {
         self.per_log_cost
     }
+
+    pub fn per_hash_cost(&self) -> u64 {
+        self.per_hash_cost
+    }
+
+    pub fn per_hash_block_cost(&self) -> u64 {
+        self.per_hash_block_cost
+    }
+
+    pub fn per_signature_verification_cost(&self) -> u64 {
+        self.per_signature_verification_cost
+    }
+
+    /// The cost of natively hashing `num_bytes` of data. Every started block of [HASH_BLOCK_SIZE] bytes is charged.
+    pub fn hash_cost(&self, num_bytes: usize) -> u64 {
+        self.per_hash_cost + self.hash_blocks_cost(num_bytes)
+    }
+
+    /// The cost of natively verifying a signature of a message of `message_len` bytes
+    pub fn signature_verification_cost(&self, message_len: usize) -> u64 {
+        self.per_signature_verification_cost + self.hash_blocks_cost(message_len)
+    }
+
+    fn hash_blocks_cost(&self, num_bytes: usize) -> u64 {
+        num_bytes.div_ceil(HASH_BLOCK_SIZE) as u64 * self.per_hash_block_cost
+    }
 }
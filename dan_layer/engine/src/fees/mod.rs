@@ -2,7 +2,7 @@
 //   SPDX-License-Identifier: BSD-3-Clause
 
 mod fee_table;
-pub use fee_table::FeeTable;
+pub use fee_table::{FeeTable, HASH_BLOCK_SIZE};
 
 mod fee_module;
 pub use fee_module::FeeModule;
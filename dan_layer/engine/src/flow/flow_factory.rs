@@ -45,6 +45,8 @@ impl FlowFactory {
                 output: Type::Unit,
                 is_mut: false,
             }],
+            events: vec![],
+            types: vec![],
         });
 
         let _test_build = FlowInstance::try_build(
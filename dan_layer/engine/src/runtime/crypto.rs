@@ -0,0 +1,59 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use blake2::{digest::consts::U32, Blake2b};
+use sha2::{Digest, Sha256};
+use sha3::Keccak256;
+use tari_common_types::types::{PrivateKey, PublicKey, Signature};
+use tari_crypto::tari_utilities::ByteArray;
+use tari_template_lib::{
+    crypto::{
+        Ed25519PublicKeyBytes,
+        Ed25519SignatureBytes,
+        HashAlgorithm,
+        RistrettoPublicKeyBytes,
+        SchnorrSignatureBytes,
+    },
+    Hash,
+};
+
+pub fn hash(algorithm: HashAlgorithm, data: &[u8]) -> Hash {
+    let digest: [u8; Hash::LENGTH] = match algorithm {
+        HashAlgorithm::Blake2b256 => Blake2b::<U32>::digest(data).into(),
+        HashAlgorithm::Sha256 => Sha256::digest(data).into(),
+        HashAlgorithm::Keccak256 => Keccak256::digest(data).into(),
+    };
+    Hash::from_array(digest)
+}
+
+/// Returns false if any of the keys or the signature are not canonical encodings
+pub fn verify_schnorr_signature(
+    public_key: &RistrettoPublicKeyBytes,
+    public_nonce: &RistrettoPublicKeyBytes,
+    signature: &SchnorrSignatureBytes,
+    message: &[u8],
+) -> bool {
+    let Ok(public_key) = PublicKey::from_canonical_bytes(public_key.as_bytes()) else {
+        return false;
+    };
+    let Ok(public_nonce) = PublicKey::from_canonical_bytes(public_nonce.as_bytes()) else {
+        return false;
+    };
+    let Ok(signature) = PrivateKey::from_canonical_bytes(signature.as_bytes()) else {
+        return false;
+    };
+    Signature::new(public_nonce, signature).verify(&public_key, message)
+}
+
+/// Uses strict verification, which rejects weak public keys and non-canonical signatures
+pub fn verify_ed25519_signature(
+    public_key: &Ed25519PublicKeyBytes,
+    signature: &Ed25519SignatureBytes,
+    message: &[u8],
+) -> bool {
+    let Ok(public_key) = ed25519_dalek::VerifyingKey::from_bytes(public_key) else {
+        return false;
+    };
+    let signature = ed25519_dalek::Signature::from_bytes(signature);
+    public_key.verify_strict(message, &signature).is_ok()
+}
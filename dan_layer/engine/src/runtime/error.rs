@@ -187,6 +187,16 @@ pub enum RuntimeError {
     TransactionReceiptNotFound,
     #[error("Component already exists {address}")]
     ComponentAlreadyExists { address: ComponentAddress },
+    #[error("Cannot upgrade component from template {from} to {to}: {details}")]
+    IncompatibleTemplateUpgrade {
+        from: TemplateAddress,
+        to: TemplateAddress,
+        details: String,
+    },
+    #[error("Cannot set the state of component {address} after its template was upgraded in the same call")]
+    ComponentStateSetAfterUpgrade { address: ComponentAddress },
+    #[error("Cannot modify substate {id} in a read-only call")]
+    WriteInReadOnlyCall { id: SubstateId },
     #[error("Cross-template call function error of function '{function}' on template '{template_address}': {details}")]
     CrossTemplateCallFunctionError {
         template_address: TemplateAddress,
@@ -232,6 +242,8 @@ pub enum RuntimeError {
     NoActiveCallFrame,
     #[error("Max call depth {max_depth} exceeded")]
     MaxCallDepthExceeded { max_depth: usize },
+    #[error("Component {component_address} does not allow reentrant calls and is already in the call stack")]
+    ReentrantCall { component_address: ComponentAddress },
     #[error("{action} can only be called from within a component context")]
     NotInComponentContext { action: ActionIdent },
     #[error("Duplicate bucket {bucket_id}")]
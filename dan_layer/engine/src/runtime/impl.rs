@@ -20,7 +20,7 @@
 //   WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 //   USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use std::sync::Arc;
+use std::{collections::BTreeMap, sync::Arc};
 
 use log::{warn, *};
 use tari_common::configuration::Network;
@@ -34,6 +34,7 @@ use tari_engine_types::{
     confidential::{get_commitment_factory, get_range_proof_service, ConfidentialClaim, ConfidentialOutput},
     entity_id_provider::EntityIdProvider,
     events::Event,
+    execution_trace::TraceEvent,
     indexed_value::IndexedValue,
     instruction_result::InstructionResult,
     lock::LockFlag,
@@ -45,7 +46,7 @@ use tari_engine_types::{
     vault::Vault,
     TemplateAddress,
 };
-use tari_template_abi::{TemplateDef, Type};
+use tari_template_abi::{FunctionDef, TemplateDef, Type};
 use tari_template_builtin::{ACCOUNT_NFT_TEMPLATE_ADDRESS, ACCOUNT_TEMPLATE_ADDRESS};
 use tari_template_lib::{
     args,
@@ -64,6 +65,7 @@ use tari_template_lib::{
         ConsensusAction,
         CreateComponentArg,
         CreateResourceArg,
+        CryptoAction,
         GenerateRandomAction,
         InvokeResult,
         LogLevel,
@@ -90,8 +92,8 @@ use tari_template_lib::{
         Amount,
         BucketId,
         ComponentAddress,
+        ConfidentialWithdrawProof,
         EntityId,
-        Metadata,
         NonFungible,
         NonFungibleAddress,
         NotAuthorized,
@@ -106,12 +108,15 @@ use tari_template_lib::{
 use super::{working_state::WorkingState, Runtime};
 use crate::{
     runtime::{
+        crypto,
         engine_args::EngineArgs,
         error::AssertError,
         locking::{LockError, LockedSubstate},
         scope::PushCallFrame,
         tracker::StateTracker,
         utils::to_ristretto_public_key_bytes,
+        ActionIdent,
+        CryptoOperation,
         RuntimeError,
         RuntimeInterface,
         RuntimeModule,
@@ -169,12 +174,22 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
     }
 
     fn invoke_modules_on_runtime_call(&self, function: &'static str) -> Result<(), RuntimeError> {
+        self.tracker.trace(|| TraceEvent::RuntimeCall {
+            function: function.to_string(),
+        });
         for module in &self.modules {
             module.on_runtime_call(&self.tracker, function)?;
         }
         Ok(())
     }
 
+    fn invoke_modules_on_crypto_operation(&self, operation: CryptoOperation) -> Result<(), RuntimeError> {
+        for module in &self.modules {
+            module.on_crypto_operation(&self.tracker, operation)?;
+        }
+        Ok(())
+    }
+
     fn invoke_modules_on_before_finalize(&self) -> Result<(), RuntimeError> {
         for module in &self.modules {
             module.on_before_finalize(&self.tracker)?;
@@ -222,6 +237,35 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
         })
     }
 
+    /// Locks the vault and its resource for a withdraw and checks that the caller is authorized to withdraw the
+    /// resource, invoking the resource's auth hook if it has one.
+    fn lock_vault_for_withdraw(&self, vault_id: VaultId) -> Result<(LockedSubstate, LockedSubstate), RuntimeError> {
+        let (vault_lock, resource_lock, maybe_auth_hook, auth_caller) = self.tracker.write_with(|state_mut| {
+            let vault_lock = state_mut.lock_substate(&SubstateId::Vault(vault_id), LockFlag::Write)?;
+
+            let resource_address = state_mut.get_vault(&vault_lock)?.resource_address();
+
+            let resource_lock = state_mut.lock_substate(&SubstateId::Resource(*resource_address), LockFlag::Read)?;
+
+            let resource = state_mut.get_resource(&resource_lock)?;
+
+            state_mut.authorization().check_resource_access_rules(
+                ResourceAuthAction::Withdraw,
+                resource.as_ownership(),
+                resource.access_rules(),
+            )?;
+
+            let auth_caller = state_mut.get_auth_caller()?;
+            Ok::<_, RuntimeError>((vault_lock, resource_lock, resource.auth_hook().cloned(), auth_caller))
+        })?;
+
+        if let Some(auth_hook) = maybe_auth_hook {
+            self.invoke_resource_access_hook(auth_hook, auth_caller, ResourceAuthAction::Withdraw)?;
+        }
+
+        Ok((vault_lock, resource_lock))
+    }
+
     fn emit_vault_events<T: Into<String>>(
         &self,
         topic: T,
@@ -235,11 +279,12 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
         let (template_address, _) = state.current_template()?;
         let resource_address = state.get_vault(vault_lock)?.resource_address();
 
-        let mut payload = Metadata::new();
-        payload.insert("vault_id", vault_id.to_string());
-        payload.insert("resource_address", resource_address.to_string());
-        payload.insert("resource_type", resource_type.to_string());
-        payload.insert("amount", amount.to_string());
+        let payload = tari_bor::to_value(&BTreeMap::from([
+            ("vault_id", vault_id.to_string()),
+            ("resource_address", resource_address.to_string()),
+            ("resource_type", resource_type.to_string()),
+            ("amount", amount.to_string()),
+        ]))?;
 
         let topic = topic.into();
 
@@ -423,7 +468,7 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
         Ok(id)
     }
 
-    fn emit_event(&self, topic: String, payload: Metadata) -> Result<(), RuntimeError> {
+    fn emit_event(&self, topic: String, payload: tari_bor::Value) -> Result<(), RuntimeError> {
         // forbid template users to emit events that can be confused with the ones emitted by the engine
         if topic.starts_with(STANDARD_TOPIC_PREFIX) {
             return Err(RuntimeError::InvalidEventTopic { topic });
@@ -471,7 +516,14 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
     }
 
     fn lock_component(&self, address: &ComponentAddress, lock_flag: LockFlag) -> Result<LockedSubstate, RuntimeError> {
-        self.tracker.lock_substate(&SubstateId::Component(*address), lock_flag)
+        self.tracker.write_with(|state| {
+            if state.load_component(address)?.is_reentrancy_guarded() && state.is_component_in_call_stack(address) {
+                return Err(RuntimeError::ReentrantCall {
+                    component_address: *address,
+                });
+            }
+            state.lock_substate(&SubstateId::Component(*address), lock_flag)
+        })
     }
 
     fn caller_context_invoke(
@@ -538,6 +590,9 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
         args: EngineArgs,
     ) -> Result<InvokeResult, RuntimeError> {
         self.invoke_modules_on_runtime_call("component_invoke")?;
+        self.tracker.trace(|| TraceEvent::Action {
+            action: ActionIdent::from(action.clone()).to_string(),
+        });
 
         debug!(
             target: LOG_TARGET,
@@ -553,6 +608,7 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
                     owner_rule,
                     access_rules,
                     address_allocation,
+                    reentrancy_guard,
                 } = args.assert_one_arg()?;
 
                 let template_addr = self.tracker.get_template_address()?;
@@ -574,6 +630,7 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
                     owner_rule,
                     access_rules,
                     address_allocation,
+                    reentrancy_guard,
                 )?;
                 Ok(InvokeResult::encode(&component_address)?)
             },
@@ -652,6 +709,15 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
                         });
                     }
 
+                    // The calling template code belongs to the template before the upgrade and may not write its state
+                    // layout into the upgraded component
+                    let (current_template, _) = state.current_template()?;
+                    if state.get_component(&component_lock)?.template_address != *current_template {
+                        return Err(RuntimeError::ComponentStateSetAfterUpgrade {
+                            address: component_address,
+                        });
+                    }
+
                     state.modify_component_with(&component_lock, |component| {
                         if component_state == *component.state() {
                             return false;
@@ -720,18 +786,93 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
 
                 args.assert_no_args("Component::GetTemplateAddress")?;
 
-                // The template can never change so we'll just fetch the component
-                self.tracker.read_with(|state| {
-                    let substate = state.store().get_unmodified_substate(&component_address.into())?;
-                    let component = substate
-                        .substate_value()
-                        .component()
-                        .ok_or(RuntimeError::ComponentNotFound {
-                            address: component_address,
+                // The template may have been upgraded in this transaction so we read it from the working state
+                self.tracker.write_with(|state| {
+                    let template_address = state.get_template_for_component(&component_address)?;
+                    Ok(InvokeResult::encode(&template_address)?)
+                })
+            },
+            ComponentAction::UpgradeTemplate => {
+                let component_address =
+                    component_ref
+                        .as_component_address()
+                        .ok_or_else(|| RuntimeError::InvalidArgument {
+                            argument: "component_ref",
+                            reason: "UpgradeTemplate component action requires a component address".to_string(),
                         })?;
 
-                    Ok(InvokeResult::encode(&component.template_address)?)
-                })
+                let new_template_address: TemplateAddress = args.assert_one_arg()?;
+
+                let (current_template_address, current_state, access_rules) = self.tracker.write_with(|state| {
+                    let component_lock = state
+                        .current_call_scope()?
+                        .get_current_component_lock()
+                        .cloned()
+                        .ok_or(RuntimeError::NotInComponentContext {
+                            action: ComponentAction::UpgradeTemplate.into(),
+                        })?;
+                    // We only allow upgrading the current component
+                    if *component_lock.address() != component_address {
+                        return Err(RuntimeError::LockError(LockError::SubstateNotLocked {
+                            address: SubstateId::Component(component_address),
+                        }));
+                    }
+                    let component = state.get_component(&component_lock)?;
+                    state.authorization().check_component_upgrade_rules(component)?;
+
+                    Ok::<_, RuntimeError>((
+                        component.template_address,
+                        component.state().clone(),
+                        component.access_rules().clone(),
+                    ))
+                })?;
+
+                if current_template_address == new_template_address {
+                    return Err(RuntimeError::IncompatibleTemplateUpgrade {
+                        from: current_template_address,
+                        to: new_template_address,
+                        details: "Component already uses this template".to_string(),
+                    });
+                }
+
+                let current_template = self.get_template_def(&current_template_address)?;
+                let new_template = self.get_template_def(&new_template_address)?;
+                validate_template_upgrade(
+                    &current_template_address,
+                    &current_template,
+                    &new_template_address,
+                    &new_template,
+                )?;
+                validate_component_access_rule_methods(&access_rules, &new_template)?;
+
+                let new_state = if new_template.get_function(MIGRATE_FUNCTION_NAME).is_some() {
+                    let result = self
+                        .invoke_template_function(&new_template_address, MIGRATE_FUNCTION_NAME, args![current_state])?;
+                    Some(result.indexed.value().clone())
+                } else {
+                    None
+                };
+
+                self.tracker.write_with(|state| {
+                    let component_lock = state
+                        .current_call_scope()?
+                        .get_current_component_lock()
+                        .cloned()
+                        .ok_or(RuntimeError::NotInComponentContext {
+                            action: ComponentAction::UpgradeTemplate.into(),
+                        })?;
+
+                    state.modify_component_with(&component_lock, |component| {
+                        component.template_address = new_template_address;
+                        component.module_name = new_template.template_name().to_string();
+                        if let Some(new_state) = new_state {
+                            component.body.set(new_state);
+                        }
+                        true
+                    })
+                })?;
+
+                Ok(InvokeResult::unit())
             },
         }
     }
@@ -1085,6 +1226,9 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
         args: EngineArgs,
     ) -> Result<InvokeResult, RuntimeError> {
         self.invoke_modules_on_runtime_call("vault_invoke")?;
+        self.tracker.trace(|| TraceEvent::Action {
+            action: ActionIdent::from(action).to_string(),
+        });
 
         debug!(target: LOG_TARGET, "Vault invoke: {} {:?}", vault_ref, action,);
 
@@ -1218,30 +1362,7 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
                 })?;
                 let arg: VaultWithdrawArg = args.assert_one_arg()?;
 
-                let (vault_lock, resource_lock, maybe_auth_hook, auth_caller) =
-                    self.tracker.write_with(|state_mut| {
-                        let vault_lock = state_mut.lock_substate(&SubstateId::Vault(vault_id), LockFlag::Write)?;
-
-                        let resource_address = state_mut.get_vault(&vault_lock)?.resource_address();
-
-                        let resource_lock =
-                            state_mut.lock_substate(&SubstateId::Resource(*resource_address), LockFlag::Read)?;
-
-                        let resource = state_mut.get_resource(&resource_lock)?;
-
-                        state_mut.authorization().check_resource_access_rules(
-                            ResourceAuthAction::Withdraw,
-                            resource.as_ownership(),
-                            resource.access_rules(),
-                        )?;
-
-                        let auth_caller = state_mut.get_auth_caller()?;
-                        Ok::<_, RuntimeError>((vault_lock, resource_lock, resource.auth_hook().cloned(), auth_caller))
-                    })?;
-
-                if let Some(auth_hook) = maybe_auth_hook {
-                    self.invoke_resource_access_hook(auth_hook, auth_caller, ResourceAuthAction::Withdraw)?;
-                }
+                let (vault_lock, resource_lock) = self.lock_vault_for_withdraw(vault_id)?;
 
                 self.tracker.write_with(|state| {
                     let resource = state.get_resource(&resource_lock)?;
@@ -1288,6 +1409,45 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
                     Ok(InvokeResult::encode(&bucket)?)
                 })
             },
+            VaultAction::WithdrawConfidentialMany => {
+                let vault_id = vault_ref.vault_id().ok_or_else(|| RuntimeError::InvalidArgument {
+                    argument: "vault_ref",
+                    reason: "WithdrawConfidentialMany vault action requires a vault id".to_string(),
+                })?;
+                let proof: ConfidentialWithdrawProof = args.assert_one_arg()?;
+
+                let (vault_lock, resource_lock) = self.lock_vault_for_withdraw(vault_id)?;
+
+                self.tracker.write_with(|state| {
+                    let resource = state.get_resource(&resource_lock)?;
+                    let maybe_view_key = resource.view_key().cloned();
+
+                    let amount = proof.revealed_input_amount();
+                    let vault_mut = state.get_vault_mut(&vault_lock)?;
+                    let resource_containers = vault_mut.withdraw_confidential_many(proof, maybe_view_key.as_ref())?;
+
+                    self.emit_vault_events(
+                        VAULT_WITHDRAW_TOPIC,
+                        vault_id,
+                        &vault_lock,
+                        amount,
+                        ResourceType::Confidential,
+                        state,
+                    )?;
+
+                    let mut buckets = Vec::with_capacity(resource_containers.len());
+                    for resource_container in resource_containers {
+                        let bucket_id = state.id_provider()?.new_bucket_id();
+                        state.new_bucket(bucket_id, resource_container)?;
+                        buckets.push(tari_template_lib::models::Bucket::from_id(bucket_id));
+                    }
+
+                    state.unlock_substate(vault_lock)?;
+                    state.unlock_substate(resource_lock)?;
+
+                    Ok(InvokeResult::encode(&buckets)?)
+                })
+            },
             VaultAction::GetBalance => {
                 let vault_id = vault_ref.vault_id().ok_or_else(|| RuntimeError::InvalidArgument {
                     argument: "vault_ref",
@@ -2141,6 +2301,40 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
         }
     }
 
+    fn crypto_invoke(&self, action: CryptoAction) -> Result<InvokeResult, RuntimeError> {
+        self.invoke_modules_on_runtime_call("crypto_invoke")?;
+        match action {
+            CryptoAction::Hash { algorithm, data } => {
+                self.invoke_modules_on_crypto_operation(CryptoOperation::Hash { num_bytes: data.len() })?;
+                let hash = crypto::hash(algorithm, &data);
+                Ok(InvokeResult::encode(&hash)?)
+            },
+            CryptoAction::VerifySchnorrSignature {
+                public_key,
+                public_nonce,
+                signature,
+                message,
+            } => {
+                self.invoke_modules_on_crypto_operation(CryptoOperation::VerifySignature {
+                    message_len: message.len(),
+                })?;
+                let is_valid = crypto::verify_schnorr_signature(&public_key, &public_nonce, &signature, &message);
+                Ok(InvokeResult::encode(&is_valid)?)
+            },
+            CryptoAction::VerifyEd25519Signature {
+                public_key,
+                signature,
+                message,
+            } => {
+                self.invoke_modules_on_crypto_operation(CryptoOperation::VerifySignature {
+                    message_len: message.len(),
+                })?;
+                let is_valid = crypto::verify_ed25519_signature(&public_key, &signature, &message);
+                Ok(InvokeResult::encode(&is_valid)?)
+            },
+        }
+    }
+
     fn call_invoke(&self, action: CallAction, args: EngineArgs) -> Result<InvokeResult, RuntimeError> {
         self.invoke_modules_on_runtime_call("call_invoke")?;
         debug!(
@@ -2312,6 +2506,15 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
     }
 
     fn check_component_access_rules(&self, method: &str, locked: &LockedSubstate) -> Result<(), RuntimeError> {
+        if let Some(component_address) = locked.address().as_component_address() {
+            self.tracker.trace(|| TraceEvent::Action {
+                action: ActionIdent::ComponentCallMethod {
+                    component_address,
+                    method: method.to_string(),
+                }
+                .to_string(),
+            });
+        }
         self.tracker
             .read_with(|state| state.authorization().check_component_access_rules(method, locked))
     }
@@ -2359,6 +2562,91 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
             Ok(())
         })
     }
+
+    fn record_wasm_points(&self, points_used: u64) -> Result<(), RuntimeError> {
+        for module in &self.modules {
+            module.on_wasm_execution(&self.tracker, points_used)?;
+        }
+        Ok(())
+    }
+}
+
+/// The optional template function that converts the state of a component when upgrading to the template
+const MIGRATE_FUNCTION_NAME: &str = "migrate";
+
+fn is_method(function: &FunctionDef) -> bool {
+    function.arguments.first().is_some_and(|arg| arg.name == "self")
+}
+
+/// Checks that every method of the current template is callable on the new template with the same signature and that
+/// the `migrate` function, if present, converts a state into the new template state
+fn validate_template_upgrade(
+    current_address: &TemplateAddress,
+    current_template: &TemplateDef,
+    new_address: &TemplateAddress,
+    new_template: &TemplateDef,
+) -> Result<(), RuntimeError> {
+    let incompatible = |details: String| RuntimeError::IncompatibleTemplateUpgrade {
+        from: *current_address,
+        to: *new_address,
+        details,
+    };
+
+    for current in current_template.functions().iter().filter(|f| is_method(f)) {
+        let new = new_template
+            .get_function(&current.name)
+            .filter(|f| is_method(f))
+            .ok_or_else(|| incompatible(format!("Method '{}' not found in new template", current.name)))?;
+
+        let current_args = &current.arguments[1..];
+        let new_args = &new.arguments[1..];
+        let args_compatible = current_args.len() == new_args.len() &&
+            current_args
+                .iter()
+                .zip(new_args)
+                .all(|(a, b)| is_compatible_type(&a.arg_type, &b.arg_type));
+        if !args_compatible || !is_compatible_type(&current.output, &new.output) {
+            return Err(incompatible(format!(
+                "Method '{}' has a different signature in the new template",
+                current.name
+            )));
+        }
+    }
+
+    if let Some(migrate) = new_template.get_function(MIGRATE_FUNCTION_NAME) {
+        let expected_output = Type::Other {
+            name: new_template.template_name().to_string(),
+        };
+        if is_method(migrate) || migrate.arguments.len() != 1 || migrate.output != expected_output {
+            return Err(incompatible(format!(
+                "The '{}' function must take the current state as its only argument and return {}",
+                MIGRATE_FUNCTION_NAME,
+                new_template.template_name()
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Compares two ABI types for the purpose of a template upgrade. Templates built before `Option`, `Box` and map types
+/// were described in the ABI encode them as `Type::Other` with the bare type name and no type parameters (e.g.
+/// `Other { name: "Option" }`), so these are compatible with any type that the newer encoding produces for them.
+fn is_compatible_type(current: &Type, new: &Type) -> bool {
+    match (current, new) {
+        (Type::Vec(a), Type::Vec(b)) | (Type::Option(a), Type::Option(b)) => is_compatible_type(a, b),
+        (Type::Tuple(a), Type::Tuple(b)) => {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| is_compatible_type(a, b))
+        },
+        (Type::Map(ak, av), Type::Map(bk, bv)) => is_compatible_type(ak, bk) && is_compatible_type(av, bv),
+        // Box<T> is now described as T
+        (Type::Other { name }, _) | (_, Type::Other { name }) if name == "Box" => true,
+        (Type::Option(_), Type::Other { name }) | (Type::Other { name }, Type::Option(_)) => name == "Option",
+        (Type::Map(..), Type::Other { name }) | (Type::Other { name }, Type::Map(..)) => {
+            matches!(name.as_str(), "HashMap" | "BTreeMap" | "IndexMap")
+        },
+        (a, b) => a == b,
+    }
 }
 
 fn validate_component_access_rule_methods(
@@ -2375,3 +2663,68 @@ fn validate_component_access_rule_methods(
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use tari_template_abi::{ArgDef, TemplateDefV1};
+
+    use super::*;
+
+    fn template_with_method(name: &str, arg_type: Type, output: Type) -> TemplateDef {
+        TemplateDef::V1(TemplateDefV1 {
+            template_name: name.to_string(),
+            tari_version: "0.1.0".to_string(),
+            functions: vec![FunctionDef {
+                name: "set".to_string(),
+                arguments: vec![
+                    ArgDef {
+                        name: "self".to_string(),
+                        arg_type: Type::Other {
+                            name: "&mut self".to_string(),
+                        },
+                    },
+                    ArgDef {
+                        name: "value".to_string(),
+                        arg_type,
+                    },
+                ],
+                output,
+                is_mut: true,
+            }],
+            events: vec![],
+            types: vec![],
+        })
+    }
+
+    fn other(name: &str) -> Type {
+        Type::Other { name: name.to_string() }
+    }
+
+    #[test]
+    fn it_accepts_legacy_option_and_map_encodings() {
+        // Built before Option and maps were described in the ABI
+        let legacy = template_with_method("Legacy", other("BTreeMap"), other("Option"));
+        let current = template_with_method(
+            "Current",
+            Type::Map(Box::new(Type::String), Box::new(Type::U64)),
+            Type::Option(Box::new(Type::U32)),
+        );
+
+        validate_template_upgrade(&Default::default(), &legacy, &Default::default(), &current).unwrap();
+        validate_template_upgrade(&Default::default(), &current, &Default::default(), &legacy).unwrap();
+    }
+
+    #[test]
+    fn it_rejects_changed_types() {
+        let current = template_with_method("Current", Type::Option(Box::new(Type::U32)), Type::Unit);
+        let new = template_with_method("New", Type::Option(Box::new(Type::U64)), Type::Unit);
+        let err = validate_template_upgrade(&Default::default(), &current, &Default::default(), &new).unwrap_err();
+        assert!(matches!(err, RuntimeError::IncompatibleTemplateUpgrade { .. }));
+
+        // A legacy Option is not compatible with a map
+        let legacy = template_with_method("Legacy", other("Option"), Type::Unit);
+        let new = template_with_method("New", Type::Map(Box::new(Type::U8), Box::new(Type::U8)), Type::Unit);
+        let err = validate_template_upgrade(&Default::default(), &legacy, &Default::default(), &new).unwrap_err();
+        assert!(matches!(err, RuntimeError::IncompatibleTemplateUpgrade { .. }));
+    }
+}
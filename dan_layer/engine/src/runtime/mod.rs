@@ -36,11 +36,15 @@ mod actions;
 pub use actions::*;
 
 mod module;
-pub use module::{RuntimeModule, RuntimeModuleError};
+pub use module::{CryptoOperation, RuntimeModule, RuntimeModuleError};
 
+mod crypto;
 mod fee_state;
 mod tracker;
 
+mod trace;
+pub use trace::ExecutionTracer;
+
 mod locking;
 pub mod scope;
 pub use locking::{LockError, LockState};
@@ -75,6 +79,7 @@ use tari_template_lib::{
         ComponentAction,
         ComponentRef,
         ConsensusAction,
+        CryptoAction,
         GenerateRandomAction,
         InvokeResult,
         LogLevel,
@@ -87,7 +92,7 @@ use tari_template_lib::{
         WorkspaceAction,
     },
     invoke_args,
-    models::{ComponentAddress, EntityId, Metadata, NonFungibleAddress, VaultRef},
+    models::{ComponentAddress, EntityId, NonFungibleAddress, VaultRef},
 };
 pub use tracker::StateTracker;
 
@@ -95,7 +100,7 @@ use crate::runtime::{locking::LockedSubstate, scope::PushCallFrame};
 
 pub trait RuntimeInterface: Send + Sync {
     fn next_entity_id(&self) -> Result<EntityId, RuntimeError>;
-    fn emit_event(&self, topic: String, payload: Metadata) -> Result<(), RuntimeError>;
+    fn emit_event(&self, topic: String, payload: tari_bor::Value) -> Result<(), RuntimeError>;
 
     fn emit_log(&self, level: LogLevel, message: String) -> Result<(), RuntimeError>;
 
@@ -151,6 +156,8 @@ pub trait RuntimeInterface: Send + Sync {
 
     fn generate_random_invoke(&self, action: GenerateRandomAction) -> Result<InvokeResult, RuntimeError>;
 
+    fn crypto_invoke(&self, action: CryptoAction) -> Result<InvokeResult, RuntimeError>;
+
     fn generate_uuid(&self) -> Result<[u8; 32], RuntimeError>;
 
     fn set_last_instruction_output(&self, value: IndexedValue) -> Result<(), RuntimeError>;
@@ -182,6 +189,8 @@ pub trait RuntimeInterface: Send + Sync {
     fn pop_call_frame(&self) -> Result<(), RuntimeError>;
 
     fn publish_template(&self, template: Vec<u8>) -> Result<(), RuntimeError>;
+
+    fn record_wasm_points(&self, points_used: u64) -> Result<(), RuntimeError>;
 }
 
 #[derive(Clone)]
@@ -15,6 +15,28 @@ pub trait RuntimeModule: Send + Sync {
     fn on_before_finalize(&self, _track: &StateTracker) -> Result<(), RuntimeModuleError> {
         Ok(())
     }
+
+    /// Called when a WASM template function returns with the number of metering points it used. Nested template calls
+    /// report their points separately.
+    fn on_wasm_execution(&self, _track: &StateTracker, _points_used: u64) -> Result<(), RuntimeModuleError> {
+        Ok(())
+    }
+
+    /// Called when a template uses one of the native cryptographic functions of the engine
+    fn on_crypto_operation(
+        &self,
+        _track: &StateTracker,
+        _operation: CryptoOperation,
+    ) -> Result<(), RuntimeModuleError> {
+        Ok(())
+    }
+}
+
+/// A cryptographic operation that the engine performed natively on behalf of a template
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CryptoOperation {
+    Hash { num_bytes: usize },
+    VerifySignature { message_len: usize },
 }
 
 #[derive(Debug, thiserror::Error)]
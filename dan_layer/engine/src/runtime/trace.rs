@@ -0,0 +1,27 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use std::sync::{Arc, Mutex};
+
+use tari_engine_types::execution_trace::{ExecutionTrace, TraceEvent};
+
+/// Collects trace events during execution. Clones share the same trace so that events recorded before a working state
+/// reset (e.g. when instructions fail after the fee checkpoint) are retained.
+#[derive(Debug, Clone, Default)]
+pub struct ExecutionTracer {
+    trace: Arc<Mutex<ExecutionTrace>>,
+}
+
+impl ExecutionTracer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&self, depth: usize, event: TraceEvent) {
+        self.trace.lock().unwrap().push(depth, event);
+    }
+
+    pub fn take(&self) -> ExecutionTrace {
+        std::mem::take(&mut *self.trace.lock().unwrap())
+    }
+}
@@ -21,6 +21,7 @@
 //   USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use std::{
+    collections::BTreeMap,
     convert::TryFrom,
     sync::{Arc, Mutex, RwLock},
 };
@@ -33,6 +34,7 @@ use tari_engine_types::{
     component::{ComponentBody, ComponentHeader},
     confidential::UnclaimedConfidentialOutput,
     events::Event,
+    execution_trace::TraceEvent,
     fees::FeeSource,
     indexed_value::{IndexedValue, IndexedWellKnownTypes},
     lock::LockFlag,
@@ -44,7 +46,7 @@ use tari_engine_types::{
 use tari_template_lib::{
     auth::{ComponentAccessRules, OwnerRule},
     crypto::RistrettoPublicKeyBytes,
-    models::{AddressAllocation, Amount, BucketId, ComponentAddress, Metadata, UnclaimedConfidentialOutputAddress},
+    models::{AddressAllocation, Amount, BucketId, ComponentAddress, UnclaimedConfidentialOutputAddress},
     Hash,
 };
 
@@ -54,6 +56,7 @@ use crate::{
         scope::{CallScope, PushCallFrame},
         working_state::WorkingState,
         workspace::Workspace,
+        ExecutionTracer,
         RuntimeError,
     },
     state_store::memory::ReadOnlyMemoryStateStore,
@@ -85,6 +88,18 @@ impl StateTracker {
         }
     }
 
+    pub fn set_read_only(&self) {
+        self.write_with(|state| state.set_read_only());
+    }
+
+    pub fn enable_tracing(&self, tracer: ExecutionTracer) {
+        self.write_with(|state| state.set_tracer(tracer));
+    }
+
+    pub fn trace<F: FnOnce() -> TraceEvent>(&self, event: F) {
+        self.read_with(|state| state.trace(event));
+    }
+
     pub fn get_current_epoch(&self) -> Result<Epoch, RuntimeError> {
         self.read_with(|state| state.get_current_epoch())
     }
@@ -155,6 +170,7 @@ impl StateTracker {
         owner_rule: OwnerRule,
         access_rules: ComponentAccessRules,
         address_allocation: Option<AddressAllocation<ComponentAddress>>,
+        reentrancy_guard: bool,
     ) -> Result<ComponentAddress, RuntimeError> {
         self.write_with(|state| {
             let (template_address, module_name) =
@@ -177,6 +193,7 @@ impl StateTracker {
                 access_rules,
                 owner_rule,
                 entity_id: component_address.entity_id(),
+                reentrancy_guard,
                 body: component,
             };
             let substate_id = SubstateId::Component(component_address);
@@ -198,7 +215,7 @@ impl StateTracker {
                 template_address,
                 state.transaction_hash(),
                 "component-created".to_string(),
-                Metadata::from([("module_name".to_string(), module_name)]),
+                tari_bor::to_value(&BTreeMap::from([("module_name", module_name)]))?,
             ));
 
             debug!(target: LOG_TARGET, "New component created: {}", component_address);
@@ -269,6 +286,7 @@ impl StateTracker {
         self.write_with(|state| {
             debug!(target: LOG_TARGET, "Add fee: source: {:?}, amount: {}", source, amount);
             state.fee_state_mut().fee_charges.insert(source, amount);
+            state.trace(|| TraceEvent::FeeCharged { source, amount });
         })
     }
 
@@ -1,15 +1,19 @@
 //   Copyright 2023 The Tari Project
 //   SPDX-License-Identifier: BSD-3-Clause
 
-use tari_template_lib::auth::{
-    AccessRule,
-    OwnerRule,
-    Ownership,
-    RequireRule,
-    ResourceAccessRules,
-    ResourceAuthAction,
-    RestrictedAccessRule,
-    RuleRequirement,
+use tari_engine_types::component::ComponentHeader;
+use tari_template_lib::{
+    args::ComponentAction,
+    auth::{
+        AccessRule,
+        OwnerRule,
+        Ownership,
+        RequireRule,
+        ResourceAccessRules,
+        ResourceAuthAction,
+        RestrictedAccessRule,
+        RuleRequirement,
+    },
 };
 
 use crate::runtime::{
@@ -95,6 +99,21 @@ impl<'a> Authorization<'a> {
         Ok(())
     }
 
+    /// Upgrading the template of a component requires the owner and the upgrade rule of the component
+    pub fn check_component_upgrade_rules(&self, component: &ComponentHeader) -> Result<(), RuntimeError> {
+        self.require_ownership(ComponentAction::UpgradeTemplate, component.as_ownership())?;
+
+        let scope = self.state.current_call_scope()?.auth_scope();
+        let rule = component.access_rules().get_upgrade_template_rule();
+        if !check_access_rule(self.state, scope, rule)? {
+            return Err(RuntimeError::AccessDenied {
+                action_ident: ComponentAction::UpgradeTemplate.into(),
+            });
+        }
+
+        Ok(())
+    }
+
     pub fn require_ownership<A: Into<ActionIdent>>(
         &self,
         action: A,
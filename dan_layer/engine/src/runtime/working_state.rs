@@ -16,6 +16,7 @@ use tari_engine_types::{
     bucket::Bucket,
     component::ComponentHeader,
     events::Event,
+    execution_trace::TraceEvent,
     fee_claim::{FeeClaim, FeeClaimAddress},
     fees::FeeReceipt,
     id_provider::{IdProvider, ObjectIds},
@@ -59,6 +60,7 @@ use crate::{
         state_store::WorkingStateStore,
         tracker_auth::Authorization,
         ActionIdent,
+        ExecutionTracer,
         RuntimeError,
         TransactionCommitError,
     },
@@ -89,6 +91,8 @@ pub(super) struct WorkingState {
     initial_call_scope: CallScope,
 
     fee_state: FeeState,
+    read_only: bool,
+    tracer: Option<ExecutionTracer>,
 }
 
 impl WorkingState {
@@ -118,6 +122,24 @@ impl WorkingState {
             initial_call_scope,
             fee_state: FeeState::new(),
             object_ids: ObjectIds::new(1000),
+            read_only: false,
+            tracer: None,
+        }
+    }
+
+    /// Forbids any substate from being created or write locked for the rest of the execution
+    pub fn set_read_only(&mut self) {
+        self.read_only = true;
+    }
+
+    pub fn set_tracer(&mut self, tracer: ExecutionTracer) {
+        self.tracer = Some(tracer);
+    }
+
+    /// Records a trace event at the current call depth if tracing is enabled
+    pub fn trace<F: FnOnce() -> TraceEvent>(&self, event: F) {
+        if let Some(tracer) = &self.tracer {
+            tracer.record(self.call_frame_depth(), event());
         }
     }
 
@@ -144,18 +166,31 @@ impl WorkingState {
         value: V,
     ) -> Result<(), RuntimeError> {
         let address = address.into();
+        if self.read_only {
+            return Err(RuntimeError::WriteInReadOnlyCall { id: address });
+        }
         self.current_call_scope_mut()?.add_substate_to_scope(address.clone())?;
         self.store.insert(address, value.into())?;
         Ok(())
     }
 
     pub fn lock_substate(&mut self, addr: &SubstateId, lock_flag: LockFlag) -> Result<LockedSubstate, RuntimeError> {
+        if self.read_only && lock_flag.is_write() {
+            return Err(RuntimeError::WriteInReadOnlyCall { id: addr.clone() });
+        }
         let lock_id = self.store.try_lock(addr, lock_flag)?;
+        self.trace(|| TraceEvent::SubstateLocked {
+            substate_id: addr.clone(),
+            lock_flag,
+        });
         Ok(LockedSubstate::new(addr.clone(), lock_id, lock_flag))
     }
 
     pub fn unlock_substate(&mut self, lock: LockedSubstate) -> Result<(), RuntimeError> {
         self.store.try_unlock(lock.lock_id())?;
+        self.trace(|| TraceEvent::SubstateUnlocked {
+            substate_id: lock.address().clone(),
+        });
         Ok(())
     }
 
@@ -403,6 +438,7 @@ impl WorkingState {
             scope_mut.remove_bucket_from_scope(bucket_id);
             scope_mut.add_substate_to_owned(resource_addr.into());
         }
+        self.trace(|| TraceEvent::BucketTaken { bucket_id });
         Ok(bucket)
     }
 
@@ -445,6 +481,7 @@ impl WorkingState {
             .proofs
             .remove(&proof_id)
             .ok_or(RuntimeError::ProofNotFound { proof_id })?;
+        self.trace(|| TraceEvent::ProofDropped { proof_id });
 
         // Unlock funds
         match *proof.container() {
@@ -666,6 +703,11 @@ impl WorkingState {
             }
         }
 
+        self.trace(|| TraceEvent::BucketCreated {
+            bucket_id,
+            resource_address: *resource.resource_address(),
+            amount: resource.amount(),
+        });
         let bucket = Bucket::new(bucket_id, resource);
         if self.buckets.insert(bucket_id, bucket).is_some() {
             return Err(RuntimeError::DuplicateBucket { bucket_id });
@@ -676,6 +718,10 @@ impl WorkingState {
 
     pub fn new_proof(&mut self, proof_id: ProofId, locked_funds: LockedResource) -> Result<(), RuntimeError> {
         debug!(target: LOG_TARGET, "New proof {}", proof_id);
+        self.trace(|| TraceEvent::ProofCreated {
+            proof_id,
+            resource_address: *locked_funds.resource_address(),
+        });
         if self.proofs.insert(proof_id, Proof::new(locked_funds)).is_some() {
             return Err(RuntimeError::DuplicateProof { proof_id });
         }
@@ -920,6 +966,17 @@ impl WorkingState {
         self.call_frames.len()
     }
 
+    /// Returns true if any active call frame is executing a method on the given component
+    pub fn is_component_in_call_stack(&self, component_address: &ComponentAddress) -> bool {
+        self.call_frames.iter().any(|frame| {
+            frame
+                .scope()
+                .get_current_component_lock()
+                .and_then(|lock| lock.address().as_component_address())
+                .is_some_and(|addr| addr == *component_address)
+        })
+    }
+
     /// Returns template address and module name
     pub fn current_template(&self) -> Result<(&TemplateAddress, &str), RuntimeError> {
         self.call_frames
@@ -978,10 +1035,23 @@ impl WorkingState {
         }
 
         self.call_frames.push(new_frame);
+        self.trace(|| {
+            let frame = self.call_frames.last().expect("frame was just pushed");
+            let (template_address, module_name) = frame.current_template();
+            TraceEvent::CallFramePushed {
+                template_address: *template_address,
+                module_name: module_name.to_string(),
+                component_address: frame
+                    .scope()
+                    .get_current_component_lock()
+                    .and_then(|lock| lock.address().as_component_address()),
+            }
+        });
         Ok(())
     }
 
     pub fn pop_frame(&mut self) -> Result<(), RuntimeError> {
+        self.trace(|| TraceEvent::CallFramePopped);
         let current_frame = self.call_frames.pop().ok_or(RuntimeError::NoActiveCallFrame)?;
 
         let scope = current_frame.into_scope();
@@ -1014,12 +1084,13 @@ impl WorkingState {
     }
 
     pub fn take_state(&mut self) -> Self {
-        let new_state = WorkingState::new(
+        let mut new_state = WorkingState::new(
             self.store.state_store().clone(),
             VirtualSubstates::new(),
             CallScope::new(),
             self.transaction_hash,
         );
+        new_state.tracer = self.tracer.clone();
         mem::replace(self, new_state)
     }
 
@@ -43,6 +43,8 @@ pub enum TransactionError {
     ValueVisitorError(#[from] IndexedValueError),
     #[error("Function {name} not found")]
     FunctionNotFound { name: String },
+    #[error("Method {name} mutates its component and cannot be called as a view")]
+    MutableMethodInViewCall { name: String },
     #[error("Invariant error: {details}")]
     InvariantError { details: String },
     #[error("Load template error: {0}")]
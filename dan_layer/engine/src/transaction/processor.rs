@@ -48,6 +48,7 @@ use tari_template_lib::{
     invoke_args,
     models::{Bucket, ComponentAddress, NonFungibleAddress},
     prelude::{AccessRules, TemplateAddress},
+    Hash,
 };
 use tari_transaction::Transaction;
 use tari_utilities::ByteArray;
@@ -57,6 +58,7 @@ use crate::{
         scope::{CallScope, PushCallFrame},
         AuthParams,
         AuthorizationScope,
+        ExecutionTracer,
         Runtime,
         RuntimeInterfaceImpl,
         RuntimeModule,
@@ -70,6 +72,7 @@ use crate::{
 };
 
 const LOG_TARGET: &str = "tari::dan::engine::instruction_processor";
+/// The default maximum call depth, see [`TransactionProcessorConfig::max_call_depth`]
 pub const MAX_CALL_DEPTH: usize = 10;
 const ACCOUNT_CONSTRUCTOR_FUNCTION: &str = "create";
 
@@ -77,6 +80,10 @@ const ACCOUNT_CONSTRUCTOR_FUNCTION: &str = "create";
 pub struct TransactionProcessorConfig {
     pub network: Network,
     pub template_binary_max_size_bytes: usize,
+    /// The maximum depth of the call frame stack, including cross-template calls
+    pub max_call_depth: usize,
+    /// If true, an execution trace is recorded and returned in the execute result
+    pub enable_tracing: bool,
 }
 
 impl TransactionProcessorConfig {
@@ -90,6 +97,8 @@ impl Default for TransactionProcessorConfig {
         Self {
             network: Default::default(),
             template_binary_max_size_bytes: 1000 * 1000 * 5, // 5MB
+            max_call_depth: MAX_CALL_DEPTH,
+            enable_tracing: false,
         }
     }
 }
@@ -110,6 +119,16 @@ impl TransactionProcessorConfigBuilder {
         self
     }
 
+    pub fn with_max_call_depth(&mut self, max_call_depth: usize) -> &mut Self {
+        self.config.max_call_depth = max_call_depth;
+        self
+    }
+
+    pub fn with_tracing(&mut self, enabled: bool) -> &mut Self {
+        self.config.enable_tracing = enabled;
+        self
+    }
+
     pub fn build(&self) -> TransactionProcessorConfig {
         self.config.clone()
     }
@@ -163,6 +182,10 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate> + 'static> T
         }
 
         let tracker = StateTracker::new(state_db, virtual_substates, initial_call_scope, transaction.hash());
+        let tracer = config.enable_tracing.then(ExecutionTracer::new);
+        if let Some(tracer) = &tracer {
+            tracker.enable_tracing(tracer.clone());
+        }
 
         // TODO: If the seal signer is authorized we use this as the signer public key, if not we use the first
         // signature as the "default" owner. This is due to limitations of the current transaction model.
@@ -182,7 +205,7 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate> + 'static> T
             transaction_signer_public_key,
             entity_id_provider,
             modules,
-            MAX_CALL_DEPTH,
+            config.max_call_depth,
             config.network,
         )?;
 
@@ -204,6 +227,7 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate> + 'static> T
                     return Ok(ExecuteResult {
                         finalize,
                         execution_time: timer.elapsed(),
+                        trace: tracer.as_ref().map(ExecutionTracer::take),
                     });
                 }
                 execution_results
@@ -215,6 +239,7 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate> + 'static> T
                         RejectReason::ExecutionFailure(err.to_string()),
                     ),
                     execution_time: timer.elapsed(),
+                    trace: tracer.as_ref().map(ExecutionTracer::take),
                 });
             },
         };
@@ -232,6 +257,7 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate> + 'static> T
                 Ok(ExecuteResult {
                     finalize,
                     execution_time: timer.elapsed(),
+                    trace: tracer.as_ref().map(ExecutionTracer::take),
                 })
             },
             // This can happen e.g if you have dangling buckets after running the instructions
@@ -253,11 +279,75 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate> + 'static> T
                 Ok(ExecuteResult {
                     finalize,
                     execution_time: timer.elapsed(),
+                    trace: tracer.as_ref().map(ExecutionTracer::take),
                 })
             },
         }
     }
 
+    /// Calls a method that does not mutate its component (i.e. not `&mut self`) outside of a transaction. The call is
+    /// executed in read-only mode, any attempt to create or write lock a substate fails.
+    pub fn call_view(
+        self,
+        component_address: &ComponentAddress,
+        method: &str,
+        args: Vec<Arg>,
+    ) -> Result<InstructionResult, TransactionError> {
+        let Self {
+            config,
+            template_provider,
+            state_db,
+            auth_params,
+            virtual_substates,
+            modules,
+        } = self;
+
+        // View calls are not part of a transaction and cannot create any entities
+        let view_hash = Hash::default();
+        let mut initial_call_scope = CallScope::new();
+        initial_call_scope.set_auth_scope(AuthorizationScope::new(auth_params.initial_ownership_proofs));
+        initial_call_scope.add_substate_to_owned((*component_address).into());
+
+        let tracker = StateTracker::new(state_db, virtual_substates, initial_call_scope, view_hash);
+        tracker.set_read_only();
+
+        let runtime_interface = RuntimeInterfaceImpl::initialize(
+            tracker,
+            template_provider.clone(),
+            PublicKey::default(),
+            EntityIdProvider::new(view_hash, 0),
+            modules,
+            config.max_call_depth,
+            config.network,
+        )?;
+        let runtime = Runtime::new(Arc::new(runtime_interface));
+
+        let component = runtime.interface().load_component(component_address)?;
+        let template = template_provider
+            .get_template_module(&component.template_address)
+            .map_err(|e| TransactionError::FailedToLoadTemplate {
+                address: component.template_address,
+                details: e.to_string(),
+            })?
+            .ok_or(TransactionError::TemplateNotFound {
+                address: component.template_address,
+            })?;
+        let function_def =
+            template
+                .template_def()
+                .get_function(method)
+                .ok_or_else(|| TransactionError::FunctionNotFound {
+                    name: method.to_string(),
+                })?;
+        if function_def.is_mut {
+            return Err(TransactionError::MutableMethodInViewCall {
+                name: method.to_string(),
+            });
+        }
+
+        Self::call_method(&*template_provider, &runtime, component_address, method, args)
+    }
+
     fn process_instructions(
         config: &TransactionProcessorConfig,
         template_provider: &TTemplateProvider,
@@ -20,13 +20,27 @@
 //   WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 //   USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use wasmer::{wasmparser::Operator, ModuleMiddleware};
-use wasmer_middlewares::Metering;
+use wasmer::{wasmparser::Operator, AsStoreMut, Instance, ModuleMiddleware};
+use wasmer_middlewares::{
+    metering::{get_remaining_points, MeteringPoints},
+    Metering,
+};
+
+/// The number of metering points available to each template invocation
+pub const WASM_POINTS_LIMIT: u64 = 100_000_000;
 
 pub fn middleware(limit: u64) -> impl ModuleMiddleware {
     Metering::new(limit, cost_function)
 }
 
+/// Returns the number of metering points consumed by the instance so far
+pub fn points_used<S: AsStoreMut>(store: &mut S, instance: &Instance) -> u64 {
+    match get_remaining_points(store, instance) {
+        MeteringPoints::Remaining(remaining) => WASM_POINTS_LIMIT - remaining,
+        MeteringPoints::Exhausted => WASM_POINTS_LIMIT,
+    }
+}
+
 #[allow(clippy::too_many_lines)]
 fn cost_function(op: &Operator) -> u64 {
     match op {
@@ -98,7 +98,7 @@ impl WasmModule {
         let mut compiler = Cranelift::new();
         compiler.opt_level(CraneliftOptLevel::Speed).canonicalize_nans(true);
         // TODO: Configure metering limit
-        compiler.push_middleware(Arc::new(metering::middleware(100_000_000)));
+        compiler.push_middleware(Arc::new(metering::middleware(metering::WASM_POINTS_LIMIT)));
         let mut engine = Engine::from(compiler);
         engine.set_tunables(tunables);
 
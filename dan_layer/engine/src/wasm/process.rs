@@ -32,6 +32,7 @@ use tari_template_lib::{
         CallerContextInvokeArg,
         ComponentInvokeArg,
         ConsensusInvokeArg,
+        CryptoInvokeArg,
         EmitEventArg,
         EmitLogArg,
         GenerateRandomInvokeArg,
@@ -53,6 +54,7 @@ use crate::{
     wasm::{
         environment::{AllocPtr, WasmEnv},
         error::WasmExecutionError,
+        metering,
         module::MainFunction,
         LoadedWasmTemplate,
     },
@@ -187,6 +189,9 @@ impl WasmProcess {
                 log::debug!(target: LOG_TARGET, "proof action = {:?}", arg.action);
                 env.interface().proof_invoke(arg.proof_ref, arg.action, arg.args.into())
             }),
+            EngineOp::CryptoInvoke => Self::handle(store, env_mut, arg, |env, arg: CryptoInvokeArg| {
+                env.interface().crypto_invoke(arg.action)
+            }),
             EngineOp::BuiltinTemplateInvoke => {
                 Self::handle(store, env_mut, arg, |env, arg: BuiltinTemplateInvokeArg| {
                     env.interface().builtin_template_invoke(arg.action)
@@ -275,6 +280,8 @@ impl Invokable<Store> for WasmProcess {
 
         let call_info_ptr = self.alloc_and_write(store, &call_info)?;
         let res = func.call(store, call_info_ptr.as_wasm_ptr(), call_info_ptr.len());
+        let points_used = metering::points_used(store, &self.instance);
+        self.env.state().interface().record_wasm_points(points_used)?;
         // No need to free since the exported function should free the memory by dropping it at the end - however, if it
         // does not the memory will be freed once the VM is destructed
         // self.env.as_ref(store).free(store, call_info_ptr)?;
@@ -1,7 +1,10 @@
 //   Copyright 2023 The Tari Project
 //   SPDX-License-Identifier: BSD-3-Clause
 
-use tari_dan_engine::{runtime::ActionIdent, transaction::MAX_CALL_DEPTH};
+use tari_dan_engine::{
+    runtime::{ActionIdent, RuntimeError},
+    transaction::MAX_CALL_DEPTH,
+};
 use tari_engine_types::{
     commit_result::{ExecuteResult, RejectReason},
     instruction::Instruction,
@@ -11,7 +14,10 @@ use tari_template_lib::{
     models::{ComponentAddress, TemplateAddress},
     prelude::{Amount, ResourceAddress},
 };
-use tari_template_test_tooling::{support::assert_error::assert_access_denied_for_action, TemplateTest};
+use tari_template_test_tooling::{
+    support::assert_error::{assert_access_denied_for_action, assert_reject_reason},
+    TemplateTest,
+};
 use tari_transaction::Transaction;
 
 struct ComposabilityTest {
@@ -365,3 +371,38 @@ fn it_fails_when_surpassing_recursion_limit() {
     // CallInvoke" we should be able to assert a more specific error cause
     assert!(matches!(reason, RejectReason::ExecutionFailure(_)));
 }
+
+#[test]
+fn it_enforces_the_configured_call_depth() {
+    let mut test = setup();
+    let (_, _, private_key) = test.template_test.create_funded_account();
+
+    let composability_0 = initialize_composability(&mut test).composability_component;
+    let composability_1 = initialize_composability(&mut test).composability_component;
+    test.template_test.call_method::<()>(
+        composability_1,
+        "set_nested_composability",
+        args![composability_0],
+        vec![],
+    );
+
+    // composability_1 -> composability_0 -> state requires 3 call frames
+    test.template_test.set_max_call_depth(2);
+    let result = test
+        .template_test
+        .try_execute(
+            Transaction::builder()
+                .call_method(composability_1, "get_nested_value", args![])
+                .build_and_seal(&private_key),
+            vec![],
+        )
+        .unwrap();
+    let reason = result.expect_transaction_failure();
+    assert_reject_reason(reason, RuntimeError::MaxCallDepthExceeded { max_depth: 2 });
+
+    test.template_test.set_max_call_depth(3);
+    let value: u32 = test
+        .template_test
+        .call_method(composability_1, "get_nested_value", args![], vec![]);
+    assert_eq!(value, 0);
+}
@@ -2,13 +2,16 @@
 //   SPDX-License-Identifier: BSD-3-Clause
 
 use rand::rngs::OsRng;
-use tari_common_types::types::PublicKey;
+use tari_common_types::types::{PrivateKey, PublicKey};
 use tari_crypto::keys::PublicKey as _;
-use tari_engine_types::{resource_container::ResourceError, substate::SubstateId};
+use tari_engine_types::{
+    resource_container::ResourceError,
+    substate::{SubstateDiff, SubstateId},
+};
 use tari_template_lib::{
     args,
     crypto::RistrettoPublicKeyBytes,
-    models::{Amount, ComponentAddress},
+    models::{Amount, ComponentAddress, NonFungibleAddress},
     prelude::ConfidentialOutputStatement,
 };
 use tari_template_test_tooling::{
@@ -18,6 +21,7 @@ use tari_template_test_tooling::{
             generate_confidential_proof,
             generate_confidential_proof_with_view_key,
             generate_withdraw_proof,
+            generate_withdraw_proof_with_additional_outputs,
             generate_withdraw_proof_with_inputs,
             generate_withdraw_proof_with_view_key,
         },
@@ -512,3 +516,179 @@ fn mint_with_view_key() {
         .unwrap();
     assert_eq!(total_balance, Some(55));
 }
+
+/// Deposits `amount` confidential funds from the faucet into a new account and returns the account along with the
+/// mask of the deposited commitment.
+fn fund_account_confidentially(
+    test: &mut TemplateTest,
+    faucet: ComponentAddress,
+    faucet_mask: &PrivateKey,
+    faucet_supply: Amount,
+    amount: Amount,
+    view_key: &PublicKey,
+) -> (ComponentAddress, NonFungibleAddress, PrivateKey, PrivateKey) {
+    let (account, owner_proof, owner_key) = test.create_empty_account();
+    let withdraw_proof = generate_withdraw_proof_with_view_key(
+        faucet_mask,
+        faucet_supply,
+        amount,
+        Some(faucet_supply - amount),
+        Amount(0),
+        view_key,
+    );
+    test.execute_expect_success(
+        Transaction::builder()
+            .call_method(faucet, "take_free_coins", args![withdraw_proof.proof])
+            .put_last_instruction_output_on_workspace("coins")
+            .call_method(account, "deposit", args![Workspace("coins")])
+            .build_and_seal(&owner_key),
+        vec![owner_proof.clone()],
+    );
+    (account, owner_proof, owner_key, withdraw_proof.output_mask)
+}
+
+fn brute_force_account_balance(
+    diff: &SubstateDiff,
+    account: ComponentAddress,
+    view_key_secret: &PrivateKey,
+) -> Option<u64> {
+    let entity_id = account.entity_id();
+    let vault = diff
+        .up_iter()
+        .find(|(addr, _)| addr.is_vault() && addr.as_vault_id().unwrap().entity_id() == entity_id)
+        .map(|(_, vault)| vault.substate_value().as_vault().unwrap())
+        .unwrap();
+    vault
+        .try_brute_force_confidential_balance(view_key_secret, 0..=1000, &mut AlwaysMissLookupTable)
+        .unwrap()
+}
+
+#[test]
+fn withdraw_confidential_many_pays_multiple_recipients() {
+    let (view_key_secret, ref view_key) = PublicKey::random_keypair(&mut OsRng);
+    let (confidential_proof, faucet_mask, _change) =
+        generate_confidential_proof_with_view_key(Amount(100_000), None, view_key);
+    let (mut test, faucet, faucet_resx) = setup(confidential_proof, Some(view_key));
+
+    let (sender, sender_proof, sender_key, sender_mask) =
+        fund_account_confidentially(&mut test, faucet, &faucet_mask, Amount(100_000), Amount(1000), view_key);
+    let (account1, _, _) = test.create_empty_account();
+    let (account2, _, _) = test.create_empty_account();
+    let (account3, _, _) = test.create_empty_account();
+
+    let withdraw = generate_withdraw_proof_with_additional_outputs(
+        &[(sender_mask, Amount(1000))],
+        Amount::zero(),
+        &[
+            (Amount(300), Amount::zero()),
+            (Amount(200), Amount::zero()),
+            (Amount(100), Amount::zero()),
+        ],
+        Some(Amount(400)),
+        Some(view_key),
+    );
+
+    let result = test.execute_expect_success(
+        Transaction::builder()
+            .call_method(sender, "withdraw_confidential_many", args![faucet_resx, withdraw.proof])
+            .put_last_instruction_output_on_workspace("buckets")
+            .call_method(account1, "deposit", args![Workspace("buckets.0")])
+            .call_method(account2, "deposit", args![Workspace("buckets.1")])
+            .call_method(account3, "deposit", args![Workspace("buckets.2")])
+            .build_and_seal(&sender_key),
+        vec![sender_proof],
+    );
+
+    let diff = result.finalize.result.accept().unwrap();
+    assert_eq!(brute_force_account_balance(diff, sender, &view_key_secret), Some(400));
+    assert_eq!(brute_force_account_balance(diff, account1, &view_key_secret), Some(300));
+    assert_eq!(brute_force_account_balance(diff, account2, &view_key_secret), Some(200));
+    assert_eq!(brute_force_account_balance(diff, account3, &view_key_secret), Some(100));
+}
+
+#[test]
+fn withdraw_confidential_many_pays_confidential_and_revealed_outputs() {
+    let (view_key_secret, ref view_key) = PublicKey::random_keypair(&mut OsRng);
+    let (confidential_proof, faucet_mask, _change) =
+        generate_confidential_proof_with_view_key(Amount(100_000), None, view_key);
+    let (mut test, faucet, faucet_resx) = setup(confidential_proof, Some(view_key));
+
+    let (sender, sender_proof, sender_key, sender_mask) =
+        fund_account_confidentially(&mut test, faucet, &faucet_mask, Amount(100_000), Amount(1000), view_key);
+    let (account1, _, _) = test.create_empty_account();
+    let (account2, _, _) = test.create_empty_account();
+    let (account3, _, _) = test.create_empty_account();
+
+    // One confidential, one revealed and one split between confidential and revealed output
+    let withdraw = generate_withdraw_proof_with_additional_outputs(
+        &[(sender_mask, Amount(1000))],
+        Amount::zero(),
+        &[
+            (Amount(300), Amount::zero()),
+            (Amount::zero(), Amount(200)),
+            (Amount(50), Amount(50)),
+        ],
+        Some(Amount(400)),
+        Some(view_key),
+    );
+
+    let result = test.execute_expect_success(
+        Transaction::builder()
+            .call_method(sender, "withdraw_confidential_many", args![faucet_resx, withdraw.proof])
+            .put_last_instruction_output_on_workspace("buckets")
+            .call_method(account1, "deposit", args![Workspace("buckets.0")])
+            .call_method(account2, "deposit", args![Workspace("buckets.1")])
+            .call_method(account3, "deposit", args![Workspace("buckets.2")])
+            .call_method(account1, "balance", args![faucet_resx])
+            .call_method(account2, "balance", args![faucet_resx])
+            .call_method(account3, "balance", args![faucet_resx])
+            .build_and_seal(&sender_key),
+        vec![sender_proof],
+    );
+
+    let execution_results = &result.finalize.execution_results;
+    assert_eq!(execution_results[5].decode::<Amount>().unwrap(), Amount::zero());
+    assert_eq!(execution_results[6].decode::<Amount>().unwrap(), Amount(200));
+    assert_eq!(execution_results[7].decode::<Amount>().unwrap(), Amount(50));
+
+    let diff = result.finalize.result.accept().unwrap();
+    assert_eq!(brute_force_account_balance(diff, sender, &view_key_secret), Some(400));
+    assert_eq!(brute_force_account_balance(diff, account1, &view_key_secret), Some(300));
+    assert_eq!(brute_force_account_balance(diff, account3, &view_key_secret), Some(50));
+}
+
+#[test]
+fn withdraw_confidential_many_fails_with_insufficient_funds() {
+    let (_view_key_secret, ref view_key) = PublicKey::random_keypair(&mut OsRng);
+    let (confidential_proof, faucet_mask, _change) =
+        generate_confidential_proof_with_view_key(Amount(100_000), None, view_key);
+    let (mut test, faucet, faucet_resx) = setup(confidential_proof, Some(view_key));
+
+    let (sender, sender_proof, sender_key, sender_mask) =
+        fund_account_confidentially(&mut test, faucet, &faucet_mask, Amount(100_000), Amount(1000), view_key);
+    let (account1, _, _) = test.create_empty_account();
+    let (account2, _, _) = test.create_empty_account();
+
+    // The second recipient is paid more than the input can cover
+    let withdraw = generate_withdraw_proof_with_additional_outputs(
+        &[(sender_mask, Amount(1000))],
+        Amount::zero(),
+        &[(Amount(300), Amount::zero()), (Amount(800), Amount::zero())],
+        None,
+        Some(view_key),
+    );
+
+    let reason = test.execute_expect_failure(
+        Transaction::builder()
+            .call_method(sender, "withdraw_confidential_many", args![faucet_resx, withdraw.proof])
+            .put_last_instruction_output_on_workspace("buckets")
+            .call_method(account1, "deposit", args![Workspace("buckets.0")])
+            .call_method(account2, "deposit", args![Workspace("buckets.1")])
+            .build_and_seal(&sender_key),
+        vec![sender_proof],
+    );
+
+    assert_reject_reason(reason, ResourceError::InvalidBalanceProof {
+        details: "Balance proof was invalid".to_string(),
+    });
+}
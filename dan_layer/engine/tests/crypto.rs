@@ -0,0 +1,184 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use ed25519_dalek::{Signer, SigningKey};
+use rand::rngs::OsRng;
+use tari_common_types::types::{PublicKey, Signature};
+use tari_crypto::{keys::PublicKey as _, tari_utilities::ByteArray};
+use tari_engine_types::fees::FeeSource;
+use tari_template_lib::{
+    args,
+    crypto::{Ed25519PublicKeyBytes, Ed25519SignatureBytes, RistrettoPublicKeyBytes, SchnorrSignatureBytes},
+    models::Amount,
+    Hash,
+};
+use tari_template_test_tooling::{support::assert_error::assert_reject_reason, test_faucet_component, TemplateTest};
+use tari_transaction::Transaction;
+
+fn setup() -> TemplateTest {
+    TemplateTest::new(vec!["tests/templates/crypto"])
+}
+
+fn schnorr_sign(message: &[u8]) -> (RistrettoPublicKeyBytes, RistrettoPublicKeyBytes, SchnorrSignatureBytes) {
+    let (secret_key, public_key) = PublicKey::random_keypair(&mut OsRng);
+    let signature = Signature::sign(&secret_key, message, &mut OsRng).unwrap();
+    (
+        RistrettoPublicKeyBytes::from_bytes(public_key.as_bytes()).unwrap(),
+        RistrettoPublicKeyBytes::from_bytes(signature.get_public_nonce().as_bytes()).unwrap(),
+        SchnorrSignatureBytes::from_bytes(signature.get_signature().as_bytes()).unwrap(),
+    )
+}
+
+#[test]
+fn it_hashes_natively() {
+    let mut test = setup();
+    let data = b"abc".to_vec();
+
+    let hash: Hash = test.call_function("CryptoTest", "blake2b_256", args![data], vec![]);
+    assert_eq!(
+        hash,
+        Hash::from_hex("bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319").unwrap()
+    );
+
+    let hash: Hash = test.call_function("CryptoTest", "sha256", args![data], vec![]);
+    assert_eq!(
+        hash,
+        Hash::from_hex("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad").unwrap()
+    );
+
+    let hash: Hash = test.call_function("CryptoTest", "keccak256", args![data], vec![]);
+    assert_eq!(
+        hash,
+        Hash::from_hex("4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45").unwrap()
+    );
+}
+
+#[test]
+fn it_verifies_schnorr_signatures() {
+    let mut test = setup();
+    let message = b"hello".to_vec();
+    let (public_key, public_nonce, signature) = schnorr_sign(&message);
+
+    let is_valid: bool = test.call_function(
+        "CryptoTest",
+        "verify_schnorr",
+        args![public_key, public_nonce, signature, message],
+        vec![],
+    );
+    assert!(is_valid);
+
+    let is_valid: bool = test.call_function(
+        "CryptoTest",
+        "verify_schnorr",
+        args![public_key, public_nonce, signature, b"goodbye".to_vec()],
+        vec![],
+    );
+    assert!(!is_valid);
+
+    // A non-canonical public key is rejected rather than failing the transaction
+    let invalid_key = RistrettoPublicKeyBytes::from_bytes(&[0xff; 32]).unwrap();
+    let is_valid: bool = test.call_function(
+        "CryptoTest",
+        "verify_schnorr",
+        args![invalid_key, public_nonce, signature, message],
+        vec![],
+    );
+    assert!(!is_valid);
+}
+
+#[test]
+fn it_verifies_ed25519_signatures() {
+    let mut test = setup();
+    let message = b"hello".to_vec();
+    let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+    let public_key = Ed25519PublicKeyBytes::from(signing_key.verifying_key().to_bytes());
+    let signature = Ed25519SignatureBytes::from(signing_key.sign(&message).to_bytes());
+
+    let is_valid: bool = test.call_function(
+        "CryptoTest",
+        "verify_ed25519",
+        args![public_key, signature, message],
+        vec![],
+    );
+    assert!(is_valid);
+
+    let is_valid: bool = test.call_function(
+        "CryptoTest",
+        "verify_ed25519",
+        args![public_key, signature, b"goodbye".to_vec()],
+        vec![],
+    );
+    assert!(!is_valid);
+}
+
+#[test]
+fn it_rejects_unsigned_oracle_prices() {
+    let mut test = setup();
+    let template_address = test.get_template_address("CryptoTest");
+    let price = 123u64;
+    let (oracle, public_nonce, signature) = schnorr_sign(&price.to_le_bytes());
+
+    let result = test.execute_expect_success(
+        Transaction::builder()
+            .call_function(template_address, "submit_oracle_price", args![
+                oracle,
+                public_nonce,
+                signature,
+                price
+            ])
+            .build_and_seal(test.get_test_secret_key()),
+        vec![],
+    );
+    let accepted: u64 = result.finalize.execution_results[0].decode().unwrap();
+    assert_eq!(accepted, price);
+
+    let reason = test.execute_expect_failure(
+        Transaction::builder()
+            .call_function(template_address, "submit_oracle_price", args![
+                oracle,
+                public_nonce,
+                signature,
+                price + 1
+            ])
+            .build_and_seal(test.get_test_secret_key()),
+        vec![],
+    );
+    assert_reject_reason(reason, "Invalid oracle signature");
+}
+
+#[test]
+fn it_charges_crypto_fees() {
+    let mut test = setup();
+    let template_address = test.get_template_address("CryptoTest");
+    let data = vec![1u8; 1000];
+    let (public_key, public_nonce, signature) = schnorr_sign(&data);
+
+    test.enable_fees();
+    let result = test.execute_expect_success(
+        Transaction::builder()
+            .fee_transaction_pay_from_component(test_faucet_component(), Amount(1000))
+            .call_function(template_address, "sha256", args![data])
+            .call_function(template_address, "verify_schnorr", args![
+                public_key,
+                public_nonce,
+                signature,
+                data
+            ])
+            .build_and_seal(test.get_test_secret_key()),
+        vec![],
+    );
+
+    let crypto_fees = result
+        .finalize
+        .fee_receipt
+        .cost_breakdown
+        .iter()
+        .find(|(source, _)| **source == FeeSource::Crypto)
+        .map(|(_, amount)| *amount)
+        .unwrap();
+    let fee_table = test.fee_table();
+    assert_eq!(
+        crypto_fees,
+        fee_table.hash_cost(data.len()) + fee_table.signature_verification_cost(data.len())
+    );
+}
@@ -1,8 +1,10 @@
 //   Copyright 2023 The Tari Project
 //   SPDX-License-Identifier: BSD-3-Clause
 
+use serde::Deserialize;
 use tari_dan_engine::runtime::RuntimeError;
 use tari_engine_types::instruction::Instruction;
+use tari_template_abi::Type;
 use tari_template_builtin::ACCOUNT_TEMPLATE_ADDRESS;
 use tari_template_lib::{
     args,
@@ -11,6 +13,12 @@ use tari_template_lib::{
 use tari_template_test_tooling::{support::assert_error::assert_reject_reason, TemplateTest};
 use tari_transaction::Transaction;
 
+#[derive(Debug, PartialEq, Deserialize)]
+struct TransferEvent {
+    amount: Amount,
+    to: String,
+}
+
 #[test]
 fn basic_emit_event() {
     let mut template_test = TemplateTest::new(vec!["tests/templates/events"]);
@@ -37,6 +45,38 @@ fn basic_emit_event() {
     );
 }
 
+#[test]
+fn emit_typed_event() {
+    let mut template_test = TemplateTest::new(vec!["tests/templates/events"]);
+    let event_emitter_template = template_test.get_template_address("EventEmitter");
+    let result = template_test
+        .execute_and_commit(
+            vec![Instruction::CallFunction {
+                template_address: event_emitter_template,
+                function: "emit_transfer".to_string(),
+                args: args![Amount(123), "alice"],
+            }],
+            vec![],
+        )
+        .expect("Failed to emit typed event");
+    assert!(result.finalize.is_accept());
+    let event = &result.finalize.events[0];
+    assert_eq!(event.topic(), "transfer");
+
+    let payload: TransferEvent = event.decode_payload().unwrap();
+    assert_eq!(payload, TransferEvent {
+        amount: Amount(123),
+        to: "alice".to_string(),
+    });
+
+    // The payload type of the topic is described in the template ABI
+    let module = template_test.get_module("EventEmitter");
+    let event_def = module.template_def().get_event("transfer").unwrap();
+    assert_eq!(event_def.payload_type, Type::Other {
+        name: "TransferEvent".to_string()
+    });
+}
+
 #[test]
 fn cannot_use_standard_topic() {
     let mut template_test = TemplateTest::new(vec!["tests/templates/events"]);
@@ -140,11 +180,11 @@ fn builtin_vault_events() {
     assert_eq!(event.template_address(), ACCOUNT_TEMPLATE_ADDRESS);
     // assert_eq!(event.component_address().unwrap(), sender_address);
     assert_eq!(
-        *event.payload().get("resource_address").unwrap(),
+        event.get_payload("resource_address").unwrap(),
         faucet_resource.to_string()
     );
-    assert_eq!(*event.payload().get("resource_type").unwrap(), "Fungible");
-    assert_eq!(*event.payload().get("amount").unwrap(), amount.to_string());
+    assert_eq!(event.get_payload("resource_type").unwrap(), "Fungible");
+    assert_eq!(event.get_payload("amount").unwrap(), amount.to_string());
 
     // a standard event for the deposit must have been emmitted
     let event = result
@@ -156,9 +196,9 @@ fn builtin_vault_events() {
     assert_eq!(event.template_address(), ACCOUNT_TEMPLATE_ADDRESS);
     // assert_eq!(event.component_address().unwrap(), receiver_address);
     assert_eq!(
-        *event.payload().get("resource_address").unwrap(),
+        event.get_payload("resource_address").unwrap(),
         faucet_resource.to_string()
     );
-    assert_eq!(*event.payload().get("resource_type").unwrap(), "Fungible");
-    assert_eq!(*event.payload().get("amount").unwrap(), amount.to_string());
+    assert_eq!(event.get_payload("resource_type").unwrap(), "Fungible");
+    assert_eq!(event.get_payload("amount").unwrap(), amount.to_string());
 }
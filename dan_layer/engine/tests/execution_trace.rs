@@ -0,0 +1,101 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use tari_engine_types::execution_trace::TraceEvent;
+use tari_template_lib::{
+    args,
+    models::{Amount, ComponentAddress},
+};
+use tari_template_test_tooling::TemplateTest;
+use tari_transaction::Transaction;
+
+#[test]
+fn it_records_an_execution_trace() {
+    let mut test = TemplateTest::new(Vec::<&str>::new());
+    let faucet_template = test.get_template_address("TestFaucet");
+    let (account, _, _) = test.create_empty_account();
+
+    let result = test.execute_expect_success(
+        Transaction::builder()
+            .call_function(faucet_template, "mint", args![Amount(1000)])
+            .build_and_seal(test.get_test_secret_key()),
+        vec![],
+    );
+    assert!(result.trace.is_none());
+    let faucet = result.finalize.execution_results[0]
+        .decode::<ComponentAddress>()
+        .unwrap();
+
+    test.enable_tracing();
+    let result = test.execute_expect_success(
+        Transaction::builder()
+            .call_method(faucet, "take_free_coins", args![])
+            .put_last_instruction_output_on_workspace("bucket")
+            .call_method(account, "deposit", args![Workspace("bucket")])
+            .build_and_seal(test.get_test_secret_key()),
+        vec![],
+    );
+
+    let trace = result.trace.as_ref().unwrap();
+    let events = trace.iter().map(|entry| &entry.event).collect::<Vec<_>>();
+
+    let pushed = events
+        .iter()
+        .filter(|e| matches!(e, TraceEvent::CallFramePushed { .. }))
+        .count();
+    let popped = events
+        .iter()
+        .filter(|e| matches!(e, TraceEvent::CallFramePopped))
+        .count();
+    assert!(pushed >= 2);
+    assert_eq!(pushed, popped);
+
+    assert!(events.iter().any(|e| matches!(
+        e,
+        TraceEvent::CallFramePushed { component_address: Some(addr), module_name, .. }
+            if *addr == faucet && module_name == "TestFaucet"
+    )));
+    assert!(events.iter().any(|e| matches!(e, TraceEvent::BucketCreated { .. })));
+    assert!(events.iter().any(|e| matches!(e, TraceEvent::BucketTaken { .. })));
+    assert!(events.iter().any(|e| matches!(
+        e,
+        TraceEvent::SubstateLocked { substate_id, .. } if *substate_id == account.into()
+    )));
+    assert!(events.iter().any(|e| matches!(
+        e,
+        TraceEvent::Action { action } if action.contains("'deposit'")
+    )));
+
+    // Every frame push is recorded at the depth of the new frame
+    assert!(trace
+        .iter()
+        .filter(|entry| matches!(entry.event, TraceEvent::CallFramePushed { .. }))
+        .all(|entry| entry.depth >= 1));
+}
+
+#[test]
+fn it_keeps_the_trace_of_failed_instructions() {
+    let mut test = TemplateTest::new(["tests/templates/reentrancy"]);
+    let reentrancy: ComponentAddress = test.call_function("Reentrancy", "new", args![], vec![]);
+
+    test.enable_tracing();
+    let result = test
+        .try_execute(
+            Transaction::builder()
+                .call_method(reentrancy, "reentrant_access", args![])
+                .build_and_seal(test.get_test_secret_key()),
+            vec![],
+        )
+        .unwrap();
+    result.expect_failure();
+
+    let trace = result.trace.unwrap();
+    assert!(trace.iter().any(|entry| matches!(
+        &entry.event,
+        TraceEvent::CallFramePushed { component_address: Some(addr), .. } if *addr == reentrancy
+    )));
+    assert!(trace.iter().any(|entry| matches!(
+        &entry.event,
+        TraceEvent::RuntimeCall { function } if function == "call_invoke"
+    )));
+}
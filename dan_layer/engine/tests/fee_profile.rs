@@ -0,0 +1,95 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use tari_template_lib::{args, models::ComponentAddress};
+use tari_template_test_tooling::{
+    fee_profile::{FeeProfileReport, FeeProfiler, FEE_PAYMENT_OVERHEAD},
+    TemplateTest,
+};
+use tari_transaction::Transaction;
+
+fn profile_state_template() -> FeeProfileReport {
+    let mut test = TemplateTest::new(vec!["tests/templates/state"]);
+    let mut profiler = FeeProfiler::new(&mut test);
+
+    let result = profiler.profile_function("State", "new", args![], &[]);
+    let component: ComponentAddress = result.finalize.execution_results[0].decode().unwrap();
+    profiler.profile_method(component, "set", args![123u32], &[]);
+    profiler.profile_method(component, "get", args![], &[]);
+
+    profiler.into_report()
+}
+
+#[test]
+fn it_profiles_template_functions() {
+    let report = profile_state_template();
+    let names = report.iter().map(|(name, _)| name).collect::<Vec<_>>();
+    assert_eq!(names, vec![
+        FEE_PAYMENT_OVERHEAD,
+        "State::get",
+        "State::new",
+        "State::set"
+    ]);
+
+    let overhead = *report.get(FEE_PAYMENT_OVERHEAD).unwrap();
+    let new = *report.get("State::new").unwrap();
+    assert!(new.wasm_points > overhead.wasm_points);
+    assert!(new.runtime_calls > overhead.runtime_calls);
+    assert!(new.storage_bytes > overhead.storage_bytes);
+    assert!(new.total_fees > overhead.total_fees);
+    assert!(report.get("State::set").unwrap().wasm_points > 0);
+    assert!(report.get("State::get").unwrap().total_fees > 0);
+
+    let table = report.to_string();
+    assert!(table.starts_with("Function"), "{}", table);
+    assert!(
+        table.lines().any(|line| line.starts_with("State::new") &&
+            line.split_whitespace().last() == Some(new.total_fees.to_string().as_str())),
+        "{}",
+        table
+    );
+
+    // Profiling the same suite again produces the same report
+    assert_eq!(profile_state_template(), report);
+}
+
+#[test]
+fn it_compares_against_a_baseline() {
+    let report = profile_state_template();
+    assert!(report.compare(&report, 0).is_empty());
+
+    let path = std::env::temp_dir().join(format!("fee_profile_baseline_{}.json", std::process::id()));
+    report.save(&path).unwrap();
+    report.assert_within_baseline(&path, 0);
+    assert_eq!(FeeProfileReport::load(&path).unwrap(), report);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "does not exist")]
+fn it_fails_if_the_baseline_is_missing() {
+    let report = profile_state_template();
+    let path = std::env::temp_dir().join(format!("fee_profile_missing_baseline_{}.json", std::process::id()));
+    report.assert_within_baseline(&path, 0);
+}
+
+#[test]
+fn it_detects_regressions() {
+    let baseline = profile_state_template();
+
+    // Simulate a change that makes State::new create more components
+    let mut test = TemplateTest::new(vec!["tests/templates/state"]);
+    let template_address = test.get_template_address("State");
+    let mut profiler = FeeProfiler::new(&mut test);
+    profiler.profile(
+        "State::new",
+        Transaction::builder().call_function(template_address, "create_multiple", args![10u32]),
+        &[],
+    );
+
+    let regressions = profiler.report().compare(&baseline, 10);
+    assert!(regressions
+        .iter()
+        .any(|regression| regression.name == "State::new" && regression.metric == "storage_bytes"));
+    assert!(regressions.iter().all(|regression| regression.name == "State::new"));
+}
@@ -0,0 +1,105 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use tari_template_lib::{args, constants::XTR, models::ComponentAddress};
+use tari_template_test_tooling::{
+    fuzz::{FuzzConfig, Fuzzer, Invariant},
+    TemplateTest,
+};
+
+fn setup() -> (TemplateTest, ComponentAddress) {
+    let mut test = TemplateTest::new(vec!["tests/templates/fuzzing"]);
+    let ledger: ComponentAddress = test.call_function("Ledger", "new", args![], vec![]);
+    (test, ledger)
+}
+
+fn ledger_adds_up(ledger: ComponentAddress) -> Invariant {
+    Invariant::new("ledger adds up", move |test, _| {
+        let balance: u64 = test.extract_component_value(ledger, "$.balance");
+        let deposited: u64 = test.extract_component_value(ledger, "$.deposited");
+        let withdrawn: u64 = test.extract_component_value(ledger, "$.withdrawn");
+        if balance + withdrawn == deposited {
+            Ok(())
+        } else {
+            Err(format!(
+                "balance {} + withdrawn {} != deposited {}",
+                balance, withdrawn, deposited
+            ))
+        }
+    })
+}
+
+#[test]
+fn it_finds_and_shrinks_an_invariant_violation() {
+    let (mut test, ledger) = setup();
+
+    let failure = Fuzzer::new(&mut test)
+        .with_seed(1)
+        .add_target(ledger)
+        .with_funded_accounts(2)
+        .add_invariant(ledger_adds_up(ledger))
+        .run()
+        .unwrap_err();
+
+    assert_eq!(failure.invariant, "ledger adds up");
+    // Any overdraw of the empty ledger breaks the invariant, so the sequence shrinks to a single withdrawal of 1
+    assert_eq!(failure.calls.len(), 1, "{}", failure);
+    assert_eq!(failure.calls[0].method, "withdraw");
+    assert!(failure.to_string().contains(".withdraw(1)"), "{}", failure);
+
+    // The state is restored after fuzzing
+    let withdrawn: u64 = test.extract_component_value(ledger, "$.withdrawn");
+    assert_eq!(withdrawn, 0);
+}
+
+#[test]
+fn it_passes_when_invariants_hold() {
+    let (mut test, ledger) = setup();
+
+    Fuzzer::new(&mut test)
+        .with_seed(2)
+        .add_target(ledger)
+        .with_funded_accounts(2)
+        .exclude_method("withdraw")
+        .add_invariant(ledger_adds_up(ledger))
+        .add_invariant(Invariant::no_negative_balances())
+        .add_invariant(Invariant::total_supply_conserved(XTR))
+        .run_and_assert();
+}
+
+#[test]
+fn it_generates_boundary_values_across_the_full_range() {
+    let (mut test, ledger) = setup();
+
+    let failure = Fuzzer::new(&mut test)
+        .with_config(FuzzConfig {
+            seed: 3,
+            runs: 5,
+            calls_per_run: 50,
+        })
+        .add_target(ledger)
+        .exclude_method("deposit")
+        .exclude_method("checked_withdraw")
+        .exclude_method("withdraw")
+        .exclude_method("set_memo")
+        .add_invariant(Invariant::new(
+            "boundary values are not all generated",
+            move |test, _| {
+                let all_seen = ["u128_max_seen", "i128_min_seen", "negative_one_seen", "amount_min_seen"]
+                    .iter()
+                    .all(|field| test.extract_component_value::<bool>(ledger, &format!("$.{}", field)));
+                if all_seen {
+                    Err("all boundary values were generated".to_string())
+                } else {
+                    Ok(())
+                }
+            },
+        ))
+        .run()
+        .unwrap_err();
+
+    let calls = failure.to_string();
+    assert!(calls.contains(&u128::MAX.to_string()), "{}", failure);
+    assert!(calls.contains(&i128::MIN.to_string()), "{}", failure);
+    assert!(calls.contains(&i64::MIN.to_string()), "{}", failure);
+}
@@ -0,0 +1,118 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use tari_dan_engine::runtime::ActionIdent;
+use tari_template_lib::{args, constants::XTR, models::Amount};
+use tari_template_test_tooling::{
+    support::{
+        assert_error::assert_access_denied_for_action,
+        matchers::{assert_balance_change, assert_event_count, assert_event_emitted, assert_event_payload},
+    },
+    TemplateTest,
+    TestSigner,
+};
+use tari_transaction::Transaction;
+
+#[test]
+fn it_advances_the_epoch() {
+    let mut test = TemplateTest::new(vec!["tests/templates/consensus"]);
+    assert_eq!(test.current_epoch(), 0);
+
+    test.advance_epoch(10);
+    let epoch: u64 = test.call_function("TestConsensus", "current_epoch", args![], vec![]);
+    assert_eq!(epoch, 10);
+
+    test.set_epoch(3).advance_epoch(2);
+    let epoch: u64 = test.call_function("TestConsensus", "current_epoch", args![], vec![]);
+    assert_eq!(epoch, 5);
+}
+
+#[test]
+fn it_treats_a_component_created_after_the_snapshot_as_having_a_zero_balance() {
+    let mut test = TemplateTest::new(Vec::<&str>::new());
+    let snapshot = test.snapshot();
+
+    let (account, _, _) = test.create_funded_account();
+    let balance = test.get_component_balance(account, XTR);
+    assert!(balance.is_positive());
+    assert_eq!(snapshot.get_component_balance(account, XTR), Amount::zero());
+    assert_balance_change(&test, &snapshot, account, XTR, balance);
+}
+
+#[test]
+fn it_restores_a_snapshot() {
+    let mut test = TemplateTest::new(Vec::<&str>::new());
+    let (sender, _, sender_key) = test.create_funded_account();
+    let (receiver, _, _) = test.create_funded_account();
+    let sender_signer = TestSigner::from_secret_key(sender_key);
+
+    let snapshot = test.snapshot();
+    test.advance_epoch(1);
+    test.execute_expect_success_signed_by(
+        Transaction::builder()
+            .call_method(sender, "withdraw", args![XTR, Amount(100)])
+            .put_last_instruction_output_on_workspace("bucket")
+            .call_method(receiver, "deposit", args![Workspace("bucket")]),
+        &[&sender_signer],
+    );
+    assert_balance_change(&test, &snapshot, sender, XTR, -100);
+    assert_balance_change(&test, &snapshot, receiver, XTR, 100);
+
+    test.restore(&snapshot);
+    assert_eq!(test.current_epoch(), 0);
+    assert_balance_change(&test, &snapshot, sender, XTR, 0);
+    assert_balance_change(&test, &snapshot, receiver, XTR, 0);
+}
+
+#[test]
+fn it_executes_transactions_signed_by_multiple_signers() {
+    let mut test = TemplateTest::new(Vec::<&str>::new());
+    let (alice_account, _, alice_key) = test.create_funded_account();
+    let (bob_account, _, bob_key) = test.create_funded_account();
+    let (receiver, _, _) = test.create_funded_account();
+    let alice = TestSigner::from_secret_key(alice_key);
+    let bob = TestSigner::from_secret_key(bob_key);
+
+    let transfer_from_both = || {
+        Transaction::builder()
+            .call_method(alice_account, "withdraw", args![XTR, Amount(10)])
+            .put_last_instruction_output_on_workspace("alice_bucket")
+            .call_method(bob_account, "withdraw", args![XTR, Amount(20)])
+            .put_last_instruction_output_on_workspace("bob_bucket")
+            .call_method(receiver, "deposit", args![Workspace("alice_bucket")])
+            .call_method(receiver, "deposit", args![Workspace("bob_bucket")])
+    };
+
+    // Bob has not signed so the withdrawal from his account is denied
+    let reason = test.execute_expect_failure_signed_by(transfer_from_both(), &[&alice]);
+    assert_access_denied_for_action(reason, ActionIdent::ComponentCallMethod {
+        component_address: bob_account,
+        method: "withdraw".to_string(),
+    });
+
+    let snapshot = test.snapshot();
+    let result = test.execute_expect_success_signed_by(transfer_from_both(), &[&alice, &bob]);
+
+    assert_event_count(&result, "withdraw", 2);
+    assert_event_payload(&result, "withdraw", "amount", Amount(20));
+    let deposit = assert_event_emitted(&result, "deposit");
+    assert_eq!(deposit.get_payload("resource").unwrap(), XTR.to_string());
+    assert_balance_change(&test, &snapshot, alice_account, XTR, -10);
+    assert_balance_change(&test, &snapshot, bob_account, XTR, -20);
+    assert_balance_change(&test, &snapshot, receiver, XTR, 30);
+}
+
+#[test]
+fn it_creates_signers_with_unique_keys() {
+    let mut test = TemplateTest::new(Vec::<&str>::new());
+    let alice = test.create_signer();
+    let bob = test.create_signer();
+    assert_ne!(alice.public_key(), bob.public_key());
+    assert_ne!(alice.owner_proof(), bob.owner_proof());
+
+    let (account, _, _) = test.create_funded_account();
+    assert_eq!(
+        test.get_component_balance(account, XTR),
+        test.call_method::<Amount>(account, "balance", args![XTR], vec![])
+    );
+}
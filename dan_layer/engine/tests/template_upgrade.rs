@@ -0,0 +1,127 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use tari_bor::{from_value, to_value};
+use tari_dan_engine::runtime::RuntimeError;
+use tari_engine_types::substate::{Substate, SubstateId};
+use tari_template_lib::{args, args::ComponentAction, auth::AccessRule, models::ComponentAddress};
+use tari_template_test_tooling::{
+    support::assert_error::{assert_access_denied_for_action, assert_reject_reason},
+    TemplateTest,
+};
+use tari_transaction::Transaction;
+
+fn setup() -> (TemplateTest, ComponentAddress) {
+    let mut test = TemplateTest::new(["tests/templates/upgrade_v1", "tests/templates/upgrade_v2"]);
+    let template_address = test.get_template_address("Counter");
+    let component_address: ComponentAddress = test.call_function(template_address, "new", args![], vec![]);
+    (test, component_address)
+}
+
+#[test]
+fn it_upgrades_and_migrates_component_state() {
+    let (mut test, component_address) = setup();
+    let v2_template = test.get_template_address("CounterV2");
+
+    test.call_method::<()>(component_address, "increase", args![], vec![]);
+    let owner_proof = test.get_test_proof();
+    test.call_method::<()>(component_address, "upgrade", args![v2_template], vec![owner_proof]);
+
+    // The value is kept and the migrated step is used by the new template
+    test.call_method::<()>(component_address, "increase", args![], vec![]);
+    let value: u32 = test.call_method(component_address, "value", args![], vec![]);
+    assert_eq!(value, 11);
+
+    // Methods that only exist in the new template can be called
+    test.call_method::<()>(component_address, "set_step", args![2], vec![]);
+    test.call_method::<()>(component_address, "increase", args![], vec![]);
+    let value: u32 = test.call_method(component_address, "value", args![], vec![]);
+    assert_eq!(value, 13);
+}
+
+#[test]
+fn it_denies_upgrade_by_non_owner() {
+    let (mut test, component_address) = setup();
+    let v2_template = test.get_template_address("CounterV2");
+    let (user_proof, _, user_key) = test.create_owner_proof();
+
+    let reason = test.execute_expect_failure(
+        Transaction::builder()
+            .call_method(component_address, "upgrade", args![v2_template])
+            .build_and_seal(&user_key),
+        vec![user_proof],
+    );
+
+    assert_access_denied_for_action(reason, ComponentAction::UpgradeTemplate);
+}
+
+#[test]
+fn it_denies_upgrade_of_a_component_created_before_upgrade_rules_existed() {
+    let (mut test, component_address) = setup();
+    let v2_template = test.get_template_address("CounterV2");
+
+    // Rewrite the component with access rules serialised without the upgrade_template rule
+    let id = SubstateId::Component(component_address);
+    let substate = test.read_only_state_store().get_substate(&id).unwrap();
+    let mut component = substate.substate_value().component().unwrap().clone();
+    let mut access_rules = to_value(component.access_rules()).unwrap();
+    access_rules
+        .as_map_mut()
+        .unwrap()
+        .retain(|(key, _)| key.as_text() != Some("upgrade_template"));
+    component.set_access_rules(from_value(&access_rules).unwrap());
+    assert_eq!(
+        *component.access_rules().get_upgrade_template_rule(),
+        AccessRule::DenyAll
+    );
+    test.set_substate(id, Substate::new(substate.version(), component));
+
+    let reason = test.execute_expect_failure(
+        Transaction::builder()
+            .call_method(component_address, "upgrade", args![v2_template])
+            .build_and_seal(test.get_test_secret_key()),
+        vec![test.get_test_proof()],
+    );
+
+    assert_access_denied_for_action(reason, ComponentAction::UpgradeTemplate);
+}
+
+#[test]
+fn it_rejects_upgrade_to_incompatible_template() {
+    let (mut test, component_address) = setup();
+    let v1_template = test.get_template_address("Counter");
+    let v2_template = test.get_template_address("CounterV2");
+    let owner_proof = test.get_test_proof();
+    test.call_method::<()>(component_address, "upgrade", args![v2_template], vec![owner_proof]);
+
+    // The v1 template is missing the set_step method
+    let reason = test.execute_expect_failure(
+        Transaction::builder()
+            .call_method(component_address, "upgrade", args![v1_template])
+            .build_and_seal(test.get_test_secret_key()),
+        vec![test.get_test_proof()],
+    );
+
+    assert_reject_reason(reason, RuntimeError::IncompatibleTemplateUpgrade {
+        from: v2_template,
+        to: v1_template,
+        details: "Method 'set_step' not found in new template".to_string(),
+    });
+}
+
+#[test]
+fn it_prevents_setting_the_old_state_after_upgrade() {
+    let (mut test, component_address) = setup();
+    let v2_template = test.get_template_address("CounterV2");
+
+    let reason = test.execute_expect_failure(
+        Transaction::builder()
+            .call_method(component_address, "upgrade_and_increase", args![v2_template])
+            .build_and_seal(test.get_test_secret_key()),
+        vec![test.get_test_proof()],
+    );
+
+    assert_reject_reason(reason, RuntimeError::ComponentStateSetAfterUpgrade {
+        address: component_address,
+    });
+}
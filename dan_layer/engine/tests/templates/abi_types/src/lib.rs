@@ -0,0 +1,70 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use std::collections::BTreeMap;
+
+use tari_template_lib::prelude::*;
+
+pub mod external {
+    use tari_template_lib::template_dependencies::serde;
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    #[serde(crate = "self::serde")]
+    pub struct Memo(pub String);
+}
+
+#[template]
+mod abi_types_template {
+    use super::{external::Memo, *};
+
+    pub struct OrderBook {
+        orders: BTreeMap<u64, Order>,
+        memo: Option<Memo>,
+    }
+
+    #[derive(Clone)]
+    pub struct Order {
+        price: Amount,
+        side: Side,
+        note: Option<String>,
+        #[serde(rename = "qty")]
+        quantity: u64,
+        #[serde(skip)]
+        cached_total: Option<Amount>,
+    }
+
+    #[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
+    #[serde(crate = "self::serde")]
+    pub enum Side {
+        #[serde(rename = "BID")]
+        Buy,
+        Sell,
+    }
+
+    impl OrderBook {
+        pub fn new() -> Component<Self> {
+            Component::new(Self {
+                orders: BTreeMap::new(),
+                memo: None,
+            })
+            .with_access_rules(AccessRules::allow_all())
+            .create()
+        }
+
+        pub fn place_order(&mut self, id: u64, order: Order) {
+            self.orders.insert(id, order);
+        }
+
+        pub fn get_order(&self, id: u64) -> Option<Order> {
+            self.orders.get(&id).cloned()
+        }
+
+        pub fn set_memo(&mut self, memo: Memo) {
+            self.memo = Some(memo);
+        }
+
+        pub fn get_orders(&self) -> BTreeMap<u64, Order> {
+            self.orders.clone()
+        }
+    }
+}
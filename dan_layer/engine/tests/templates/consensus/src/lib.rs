@@ -30,7 +30,7 @@ mod consensus_template {
 
     impl TestConsensus {
         pub fn current_epoch() -> u64 {
-            Consensus::current_epoch()    
+            Consensus::current_epoch()
         }
     }
 }
@@ -0,0 +1,62 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use tari_template_lib::prelude::*;
+
+#[template]
+mod crypto_template {
+    use tari_template_lib::{
+        crypto,
+        crypto::{Ed25519PublicKeyBytes, Ed25519SignatureBytes, SchnorrSignatureBytes},
+        Hash,
+    };
+
+    use super::*;
+
+    pub struct CryptoTest {}
+
+    impl CryptoTest {
+        pub fn blake2b_256(data: Vec<u8>) -> Hash {
+            crypto::blake2b_256(&data)
+        }
+
+        pub fn sha256(data: Vec<u8>) -> Hash {
+            crypto::sha256(&data)
+        }
+
+        pub fn keccak256(data: Vec<u8>) -> Hash {
+            crypto::keccak256(&data)
+        }
+
+        pub fn verify_schnorr(
+            public_key: RistrettoPublicKeyBytes,
+            public_nonce: RistrettoPublicKeyBytes,
+            signature: SchnorrSignatureBytes,
+            message: Vec<u8>,
+        ) -> bool {
+            crypto::verify_schnorr_signature(&public_key, &public_nonce, &signature, &message)
+        }
+
+        pub fn verify_ed25519(
+            public_key: Ed25519PublicKeyBytes,
+            signature: Ed25519SignatureBytes,
+            message: Vec<u8>,
+        ) -> bool {
+            crypto::verify_ed25519_signature(&public_key, &signature, &message)
+        }
+
+        /// Accepts a price only if it is signed by the oracle
+        pub fn submit_oracle_price(
+            oracle: RistrettoPublicKeyBytes,
+            public_nonce: RistrettoPublicKeyBytes,
+            signature: SchnorrSignatureBytes,
+            price: u64,
+        ) -> u64 {
+            assert!(
+                crypto::verify_schnorr_signature(&oracle, &public_nonce, &signature, &price.to_le_bytes()),
+                "Invalid oracle signature"
+            );
+            price
+        }
+    }
+}
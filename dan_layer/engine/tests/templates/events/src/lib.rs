@@ -20,7 +20,7 @@
 //   WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 //   USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 use tari_template_lib::prelude::*;
 
@@ -30,11 +30,21 @@ mod event {
 
     pub struct EventEmitter {}
 
+    #[event(topic = "transfer")]
+    pub struct TransferEvent {
+        amount: Amount,
+        to: String,
+    }
+
     impl EventEmitter {
         pub fn test_function(topic: String) {
             println!("Emitting a new event");
-            let payload = [("my", "event")];
+            let payload = BTreeMap::from([("my", "event")]);
             emit_event(topic, payload);
         }
+
+        pub fn emit_transfer(amount: Amount, to: String) {
+            emit_event("transfer", TransferEvent { amount, to });
+        }
     }
 }
@@ -0,0 +1,72 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use tari_template_lib::prelude::*;
+
+#[template]
+mod ledger_template {
+    use super::*;
+
+    pub struct Ledger {
+        balance: u64,
+        deposited: u64,
+        withdrawn: u64,
+        memo: Option<String>,
+        u128_max_seen: bool,
+        i128_min_seen: bool,
+        negative_one_seen: bool,
+        amount_min_seen: bool,
+    }
+
+    impl Ledger {
+        pub fn new() -> Component<Self> {
+            Component::new(Self {
+                balance: 0,
+                deposited: 0,
+                withdrawn: 0,
+                memo: None,
+                u128_max_seen: false,
+                i128_min_seen: false,
+                negative_one_seen: false,
+                amount_min_seen: false,
+            })
+            .with_access_rules(AccessRules::new().default(rule!(allow_all)))
+            .create()
+        }
+
+        pub fn deposit(&mut self, amount: u32) {
+            self.balance += u64::from(amount);
+            self.deposited += u64::from(amount);
+        }
+
+        pub fn checked_withdraw(&mut self, amount: u32) {
+            let amount = u64::from(amount);
+            assert!(amount <= self.balance, "Insufficient balance");
+            self.balance -= amount;
+            self.withdrawn += amount;
+        }
+
+        /// Deliberately broken: overdrawing empties the balance but records the full amount as withdrawn
+        pub fn withdraw(&mut self, amount: u32) {
+            let amount = u64::from(amount);
+            self.balance = self.balance.saturating_sub(amount);
+            self.withdrawn += amount;
+        }
+
+        pub fn set_memo(&mut self, memo: Option<String>) {
+            self.memo = memo;
+        }
+
+        /// Records which boundary values the caller has passed in
+        pub fn record_boundaries(&mut self, unsigned: u128, signed: i128, amount: Amount) {
+            self.u128_max_seen |= unsigned == u128::MAX;
+            self.i128_min_seen |= signed == i128::MIN;
+            self.negative_one_seen |= signed == -1;
+            self.amount_min_seen |= amount == Amount(i64::MIN);
+        }
+
+        pub fn balance(&self) -> u64 {
+            self.balance
+        }
+    }
+}
@@ -31,6 +31,16 @@ mod dangling_template {
             .create()
         }
 
+        pub fn new_guarded() -> Component<Self> {
+            Component::new(Self {
+                vault: None,
+                is_allowed: true,
+            })
+            .with_access_rules(AccessRules::allow_all())
+            .with_reentrancy_guard()
+            .create()
+        }
+
         pub fn withdraw(&mut self, amount: Amount) -> Bucket {
             self.vault.as_mut().unwrap().withdraw(amount)
         }
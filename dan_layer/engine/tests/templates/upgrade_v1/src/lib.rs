@@ -0,0 +1,42 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use tari_template_lib::prelude::*;
+
+#[template]
+mod counter_template {
+    use super::*;
+
+    pub struct Counter {
+        value: u32,
+    }
+
+    impl Counter {
+        pub fn new() -> Component<Self> {
+            Component::new(Self { value: 0 })
+                .with_access_rules(
+                    AccessRules::new()
+                        .default(rule!(allow_all))
+                        .upgrade_template_rule(rule!(allow_all)),
+                )
+                .create()
+        }
+
+        pub fn value(&self) -> u32 {
+            self.value
+        }
+
+        pub fn increase(&mut self) {
+            self.value += 1;
+        }
+
+        pub fn upgrade(&self, template_address: TemplateAddress) {
+            ComponentManager::current().upgrade_template(template_address);
+        }
+
+        pub fn upgrade_and_increase(&mut self, template_address: TemplateAddress) {
+            ComponentManager::current().upgrade_template(template_address);
+            self.value += 1;
+        }
+    }
+}
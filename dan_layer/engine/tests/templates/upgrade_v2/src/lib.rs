@@ -0,0 +1,49 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use tari_template_lib::prelude::*;
+
+#[template]
+mod counter_v2_template {
+    use super::*;
+
+    pub struct CounterV2 {
+        value: u32,
+        step: u32,
+    }
+
+    /// The state layout of the previous version of the template
+    pub struct CounterV1State {
+        value: u32,
+    }
+
+    impl CounterV2 {
+        pub fn migrate(old_state: CounterV1State) -> CounterV2 {
+            Self {
+                value: old_state.value,
+                step: 10,
+            }
+        }
+
+        pub fn value(&self) -> u32 {
+            self.value
+        }
+
+        pub fn increase(&mut self) {
+            self.value += self.step;
+        }
+
+        pub fn upgrade(&self, template_address: TemplateAddress) {
+            ComponentManager::current().upgrade_template(template_address);
+        }
+
+        pub fn upgrade_and_increase(&mut self, template_address: TemplateAddress) {
+            ComponentManager::current().upgrade_template(template_address);
+            self.value += self.step;
+        }
+
+        pub fn set_step(&mut self, step: u32) {
+            self.step = step;
+        }
+    }
+}
@@ -0,0 +1,50 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use tari_template_lib::prelude::*;
+
+#[template]
+mod view_template {
+    use super::*;
+
+    pub struct ViewCounter {
+        value: u32,
+        vault: Vault,
+    }
+
+    impl ViewCounter {
+        pub fn new() -> Component<Self> {
+            let bucket = ResourceBuilder::fungible().initial_supply(1000);
+            Component::new(Self {
+                value: 0,
+                vault: Vault::from_bucket(bucket),
+            })
+            .with_access_rules(AccessRules::allow_all())
+            .create()
+        }
+
+        pub fn value(&self) -> u32 {
+            self.value
+        }
+
+        pub fn value_plus(&self, amount: u32) -> u32 {
+            self.value + amount
+        }
+
+        pub fn balance(&self) -> Amount {
+            self.vault.balance()
+        }
+
+        pub fn increase(&mut self) {
+            self.value += 1;
+        }
+
+        pub fn withdraw(&self, amount: Amount) -> Bucket {
+            self.vault.withdraw(amount)
+        }
+
+        pub fn create_resource(&self) -> ResourceAddress {
+            ResourceBuilder::fungible().build()
+        }
+    }
+}
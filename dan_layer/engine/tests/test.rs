@@ -32,6 +32,7 @@ use tari_engine_types::{
     virtual_substate::{VirtualSubstate, VirtualSubstateId},
     TemplateAddress,
 };
+use tari_template_abi::{FieldDef, FieldsDef, Type, TypeDefKind, VariantDef};
 use tari_template_builtin::{ACCOUNT_NFT_TEMPLATE_ADDRESS, ACCOUNT_TEMPLATE_ADDRESS};
 use tari_template_lib::{
     args,
@@ -277,6 +278,70 @@ fn test_tuples() {
     assert_eq!(value, new_value);
 }
 
+#[test]
+fn test_abi_type_definitions() {
+    let mut template_test = TemplateTest::new(vec!["tests/templates/abi_types"]);
+
+    let module = template_test.get_module("OrderBook");
+    let template_def = module.template_def();
+    let order_type = || {
+        Box::new(Type::Other {
+            name: "Order".to_string(),
+        })
+    };
+    let fn_get_order = module.find_func_by_name("get_order").unwrap();
+    assert_eq!(fn_get_order.output, Type::Option(order_type()));
+    let fn_get_orders = module.find_func_by_name("get_orders").unwrap();
+    assert_eq!(fn_get_orders.output, Type::Map(Box::new(Type::U64), order_type()));
+
+    // structs and enums defined in the template are described in the ABI
+    let order = template_def.get_type("Order").unwrap();
+    assert_eq!(order.kind, TypeDefKind::Struct {
+        fields: FieldsDef::Named(vec![
+            FieldDef {
+                name: "price".to_string(),
+                field_type: Type::Other {
+                    name: "Amount".to_string()
+                },
+            },
+            FieldDef {
+                name: "side".to_string(),
+                field_type: Type::Other {
+                    name: "Side".to_string()
+                },
+            },
+            FieldDef {
+                name: "note".to_string(),
+                field_type: Type::Option(Box::new(Type::String)),
+            },
+            // serde renames are applied and skipped fields are omitted
+            FieldDef {
+                name: "qty".to_string(),
+                field_type: Type::U64,
+            },
+        ]),
+    });
+    let side = template_def.get_type("Side").unwrap();
+    assert_eq!(side.kind, TypeDefKind::Enum {
+        variants: vec![
+            VariantDef {
+                name: "BID".to_string(),
+                fields: FieldsDef::Unit,
+            },
+            VariantDef {
+                name: "Sell".to_string(),
+                fields: FieldsDef::Unit,
+            },
+        ],
+    });
+    // referenced engine types are included
+    assert_eq!(template_def.get_type("Amount").unwrap().kind, TypeDefKind::Engine);
+    // types declared outside the template module are flagged as external
+    assert_eq!(template_def.get_type("Memo").unwrap().kind, TypeDefKind::External);
+    assert!(template_def.get_type("Component").is_none());
+    assert!(template_def.get_type("Bucket").is_none());
+}
+
 #[test]
 fn test_get_template_address() {
     let mut template_test = TemplateTest::new(vec!["tests/templates/component_manager"]);
@@ -0,0 +1,61 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use tari_dan_engine::transaction::TransactionError;
+use tari_template_lib::{
+    args,
+    models::{Amount, ComponentAddress},
+};
+use tari_template_test_tooling::TemplateTest;
+
+fn setup() -> (TemplateTest, ComponentAddress) {
+    let mut test = TemplateTest::new(["tests/templates/view"]);
+    let component_address: ComponentAddress = test.call_function("ViewCounter", "new", args![], vec![]);
+    (test, component_address)
+}
+
+#[test]
+fn it_calls_view_methods_without_a_transaction() {
+    let (mut test, component_address) = setup();
+
+    let value: u32 = test.call_view(component_address, "value", args![]);
+    assert_eq!(value, 0);
+    let balance: Amount = test.call_view(component_address, "balance", args![]);
+    assert_eq!(balance, Amount(1000));
+
+    test.call_method::<()>(component_address, "increase", args![], vec![]);
+    let value: u32 = test.call_view(component_address, "value_plus", args![10]);
+    assert_eq!(value, 11);
+}
+
+#[test]
+fn it_rejects_mutable_methods() {
+    let (test, component_address) = setup();
+
+    let err = test.try_call_view(component_address, "increase", args![]).unwrap_err();
+    assert!(
+        matches!(err, TransactionError::MutableMethodInViewCall { ref name } if name == "increase"),
+        "unexpected error: {err}"
+    );
+}
+
+#[test]
+fn it_forbids_writes_in_view_methods() {
+    let (test, component_address) = setup();
+
+    let err = test
+        .try_call_view(component_address, "withdraw", args![Amount(10)])
+        .unwrap_err();
+    assert!(
+        err.to_string().contains("in a read-only call"),
+        "unexpected error: {err}"
+    );
+
+    let err = test
+        .try_call_view(component_address, "create_resource", args![])
+        .unwrap_err();
+    assert!(
+        err.to_string().contains("in a read-only call"),
+        "unexpected error: {err}"
+    );
+}
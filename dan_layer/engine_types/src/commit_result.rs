@@ -32,6 +32,7 @@ use ts_rs::TS;
 
 use crate::{
     events::Event,
+    execution_trace::ExecutionTrace,
     fees::FeeReceipt,
     instruction_result::InstructionResult,
     logs::LogEntry,
@@ -46,6 +47,9 @@ pub struct ExecuteResult {
     pub finalize: FinalizeResult,
     #[cfg_attr(feature = "ts", ts(type = "{secs: number, nanos: number}"))]
     pub execution_time: Duration,
+    /// The execution trace, if tracing was enabled
+    #[serde(default)]
+    pub trace: Option<ExecutionTrace>,
 }
 
 impl ExecuteResult {
@@ -53,6 +57,7 @@ impl ExecuteResult {
         Self {
             finalize: FinalizeResult::new_rejected(transaction_hash, reason),
             execution_time: Duration::default(),
+            trace: None,
         }
     }
 
@@ -67,6 +67,9 @@ pub struct ComponentHeader {
     pub owner_rule: OwnerRule,
     pub access_rules: ComponentAccessRules,
     pub entity_id: EntityId,
+    /// If true, methods on this component may not be called while another call to the component is in progress
+    #[serde(default)]
+    pub reentrancy_guard: bool,
     // TODO: Split the state from the header
     pub body: ComponentBody,
 }
@@ -100,6 +103,10 @@ impl ComponentHeader {
         self
     }
 
+    pub fn is_reentrancy_guarded(&self) -> bool {
+        self.reentrancy_guard
+    }
+
     pub fn contains_substate(&self, address: &SubstateId) -> Result<bool, IndexedValueError> {
         let found = IndexedWellKnownTypes::value_contains_substate(self.state(), address)?;
         Ok(found)
@@ -13,19 +13,45 @@ lazy_static! {
         BulletproofsPlusService::init(64, 1, CommitmentFactory::default()).unwrap();
     static ref RANGE_PROOF_AGG_2_SERVICE: BulletproofsPlusService =
         BulletproofsPlusService::init(64, 2, CommitmentFactory::default()).unwrap();
+    static ref RANGE_PROOF_AGG_4_SERVICE: BulletproofsPlusService =
+        BulletproofsPlusService::init(64, 4, CommitmentFactory::default()).unwrap();
+    static ref RANGE_PROOF_AGG_8_SERVICE: BulletproofsPlusService =
+        BulletproofsPlusService::init(64, 8, CommitmentFactory::default()).unwrap();
+    static ref RANGE_PROOF_AGG_16_SERVICE: BulletproofsPlusService =
+        BulletproofsPlusService::init(64, 16, CommitmentFactory::default()).unwrap();
 }
 
+/// The maximum number of commitments that a single range proof may cover
+pub const MAX_RANGE_PROOF_COMMITMENTS: usize = 16;
+
 pub fn get_range_proof_service(aggregation_factor: usize) -> &'static BulletproofsPlusService {
     match aggregation_factor {
         1 => &RANGE_PROOF_AGG_1_SERVICE,
         2 => &RANGE_PROOF_AGG_2_SERVICE,
+        4 => &RANGE_PROOF_AGG_4_SERVICE,
+        8 => &RANGE_PROOF_AGG_8_SERVICE,
+        16 => &RANGE_PROOF_AGG_16_SERVICE,
         _ => panic!(
-            "Unsupported BP aggregation factor {}. Expected 1 or 2",
+            "Unsupported BP aggregation factor {}. Expected 1, 2, 4, 8 or 16",
             aggregation_factor
         ),
     }
 }
 
+/// Returns the range proof service for a proof covering `num_commitments` commitments. The aggregation factor is the
+/// number of commitments rounded up to the next power of two.
+///
+/// # Panics
+/// Panics if `num_commitments` is zero or greater than [MAX_RANGE_PROOF_COMMITMENTS]
+pub fn get_range_proof_service_for(num_commitments: usize) -> &'static BulletproofsPlusService {
+    assert!(
+        (1..=MAX_RANGE_PROOF_COMMITMENTS).contains(&num_commitments),
+        "Unsupported number of range proof commitments {}",
+        num_commitments
+    );
+    get_range_proof_service(num_commitments.next_power_of_two())
+}
+
 pub fn get_commitment_factory() -> &'static CommitmentFactory {
     &COMMITMENT_FACTORY
 }
@@ -9,9 +9,9 @@ use tari_crypto::{
     ristretto::{bulletproofs_plus::RistrettoAggregatedPublicStatement, RistrettoSecretKey},
     tari_utilities::ByteArray,
 };
-use tari_template_lib::models::{Amount, ConfidentialOutputStatement, ViewableBalanceProof};
+use tari_template_lib::models::{Amount, ConfidentialOutputStatement, ConfidentialStatement, ViewableBalanceProof};
 
-use super::{challenges, get_commitment_factory, get_range_proof_service};
+use super::{challenges, get_commitment_factory, get_range_proof_service_for, MAX_RANGE_PROOF_COMMITMENTS};
 use crate::{
     confidential::{elgamal::ElgamalVerifiableBalance, ConfidentialOutput},
     resource_container::ResourceError,
@@ -23,80 +23,62 @@ pub struct ValidatedConfidentialProof {
     pub change_output: Option<ConfidentialOutput>,
     pub output_revealed_amount: Amount,
     pub change_revealed_amount: Amount,
+    pub additional_outputs: Vec<ValidatedAdditionalOutput>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ValidatedAdditionalOutput {
+    pub output: Option<ConfidentialOutput>,
+    pub revealed_amount: Amount,
 }
 
 pub fn validate_confidential_proof(
     proof: &ConfidentialOutputStatement,
     view_key: Option<&PublicKey>,
 ) -> Result<ValidatedConfidentialProof, ResourceError> {
-    if proof.output_revealed_amount.is_negative() || proof.change_revealed_amount.is_negative() {
+    if proof.output_revealed_amount.is_negative() ||
+        proof.change_revealed_amount.is_negative() ||
+        proof.additional_outputs.iter().any(|o| o.revealed_amount.is_negative())
+    {
         return Err(ResourceError::InvalidConfidentialProof {
             details: "Revealed amounts must be positive".to_string(),
         });
     }
 
+    if proof.total_revealed_amount().is_none() {
+        return Err(ResourceError::InvalidConfidentialProof {
+            details: "Total revealed amount overflows".to_string(),
+        });
+    }
+
     let maybe_output = proof
         .output_statement
         .as_ref()
-        .map(|statement| {
-            let output_commitment =
-                Commitment::from_canonical_bytes(statement.commitment.as_bytes()).map_err(|_| {
-                    ResourceError::InvalidConfidentialProof {
-                        details: "Invalid commitment".to_string(),
-                    }
-                })?;
-
-            let output_public_nonce = PublicKey::from_canonical_bytes(statement.sender_public_nonce.as_bytes())
-                .map_err(|_| ResourceError::InvalidConfidentialProof {
-                    details: "Invalid sender public nonce".to_string(),
-                })?;
-
-            let viewable_balance = validate_elgamal_verifiable_balance_proof(
-                &output_commitment,
-                view_key,
-                statement.viewable_balance_proof.as_ref(),
-            )?;
-
-            Ok(ConfidentialOutput {
-                commitment: output_commitment,
-                stealth_public_nonce: output_public_nonce,
-                encrypted_data: statement.encrypted_data.clone(),
-                minimum_value_promise: statement.minimum_value_promise,
-                viewable_balance,
-            })
-        })
+        .map(|statement| validate_confidential_statement(statement, view_key))
         .transpose()?;
 
     let maybe_change = proof
         .change_statement
         .as_ref()
-        .map(|stmt| {
-            let commitment = Commitment::from_canonical_bytes(&*stmt.commitment).map_err(|_| {
-                ResourceError::InvalidConfidentialProof {
-                    details: "Invalid commitment".to_string(),
-                }
-            })?;
-
-            let stealth_public_nonce = PublicKey::from_canonical_bytes(&*stmt.sender_public_nonce).map_err(|_| {
-                ResourceError::InvalidConfidentialProof {
-                    details: "Invalid sender public nonce".to_string(),
-                }
-            })?;
-
-            let viewable_balance =
-                validate_elgamal_verifiable_balance_proof(&commitment, view_key, stmt.viewable_balance_proof.as_ref())?;
+        .map(|statement| validate_confidential_statement(statement, view_key))
+        .transpose()?;
 
-            Ok(ConfidentialOutput {
-                commitment,
-                stealth_public_nonce,
-                encrypted_data: stmt.encrypted_data.clone(),
-                minimum_value_promise: stmt.minimum_value_promise,
-                viewable_balance,
+    let additional_outputs = proof
+        .additional_outputs
+        .iter()
+        .map(|output| {
+            Ok(ValidatedAdditionalOutput {
+                output: output
+                    .statement
+                    .as_ref()
+                    .map(|statement| validate_confidential_statement(statement, view_key))
+                    .transpose()?,
+                revealed_amount: output.revealed_amount,
             })
         })
-        .transpose()?;
+        .collect::<Result<Vec<_>, ResourceError>>()?;
 
-    if maybe_output.is_none() && maybe_change.is_none() {
+    if proof.statements().next().is_none() {
         if !proof.range_proof.is_empty() {
             return Err(ResourceError::InvalidConfidentialProof {
                 details: "Range proof is invalid because it was provided (non-empty) but the proof contained no \
@@ -113,6 +95,36 @@ pub fn validate_confidential_proof(
         change_output: maybe_change,
         output_revealed_amount: proof.output_revealed_amount,
         change_revealed_amount: proof.change_revealed_amount,
+        additional_outputs,
+    })
+}
+
+fn validate_confidential_statement(
+    statement: &ConfidentialStatement,
+    view_key: Option<&PublicKey>,
+) -> Result<ConfidentialOutput, ResourceError> {
+    let commitment = Commitment::from_canonical_bytes(statement.commitment.as_bytes()).map_err(|_| {
+        ResourceError::InvalidConfidentialProof {
+            details: "Invalid commitment".to_string(),
+        }
+    })?;
+
+    let stealth_public_nonce =
+        PublicKey::from_canonical_bytes(statement.sender_public_nonce.as_bytes()).map_err(|_| {
+            ResourceError::InvalidConfidentialProof {
+                details: "Invalid sender public nonce".to_string(),
+            }
+        })?;
+
+    let viewable_balance =
+        validate_elgamal_verifiable_balance_proof(&commitment, view_key, statement.viewable_balance_proof.as_ref())?;
+
+    Ok(ConfidentialOutput {
+        commitment,
+        stealth_public_nonce,
+        encrypted_data: statement.encrypted_data.clone(),
+        minimum_value_promise: statement.minimum_value_promise,
+        viewable_balance,
     })
 }
 
@@ -221,9 +233,7 @@ pub fn validate_elgamal_verifiable_balance_proof(
 
 fn validate_bullet_proof(proof: &ConfidentialOutputStatement) -> Result<(), ResourceError> {
     let statements = proof
-        .output_statement
-        .iter()
-        .chain(proof.change_statement.iter())
+        .statements()
         .map(|stmt| {
             let commitment = Commitment::from_canonical_bytes(&*stmt.commitment).map_err(|_| {
                 ResourceError::InvalidConfidentialProof {
@@ -237,9 +247,8 @@ fn validate_bullet_proof(proof: &ConfidentialOutputStatement) -> Result<(), Reso
         })
         .collect::<Result<Vec<_>, _>>()?;
 
-    // Either 0, 1 or 2
-    let agg_factor = statements.len();
-    if agg_factor == 0 {
+    let num_commitments = statements.len();
+    if num_commitments == 0 {
         // No outputs, so no rangeproof needed (revealed mint)
         if proof.range_proof.is_empty() {
             return Ok(());
@@ -248,11 +257,22 @@ fn validate_bullet_proof(proof: &ConfidentialOutputStatement) -> Result<(), Reso
             details: "Range proof is invalid because it was provided but the proof contained no outputs".to_string(),
         });
     }
+    if num_commitments > MAX_RANGE_PROOF_COMMITMENTS {
+        return Err(ResourceError::InvalidConfidentialProof {
+            details: format!(
+                "Proof contains {} confidential outputs, exceeding the maximum of {}",
+                num_commitments, MAX_RANGE_PROOF_COMMITMENTS
+            ),
+        });
+    }
 
-    let public_statement = RistrettoAggregatedPublicStatement::init(statements).unwrap();
+    let public_statement =
+        RistrettoAggregatedPublicStatement::init(statements).map_err(|e| ResourceError::InvalidConfidentialProof {
+            details: format!("Invalid range proof statement: {}", e),
+        })?;
 
     let proofs = vec![&proof.range_proof];
-    get_range_proof_service(agg_factor)
+    get_range_proof_service_for(num_commitments)
         .verify_batch(proofs, vec![&public_statement])
         .map_err(|e| ResourceError::InvalidConfidentialProof {
             details: format!("Invalid range proof: {}", e),
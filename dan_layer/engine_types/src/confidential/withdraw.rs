@@ -9,7 +9,7 @@ use tari_template_lib::{
     models::{Amount, ConfidentialWithdrawProof, EncryptedData},
 };
 
-use super::{challenges, get_commitment_factory, validate_confidential_proof};
+use super::{challenges, get_commitment_factory, validate_confidential_proof, ValidatedAdditionalOutput};
 use crate::{confidential::elgamal::ElgamalVerifiableBalance, resource_container::ResourceError};
 
 #[derive(Debug, Clone)]
@@ -26,6 +26,8 @@ pub struct ValidatedConfidentialWithdrawProof {
     pub output_revealed_amount: Amount,
     /// Amount of revealed value to include in the revealed value of the change output
     pub change_revealed_amount: Amount,
+    /// Outputs in addition to the output, each of which is withdrawn into a separate container
+    pub additional_outputs: Vec<ValidatedAdditionalOutput>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -55,8 +57,10 @@ pub(crate) fn validate_confidential_withdraw<'a, I: IntoIterator<Item = &'a Comm
 
     let input_revealed_amount = withdraw_proof.input_revealed_amount;
     // We expect the revealed amount to be excluded from the output commitment.
-    let total_output_revealed_amount =
-        withdraw_proof.output_proof.output_revealed_amount + withdraw_proof.output_proof.change_revealed_amount;
+    let total_output_revealed_amount = withdraw_proof
+        .output_proof
+        .total_revealed_amount()
+        .expect("validate_confidential_proof checks that the total revealed amount does not overflow");
 
     // Balance proof not required if only revealed funds are transferred
     if withdraw_proof.is_revealed_only() {
@@ -76,15 +80,21 @@ pub(crate) fn validate_confidential_withdraw<'a, I: IntoIterator<Item = &'a Comm
             input_revealed_amount,
             output_revealed_amount: withdraw_proof.output_proof.output_revealed_amount,
             change_revealed_amount: withdraw_proof.output_proof.change_revealed_amount,
+            additional_outputs: validated_proof.additional_outputs,
         });
     }
 
-    // k.G + v.H or 0.G if None
+    // Sum of k.G + v.H of the output and additional outputs, or 0.G if there are none
     let output_commitment = validated_proof
         .output
-        .as_ref()
-        .map(|o| o.commitment.as_public_key().clone())
-        .unwrap_or_default();
+        .iter()
+        .chain(
+            validated_proof
+                .additional_outputs
+                .iter()
+                .filter_map(|o| o.output.as_ref()),
+        )
+        .fold(PublicKey::default(), |sum, o| sum + o.commitment.as_public_key());
 
     // 0.G + v.H
     let revealed_output_commitment =
@@ -134,6 +144,7 @@ pub(crate) fn validate_confidential_withdraw<'a, I: IntoIterator<Item = &'a Comm
         input_revealed_amount: withdraw_proof.input_revealed_amount,
         output_revealed_amount: withdraw_proof.output_proof.output_revealed_amount,
         change_revealed_amount: withdraw_proof.output_proof.change_revealed_amount,
+        additional_outputs: validated_proof.additional_outputs,
     })
 }
 
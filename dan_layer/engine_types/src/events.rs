@@ -22,11 +22,9 @@
 
 use std::fmt::Display;
 
-use serde::{Deserialize, Serialize};
-use tari_template_lib::{
-    models::{Metadata, TemplateAddress},
-    Hash,
-};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use tari_bor::BorError;
+use tari_template_lib::{models::TemplateAddress, Hash};
 #[cfg(feature = "ts")]
 use ts_rs::TS;
 
@@ -43,9 +41,10 @@ pub struct Event {
     #[cfg_attr(feature = "ts", ts(type = "string"))]
     tx_hash: Hash,
     topic: String,
-    // NOTE: We need to use an ordered map here. HashMaps are unordered, so when we pledge this state the hash
-    // resulting hash may differ.
-    payload: Metadata,
+    /// The CBOR-encoded payload given by the emitter
+    #[serde(with = "serde_with::cbor_value")]
+    #[cfg_attr(feature = "ts", ts(type = "any"))]
+    payload: tari_bor::Value,
 }
 
 impl Event {
@@ -54,7 +53,7 @@ impl Event {
         template_address: TemplateAddress,
         tx_hash: Hash,
         topic: String,
-        payload: Metadata,
+        payload: tari_bor::Value,
     ) -> Self {
         Self {
             substate_id,
@@ -81,21 +80,36 @@ impl Event {
         self.topic.clone()
     }
 
-    pub fn add_payload(&mut self, key: String, value: String) {
-        self.payload.insert(key, value);
-    }
-
+    /// Returns the value of a field of the payload if the payload is a map and the value is a string
     pub fn get_payload(&self, key: &str) -> Option<String> {
-        self.payload.get(key).cloned()
+        let tari_bor::Value::Map(entries) = untagged(&self.payload) else {
+            return None;
+        };
+        entries.iter().find_map(|(k, v)| match (untagged(k), untagged(v)) {
+            (tari_bor::Value::Text(k), tari_bor::Value::Text(v)) if k == key => Some(v.clone()),
+            _ => None,
+        })
     }
 
-    pub fn payload(&self) -> &Metadata {
+    pub fn payload(&self) -> &tari_bor::Value {
         &self.payload
     }
 
-    pub fn into_payload(self) -> Metadata {
+    pub fn into_payload(self) -> tari_bor::Value {
         self.payload
     }
+
+    /// Decodes the payload into the type that the emitter encoded
+    pub fn decode_payload<T: DeserializeOwned>(&self) -> Result<T, BorError> {
+        tari_bor::from_value(&self.payload)
+    }
+}
+
+fn untagged(value: &tari_bor::Value) -> &tari_bor::Value {
+    match value {
+        tari_bor::Value::Tag(_, inner) => untagged(inner),
+        value => value,
+    }
 }
 
 impl Display for Event {
@@ -0,0 +1,136 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+use tari_template_lib::models::{Amount, BucketId, ComponentAddress, ProofId, ResourceAddress, TemplateAddress};
+#[cfg(feature = "ts")]
+use ts_rs::TS;
+
+use crate::{fees::FeeSource, lock::LockFlag, serde_with, substate::SubstateId};
+
+/// A step-by-step record of everything the engine did while executing a transaction. Only produced when tracing is
+/// enabled on the transaction processor (e.g. for dry runs).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS), ts(export, export_to = "../../bindings/src/types/"))]
+pub struct ExecutionTrace {
+    entries: Vec<TraceEntry>,
+}
+
+impl ExecutionTrace {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, depth: usize, event: TraceEvent) {
+        self.entries.push(TraceEntry { depth, event });
+    }
+
+    pub fn entries(&self) -> &[TraceEntry] {
+        &self.entries
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &TraceEntry> + '_ {
+        self.entries.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS), ts(export, export_to = "../../bindings/src/types/"))]
+pub struct TraceEntry {
+    /// The call frame depth at which the event occurred. Zero is outside of any template call.
+    pub depth: usize,
+    pub event: TraceEvent,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS), ts(export, export_to = "../../bindings/src/types/"))]
+pub enum TraceEvent {
+    /// A call from the template into the engine
+    RuntimeCall {
+        function: String,
+    },
+    /// An action that is subject to authorization (e.g. a component method call or a native vault operation)
+    Action {
+        action: String,
+    },
+    CallFramePushed {
+        #[serde(with = "serde_with::hex")]
+        #[cfg_attr(feature = "ts", ts(type = "string"))]
+        template_address: TemplateAddress,
+        module_name: String,
+        component_address: Option<ComponentAddress>,
+    },
+    CallFramePopped,
+    SubstateLocked {
+        substate_id: SubstateId,
+        lock_flag: LockFlag,
+    },
+    SubstateUnlocked {
+        substate_id: SubstateId,
+    },
+    BucketCreated {
+        bucket_id: BucketId,
+        resource_address: ResourceAddress,
+        amount: Amount,
+    },
+    BucketTaken {
+        bucket_id: BucketId,
+    },
+    ProofCreated {
+        proof_id: ProofId,
+        resource_address: ResourceAddress,
+    },
+    ProofDropped {
+        proof_id: ProofId,
+    },
+    FeeCharged {
+        source: FeeSource,
+        #[cfg_attr(feature = "ts", ts(type = "number"))]
+        amount: u64,
+    },
+}
+
+impl Display for TraceEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TraceEvent::RuntimeCall { function } => write!(f, "runtime call {function}"),
+            TraceEvent::Action { action } => write!(f, "action {action}"),
+            TraceEvent::CallFramePushed {
+                template_address,
+                module_name,
+                component_address,
+            } => {
+                write!(f, "enter {module_name} ({template_address})")?;
+                if let Some(component_address) = component_address {
+                    write!(f, " on {component_address}")?;
+                }
+                Ok(())
+            },
+            TraceEvent::CallFramePopped => write!(f, "exit"),
+            TraceEvent::SubstateLocked { substate_id, lock_flag } => write!(f, "lock {lock_flag} {substate_id}"),
+            TraceEvent::SubstateUnlocked { substate_id } => write!(f, "unlock {substate_id}"),
+            TraceEvent::BucketCreated {
+                bucket_id,
+                resource_address,
+                amount,
+            } => write!(f, "new bucket {bucket_id} with {amount} of {resource_address}"),
+            TraceEvent::BucketTaken { bucket_id } => write!(f, "take bucket {bucket_id}"),
+            TraceEvent::ProofCreated {
+                proof_id,
+                resource_address,
+            } => write!(f, "new proof {proof_id} for {resource_address}"),
+            TraceEvent::ProofDropped { proof_id } => write!(f, "drop proof {proof_id}"),
+            TraceEvent::FeeCharged { source, amount } => write!(f, "fee {amount} ({source:?})"),
+        }
+    }
+}
@@ -72,6 +72,7 @@ pub enum FeeSource {
     Storage,
     Events,
     Logs,
+    Crypto,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
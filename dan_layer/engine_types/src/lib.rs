@@ -7,6 +7,7 @@ pub mod commit_result;
 pub mod component;
 pub mod confidential;
 pub mod events;
+pub mod execution_trace;
 pub mod fee_claim;
 pub mod fees;
 pub mod hashing;
@@ -97,6 +97,11 @@ impl ResourceContainer {
         if proof.change_statement.is_some() {
             return Err(ResourceError::InvalidConfidentialMintWithChange);
         }
+        if !proof.additional_outputs.is_empty() {
+            return Err(ResourceError::InvalidConfidentialProof {
+                details: "Additional outputs are not allowed for minting".to_string(),
+            });
+        }
         if !proof.change_revealed_amount.is_zero() {
             return Err(ResourceError::InvalidConfidentialProof {
                 details: "Change revealed amount must be zero for minting".to_string(),
@@ -368,6 +373,22 @@ impl ResourceContainer {
         proof: ConfidentialWithdrawProof,
         view_key: Option<&PublicKey>,
     ) -> Result<ResourceContainer, ResourceError> {
+        if !proof.output_proof.additional_outputs.is_empty() {
+            return Err(ResourceError::InvalidConfidentialProof {
+                details: "Additional outputs are only allowed when withdrawing many confidential outputs".to_string(),
+            });
+        }
+        let mut containers = self.withdraw_confidential_many(proof, view_key)?;
+        Ok(containers.remove(0))
+    }
+
+    /// Withdraws the outputs of a confidential withdraw proof into one container per output. The first container
+    /// holds the output, followed by one container per additional output in the order given in the proof.
+    pub fn withdraw_confidential_many(
+        &mut self,
+        proof: ConfidentialWithdrawProof,
+        view_key: Option<&PublicKey>,
+    ) -> Result<Vec<ResourceContainer>, ResourceError> {
         match self {
             ResourceContainer::Fungible { .. } => Err(ResourceError::OperationNotAllowed(
                 "Cannot withdraw confidential assets from a fungible resource".to_string(),
@@ -435,11 +456,21 @@ impl ResourceContainer {
                     *revealed_amount += validated_proof.change_revealed_amount;
                 }
 
-                Ok(ResourceContainer::confidential(
-                    *self.resource_address(),
+                let resource_address = *self.resource_address();
+                let mut containers = Vec::with_capacity(1 + validated_proof.additional_outputs.len());
+                containers.push(ResourceContainer::confidential(
+                    resource_address,
                     validated_proof.output.map(|o| (o.commitment.clone(), o)),
                     validated_proof.output_revealed_amount,
-                ))
+                ));
+                containers.extend(validated_proof.additional_outputs.into_iter().map(|additional| {
+                    ResourceContainer::confidential(
+                        resource_address,
+                        additional.output.map(|o| (o.commitment.clone(), o)),
+                        additional.revealed_amount,
+                    )
+                }));
+                Ok(containers)
             },
         }
     }
@@ -76,6 +76,14 @@ impl Vault {
         self.resource_container.withdraw_confidential(proof, view_key)
     }
 
+    pub fn withdraw_confidential_many(
+        &mut self,
+        proof: ConfidentialWithdrawProof,
+        view_key: Option<&PublicKey>,
+    ) -> Result<Vec<ResourceContainer>, ResourceError> {
+        self.resource_container.withdraw_confidential_many(proof, view_key)
+    }
+
     pub fn recall_all(&mut self) -> Result<ResourceContainer, ResourceError> {
         self.resource_container.recall_all()
     }
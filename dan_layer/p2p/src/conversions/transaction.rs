@@ -33,6 +33,7 @@ use tari_template_lib::{
     auth::OwnerRule,
     crypto::{BalanceProofSignature, PedersonCommitmentBytes, RistrettoPublicKeyBytes},
     models::{
+        AdditionalOutputStatement,
         Amount,
         ConfidentialOutputStatement,
         ConfidentialStatement,
@@ -489,6 +490,11 @@ impl TryFrom<proto::transaction::ConfidentialOutputStatement> for ConfidentialOu
             range_proof: val.range_proof,
             output_revealed_amount: val.output_revealed_amount.try_into()?,
             change_revealed_amount: val.change_revealed_amount.try_into()?,
+            additional_outputs: val
+                .additional_outputs
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
         })
     }
 }
@@ -507,6 +513,32 @@ impl From<ConfidentialOutputStatement> for proto::transaction::ConfidentialOutpu
                 .change_revealed_amount
                 .as_u64_checked()
                 .expect("change_revealed_amount is negative or too large"),
+            additional_outputs: val.additional_outputs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+// -------------------------------- AdditionalOutputStatement -------------------------------- //
+
+impl TryFrom<proto::transaction::AdditionalOutputStatement> for AdditionalOutputStatement {
+    type Error = anyhow::Error;
+
+    fn try_from(val: proto::transaction::AdditionalOutputStatement) -> Result<Self, Self::Error> {
+        Ok(AdditionalOutputStatement {
+            statement: val.statement.map(TryInto::try_into).transpose()?,
+            revealed_amount: val.revealed_amount.try_into()?,
+        })
+    }
+}
+
+impl From<AdditionalOutputStatement> for proto::transaction::AdditionalOutputStatement {
+    fn from(val: AdditionalOutputStatement) -> Self {
+        Self {
+            statement: val.statement.map(Into::into),
+            revealed_amount: val
+                .revealed_amount
+                .as_u64_checked()
+                .expect("revealed_amount is negative or too large"),
         }
     }
 }
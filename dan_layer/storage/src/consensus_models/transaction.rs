@@ -191,6 +191,7 @@ impl TransactionRecord {
                     FinalizeResult::new_rejected(self.transaction.id().into_array().into(), reason.clone())
                 }),
                 execution_time,
+                trace: None,
             }
         } else {
             // If there's no abort reason or execution result, return None here
@@ -229,6 +230,7 @@ impl TransactionRecord {
                         )
                     }),
                     execution_time,
+                    trace: None,
                 })
             }
         })
@@ -58,6 +58,30 @@ impl TemplateDef {
             TemplateDef::V1(def) => &def.functions,
         }
     }
+
+    pub fn get_event(&self, topic: &str) -> Option<&EventDef> {
+        match self {
+            TemplateDef::V1(def) => def.get_event(topic),
+        }
+    }
+
+    pub fn events(&self) -> &[EventDef] {
+        match self {
+            TemplateDef::V1(def) => &def.events,
+        }
+    }
+
+    pub fn get_type(&self, name: &str) -> Option<&TypeDef> {
+        match self {
+            TemplateDef::V1(def) => def.get_type(name),
+        }
+    }
+
+    pub fn types(&self) -> &[TypeDef] {
+        match self {
+            TemplateDef::V1(def) => &def.types,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -70,12 +94,27 @@ pub struct TemplateDefV1 {
     pub template_name: String,
     pub tari_version: String,
     pub functions: Vec<FunctionDef>,
+    /// The payload types of the events emitted by the template. Templates built before events were described in the
+    /// ABI have none.
+    #[serde(default)]
+    pub events: Vec<EventDef>,
+    /// The definitions of the types referenced by name (`Type::Other`) in the functions and events of the template
+    #[serde(default)]
+    pub types: Vec<TypeDef>,
 }
 
 impl TemplateDefV1 {
     pub fn get_function(&self, name: &str) -> Option<&FunctionDef> {
         self.functions.iter().find(|f| f.name.as_str() == name)
     }
+
+    pub fn get_event(&self, topic: &str) -> Option<&EventDef> {
+        self.events.iter().find(|e| e.topic.as_str() == topic)
+    }
+
+    pub fn get_type(&self, name: &str) -> Option<&TypeDef> {
+        self.types.iter().find(|t| t.name.as_str() == name)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -91,6 +130,17 @@ pub struct FunctionDef {
     pub is_mut: bool,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(ts_rs::TS),
+    ts(export, export_to = "../../bindings/src/types/")
+)]
+pub struct EventDef {
+    pub topic: String,
+    pub payload_type: Type,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(
     feature = "ts",
@@ -102,6 +152,71 @@ pub struct ArgDef {
     pub arg_type: Type,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(ts_rs::TS),
+    ts(export, export_to = "../../bindings/src/types/")
+)]
+pub struct TypeDef {
+    pub name: String,
+    pub kind: TypeDefKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(ts_rs::TS),
+    ts(export, export_to = "../../bindings/src/types/")
+)]
+pub enum TypeDefKind {
+    Struct {
+        fields: FieldsDef,
+    },
+    Enum {
+        variants: Vec<VariantDef>,
+    },
+    /// A type provided by the engine (e.g. `Bucket` or `Amount`), encoded as defined in `tari_template_lib`
+    Engine,
+    /// A type declared outside the template module. Its definition is not visible to the template macro, so its
+    /// encoding is not described.
+    External,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(ts_rs::TS),
+    ts(export, export_to = "../../bindings/src/types/")
+)]
+pub enum FieldsDef {
+    Named(Vec<FieldDef>),
+    Unnamed(Vec<Type>),
+    Unit,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(ts_rs::TS),
+    ts(export, export_to = "../../bindings/src/types/")
+)]
+pub struct FieldDef {
+    pub name: String,
+    pub field_type: Type,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(ts_rs::TS),
+    ts(export, export_to = "../../bindings/src/types/")
+)]
+pub struct VariantDef {
+    pub name: String,
+    pub fields: FieldsDef,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[cfg_attr(
     feature = "ts",
@@ -125,6 +240,9 @@ pub enum Type {
     String,
     Vec(Box<Type>),
     Tuple(Vec<Type>),
+    Option(Box<Type>),
+    /// A map with keys and values of the given types (e.g. `BTreeMap` or `HashMap`)
+    Map(Box<Type>, Box<Type>),
     Other {
         name: String,
     },
@@ -161,6 +279,8 @@ impl std::fmt::Display for Type {
                 let type_list = types.iter().map(|t| format!("{:?}", t)).collect::<Vec<_>>().join(",");
                 write!(f, "Tuple<{}>", type_list)
             },
+            Type::Option(t) => write!(f, "Option<{}>", t),
+            Type::Map(k, v) => write!(f, "Map<{}, {}>", k, v),
             Type::Other { name } => write!(f, "{}", name),
         }
     }
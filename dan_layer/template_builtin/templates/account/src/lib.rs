@@ -33,16 +33,19 @@ mod account_template {
     }
 
     impl Account {
-        pub fn create(public_key_token: NonFungibleAddress, owner_rule: Option<OwnerRule>, access_rules: Option<AccessRules>, bucket: Option<Bucket>) -> Component<Account> {
+        pub fn create(
+            public_key_token: NonFungibleAddress,
+            owner_rule: Option<OwnerRule>,
+            access_rules: Option<AccessRules>,
+            bucket: Option<Bucket>,
+        ) -> Component<Account> {
             // extract the public key from the token
             // we only allow tokens that correspond to public keys
             let public_key = public_key_token
                 .to_public_key()
                 .unwrap_or_else(|| panic!("public_key_token is not a valid public key: {}", public_key_token));
 
-            let owner_rule = owner_rule.unwrap_or(
-                OwnerRule::ByPublicKey(public_key)
-            );
+            let owner_rule = owner_rule.unwrap_or(OwnerRule::ByPublicKey(public_key));
 
             let access_rules = access_rules.unwrap_or(
                 AccessRules::new()
@@ -52,7 +55,7 @@ mod account_template {
                     .add_method_rule("deposit_all", rule!(allow_all))
                     .add_method_rule("get_non_fungible_ids", rule!(allow_all))
                     // By defaul, only the owner of the token will be able to withdraw funds from the account
-                    .default(rule!(non_fungible(public_key_token)))
+                    .default(rule!(non_fungible(public_key_token))),
             );
 
             // add the funds from the (optional) bucket
@@ -82,33 +85,35 @@ mod account_template {
 
         // #[access_rule(requires(owner_badge))]
         pub fn withdraw(&mut self, resource: ResourceAddress, amount: Amount) -> Bucket {
-            // TODO: clean up hashmap api in emit_event
-            emit_event("withdraw", [
-                ("amount", amount.to_string()),
-                ("resource", resource.to_string()),
-            ]);
+            emit_event(
+                "withdraw",
+                BTreeMap::from([("amount", amount.to_string()), ("resource", resource.to_string())]),
+            );
             let v = self.get_vault_mut(resource);
             v.withdraw(amount)
         }
 
         // #[access_rules(requires(owner_badge))]
         pub fn withdraw_non_fungible(&mut self, resource: ResourceAddress, nf_id: NonFungibleId) -> Bucket {
-            emit_event("withdraw_non_fungible", [
-                ("id", nf_id.to_string()),
-                ("resource", resource.to_string()),
-            ]);
+            emit_event(
+                "withdraw_non_fungible",
+                BTreeMap::from([("id", nf_id.to_string()), ("resource", resource.to_string())]),
+            );
             let v = self.get_vault_mut(resource);
             v.withdraw_non_fungibles([nf_id])
         }
 
         pub fn withdraw_many_non_fungibles(&mut self, resource: ResourceAddress, nf_ids: Vec<NonFungibleId>) -> Bucket {
-            emit_event("withdraw_many_non_fungibles", [
-                ("resource", resource.to_string()),
-                (
-                    "ids",
-                    nf_ids.iter().map(ToString::to_string).collect::<Vec<_>>().join(","),
-                ),
-            ]);
+            emit_event(
+                "withdraw_many_non_fungibles",
+                BTreeMap::from([
+                    ("resource", resource.to_string()),
+                    (
+                        "ids",
+                        nf_ids.iter().map(ToString::to_string).collect::<Vec<_>>().join(","),
+                    ),
+                ]),
+            );
             let v = self.get_vault_mut(resource);
             v.withdraw_non_fungibles(nf_ids)
         }
@@ -119,21 +124,50 @@ mod account_template {
             resource: ResourceAddress,
             withdraw_proof: ConfidentialWithdrawProof,
         ) -> Bucket {
-            emit_event("withdraw_confidential", [
-                ("num_inputs", withdraw_proof.inputs.len().to_string()),
-                ("resource", resource.to_string()),
-            ]);
+            emit_event(
+                "withdraw_confidential",
+                BTreeMap::from([
+                    ("num_inputs", withdraw_proof.inputs.len().to_string()),
+                    ("resource", resource.to_string()),
+                ]),
+            );
 
             let v = self.get_vault_mut(resource);
             v.withdraw_confidential(withdraw_proof)
         }
 
+        /// Withdraws each output of the proof into a separate bucket, so that a single proof can pay many recipients.
+        // #[access_rules(requires(owner_badge))]
+        pub fn withdraw_confidential_many(
+            &mut self,
+            resource: ResourceAddress,
+            withdraw_proof: ConfidentialWithdrawProof,
+        ) -> Vec<Bucket> {
+            emit_event(
+                "withdraw_confidential_many",
+                BTreeMap::from([
+                    ("num_inputs", withdraw_proof.inputs.len().to_string()),
+                    (
+                        "num_outputs",
+                        (withdraw_proof.output_proof.additional_outputs.len() + 1).to_string(),
+                    ),
+                    ("resource", resource.to_string()),
+                ]),
+            );
+
+            let v = self.get_vault_mut(resource);
+            v.withdraw_confidential_many(withdraw_proof)
+        }
+
         // #[access_rules(allow_all)]
         pub fn deposit(&mut self, bucket: Bucket) {
-            emit_event("deposit", [
-                ("amount", bucket.amount().to_string()),
-                ("resource", bucket.resource_address().to_string()),
-            ]);
+            emit_event(
+                "deposit",
+                BTreeMap::from([
+                    ("amount", bucket.amount().to_string()),
+                    ("resource", bucket.resource_address().to_string()),
+                ]),
+            );
             let resource_address = bucket.resource_address();
             let vault_mut = self
                 .vaults
@@ -171,19 +205,25 @@ mod account_template {
         }
 
         pub fn reveal_confidential(&mut self, resource: ResourceAddress, proof: ConfidentialWithdrawProof) -> Bucket {
-            emit_event("reveal_confidential", [
-                ("num_inputs", proof.inputs.len().to_string()),
-                ("resource", resource.to_string()),
-            ]);
+            emit_event(
+                "reveal_confidential",
+                BTreeMap::from([
+                    ("num_inputs", proof.inputs.len().to_string()),
+                    ("resource", resource.to_string()),
+                ]),
+            );
             let v = self.get_vault_mut(resource);
             v.reveal_confidential(proof)
         }
 
         pub fn join_confidential(&mut self, resource: ResourceAddress, proof: ConfidentialWithdrawProof) {
-            emit_event("join_confidential", [
-                ("num_inputs", proof.inputs.len().to_string()),
-                ("resource", resource.to_string()),
-            ]);
+            emit_event(
+                "join_confidential",
+                BTreeMap::from([
+                    ("num_inputs", proof.inputs.len().to_string()),
+                    ("resource", resource.to_string()),
+                ]),
+            );
             self.get_vault_mut(resource).join_confidential(proof);
         }
 
@@ -191,19 +231,25 @@ mod account_template {
 
         /// Pay fees from previously revealed confidential resource.
         pub fn pay_fee(&mut self, amount: Amount) {
-            emit_event("pay_fee", [("amount", amount.to_string())]);
+            emit_event("pay_fee", BTreeMap::from([("amount", amount.to_string())]));
             self.get_vault_mut(CONFIDENTIAL_TARI_RESOURCE_ADDRESS).pay_fee(amount);
         }
 
         /// Reveal confidential tokens and return the revealed bucket to pay fees.
         pub fn pay_fee_confidential(&mut self, proof: ConfidentialWithdrawProof) {
-            emit_event("pay_fee_confidential", [("num_inputs", proof.inputs.len().to_string())]);
+            emit_event(
+                "pay_fee_confidential",
+                BTreeMap::from([("num_inputs", proof.inputs.len().to_string())]),
+            );
             self.get_vault_mut(CONFIDENTIAL_TARI_RESOURCE_ADDRESS)
                 .pay_fee_confidential(proof);
         }
 
         pub fn create_proof_for_resource(&mut self, resource: ResourceAddress) -> Proof {
-            emit_event("create_proof_for_resource", [("resource", resource.to_string())]);
+            emit_event(
+                "create_proof_for_resource",
+                BTreeMap::from([("resource", resource.to_string())]),
+            );
             let v = self.get_vault_mut(resource);
             v.create_proof()
         }
@@ -213,19 +259,22 @@ mod account_template {
             resource: ResourceAddress,
             ids: Vec<NonFungibleId>,
         ) -> Proof {
-            emit_event("create_proof_by_non_fungible_ids", [
-                ("resource", resource.to_string()),
-                ("ids", ids.iter().map(ToString::to_string).collect::<Vec<_>>().join(",")),
-            ]);
+            emit_event(
+                "create_proof_by_non_fungible_ids",
+                BTreeMap::from([
+                    ("resource", resource.to_string()),
+                    ("ids", ids.iter().map(ToString::to_string).collect::<Vec<_>>().join(",")),
+                ]),
+            );
             let v = self.get_vault_mut(resource);
             v.create_proof_by_non_fungible_ids(ids.into_iter().collect())
         }
 
         pub fn create_proof_by_amount(&mut self, resource: ResourceAddress, amount: Amount) -> Proof {
-            emit_event("create_proof_by_amount", [
-                ("resource", resource.to_string()),
-                ("amount", amount.to_string()),
-            ]);
+            emit_event(
+                "create_proof_by_amount",
+                BTreeMap::from([("resource", resource.to_string()), ("amount", amount.to_string())]),
+            );
             let v = self.get_vault_mut(resource);
             v.create_proof_by_amount(amount)
         }
@@ -20,6 +20,8 @@
 //   WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 //   USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use std::collections::BTreeMap;
+
 use tari_template_lib::prelude::*;
 
 #[template]
@@ -61,11 +63,14 @@ mod account_non_fungible_template {
         }
 
         pub fn mint_specific(&mut self, id: NonFungibleId, metadata: Metadata) -> Bucket {
-            emit_event("mint", [
-                ("id".to_string(), id.to_string()),
-                ("metadata".to_string(), metadata.to_string()),
-                ("resource_address".to_string(), self.resource_address.to_string()),
-            ]);
+            emit_event(
+                "mint",
+                BTreeMap::from([
+                    ("id".to_string(), id.to_string()),
+                    ("metadata".to_string(), metadata.to_string()),
+                    ("resource_address".to_string(), self.resource_address.to_string()),
+                ]),
+            );
 
             // Mint the NFT, this will fail if the token ID already exists
             ResourceManager::get(self.resource_address).mint_non_fungible(id, &metadata, &{})
@@ -23,6 +23,7 @@
 use std::collections::{BTreeMap, BTreeSet};
 
 use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, Bytes};
 use tari_template_abi::rust::{
     fmt::{Display, Formatter},
     str::FromStr,
@@ -33,7 +34,14 @@ use ts_rs::TS;
 use crate::{
     args::Arg,
     auth::{AuthHook, OwnerRule, ResourceAccessRules},
-    crypto::{PedersonCommitmentBytes, RistrettoPublicKeyBytes},
+    crypto::{
+        Ed25519PublicKeyBytes,
+        Ed25519SignatureBytes,
+        HashAlgorithm,
+        PedersonCommitmentBytes,
+        RistrettoPublicKeyBytes,
+        SchnorrSignatureBytes,
+    },
     models::{
         AddressAllocation,
         Amount,
@@ -127,6 +135,7 @@ pub enum ComponentAction {
     SetState,
     SetAccessRules,
     GetTemplateAddress,
+    UpgradeTemplate,
 }
 
 /// Encapsulates all the ways that a component can be referenced
@@ -161,6 +170,8 @@ pub struct CreateComponentArg {
     pub owner_rule: OwnerRule,
     pub access_rules: ComponentAccessRules,
     pub address_allocation: Option<AddressAllocation<ComponentAddress>>,
+    #[serde(default)]
+    pub reentrancy_guard: bool,
 }
 
 // -------------------------------- Events -------------------------------- //
@@ -169,7 +180,7 @@ pub struct CreateComponentArg {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct EmitEventArg {
     pub topic: String,
-    pub payload: Metadata,
+    pub payload: tari_bor::Value,
 }
 
 // -------------------------------- Resource -------------------------------- //
@@ -331,6 +342,7 @@ pub enum VaultAction {
     CreateProofByNonFungibles,
     CreateProofByConfidentialResource,
     GetNonFungibles,
+    WithdrawConfidentialMany,
 }
 
 impl VaultAction {
@@ -502,6 +514,38 @@ pub enum GenerateRandomAction {
     GetRandomBytes { len: u32 },
 }
 
+// -------------------------------- Crypto -------------------------------- //
+
+/// A native cryptographic operation argument
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CryptoInvokeArg {
+    pub action: CryptoAction,
+}
+
+/// The possible cryptographic operations that the engine performs natively
+#[serde_as]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum CryptoAction {
+    Hash {
+        algorithm: HashAlgorithm,
+        #[serde_as(as = "Bytes")]
+        data: Vec<u8>,
+    },
+    VerifySchnorrSignature {
+        public_key: RistrettoPublicKeyBytes,
+        public_nonce: RistrettoPublicKeyBytes,
+        signature: SchnorrSignatureBytes,
+        #[serde_as(as = "Bytes")]
+        message: Vec<u8>,
+    },
+    VerifyEd25519Signature {
+        public_key: Ed25519PublicKeyBytes,
+        signature: Ed25519SignatureBytes,
+        #[serde_as(as = "Bytes")]
+        message: Vec<u8>,
+    },
+}
+
 // -------------------------------- CallerContext -------------------------------- //
 
 /// A caller context operation argument
@@ -112,6 +112,10 @@ pub struct ComponentAccessRules {
     #[cfg_attr(feature = "ts", ts(type = "Record<string, AccessRule>"))]
     method_access: BTreeMap<String, AccessRule>,
     default: AccessRule,
+    /// Who, in addition to the owner, must authorize upgrading the template of the component. Components created
+    /// before this rule existed cannot be upgraded.
+    #[serde(default = "deny_all")]
+    upgrade_template: AccessRule,
 }
 
 impl ComponentAccessRules {
@@ -121,6 +125,7 @@ impl ComponentAccessRules {
         Self {
             method_access: BTreeMap::new(),
             default: AccessRule::DenyAll,
+            upgrade_template: AccessRule::DenyAll,
         }
     }
 
@@ -130,6 +135,7 @@ impl ComponentAccessRules {
         Self {
             method_access: BTreeMap::new(),
             default: AccessRule::AllowAll,
+            upgrade_template: AccessRule::DenyAll,
         }
     }
 
@@ -145,17 +151,33 @@ impl ComponentAccessRules {
         self
     }
 
+    /// Set up the access rule for upgrading the template of the component. The component owner must authorize the
+    /// upgrade as well. By default, components cannot be upgraded.
+    pub fn upgrade_template_rule(mut self, rule: AccessRule) -> Self {
+        self.upgrade_template = rule;
+        self
+    }
+
     /// Return the access rule of a particular method in the component
     pub fn get_method_access_rule(&self, name: &str) -> &AccessRule {
         self.method_access.get(name).unwrap_or(&self.default)
     }
 
+    /// Return the access rule for upgrading the template of the component
+    pub fn get_upgrade_template_rule(&self) -> &AccessRule {
+        &self.upgrade_template
+    }
+
     /// Return an iterator over the access rules of all methods
     pub fn method_access_rules_iter(&self) -> impl Iterator<Item = (&String, &AccessRule)> {
         self.method_access.iter()
     }
 }
 
+fn deny_all() -> AccessRule {
+    AccessRule::DenyAll
+}
+
 impl Default for ComponentAccessRules {
     fn default() -> Self {
         Self::new()
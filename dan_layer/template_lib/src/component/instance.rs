@@ -18,6 +18,7 @@ pub struct ComponentBuilder<T> {
     access_rules: ComponentAccessRules,
     public_key_address: Option<RistrettoPublicKeyBytes>,
     address_allocation: Option<AddressAllocation<ComponentAddress>>,
+    reentrancy_guard: bool,
 }
 
 impl<T> ComponentBuilder<T> {
@@ -29,6 +30,7 @@ impl<T> ComponentBuilder<T> {
             access_rules: ComponentAccessRules::new(),
             public_key_address: None,
             address_allocation: None,
+            reentrancy_guard: false,
         }
     }
 
@@ -55,6 +57,13 @@ impl<T> ComponentBuilder<T> {
         self.access_rules = access_rules;
         self
     }
+
+    /// Prevents the component's methods from being re-entered, i.e. called (directly or via another component) while a
+    /// call to the component is already in progress
+    pub fn with_reentrancy_guard(mut self) -> Self {
+        self.reentrancy_guard = true;
+        self
+    }
 }
 
 impl<T: serde::Serialize> ComponentBuilder<T> {
@@ -70,7 +79,13 @@ impl<T: serde::Serialize> ComponentBuilder<T> {
             .map(|pk| CallerContext::allocate_component_address(Some(pk)))
             .or(self.address_allocation);
 
-        let address = engine().create_component(self.component, self.owner_rule, self.access_rules, address_allocation);
+        let address = engine().create_component(
+            self.component,
+            self.owner_rule,
+            self.access_rules,
+            address_allocation,
+            self.reentrancy_guard,
+        );
         Component::from_address(address)
     }
 }
@@ -122,6 +122,24 @@ impl ComponentManager {
         });
     }
 
+    /// Upgrades the component to use the template at `template_address`. The upgrade must be authorized by the
+    /// component owner and by the upgrade rule in the component's access rules.
+    ///
+    /// The new template must provide every method of the current template with the same arguments and return type.
+    /// If the new template defines a `migrate` function, it is called with the current state of the component and
+    /// must return the template struct (by name, not `Self`) that becomes the new state. Otherwise the state is kept
+    /// as is.
+    ///
+    /// The component state cannot be updated after the upgrade in the same call, so this must be called from a method
+    /// that does not take `&mut self`. It will panic if the upgrade is not allowed.
+    pub fn upgrade_template(&self, template_address: TemplateAddress) {
+        call_engine::<_, InvokeResult>(EngineOp::ComponentInvoke, &ComponentInvokeArg {
+            component_ref: ComponentRef::Ref(self.address),
+            action: ComponentAction::UpgradeTemplate,
+            args: invoke_args![template_address],
+        });
+    }
+
     /// Returns the template address of the component that is being managed
     pub fn get_template_address(&self) -> TemplateAddress {
         let result = call_engine::<_, InvokeResult>(EngineOp::ComponentInvoke, &ComponentInvokeArg {
@@ -0,0 +1,130 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, Bytes};
+use tari_template_abi::rust::ops::Deref;
+
+use crate::crypto::InvalidByteLengthError;
+
+/// The bytes of a compressed Ed25519 public key
+#[serde_as]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Ed25519PublicKeyBytes(#[serde_as(as = "Bytes")] [u8; Ed25519PublicKeyBytes::length()]);
+
+impl Ed25519PublicKeyBytes {
+    pub const fn length() -> usize {
+        32
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, InvalidByteLengthError> {
+        if bytes.len() != Self::length() {
+            return Err(InvalidByteLengthError {
+                size: bytes.len(),
+                expected: Self::length(),
+            });
+        }
+
+        let mut key = [0u8; Self::length()];
+        key.copy_from_slice(bytes);
+        Ok(Self(key))
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn into_array(self) -> [u8; Self::length()] {
+        self.0
+    }
+}
+
+impl TryFrom<&[u8]> for Ed25519PublicKeyBytes {
+    type Error = InvalidByteLengthError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_bytes(value)
+    }
+}
+
+impl AsRef<[u8]> for Ed25519PublicKeyBytes {
+    fn as_ref(&self) -> &[u8] {
+        self.deref().as_ref()
+    }
+}
+
+impl Deref for Ed25519PublicKeyBytes {
+    type Target = [u8; Self::length()];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<[u8; Ed25519PublicKeyBytes::length()]> for Ed25519PublicKeyBytes {
+    fn from(bytes: [u8; Ed25519PublicKeyBytes::length()]) -> Self {
+        Self(bytes)
+    }
+}
+
+/// The bytes of an Ed25519 signature (R || s)
+#[serde_as]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Ed25519SignatureBytes(#[serde_as(as = "Bytes")] [u8; Ed25519SignatureBytes::length()]);
+
+impl Ed25519SignatureBytes {
+    pub const fn length() -> usize {
+        64
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, InvalidByteLengthError> {
+        if bytes.len() != Self::length() {
+            return Err(InvalidByteLengthError {
+                size: bytes.len(),
+                expected: Self::length(),
+            });
+        }
+
+        let mut sig = [0u8; Self::length()];
+        sig.copy_from_slice(bytes);
+        Ok(Self(sig))
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn into_array(self) -> [u8; Self::length()] {
+        self.0
+    }
+}
+
+impl TryFrom<&[u8]> for Ed25519SignatureBytes {
+    type Error = InvalidByteLengthError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_bytes(value)
+    }
+}
+
+impl AsRef<[u8]> for Ed25519SignatureBytes {
+    fn as_ref(&self) -> &[u8] {
+        self.deref().as_ref()
+    }
+}
+
+impl Deref for Ed25519SignatureBytes {
+    type Target = [u8; Self::length()];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<[u8; Ed25519SignatureBytes::length()]> for Ed25519SignatureBytes {
+    fn from(bytes: [u8; Ed25519SignatureBytes::length()]) -> Self {
+        Self(bytes)
+    }
+}
@@ -1,16 +1,20 @@
 //   Copyright 2023 The Tari Project
 //   SPDX-License-Identifier: BSD-3-Clause
 
-//! Cryptography utilities related to public keys and balance proofs
+//! Cryptography utilities related to public keys, balance proofs, hashing and signature verification
 
 mod balance_proof;
 mod commitment;
+mod ed25519;
 mod error;
+mod native;
 mod ristretto;
 mod schnorr;
 
 pub use balance_proof::*;
 pub use commitment::*;
+pub use ed25519::*;
 pub use error::*;
+pub use native::*;
 pub use ristretto::*;
 pub use schnorr::*;
@@ -0,0 +1,94 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+//! Hashing and signature verification that is executed natively by the engine. These are much cheaper than
+//! performing the same computation in WASM and are charged according to the fee table.
+
+use serde::{Deserialize, Serialize};
+use tari_template_abi::{call_engine, rust::fmt, EngineOp};
+
+use crate::{
+    args::{CryptoAction, CryptoInvokeArg, InvokeResult},
+    crypto::{Ed25519PublicKeyBytes, Ed25519SignatureBytes, RistrettoPublicKeyBytes, SchnorrSignatureBytes},
+    Hash,
+};
+
+/// The hash functions that the engine provides. All of them produce a 32-byte digest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HashAlgorithm {
+    Blake2b256,
+    Sha256,
+    Keccak256,
+}
+
+impl fmt::Display for HashAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HashAlgorithm::Blake2b256 => write!(f, "Blake2b-256"),
+            HashAlgorithm::Sha256 => write!(f, "SHA-256"),
+            HashAlgorithm::Keccak256 => write!(f, "Keccak-256"),
+        }
+    }
+}
+
+/// Hashes the data with the given algorithm
+pub fn hash(algorithm: HashAlgorithm, data: &[u8]) -> Hash {
+    let resp: InvokeResult = call_engine(EngineOp::CryptoInvoke, &CryptoInvokeArg {
+        action: CryptoAction::Hash {
+            algorithm,
+            data: data.to_vec(),
+        },
+    });
+    resp.decode().expect("Crypto Hash returned invalid hash")
+}
+
+/// Returns the unkeyed Blake2b hash of the data with a 32-byte output
+pub fn blake2b_256(data: &[u8]) -> Hash {
+    hash(HashAlgorithm::Blake2b256, data)
+}
+
+pub fn sha256(data: &[u8]) -> Hash {
+    hash(HashAlgorithm::Sha256, data)
+}
+
+/// Returns the Keccak-256 hash of the data, as used by Ethereum. This differs from the standardised SHA3-256.
+pub fn keccak256(data: &[u8]) -> Hash {
+    hash(HashAlgorithm::Keccak256, data)
+}
+
+/// Verifies a Ristretto Schnorr signature of the message that was created with the default Tari signature domain, for
+/// example by `RistrettoSchnorr::sign`. Returns false if the signature or any of the keys are invalid.
+pub fn verify_schnorr_signature(
+    public_key: &RistrettoPublicKeyBytes,
+    public_nonce: &RistrettoPublicKeyBytes,
+    signature: &SchnorrSignatureBytes,
+    message: &[u8],
+) -> bool {
+    let resp: InvokeResult = call_engine(EngineOp::CryptoInvoke, &CryptoInvokeArg {
+        action: CryptoAction::VerifySchnorrSignature {
+            public_key: *public_key,
+            public_nonce: *public_nonce,
+            signature: *signature,
+            message: message.to_vec(),
+        },
+    });
+    resp.decode()
+        .expect("Crypto VerifySchnorrSignature returned invalid result")
+}
+
+/// Verifies an Ed25519 signature of the message. Returns false if the signature or the public key are invalid.
+pub fn verify_ed25519_signature(
+    public_key: &Ed25519PublicKeyBytes,
+    signature: &Ed25519SignatureBytes,
+    message: &[u8],
+) -> bool {
+    let resp: InvokeResult = call_engine(EngineOp::CryptoInvoke, &CryptoInvokeArg {
+        action: CryptoAction::VerifyEd25519Signature {
+            public_key: *public_key,
+            signature: *signature,
+            message: message.to_vec(),
+        },
+    });
+    resp.decode()
+        .expect("Crypto VerifyEd25519Signature returned invalid result")
+}
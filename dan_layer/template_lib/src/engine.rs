@@ -56,6 +56,7 @@ impl TariEngine {
         owner_rule: OwnerRule,
         access_rules: ComponentAccessRules,
         address_allocation: Option<AddressAllocation<ComponentAddress>>,
+        reentrancy_guard: bool,
     ) -> ComponentAddress {
         let encoded_state = to_value(&initial_state).unwrap();
 
@@ -66,7 +67,8 @@ impl TariEngine {
                 encoded_state,
                 owner_rule,
                 access_rules,
-                address_allocation
+                address_allocation,
+                reentrancy_guard
             }],
         });
 
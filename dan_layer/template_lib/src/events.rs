@@ -22,14 +22,18 @@
 
 //! A wrapper for engine calls related to events
 
+use serde::Serialize;
+use tari_bor::to_value;
 use tari_template_abi::{call_engine, EngineOp};
 
-use crate::{args::EmitEventArg, models::Metadata};
+use crate::args::EmitEventArg;
 
-/// Requests the engine to emit an event that will be permanently recorded in the transaction result
-pub fn emit_event<T: Into<String>, P: Into<Metadata>>(topic: T, payload: P) {
+/// Requests the engine to emit an event that will be permanently recorded in the transaction result.
+/// The payload can be any serializable type and is CBOR-encoded. Templates can describe the payload type of a topic in
+/// their ABI by marking the payload struct with `#[event(topic = "...")]`.
+pub fn emit_event<T: Into<String>, P: Serialize>(topic: T, payload: P) {
     call_engine::<_, ()>(EngineOp::EmitEvent, &EmitEventArg {
         topic: topic.into(),
-        payload: payload.into(),
+        payload: to_value(&payload).expect("failed to encode event payload"),
     });
 }
@@ -28,6 +28,11 @@ pub struct ConfidentialOutputStatement {
     pub output_revealed_amount: Amount,
     /// The amount of revealed funds to return to the sender
     pub change_revealed_amount: Amount,
+    /// Outputs in addition to the output statement and output revealed amount. These are only allowed in withdraw
+    /// proofs given to `Vault::withdraw_confidential_many`, which withdraws each output into a separate bucket. The
+    /// range proof covers the commitments of the output, change and additional output statements in that order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub additional_outputs: Vec<AdditionalOutputStatement>,
 }
 
 impl ConfidentialOutputStatement {
@@ -39,8 +44,36 @@ impl ConfidentialOutputStatement {
             range_proof: vec![],
             output_revealed_amount: amount.into(),
             change_revealed_amount: Amount::zero(),
+            additional_outputs: vec![],
         }
     }
+
+    /// Returns an iterator over the output statement, the change statement and the statements of the additional
+    /// outputs, in the order that they are covered by the range proof.
+    pub fn statements(&self) -> impl Iterator<Item = &ConfidentialStatement> + '_ {
+        self.output_statement
+            .iter()
+            .chain(self.change_statement.iter())
+            .chain(self.additional_outputs.iter().filter_map(|o| o.statement.as_ref()))
+    }
+
+    /// The sum of the revealed amounts of the output, change and additional outputs, or None if the sum overflows
+    pub fn total_revealed_amount(&self) -> Option<Amount> {
+        self.additional_outputs.iter().try_fold(
+            self.output_revealed_amount.checked_add(self.change_revealed_amount)?,
+            |acc, o| acc.checked_add(o.revealed_amount),
+        )
+    }
+}
+
+/// An output of a withdraw proof in addition to its primary output, paid into a separate bucket
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(TS), ts(export, export_to = "../../bindings/src/types/"))]
+pub struct AdditionalOutputStatement {
+    /// Proof of the confidential resources of this output
+    pub statement: Option<ConfidentialStatement>,
+    /// The amount of revealed funds of this output
+    pub revealed_amount: Amount,
 }
 
 /// A zero-knowledge proof that a confidential resource amount is valid
@@ -177,7 +210,7 @@ impl ConfidentialWithdrawProof {
     /// The method for determining this is strict, as this can be used to determine whether to
     /// safely skip the balance proof check. To return true it requires:
     /// - Empty inputs
-    /// - Output and Change outputs must be None
+    /// - Output, change and additional output statements must be None
     /// - Empty range proof
     /// - Zero balance proof
     /// - Revealed funds > 0 in the inputs and outputs
@@ -186,13 +219,14 @@ impl ConfidentialWithdrawProof {
         self.output_proof.range_proof.is_empty() &&
         // Excess will be zero
         self.inputs.is_empty() &&
-            self.output_proof.output_statement.is_none() &&
-            self.output_proof.change_statement.is_none() &&
+            self.output_proof.statements().next().is_none() &&
             // zero balance proof
             self.balance_proof == BalanceProofSignature::zero() &&
             // There are revealed funds
             self.input_revealed_amount > Amount::zero() &&
-            self.output_proof.output_revealed_amount + self.output_proof.change_revealed_amount > Amount::zero()
+            self.output_proof
+                .total_revealed_amount()
+                .is_some_and(|amount| amount > Amount::zero())
     }
 
     pub fn revealed_input_amount(&self) -> Amount {
@@ -244,6 +244,20 @@ impl Vault {
         resp.decode().expect("failed to decode Bucket")
     }
 
+    /// Withdraws the outputs of a confidential withdraw `proof` from the vault into one bucket per output. The first
+    /// bucket contains the output statement and output revealed amount of the proof, followed by one bucket for each
+    /// of the proof's additional outputs in order.
+    /// It will panic if the proof is invalid or there are not enough tokens in the vault
+    pub fn withdraw_confidential_many(&self, proof: ConfidentialWithdrawProof) -> Vec<Bucket> {
+        let resp: InvokeResult = call_engine(EngineOp::VaultInvoke, &VaultInvokeArg {
+            vault_ref: self.vault_ref(),
+            action: VaultAction::WithdrawConfidentialMany,
+            args: invoke_args![proof],
+        });
+
+        resp.decode().expect("failed to decode Vec<Bucket>")
+    }
+
     /// Withdraws all fungible, non-fungible and revealed confidential amounts from the vault into a new bucket.
     /// NOTE: blinded confidential amounts are not withdrawn as these require a `ConfidentialWithdrawProof`.
     pub fn withdraw_all(&mut self) -> Bucket {
@@ -20,26 +20,81 @@
 //  WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 //  USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use std::collections::BTreeSet;
+
 use proc_macro2::TokenStream;
-use quote::{format_ident, quote};
-use syn::{AngleBracketedGenericArguments, GenericArgument, PathArguments, PathSegment, Result, Type, TypeTuple};
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    AngleBracketedGenericArguments,
+    Attribute,
+    Fields,
+    GenericArgument,
+    Lit,
+    Meta,
+    NestedMeta,
+    PathArguments,
+    PathSegment,
+    Result,
+    Type,
+    TypeTuple,
+};
 use tari_template_abi::{
     ArgDef,
+    EventDef,
+    FieldDef,
+    FieldsDef,
     FunctionDef,
     TemplateDef,
     TemplateDefV1,
     Type as ArgType,
+    TypeDef,
+    TypeDefKind,
+    VariantDef,
     ABI_TEMPLATE_DEF_GLOBAL_NAME,
 };
 
-use crate::template::ast::{TemplateAst, TypeAst};
+use crate::template::ast::{TemplateAst, TypeAst, TypeDefAst};
 
 pub const TARI_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// Types provided by tari_template_lib that may be used in template functions and types. These are included in the
+/// ABI as engine types, clients are expected to know how to encode them.
+const ENGINE_TYPES: &[&str] = &[
+    "AccessRule",
+    "AddressAllocation",
+    "Amount",
+    "Bucket",
+    "BucketId",
+    "ComponentAccessRules",
+    "ComponentAddress",
+    "ConfidentialOutputStatement",
+    "ConfidentialWithdrawProof",
+    "Ed25519PublicKeyBytes",
+    "Ed25519SignatureBytes",
+    "Hash",
+    "Metadata",
+    "NonFungible",
+    "NonFungibleAddress",
+    "NonFungibleId",
+    "OwnerRule",
+    "PedersonCommitmentBytes",
+    "Proof",
+    "ProofId",
+    "ResourceAccessRules",
+    "ResourceAddress",
+    "ResourceType",
+    "RistrettoPublicKeyBytes",
+    "SchnorrSignatureBytes",
+    "TemplateAddress",
+    "UnclaimedConfidentialOutputAddress",
+    "Vault",
+    "VaultId",
+];
+
 pub fn generate_abi(ast: &TemplateAst) -> Result<TokenStream> {
     let template_name_as_str = ast.template_name.to_string();
 
-    let template_def = TemplateDef::V1(TemplateDefV1 {
+    let mut template_def = TemplateDefV1 {
         template_name: template_name_as_str.clone(),
         tari_version: TARI_VERSION.to_owned(),
         functions: ast
@@ -57,12 +112,31 @@ pub fn generate_abi(ast: &TemplateAst) -> Result<TokenStream> {
                         .output_type
                         .as_ref()
                         .map(|ty| convert_to_arg_type(&template_name_as_str, ty))
+                        .transpose()?
                         .unwrap_or(ArgType::Unit),
                     is_mut,
                 })
             })
             .collect::<Result<_>>()?,
-    });
+        events: ast
+            .events
+            .iter()
+            .map(|event| EventDef {
+                topic: event.topic.clone(),
+                payload_type: ArgType::Other {
+                    name: event.payload_type.to_string(),
+                },
+            })
+            .collect(),
+        types: ast
+            .types
+            .iter()
+            .map(|ty| convert_to_type_def(&template_name_as_str, ty))
+            .collect::<Result<_>>()?,
+    };
+    let referenced_types = referenced_type_defs(&template_name_as_str, &template_def);
+    template_def.types.extend(referenced_types);
+    let template_def = TemplateDef::V1(template_def);
 
     let template_def_data = tari_bor::encode_with_len(&template_def);
     let len = template_def_data.len();
@@ -76,15 +150,15 @@ pub fn generate_abi(ast: &TemplateAst) -> Result<TokenStream> {
     Ok(output)
 }
 
-fn convert_to_arg_type(template_name: &str, ty: &TypeAst) -> ArgType {
+fn convert_to_arg_type(template_name: &str, ty: &TypeAst) -> Result<ArgType> {
     match ty {
-        TypeAst::Receiver { mutability: true } => ArgType::Other {
+        TypeAst::Receiver { mutability: true } => Ok(ArgType::Other {
             name: "&mut self".to_string(),
-        },
-        TypeAst::Receiver { mutability: false } => ArgType::Other {
+        }),
+        TypeAst::Receiver { mutability: false } => Ok(ArgType::Other {
             name: "&self".to_string(),
-        },
-        TypeAst::Typed { type_path, .. } => path_segment_to_arg_type(template_name, &type_path.path.segments[0]),
+        }),
+        TypeAst::Typed { type_path, .. } => path_to_arg_type(template_name, &type_path.path),
         TypeAst::Tuple { type_tuple, .. } => tuple_to_arg_type(template_name, type_tuple),
     }
 }
@@ -116,7 +190,7 @@ fn convert_to_arg_def(template_name: &str, rust_type: &TypeAst) -> Result<ArgDef
                 ));
             };
 
-            let arg_type = path_segment_to_arg_type(template_name, &path.path.segments[0]);
+            let arg_type = path_to_arg_type(template_name, &path.path)?;
 
             Ok(ArgDef {
                 name: arg_name.to_string(),
@@ -133,7 +207,7 @@ fn convert_to_arg_def(template_name: &str, rust_type: &TypeAst) -> Result<ArgDef
                     "convert_to_arg_def: Unnamed type is not valid in this context",
                 ));
             };
-            let arg_type = tuple_to_arg_type(template_name, type_tuple);
+            let arg_type = tuple_to_arg_type(template_name, type_tuple)?;
             Ok(ArgDef {
                 name: arg_name.to_string(),
                 arg_type,
@@ -142,8 +216,234 @@ fn convert_to_arg_def(template_name: &str, rust_type: &TypeAst) -> Result<ArgDef
     }
 }
 
-fn path_segment_to_arg_type(template_name: &str, segment: &PathSegment) -> ArgType {
-    match segment.ident.to_string().as_str() {
+fn convert_to_type_def(template_name: &str, ty: &TypeDefAst) -> Result<TypeDef> {
+    let kind = match ty {
+        TypeDefAst::Struct(item) => {
+            // The name of the type is not encoded, so a container rename does not change the encoding
+            parse_serde_attrs(&item.attrs)?;
+            TypeDefKind::Struct {
+                fields: fields_to_def(template_name, &item.fields)?,
+            }
+        },
+        TypeDefAst::Enum(item) => {
+            parse_serde_attrs(&item.attrs)?;
+            let mut variants = Vec::with_capacity(item.variants.len());
+            for variant in &item.variants {
+                let attrs = parse_serde_attrs(&variant.attrs)?;
+                if attrs.skip {
+                    continue;
+                }
+                variants.push(VariantDef {
+                    name: attrs.rename.unwrap_or_else(|| variant.ident.to_string()),
+                    fields: fields_to_def(template_name, &variant.fields)?,
+                });
+            }
+            TypeDefKind::Enum { variants }
+        },
+    };
+
+    Ok(TypeDef {
+        name: ty.name().to_string(),
+        kind,
+    })
+}
+
+fn fields_to_def(template_name: &str, fields: &Fields) -> Result<FieldsDef> {
+    match fields {
+        Fields::Named(fields) => {
+            let mut defs = Vec::with_capacity(fields.named.len());
+            for field in &fields.named {
+                let attrs = parse_serde_attrs(&field.attrs)?;
+                if attrs.skip {
+                    continue;
+                }
+                defs.push(FieldDef {
+                    name: attrs
+                        .rename
+                        .unwrap_or_else(|| field.ident.as_ref().map(|i| i.to_string()).unwrap_or_default()),
+                    field_type: syn_type_to_arg_type(template_name, &field.ty)?,
+                });
+            }
+            Ok(FieldsDef::Named(defs))
+        },
+        Fields::Unnamed(fields) => {
+            let mut types = Vec::with_capacity(fields.unnamed.len());
+            for field in &fields.unnamed {
+                let attrs = parse_serde_attrs(&field.attrs)?;
+                if attrs.skip {
+                    return Err(syn::Error::new_spanned(
+                        field,
+                        "#[serde(skip)] is not supported on unnamed fields of template types",
+                    ));
+                }
+                types.push(syn_type_to_arg_type(template_name, &field.ty)?);
+            }
+            Ok(FieldsDef::Unnamed(types))
+        },
+        Fields::Unit => Ok(FieldsDef::Unit),
+    }
+}
+
+/// The serde attributes of a template type, field or variant that change how it is described in the ABI
+#[derive(Default)]
+struct SerdeAttrs {
+    rename: Option<String>,
+    skip: bool,
+}
+
+/// Serde attributes that do not change the encoding of a type
+const ENCODING_NEUTRAL_SERDE_ATTRS: &[&str] =
+    &["alias", "bound", "crate", "default", "deny_unknown_fields", "expecting"];
+
+/// Parses the `#[serde(...)]` attributes. Attributes that change the encoding in a way that cannot be described in
+/// the ABI (e.g. `rename_all`, `tag` or `flatten`) are a compile error.
+fn parse_serde_attrs(attrs: &[Attribute]) -> Result<SerdeAttrs> {
+    let mut serde_attrs = SerdeAttrs::default();
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("serde")) {
+        let Meta::List(list) = attr.parse_meta()? else {
+            return Err(syn::Error::new_spanned(attr, "Expected #[serde(...)]"));
+        };
+        for nested in &list.nested {
+            match nested {
+                NestedMeta::Meta(Meta::NameValue(name_value)) if name_value.path.is_ident("rename") => {
+                    let Lit::Str(rename) = &name_value.lit else {
+                        return Err(syn::Error::new_spanned(&name_value.lit, "Expected a string literal"));
+                    };
+                    serde_attrs.rename = Some(rename.value());
+                },
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("skip") => {
+                    serde_attrs.skip = true;
+                },
+                NestedMeta::Meta(meta)
+                    if ENCODING_NEUTRAL_SERDE_ATTRS
+                        .iter()
+                        .any(|neutral| meta.path().is_ident(neutral)) => {},
+                nested => {
+                    return Err(syn::Error::new_spanned(
+                        nested,
+                        "This serde attribute is not supported in template types because it cannot be described in \
+                         the template ABI",
+                    ))
+                },
+            }
+        }
+    }
+    Ok(serde_attrs)
+}
+
+/// Returns definitions for the referenced types that are not declared in the template module. Engine types are
+/// described as such. Any other type is declared elsewhere (e.g. in another module or crate), where the macro cannot
+/// see its definition, so it is described as external.
+fn referenced_type_defs(template_name: &str, template_def: &TemplateDefV1) -> Vec<TypeDef> {
+    let mut names = BTreeSet::new();
+    for func in &template_def.functions {
+        for arg in &func.arguments {
+            collect_type_names(&arg.arg_type, &mut names);
+        }
+        collect_type_names(&func.output, &mut names);
+    }
+    for event in &template_def.events {
+        collect_type_names(&event.payload_type, &mut names);
+    }
+    for ty in &template_def.types {
+        match &ty.kind {
+            TypeDefKind::Struct { fields } => collect_field_type_names(fields, &mut names),
+            TypeDefKind::Enum { variants } => variants
+                .iter()
+                .for_each(|variant| collect_field_type_names(&variant.fields, &mut names)),
+            TypeDefKind::Engine => {},
+        }
+    }
+
+    let component_type = format!("Component<{}>", template_name);
+    names
+        .into_iter()
+        .filter(|name| {
+            template_def.get_type(name).is_none() &&
+                !matches!(*name, "&self" | "&mut self" | "Component") &&
+                *name != component_type
+        })
+        .map(|name| TypeDef {
+            name: name.to_string(),
+            kind: if ENGINE_TYPES.contains(&name) {
+                TypeDefKind::Engine
+            } else {
+                TypeDefKind::External
+            },
+        })
+        .collect()
+}
+
+fn collect_field_type_names<'a>(fields: &'a FieldsDef, names: &mut BTreeSet<&'a str>) {
+    match fields {
+        FieldsDef::Named(fields) => fields
+            .iter()
+            .for_each(|field| collect_type_names(&field.field_type, names)),
+        FieldsDef::Unnamed(types) => types.iter().for_each(|ty| collect_type_names(ty, names)),
+        FieldsDef::Unit => {},
+    }
+}
+
+fn collect_type_names<'a>(ty: &'a ArgType, names: &mut BTreeSet<&'a str>) {
+    match ty {
+        ArgType::Vec(ty) | ArgType::Option(ty) => collect_type_names(ty, names),
+        ArgType::Map(key, value) => {
+            collect_type_names(key, names);
+            collect_type_names(value, names);
+        },
+        ArgType::Tuple(types) => types.iter().for_each(|ty| collect_type_names(ty, names)),
+        ArgType::Other { name } => {
+            names.insert(name.as_str());
+        },
+        _ => {},
+    }
+}
+
+fn syn_type_to_arg_type(template_name: &str, ty: &Type) -> Result<ArgType> {
+    match ty {
+        Type::Path(path) => path_to_arg_type(template_name, &path.path),
+        Type::Tuple(tuple) if tuple.elems.is_empty() => Ok(ArgType::Unit),
+        Type::Tuple(tuple) => tuple_to_arg_type(template_name, tuple),
+        Type::Array(array) => Ok(ArgType::Vec(Box::new(syn_type_to_arg_type(
+            template_name,
+            &array.elem,
+        )?))),
+        Type::Slice(slice) => Ok(ArgType::Vec(Box::new(syn_type_to_arg_type(
+            template_name,
+            &slice.elem,
+        )?))),
+        Type::Reference(reference) => syn_type_to_arg_type(template_name, &reference.elem),
+        Type::Paren(paren) => syn_type_to_arg_type(template_name, &paren.elem),
+        Type::Group(group) => syn_type_to_arg_type(template_name, &group.elem),
+        ty => Ok(ArgType::Other {
+            name: ty.to_token_stream().to_string(),
+        }),
+    }
+}
+
+fn path_to_arg_type(template_name: &str, path: &syn::Path) -> Result<ArgType> {
+    // Use the last segment so that qualified types (e.g. std::collections::BTreeMap) are recognised
+    match path.segments.last() {
+        Some(segment) => path_segment_to_arg_type(template_name, segment),
+        None => Ok(ArgType::Unit),
+    }
+}
+
+fn generic_type_args(segment: &PathSegment) -> Vec<&Type> {
+    match &segment.arguments {
+        PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. }) => args
+            .iter()
+            .filter_map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+        PathArguments::Parenthesized(_) | PathArguments::None => vec![],
+    }
+}
+
+fn path_segment_to_arg_type(template_name: &str, segment: &PathSegment) -> Result<ArgType> {
+    let arg_type = match segment.ident.to_string().as_str() {
         "" => ArgType::Unit,
         "bool" => ArgType::Bool,
         "i8" => ArgType::I8,
@@ -157,46 +457,50 @@ fn path_segment_to_arg_type(template_name: &str, segment: &PathSegment) -> ArgTy
         "u64" => ArgType::U64,
         "u128" => ArgType::U128,
         "String" => ArgType::String,
-        "Vec" => {
-            match &segment.arguments {
-                PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. }) => {
-                    match &args[0] {
-                        GenericArgument::Type(Type::Path(path)) => {
-                            let ty = path_segment_to_arg_type(template_name, &path.path.segments[0]);
-                            ArgType::Vec(Box::new(ty))
-                        },
-                        GenericArgument::Type(Type::Tuple(tuple)) => tuple_to_arg_type(template_name, tuple),
-                        // TODO: These should be errors
-                        a => panic!("Invalid vec generic argument {:?}", a),
-                    }
-                },
-                PathArguments::Parenthesized(_) | PathArguments::None => {
-                    panic!("Vec must specify a type {:?}", segment)
-                },
+        "Vec" | "Option" | "Box" => {
+            let args = generic_type_args(segment);
+            let Some(ty) = args.first() else {
+                return Err(syn::Error::new_spanned(
+                    segment,
+                    format!("{} must specify a type", segment.ident),
+                ));
+            };
+            let ty = syn_type_to_arg_type(template_name, ty)?;
+            match segment.ident.to_string().as_str() {
+                "Vec" => ArgType::Vec(Box::new(ty)),
+                "Option" => ArgType::Option(Box::new(ty)),
+                _ => ty,
             }
         },
+        "HashMap" | "BTreeMap" | "IndexMap" => {
+            let args = generic_type_args(segment);
+            let [key, value, ..] = args.as_slice() else {
+                return Err(syn::Error::new_spanned(
+                    segment,
+                    format!("{} must specify a key and value type", segment.ident),
+                ));
+            };
+            ArgType::Map(
+                Box::new(syn_type_to_arg_type(template_name, key)?),
+                Box::new(syn_type_to_arg_type(template_name, value)?),
+            )
+        },
         "Self" => ArgType::Other {
             name: format!("Component<{}>", template_name),
         },
         type_name => ArgType::Other {
             name: type_name.to_string(),
         },
-    }
+    };
+    Ok(arg_type)
 }
 
-fn tuple_to_arg_type(template_name: &str, tuple: &TypeTuple) -> ArgType {
+fn tuple_to_arg_type(template_name: &str, tuple: &TypeTuple) -> Result<ArgType> {
     let subtypes = tuple
         .elems
         .iter()
-        .map(|t| {
-            match t {
-                Type::Path(path) => path_segment_to_arg_type(template_name, &path.path.segments[0]),
-                Type::Tuple(subtuple) => tuple_to_arg_type(template_name, subtuple),
-                // TODO: These should be errors
-                a => panic!("Invalid Tuple subtype argument {:?}", a),
-            }
-        })
-        .collect::<Vec<_>>();
+        .map(|t| syn_type_to_arg_type(template_name, t))
+        .collect::<Result<_>>()?;
 
-    ArgType::Tuple(subtypes)
+    Ok(ArgType::Tuple(subtypes))
 }
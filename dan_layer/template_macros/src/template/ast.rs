@@ -26,14 +26,20 @@ use syn::{
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
     token::Comma,
+    Attribute,
     Error,
     FnArg,
     Ident,
     ImplItem,
     ImplItemMethod,
     Item,
+    ItemEnum,
     ItemMod,
+    ItemStruct,
     ItemUse,
+    Lit,
+    Meta,
+    NestedMeta,
     Result,
     ReturnType,
     TypePath,
@@ -46,6 +52,8 @@ pub struct TemplateAst {
     pub template_name: Ident,
     pub module_content: Vec<Item>,
     pub uses: Vec<ItemUse>,
+    pub events: Vec<EventAst>,
+    pub types: Vec<TypeDefAst>,
 }
 
 impl Parse for TemplateAst {
@@ -63,13 +71,25 @@ impl Parse for TemplateAst {
         let mut template_name = None;
         let mut has_impl = false;
         let mut uses = Vec::new();
+        let mut events = Vec::new();
+        let mut types = Vec::new();
 
         for item in items {
             match item {
                 Item::Struct(ref mut item) => {
+                    let event_topic = take_event_topic(&mut item.attrs)?;
                     item.attrs
                         .push(syn::parse_quote!(#[derive(Debug, serde::Serialize, serde::Deserialize)]));
                     item.attrs.push(syn::parse_quote!(#[serde(crate = "self::serde")]));
+                    types.push(TypeDefAst::Struct(item.clone()));
+                    // Event payload structs are never the template struct
+                    if let Some(topic) = event_topic {
+                        events.push(EventAst {
+                            topic,
+                            payload_type: item.ident.clone(),
+                        });
+                        continue;
+                    }
                     // Use the first struct name as the template name
                     // TODO: remove this assumption in favor of "marking" the struct as a template struct
                     // #[template(Component)]
@@ -77,6 +97,9 @@ impl Parse for TemplateAst {
                         template_name = Some(item.ident.clone());
                     }
                 },
+                Item::Enum(item) => {
+                    types.push(TypeDefAst::Enum(item.clone()));
+                },
                 // TODO: check name matches template name
                 Item::Impl(_) => {
                     has_impl = true;
@@ -112,10 +135,32 @@ impl Parse for TemplateAst {
                 .map(|(_, c)| c)
                 .ok_or_else(|| Error::new(module.ident.span(), "Template module must contain content"))?,
             uses,
+            events,
+            types,
         })
     }
 }
 
+/// Removes the `#[event(topic = "...")]` attribute from a struct and returns the topic, if present
+fn take_event_topic(attrs: &mut Vec<Attribute>) -> Result<Option<String>> {
+    let Some(pos) = attrs.iter().position(|attr| attr.path.is_ident("event")) else {
+        return Ok(None);
+    };
+    let attr = attrs.remove(pos);
+    let invalid = || Error::new_spanned(&attr, "expected #[event(topic = \"...\")]");
+
+    let Meta::List(list) = attr.parse_meta()? else {
+        return Err(invalid());
+    };
+    match list.nested.first() {
+        Some(NestedMeta::Meta(Meta::NameValue(name_value))) if list.nested.len() == 1 => match &name_value.lit {
+            Lit::Str(topic) if name_value.path.is_ident("topic") => Ok(Some(topic.value())),
+            _ => Err(invalid()),
+        },
+        _ => Err(invalid()),
+    }
+}
+
 impl TemplateAst {
     pub fn get_functions(&self) -> impl Iterator<Item = FunctionAst> + '_ {
         self.module_content
@@ -222,6 +267,26 @@ impl TemplateAst {
     }
 }
 
+pub struct EventAst {
+    pub topic: String,
+    pub payload_type: Ident,
+}
+
+/// A struct or enum defined in the template module
+pub enum TypeDefAst {
+    Struct(ItemStruct),
+    Enum(ItemEnum),
+}
+
+impl TypeDefAst {
+    pub fn name(&self) -> &Ident {
+        match self {
+            TypeDefAst::Struct(item) => &item.ident,
+            TypeDefAst::Enum(item) => &item.ident,
+        }
+    }
+}
+
 pub struct FunctionAst {
     pub name: String,
     pub input_types: Vec<TypeAst>,
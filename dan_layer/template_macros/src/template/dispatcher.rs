@@ -205,6 +205,7 @@ fn replace_self_in_single_value(type_path: &TypePath) -> Option<Stmt> {
                 ::tari_template_lib::auth::OwnerRule::default(),
                 ::tari_template_lib::auth::ComponentAccessRules::new(),
                 None,
+                false,
             );
         });
     }
@@ -231,6 +232,7 @@ fn replace_self_in_tuple(type_tuple: &TypeTuple) -> Stmt {
                             ::tari_template_lib::auth::OwnerRule::default(),
                             :tari_template_lib::auth::ComponentAccessRules::new(),
                             None,
+                            false,
                         )
                     }
                 } else {
@@ -0,0 +1,352 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+//! Fee profiling of template functions. The [FeeProfiler] executes a suite of calls with fees enabled and reports the
+//! WASM metering points, engine runtime calls, storage bytes and total fees of each call. A [FeeProfileReport] can be
+//! saved as a baseline and later reports compared against it to catch cost regressions.
+//!
+//! Each measurement covers the whole transaction, which includes a fee instruction that pays from the test faucet. The
+//! cost of a transaction that only pays the fee is reported as [FEE_PAYMENT_OVERHEAD].
+
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Formatter},
+    fs,
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+use serde::{Deserialize, Serialize};
+use tari_bor::encoded_len;
+use tari_dan_engine::runtime::{RuntimeModule, RuntimeModuleError, StateTracker};
+use tari_engine_types::commit_result::ExecuteResult;
+use tari_template_lib::{
+    args::Arg,
+    models::{Amount, ComponentAddress},
+};
+use tari_transaction::{Transaction, TransactionBuilder};
+
+use crate::{signer::sign_transaction, test_faucet_component, TemplateTest, TestSigner};
+
+/// The name of the profile of a transaction that only pays the fee
+pub const FEE_PAYMENT_OVERHEAD: &str = "(fee payment)";
+
+/// Setting this environment variable to any value writes the baseline in [FeeProfileReport::assert_within_baseline]
+/// instead of comparing against it
+pub const UPDATE_BASELINE_ENV_VAR: &str = "TARI_UPDATE_FEE_BASELINE";
+
+const DEFAULT_MAX_FEE: i64 = 100_000;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeeProfile {
+    pub wasm_points: u64,
+    pub runtime_calls: u64,
+    pub storage_bytes: u64,
+    pub total_fees: u64,
+}
+
+impl FeeProfile {
+    fn metrics(&self) -> [(&'static str, u64); 4] {
+        [
+            ("wasm_points", self.wasm_points),
+            ("runtime_calls", self.runtime_calls),
+            ("storage_bytes", self.storage_bytes),
+            ("total_fees", self.total_fees),
+        ]
+    }
+
+    fn worst_of(self, other: Self) -> Self {
+        Self {
+            wasm_points: self.wasm_points.max(other.wasm_points),
+            runtime_calls: self.runtime_calls.max(other.runtime_calls),
+            storage_bytes: self.storage_bytes.max(other.storage_bytes),
+            total_fees: self.total_fees.max(other.total_fees),
+        }
+    }
+}
+
+/// A metric of a profiled function that has increased beyond the tolerance of the baseline
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeeRegression {
+    pub name: String,
+    pub metric: &'static str,
+    pub baseline: u64,
+    pub current: u64,
+}
+
+impl Display for FeeRegression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {} increased from {} to {}",
+            self.name, self.metric, self.baseline, self.current
+        )
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeeProfileReport {
+    profiles: BTreeMap<String, FeeProfile>,
+}
+
+impl FeeProfileReport {
+    pub fn get(&self, name: &str) -> Option<&FeeProfile> {
+        self.profiles.get(name)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &FeeProfile)> + '_ {
+        self.profiles.iter().map(|(name, profile)| (name.as_str(), profile))
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
+        let json = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> anyhow::Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Returns the metrics that increased by more than `tolerance_percent` compared to the baseline. Functions that are
+    /// not in the baseline are ignored.
+    pub fn compare(&self, baseline: &FeeProfileReport, tolerance_percent: u64) -> Vec<FeeRegression> {
+        let mut regressions = vec![];
+        for (name, profile) in &self.profiles {
+            let Some(baseline_profile) = baseline.profiles.get(name) else {
+                continue;
+            };
+            for ((metric, current), (_, baseline)) in profile.metrics().into_iter().zip(baseline_profile.metrics()) {
+                let allowed = u128::from(baseline) * u128::from(100 + tolerance_percent) / 100;
+                if u128::from(current) > allowed {
+                    regressions.push(FeeRegression {
+                        name: name.clone(),
+                        metric,
+                        baseline,
+                        current,
+                    });
+                }
+            }
+        }
+        regressions
+    }
+
+    /// Compares the report against the baseline file and panics if any metric regressed by more than
+    /// `tolerance_percent` or the baseline does not exist. The baseline is only written if [UPDATE_BASELINE_ENV_VAR]
+    /// is set.
+    pub fn assert_within_baseline<P: AsRef<Path>>(&self, path: P, tolerance_percent: u64) {
+        let path = path.as_ref();
+        if std::env::var_os(UPDATE_BASELINE_ENV_VAR).is_some() {
+            self.save(path).unwrap();
+            eprintln!("Fee profile baseline written to {}", path.display());
+            return;
+        }
+
+        if !path.exists() {
+            panic!(
+                "Fee profile baseline {} does not exist. Run with {} set to create it.\n\n{}",
+                path.display(),
+                UPDATE_BASELINE_ENV_VAR,
+                self
+            );
+        }
+
+        let baseline = Self::load(path).unwrap();
+        let regressions = self.compare(&baseline, tolerance_percent);
+        if !regressions.is_empty() {
+            panic!(
+                "Fee profile regressed by more than {}% compared to {}:\n{}\n\n{}",
+                tolerance_percent,
+                path.display(),
+                regressions
+                    .iter()
+                    .map(|regression| format!("- {}", regression))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+                self
+            );
+        }
+    }
+
+    fn record(&mut self, name: String, profile: FeeProfile) {
+        let profile = match self.profiles.get(&name) {
+            Some(existing) => existing.worst_of(profile),
+            None => profile,
+        };
+        self.profiles.insert(name, profile);
+    }
+}
+
+impl Display for FeeProfileReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name_width = self.profiles.keys().map(|name| name.len()).max().unwrap_or(0).max(8);
+        writeln!(
+            f,
+            "{:<name_width$} {:>12} {:>13} {:>13} {:>10}",
+            "Function", "WASM points", "Runtime calls", "Storage bytes", "Total fees"
+        )?;
+        for (name, profile) in &self.profiles {
+            writeln!(
+                f,
+                "{:<name_width$} {:>12} {:>13} {:>13} {:>10}",
+                name, profile.wasm_points, profile.runtime_calls, profile.storage_bytes, profile.total_fees
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs calls with fees enabled and records their costs. If the same function is profiled more than once, the highest
+/// value of each metric is reported.
+pub struct FeeProfiler<'a> {
+    test: &'a mut TemplateTest,
+    max_fee: Amount,
+    report: FeeProfileReport,
+}
+
+impl<'a> FeeProfiler<'a> {
+    pub fn new(test: &'a mut TemplateTest) -> Self {
+        Self {
+            test,
+            max_fee: Amount(DEFAULT_MAX_FEE),
+            report: FeeProfileReport::default(),
+        }
+    }
+
+    /// Sets the maximum fee that is paid from the test faucet for each call
+    pub fn with_max_fee(&mut self, max_fee: Amount) -> &mut Self {
+        self.max_fee = max_fee;
+        self
+    }
+
+    /// Profiles a call to a template function. The profile is named `Template::function`.
+    pub fn profile_function(
+        &mut self,
+        template_name: &str,
+        function: &str,
+        args: Vec<Arg>,
+        signers: &[&TestSigner],
+    ) -> ExecuteResult {
+        let template_address = self.test.get_template_address(template_name);
+        self.profile(
+            format!("{}::{}", template_name, function),
+            Transaction::builder().call_function(template_address, function, args),
+            signers,
+        )
+    }
+
+    /// Profiles a call to a component method. The profile is named `Template::method`.
+    pub fn profile_method(
+        &mut self,
+        component_address: ComponentAddress,
+        method: &str,
+        args: Vec<Arg>,
+        signers: &[&TestSigner],
+    ) -> ExecuteResult {
+        let component = self
+            .test
+            .read_only_state_store()
+            .get_component(component_address)
+            .unwrap();
+        self.profile(
+            format!("{}::{}", component.module_name, method),
+            Transaction::builder().call_method(component_address, method, args),
+            signers,
+        )
+    }
+
+    /// Profiles the transaction under the given name and commits it. Panics if the transaction fails. If no signers
+    /// are given, the transaction is signed by the default key of the TemplateTest without any owner proofs.
+    pub fn profile<N: Into<String>>(
+        &mut self,
+        name: N,
+        builder: TransactionBuilder,
+        signers: &[&TestSigner],
+    ) -> ExecuteResult {
+        if self.report.get(FEE_PAYMENT_OVERHEAD).is_none() {
+            self.measure(FEE_PAYMENT_OVERHEAD.to_string(), Transaction::builder(), &[]);
+        }
+        self.measure(name.into(), builder, signers)
+    }
+
+    pub fn report(&self) -> &FeeProfileReport {
+        &self.report
+    }
+
+    pub fn into_report(self) -> FeeProfileReport {
+        self.report
+    }
+
+    fn measure(&mut self, name: String, builder: TransactionBuilder, signers: &[&TestSigner]) -> ExecuteResult {
+        let builder = builder.fee_transaction_pay_from_component(test_faucet_component(), self.max_fee);
+        let (transaction, proofs) = if signers.is_empty() {
+            (builder.build_and_seal(self.test.get_test_secret_key()), vec![])
+        } else {
+            sign_transaction(builder, signers)
+        };
+
+        let module = ProfilingModule::new();
+        let fees_enabled = self.test.fees_enabled();
+        self.test.enable_fees();
+        let result = self
+            .test
+            .try_execute_with_modules(transaction, proofs, vec![Arc::new(module.clone())])
+            .unwrap();
+        if !fees_enabled {
+            self.test.disable_fees();
+        }
+
+        let diff = result.expect_finalization_success();
+        self.test.commit_diff(diff);
+        result.expect_success();
+
+        let mut profile = module.get();
+        profile.total_fees = result
+            .finalize
+            .fee_receipt
+            .total_fees_charged()
+            .as_u64_checked()
+            .unwrap();
+        self.report.record(name, profile);
+        result
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct ProfilingModule {
+    profile: Arc<Mutex<FeeProfile>>,
+}
+
+impl ProfilingModule {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self) -> FeeProfile {
+        *self.profile.lock().unwrap()
+    }
+}
+
+impl RuntimeModule for ProfilingModule {
+    fn on_runtime_call(&self, _track: &StateTracker, _call: &'static str) -> Result<(), RuntimeModuleError> {
+        self.profile.lock().unwrap().runtime_calls += 1;
+        Ok(())
+    }
+
+    fn on_before_finalize(&self, track: &StateTracker) -> Result<(), RuntimeModuleError> {
+        // Measured in the same way as the storage fee charged by the FeeModule
+        let storage_bytes = track.with_substates_to_persist(|changes| {
+            changes
+                .values()
+                .map(|substate| encoded_len(substate).map(|len| len as u64))
+                .sum::<Result<u64, _>>()
+        })?;
+        self.profile.lock().unwrap().storage_bytes = storage_bytes;
+        Ok(())
+    }
+
+    fn on_wasm_execution(&self, _track: &StateTracker, points_used: u64) -> Result<(), RuntimeModuleError> {
+        self.profile.lock().unwrap().wasm_points += points_used;
+        Ok(())
+    }
+}
@@ -0,0 +1,686 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+//! Property-based fuzzing of template methods. The [Fuzzer] calls randomly chosen methods of the target components
+//! with arguments generated from the template ABI, signed by randomly chosen signers, and checks a set of
+//! [Invariant]s after every transaction. When an invariant is violated, the failing sequence of calls is shrunk to a
+//! (locally) minimal sequence that still violates it.
+
+use std::{
+    collections::HashSet,
+    fmt::{Display, Formatter},
+};
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use tari_bor::{from_value, to_value, Value};
+use tari_engine_types::substate::SubstateValue;
+use tari_template_abi::{FieldsDef, FunctionDef, TemplateDef, Type, TypeDefKind};
+use tari_template_lib::{
+    args::Arg,
+    constants::XTR,
+    models::{Amount, ComponentAddress, NonFungibleAddress, ResourceAddress},
+};
+use tari_transaction::Transaction;
+
+use crate::{
+    read_only_state_store::ReadOnlyStateStore,
+    signer::sign_transaction,
+    StateSnapshot,
+    TemplateTest,
+    TestSigner,
+};
+
+const MAX_COLLECTION_LEN: usize = 3;
+const MAX_VALUE_DEPTH: usize = 4;
+const CBOR_POSITIVE_BIGNUM_TAG: u64 = 2;
+const CBOR_NEGATIVE_BIGNUM_TAG: u64 = 3;
+
+type InvariantCheck = Box<dyn Fn(&TemplateTest, &StateSnapshot) -> Result<(), String>>;
+
+/// A property that must hold after every transaction. The check is given the current state and a snapshot of the
+/// state before the first transaction of the run.
+pub struct Invariant {
+    name: String,
+    check: InvariantCheck,
+}
+
+impl Invariant {
+    pub fn new<F>(name: impl Into<String>, check: F) -> Self
+    where F: Fn(&TemplateTest, &StateSnapshot) -> Result<(), String> + 'static {
+        Self {
+            name: name.into(),
+            check: Box::new(check),
+        }
+    }
+
+    /// No vault may ever hold a negative balance
+    pub fn no_negative_balances() -> Self {
+        Self::new("no negative balances", |test, _| {
+            let mut result = Ok(());
+            test.read_only_state_store()
+                .with_substates(|substate| {
+                    if let SubstateValue::Vault(vault) = substate.substate_value() {
+                        if vault.balance().is_negative() && result.is_ok() {
+                            result = Err(format!(
+                                "Vault of {} has a negative balance of {}",
+                                vault.resource_address(),
+                                vault.balance()
+                            ));
+                        }
+                    }
+                })
+                .unwrap();
+            result
+        })
+    }
+
+    /// The sum of all vault balances of the resource never changes
+    pub fn total_supply_conserved(resource_address: ResourceAddress) -> Self {
+        Self::new(
+            format!("total supply of {} is conserved", resource_address),
+            move |test, initial| {
+                let expected = total_vault_balance(initial.read_only_state_store(), &resource_address);
+                let actual = total_vault_balance(test.read_only_state_store(), &resource_address);
+                if actual == expected {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "Expected a total of {} in all vaults but got {}",
+                        expected, actual
+                    ))
+                }
+            },
+        )
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+fn total_vault_balance(store: ReadOnlyStateStore<'_>, resource: &ResourceAddress) -> Amount {
+    let mut total = Amount::zero();
+    store
+        .with_substates(|substate| {
+            if let SubstateValue::Vault(vault) = substate.substate_value() {
+                if vault.resource_address() == resource {
+                    total += vault.balance();
+                }
+            }
+        })
+        .unwrap();
+    total
+}
+
+#[derive(Debug, Clone)]
+pub struct FuzzConfig {
+    /// The seed of the random number generator. The same seed, targets and signers produce the same calls.
+    pub seed: u64,
+    /// The number of independent runs. Each run starts from the state at the time the fuzzer was run.
+    pub runs: usize,
+    pub calls_per_run: usize,
+}
+
+impl Default for FuzzConfig {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            runs: 20,
+            calls_per_run: 20,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct FuzzTarget {
+    component_address: ComponentAddress,
+    template_def: TemplateDef,
+    methods: Vec<FunctionDef>,
+}
+
+/// A single generated method call
+#[derive(Debug, Clone)]
+pub struct FuzzCall {
+    pub component_address: ComponentAddress,
+    pub method: String,
+    pub args: Vec<Value>,
+    pub signer: TestSigner,
+}
+
+impl FuzzCall {
+    fn to_transaction(&self) -> (Transaction, Vec<NonFungibleAddress>) {
+        let args = self.args.iter().map(|arg| Arg::literal(arg.clone()).unwrap()).collect();
+        sign_transaction(
+            Transaction::builder().call_method(self.component_address, &self.method, args),
+            &[&self.signer],
+        )
+    }
+}
+
+impl Display for FuzzCall {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}(", self.component_address, self.method)?;
+        for (i, arg) in self.args.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            fmt_value(f, arg)?;
+        }
+        write!(f, ") signed by {}", self.signer.public_key())
+    }
+}
+
+fn fmt_value(f: &mut Formatter<'_>, value: &Value) -> std::fmt::Result {
+    match value {
+        Value::Integer(i) => write!(f, "{}", i128::from(*i)),
+        Value::Tag(CBOR_POSITIVE_BIGNUM_TAG, _) => {
+            write!(f, "{}", from_value::<u128>(value).map_err(|_| std::fmt::Error)?)
+        },
+        Value::Tag(CBOR_NEGATIVE_BIGNUM_TAG, _) => {
+            write!(f, "{}", from_value::<i128>(value).map_err(|_| std::fmt::Error)?)
+        },
+        Value::Text(s) => write!(f, "{:?}", s),
+        Value::Bool(b) => write!(f, "{}", b),
+        Value::Null => write!(f, "null"),
+        Value::Array(values) => {
+            write!(f, "[")?;
+            for (i, value) in values.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                fmt_value(f, value)?;
+            }
+            write!(f, "]")
+        },
+        Value::Map(entries) => {
+            write!(f, "{{")?;
+            for (i, (key, value)) in entries.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                fmt_value(f, key)?;
+                write!(f, ": ")?;
+                fmt_value(f, value)?;
+            }
+            write!(f, "}}")
+        },
+        value => write!(f, "{:?}", value),
+    }
+}
+
+/// Describes a sequence of calls that violates an invariant
+#[derive(Debug, Clone)]
+pub struct FuzzFailure {
+    pub seed: u64,
+    pub run: usize,
+    pub invariant: String,
+    pub message: String,
+    /// The shrunk sequence of calls. The invariant is violated after the last call.
+    pub calls: Vec<FuzzCall>,
+}
+
+impl Display for FuzzFailure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Invariant \"{}\" violated in run {} (seed {}): {}",
+            self.invariant, self.run, self.seed, self.message
+        )?;
+        writeln!(f, "Minimal sequence of {} call(s):", self.calls.len())?;
+        for (i, call) in self.calls.iter().enumerate() {
+            writeln!(f, "  {}. {}", i + 1, call)?;
+        }
+        Ok(())
+    }
+}
+
+struct Violation {
+    index: usize,
+    invariant: String,
+    message: String,
+}
+
+pub struct Fuzzer<'a> {
+    test: &'a mut TemplateTest,
+    config: FuzzConfig,
+    targets: Vec<FuzzTarget>,
+    signers: Vec<TestSigner>,
+    components: Vec<ComponentAddress>,
+    resources: Vec<ResourceAddress>,
+    excluded_methods: HashSet<String>,
+    invariants: Vec<Invariant>,
+}
+
+impl<'a> Fuzzer<'a> {
+    pub fn new(test: &'a mut TemplateTest) -> Self {
+        Self {
+            test,
+            config: FuzzConfig::default(),
+            targets: vec![],
+            signers: vec![],
+            components: vec![],
+            resources: vec![XTR],
+            excluded_methods: HashSet::new(),
+            invariants: vec![],
+        }
+    }
+
+    pub fn with_config(&mut self, config: FuzzConfig) -> &mut Self {
+        self.config = config;
+        self
+    }
+
+    pub fn with_seed(&mut self, seed: u64) -> &mut Self {
+        self.config.seed = seed;
+        self
+    }
+
+    /// Adds a component whose methods are called. The component can also be passed as a `ComponentAddress` argument.
+    pub fn add_target(&mut self, component_address: ComponentAddress) -> &mut Self {
+        let header = self
+            .test
+            .read_only_state_store()
+            .get_component(component_address)
+            .unwrap();
+        let template_def = self.test.get_module(&header.module_name).template_def().clone();
+        self.targets.push(FuzzTarget {
+            component_address,
+            template_def,
+            methods: vec![],
+        });
+        self.components.push(component_address);
+        self
+    }
+
+    /// Methods with this name are never called on any target
+    pub fn exclude_method(&mut self, method: &str) -> &mut Self {
+        self.excluded_methods.insert(method.to_string());
+        self
+    }
+
+    pub fn add_signer(&mut self, signer: TestSigner) -> &mut Self {
+        self.signers.push(signer);
+        self
+    }
+
+    /// Adds an account that can be passed as a `ComponentAddress` argument, along with its owner as a signer
+    pub fn add_account(&mut self, account: ComponentAddress, owner: TestSigner) -> &mut Self {
+        self.components.push(account);
+        self.add_signer(owner)
+    }
+
+    /// Creates the given number of funded accounts and adds them using [Fuzzer::add_account]
+    pub fn with_funded_accounts(&mut self, count: usize) -> &mut Self {
+        for _ in 0..count {
+            let (account, _, secret_key) = self.test.create_funded_account();
+            self.add_account(account, TestSigner::from_secret_key(secret_key));
+        }
+        self
+    }
+
+    /// Adds a resource that can be passed as a `ResourceAddress` argument. XTR is always included.
+    pub fn add_resource(&mut self, resource_address: ResourceAddress) -> &mut Self {
+        self.resources.push(resource_address);
+        self
+    }
+
+    pub fn add_invariant(&mut self, invariant: Invariant) -> &mut Self {
+        self.invariants.push(invariant);
+        self
+    }
+
+    /// Runs the fuzzer. The state of the TemplateTest is restored afterwards, whether or not an invariant was
+    /// violated.
+    pub fn run(&mut self) -> Result<(), FuzzFailure> {
+        self.prepare_targets();
+        if self.signers.is_empty() {
+            self.signers
+                .push(TestSigner::from_secret_key(self.test.get_test_secret_key().clone()));
+        }
+
+        let initial = self.test.snapshot();
+        let mut rng = StdRng::seed_from_u64(self.config.seed);
+        let mut result = Ok(());
+        for run in 0..self.config.runs {
+            let calls = (0..self.config.calls_per_run)
+                .map(|_| self.generate_call(&mut rng))
+                .collect::<Vec<_>>();
+            if let Some(violation) = self.execute_calls(&initial, &calls) {
+                let calls = calls[..=violation.index].to_vec();
+                let (calls, violation) = self.shrink(&initial, calls, violation);
+                result = Err(FuzzFailure {
+                    seed: self.config.seed,
+                    run,
+                    invariant: violation.invariant,
+                    message: violation.message,
+                    calls,
+                });
+                break;
+            }
+        }
+
+        self.test.restore(&initial);
+        result
+    }
+
+    /// Runs the fuzzer and panics with the minimal failing sequence if an invariant is violated
+    pub fn run_and_assert(&mut self) {
+        if let Err(failure) = self.run() {
+            panic!("{}", failure);
+        }
+    }
+
+    fn prepare_targets(&mut self) {
+        assert!(!self.targets.is_empty(), "No fuzz targets were added");
+        for target in &mut self.targets {
+            target.methods = target
+                .template_def
+                .functions()
+                .iter()
+                .filter(|function| {
+                    function.arguments.first().map(|arg| arg.name.as_str()) == Some("self") &&
+                        !self.excluded_methods.contains(&function.name) &&
+                        function.arguments[1..]
+                            .iter()
+                            .all(|arg| can_generate(&target.template_def, &arg.arg_type))
+                })
+                .cloned()
+                .collect();
+        }
+        assert!(
+            self.targets.iter().any(|target| !target.methods.is_empty()),
+            "None of the methods of the fuzz targets can be called with generated arguments"
+        );
+    }
+
+    fn generate_call(&self, rng: &mut StdRng) -> FuzzCall {
+        let target = self
+            .targets
+            .iter()
+            .filter(|target| !target.methods.is_empty())
+            .collect::<Vec<_>>()
+            .choose(rng)
+            .copied()
+            .unwrap();
+        let method = target.methods.choose(rng).unwrap();
+        let args = method.arguments[1..]
+            .iter()
+            .map(|arg| self.generate_value(rng, &target.template_def, &arg.arg_type, 0))
+            .collect();
+        FuzzCall {
+            component_address: target.component_address,
+            method: method.name.clone(),
+            args,
+            signer: self.signers.choose(rng).unwrap().clone(),
+        }
+    }
+
+    fn generate_value(&self, rng: &mut StdRng, template_def: &TemplateDef, ty: &Type, depth: usize) -> Value {
+        let max_len = if depth < MAX_VALUE_DEPTH { MAX_COLLECTION_LEN } else { 0 };
+        match ty {
+            Type::Unit => Value::Null,
+            Type::Bool => Value::Bool(rng.gen()),
+            Type::I8 => generate_signed(rng, i8::MIN.into(), i8::MAX.into()),
+            Type::I16 => generate_signed(rng, i16::MIN.into(), i16::MAX.into()),
+            Type::I32 => generate_signed(rng, i32::MIN.into(), i32::MAX.into()),
+            Type::I64 => generate_signed(rng, i64::MIN.into(), i64::MAX.into()),
+            Type::I128 => generate_signed(rng, i128::MIN, i128::MAX),
+            Type::U8 => generate_unsigned(rng, u8::MAX.into()),
+            Type::U16 => generate_unsigned(rng, u16::MAX.into()),
+            Type::U32 => generate_unsigned(rng, u32::MAX.into()),
+            Type::U64 => generate_unsigned(rng, u64::MAX.into()),
+            Type::U128 => generate_unsigned(rng, u128::MAX),
+            Type::String => {
+                let len = rng.gen_range(0..=8);
+                Value::Text((0..len).map(|_| rng.gen_range('a'..='z')).collect())
+            },
+            Type::Vec(ty) => Value::Array(
+                (0..rng.gen_range(0..=max_len))
+                    .map(|_| self.generate_value(rng, template_def, ty, depth + 1))
+                    .collect(),
+            ),
+            Type::Tuple(types) => Value::Array(
+                types
+                    .iter()
+                    .map(|ty| self.generate_value(rng, template_def, ty, depth + 1))
+                    .collect(),
+            ),
+            Type::Option(ty) => {
+                if max_len > 0 && rng.gen() {
+                    self.generate_value(rng, template_def, ty, depth + 1)
+                } else {
+                    Value::Null
+                }
+            },
+            Type::Map(key, value) => Value::Map(
+                (0..rng.gen_range(0..=max_len))
+                    .map(|_| {
+                        (
+                            self.generate_value(rng, template_def, key, depth + 1),
+                            self.generate_value(rng, template_def, value, depth + 1),
+                        )
+                    })
+                    .collect(),
+            ),
+            Type::Other { name } => match name.as_str() {
+                "Amount" => generate_signed(rng, i64::MIN.into(), i64::MAX.into()),
+                "ComponentAddress" => to_value(self.components.choose(rng).unwrap()).unwrap(),
+                "ResourceAddress" => to_value(self.resources.choose(rng).unwrap()).unwrap(),
+                "NonFungibleAddress" => to_value(self.signers.choose(rng).unwrap().owner_proof()).unwrap(),
+                _ => {
+                    let kind = &template_def
+                        .get_type(name)
+                        .unwrap_or_else(|| panic!("Type {} is not described in the template ABI", name))
+                        .kind;
+                    match kind {
+                        TypeDefKind::Struct { fields } => self.generate_fields(rng, template_def, fields, depth),
+                        TypeDefKind::Enum { variants } => {
+                            let variant = variants.choose(rng).unwrap();
+                            match &variant.fields {
+                                FieldsDef::Unit => Value::Text(variant.name.clone()),
+                                fields => Value::Map(vec![(
+                                    Value::Text(variant.name.clone()),
+                                    self.generate_fields(rng, template_def, fields, depth),
+                                )]),
+                            }
+                        },
+                        TypeDefKind::Engine | TypeDefKind::External => {
+                            panic!("Cannot generate {:?} type {}", kind, name)
+                        },
+                    }
+                },
+            },
+        }
+    }
+
+    /// Generates fields in the form that serde encodes them: named fields as a map, a single unnamed field as the
+    /// value itself and multiple unnamed fields as an array.
+    fn generate_fields(&self, rng: &mut StdRng, template_def: &TemplateDef, fields: &FieldsDef, depth: usize) -> Value {
+        match fields {
+            FieldsDef::Named(fields) => Value::Map(
+                fields
+                    .iter()
+                    .map(|field| {
+                        (
+                            Value::Text(field.name.clone()),
+                            self.generate_value(rng, template_def, &field.field_type, depth + 1),
+                        )
+                    })
+                    .collect(),
+            ),
+            FieldsDef::Unnamed(types) if types.len() == 1 => {
+                self.generate_value(rng, template_def, &types[0], depth + 1)
+            },
+            FieldsDef::Unnamed(types) => Value::Array(
+                types
+                    .iter()
+                    .map(|ty| self.generate_value(rng, template_def, ty, depth + 1))
+                    .collect(),
+            ),
+            FieldsDef::Unit => Value::Null,
+        }
+    }
+
+    /// Executes the calls from the initial state, committing the successful ones, and checks the invariants after
+    /// each call. Returns the first violation.
+    fn execute_calls(&mut self, initial: &StateSnapshot, calls: &[FuzzCall]) -> Option<Violation> {
+        self.test.restore(initial);
+        for (index, call) in calls.iter().enumerate() {
+            let (transaction, proofs) = call.to_transaction();
+            // Rejected calls (e.g. a failed assertion in the template) are expected and leave the state unchanged
+            self.test.execute_and_commit_on_success(transaction, proofs);
+
+            for invariant in &self.invariants {
+                if let Err(message) = (invariant.check)(self.test, initial) {
+                    return Some(Violation {
+                        index,
+                        invariant: invariant.name.clone(),
+                        message,
+                    });
+                }
+            }
+        }
+        None
+    }
+
+    /// Repeatedly removes calls and simplifies arguments while the sequence still violates an invariant
+    fn shrink(
+        &mut self,
+        initial: &StateSnapshot,
+        mut calls: Vec<FuzzCall>,
+        mut violation: Violation,
+    ) -> (Vec<FuzzCall>, Violation) {
+        loop {
+            let mut shrunk = false;
+
+            let mut i = 0;
+            while i < calls.len() {
+                let mut candidate = calls.clone();
+                candidate.remove(i);
+                if let Some(v) = self.execute_calls(initial, &candidate) {
+                    calls = candidate[..=v.index].to_vec();
+                    violation = v;
+                    shrunk = true;
+                } else {
+                    i += 1;
+                }
+            }
+
+            'args: for i in 0..calls.len() {
+                for j in 0..calls[i].args.len() {
+                    for simpler in shrink_value(&calls[i].args[j]) {
+                        let mut candidate = calls.clone();
+                        candidate[i].args[j] = simpler;
+                        if let Some(v) = self.execute_calls(initial, &candidate) {
+                            calls = candidate[..=v.index].to_vec();
+                            violation = v;
+                            shrunk = true;
+                            break 'args;
+                        }
+                    }
+                }
+            }
+
+            if !shrunk {
+                return (calls, violation);
+            }
+        }
+    }
+}
+
+fn generate_unsigned(rng: &mut StdRng, max: u128) -> Value {
+    // Bias towards edge cases, which are more likely to break invariants than uniformly distributed values
+    let value = match rng.gen_range(0..6) {
+        0 => 0,
+        1 => 1,
+        2 => max,
+        3 => max - 1,
+        4 => rng.gen_range(0..=max.min(100)),
+        _ => rng.gen_range(0..=max),
+    };
+    // Values larger than u64::MAX are encoded as CBOR bignums
+    to_value(&value).unwrap()
+}
+
+fn generate_signed(rng: &mut StdRng, min: i128, max: i128) -> Value {
+    let value = match rng.gen_range(0..8) {
+        0 => 0,
+        1 => 1,
+        2 => -1,
+        3 => min,
+        4 => max,
+        5 => min + 1,
+        6 => rng.gen_range(-100..=100),
+        _ => rng.gen_range(min..=max),
+    };
+    to_value(&value).unwrap()
+}
+
+fn shrink_value(value: &Value) -> Vec<Value> {
+    match value {
+        Value::Integer(i) => {
+            let i = i128::from(*i);
+            if i == 0 {
+                vec![]
+            } else {
+                vec![Value::from(0i128), Value::from(i / 2)]
+            }
+        },
+        Value::Tag(CBOR_POSITIVE_BIGNUM_TAG, _) => {
+            let i = from_value::<u128>(value).unwrap();
+            vec![Value::from(0i128), to_value(&(i / 2)).unwrap()]
+        },
+        Value::Tag(CBOR_NEGATIVE_BIGNUM_TAG, _) => {
+            let i = from_value::<i128>(value).unwrap();
+            vec![Value::from(0i128), to_value(&(i / 2)).unwrap()]
+        },
+        Value::Bool(true) => vec![Value::Bool(false)],
+        Value::Text(s) if !s.is_empty() => vec![
+            Value::Text(String::new()),
+            Value::Text(s.chars().take(s.chars().count() / 2).collect()),
+        ],
+        Value::Array(values) if !values.is_empty() => vec![Value::Array(values[1..].to_vec())],
+        _ => vec![],
+    }
+}
+
+fn can_generate(template_def: &TemplateDef, ty: &Type) -> bool {
+    can_generate_inner(template_def, ty, &mut HashSet::new())
+}
+
+fn can_generate_inner<'a>(template_def: &'a TemplateDef, ty: &'a Type, visited: &mut HashSet<&'a str>) -> bool {
+    match ty {
+        Type::Vec(ty) | Type::Option(ty) => can_generate_inner(template_def, ty, visited),
+        Type::Tuple(types) => types.iter().all(|ty| can_generate_inner(template_def, ty, visited)),
+        Type::Map(key, value) => {
+            can_generate_inner(template_def, key, visited) && can_generate_inner(template_def, value, visited)
+        },
+        Type::Other { name } => {
+            if matches!(
+                name.as_str(),
+                "Amount" | "ComponentAddress" | "ResourceAddress" | "NonFungibleAddress"
+            ) {
+                return true;
+            }
+            // Recursive types are only reachable through collections, which are empty at the maximum depth
+            if !visited.insert(name.as_str()) {
+                return true;
+            }
+            let fields_ok = |fields: &'a FieldsDef, visited: &mut HashSet<&'a str>| match fields {
+                FieldsDef::Named(fields) => fields
+                    .iter()
+                    .all(|field| can_generate_inner(template_def, &field.field_type, visited)),
+                FieldsDef::Unnamed(types) => types.iter().all(|ty| can_generate_inner(template_def, ty, visited)),
+                FieldsDef::Unit => true,
+            };
+            match template_def.get_type(name).map(|def| &def.kind) {
+                Some(TypeDefKind::Struct { fields }) => fields_ok(fields, visited),
+                Some(TypeDefKind::Enum { variants }) => {
+                    variants.iter().all(|variant| fields_ok(&variant.fields, visited))
+                },
+                Some(TypeDefKind::Engine) | Some(TypeDefKind::External) | None => false,
+            }
+        },
+        _ => true,
+    }
+}
@@ -1,14 +1,18 @@
 //  Copyright 2022 The Tari Project
 //  SPDX-License-Identifier: BSD-3-Clause
 
+pub mod fee_profile;
+pub mod fuzz;
 mod package_builder;
 mod read_only_state_store;
+mod signer;
 pub mod support;
 mod template_test;
 mod track_calls;
 
 pub use package_builder::Package;
-pub use template_test::{test_faucet_component, SubstateType, TemplateTest};
+pub use signer::{sign_transaction, TestSigner};
+pub use template_test::{test_faucet_component, StateSnapshot, SubstateType, TemplateTest};
 
 pub mod crypto {
     pub use tari_crypto::{keys::*, ristretto::*};
@@ -9,7 +9,7 @@ use tari_engine_types::{
     substate::{Substate, SubstateId},
     vault::Vault,
 };
-use tari_template_lib::models::{ComponentAddress, ResourceAddress, VaultId};
+use tari_template_lib::models::{Amount, ComponentAddress, ResourceAddress, VaultId};
 
 pub struct ReadOnlyStateStore<'a> {
     store: &'a MemoryStateStore,
@@ -39,11 +39,32 @@ impl<'a> ReadOnlyStateStore<'a> {
         Ok(IndexedValue::from_value(component.into_state()).unwrap())
     }
 
+    /// Returns the total balance of the given resource held in all vaults owned by the component
+    pub fn get_component_balance(
+        &self,
+        component_address: ComponentAddress,
+        resource_address: &ResourceAddress,
+    ) -> Result<Amount, StateStoreError> {
+        let value = self.inspect_component(component_address)?;
+        let mut balance = Amount::zero();
+        for vault_id in value.vault_ids() {
+            let vault = self.get_vault(vault_id)?;
+            if vault.resource_address() == resource_address {
+                balance += vault.balance();
+            }
+        }
+        Ok(balance)
+    }
+
     pub fn count(&self) -> Result<usize, StateStoreError> {
         let count = self.store.count();
         Ok(count)
     }
 
+    pub fn exists(&self, id: &SubstateId) -> Result<bool, StateStoreError> {
+        self.store.exists(id)
+    }
+
     pub fn get_substate(&self, id: &SubstateId) -> Result<Substate, StateStoreError> {
         let substate = self.store.get_state(id)?;
         Ok(substate.clone())
@@ -0,0 +1,64 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use tari_crypto::{
+    keys::PublicKey as _,
+    ristretto::{RistrettoPublicKey, RistrettoSecretKey},
+    tari_utilities::ByteArray,
+};
+use tari_template_lib::{crypto::RistrettoPublicKeyBytes, models::NonFungibleAddress};
+use tari_transaction::{Transaction, TransactionBuilder};
+
+/// A key pair that can sign transactions in a [TemplateTest](crate::TemplateTest), along with the owner proof that
+/// the engine grants to transactions it signs.
+#[derive(Debug, Clone)]
+pub struct TestSigner {
+    secret_key: RistrettoSecretKey,
+    public_key: RistrettoPublicKey,
+    owner_proof: NonFungibleAddress,
+}
+
+impl TestSigner {
+    pub fn from_secret_key(secret_key: RistrettoSecretKey) -> Self {
+        let public_key = RistrettoPublicKey::from_secret_key(&secret_key);
+        let public_key_bytes = RistrettoPublicKeyBytes::from_bytes(public_key.as_bytes()).unwrap();
+        Self {
+            owner_proof: NonFungibleAddress::from_public_key(public_key_bytes),
+            secret_key,
+            public_key,
+        }
+    }
+
+    pub fn secret_key(&self) -> &RistrettoSecretKey {
+        &self.secret_key
+    }
+
+    pub fn public_key(&self) -> &RistrettoPublicKey {
+        &self.public_key
+    }
+
+    pub fn owner_proof(&self) -> &NonFungibleAddress {
+        &self.owner_proof
+    }
+}
+
+/// Signs the transaction by all of the given signers and seals it with the first signer. Returns the transaction and
+/// the owner proofs of the signers.
+///
+/// # Panics
+/// Panics if no signers are provided.
+pub fn sign_transaction(
+    builder: TransactionBuilder,
+    signers: &[&TestSigner],
+) -> (Transaction, Vec<NonFungibleAddress>) {
+    let (seal_signer, _) = signers.split_first().expect("At least one signer is required");
+    let transaction = signers
+        .iter()
+        .fold(builder, |builder, signer| {
+            builder.add_signature(seal_signer.public_key(), signer.secret_key())
+        })
+        .build()
+        .seal(seal_signer.secret_key());
+    let proofs = signers.iter().map(|signer| signer.owner_proof().clone()).collect();
+    (transaction, proofs)
+}
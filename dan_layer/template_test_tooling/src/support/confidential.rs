@@ -4,7 +4,7 @@
 use rand::rngs::OsRng;
 use tari_common_types::types::{PrivateKey, PublicKey};
 use tari_crypto::{commitment::HomomorphicCommitmentFactory, keys::SecretKey, tari_utilities::ByteArray};
-use tari_dan_wallet_crypto::{ConfidentialOutputMaskAndValue, ConfidentialProofStatement};
+use tari_dan_wallet_crypto::{AdditionalOutput, ConfidentialOutputMaskAndValue, ConfidentialProofStatement};
 use tari_engine_types::confidential::get_commitment_factory;
 use tari_template_lib::{
     crypto::PedersonCommitmentBytes,
@@ -182,3 +182,75 @@ fn generate_withdraw_proof_internal(
         proof,
     }
 }
+
+pub struct MultiWithdrawProofOutput {
+    pub output_masks: Vec<PrivateKey>,
+    pub change_mask: Option<PrivateKey>,
+    pub proof: ConfidentialWithdrawProof,
+}
+
+/// Generates a proof for `withdraw_confidential_many`. Each output is a (confidential, revealed) amount pair and
+/// results in a separate bucket, in the same order.
+pub fn generate_withdraw_proof_with_additional_outputs(
+    inputs: &[(PrivateKey, Amount)],
+    input_revealed_amount: Amount,
+    outputs: &[(Amount, Amount)],
+    change_amount: Option<Amount>,
+    view_key: Option<&PublicKey>,
+) -> MultiWithdrawProofOutput {
+    assert!(!outputs.is_empty(), "at least one output is required");
+    let statement_for = |amount: Amount| {
+        // If the amount is zero, we omit the output UTXO, therefore the mask is zero
+        let mask = if amount.is_zero() {
+            PrivateKey::default()
+        } else {
+            PrivateKey::random(&mut OsRng)
+        };
+        ConfidentialProofStatement {
+            amount,
+            mask,
+            sender_public_nonce: Default::default(),
+            minimum_value_promise: 0,
+            encrypted_data: EncryptedData::try_from(vec![0; EncryptedData::min_size()]).unwrap(),
+            resource_view_key: view_key.cloned(),
+        }
+    };
+
+    let statements = outputs
+        .iter()
+        .map(|(amount, _)| statement_for(*amount))
+        .collect::<Vec<_>>();
+    let change_statement = change_amount.map(statement_for);
+    let additional_outputs = statements
+        .iter()
+        .zip(outputs)
+        .skip(1)
+        .map(|(statement, (amount, revealed_amount))| AdditionalOutput {
+            statement: Some(statement.clone()).filter(|_| !amount.is_zero()),
+            revealed_amount: *revealed_amount,
+        })
+        .collect::<Vec<_>>();
+
+    let proof = tari_dan_wallet_crypto::create_withdraw_proof_with_additional_outputs(
+        &inputs
+            .iter()
+            .map(|(mask, amount)| ConfidentialOutputMaskAndValue {
+                value: amount.as_u64_checked().unwrap(),
+                mask: mask.clone(),
+            })
+            .collect::<Vec<_>>(),
+        input_revealed_amount,
+        Some(&statements[0]).filter(|s| !s.amount.is_zero()),
+        outputs[0].1,
+        change_statement.as_ref(),
+        Amount::zero(),
+        &additional_outputs,
+    )
+    .unwrap();
+
+    MultiWithdrawProofOutput {
+        output_masks: statements.into_iter().map(|s| s.mask).collect(),
+        change_mask: change_statement.map(|s| s.mask),
+        proof,
+    }
+}
@@ -0,0 +1,89 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use std::fmt::Display;
+
+use tari_engine_types::{commit_result::ExecuteResult, events::Event};
+use tari_template_lib::models::{Amount, ComponentAddress, ResourceAddress};
+
+use crate::{StateSnapshot, TemplateTest};
+
+/// Returns all events with the given topic that were emitted by the transaction
+pub fn find_events<'a: 'b, 'b>(result: &'a ExecuteResult, topic: &'b str) -> impl Iterator<Item = &'a Event> + 'b {
+    result
+        .finalize
+        .events
+        .iter()
+        .filter(move |event| event.topic() == topic)
+}
+
+/// Asserts that the transaction emitted at least one event with the given topic and returns the first one
+pub fn assert_event_emitted<'a>(result: &'a ExecuteResult, topic: &str) -> &'a Event {
+    find_events(result, topic).next().unwrap_or_else(|| {
+        panic!(
+            "Expected event with topic \"{}\" but got [{}]",
+            topic,
+            result
+                .finalize
+                .events
+                .iter()
+                .map(|event| event.topic())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    })
+}
+
+pub fn assert_event_not_emitted(result: &ExecuteResult, topic: &str) {
+    let count = find_events(result, topic).count();
+    if count > 0 {
+        panic!("Expected no event with topic \"{}\" but got {}", topic, count)
+    }
+}
+
+pub fn assert_event_count(result: &ExecuteResult, topic: &str, expected: usize) {
+    let count = find_events(result, topic).count();
+    if count != expected {
+        panic!(
+            "Expected {} event(s) with topic \"{}\" but got {}",
+            expected, topic, count
+        )
+    }
+}
+
+/// Asserts that an event with the given topic was emitted with the string payload field set to the expected value
+pub fn assert_event_payload<V: Display>(result: &ExecuteResult, topic: &str, key: &str, expected: V) {
+    let expected = expected.to_string();
+    let found = find_events(result, topic).any(|event| event.get_payload(key).as_ref() == Some(&expected));
+    if !found {
+        panic!(
+            "Expected event with topic \"{}\" and payload \"{}\" = \"{}\" but got [{}]",
+            topic,
+            key,
+            expected,
+            find_events(result, topic)
+                .map(|event| event.get_payload(key).unwrap_or_else(|| "<missing>".to_string()))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+/// Asserts that the balance of the resource held by the component has changed by the expected (possibly negative)
+/// amount since the snapshot was taken
+pub fn assert_balance_change<A: Into<Amount>>(
+    test: &TemplateTest,
+    snapshot: &StateSnapshot,
+    component_address: ComponentAddress,
+    resource_address: ResourceAddress,
+    expected: A,
+) {
+    let expected = expected.into();
+    let change = test.balance_change_since(snapshot, component_address, resource_address);
+    if change != expected {
+        panic!(
+            "Expected balance of {} in {} to change by {} but it changed by {}",
+            resource_address, component_address, expected, change
+        )
+    }
+}
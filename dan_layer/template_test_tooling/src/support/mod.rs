@@ -4,5 +4,6 @@
 pub mod assert_error;
 pub mod confidential;
 pub mod crypto;
+pub mod matchers;
 
 pub use tari_dan_wallet_crypto::AlwaysMissLookupTable;
@@ -24,7 +24,7 @@ use tari_dan_engine::{
     runtime::{AuthParams, RuntimeModule},
     state_store::{memory::MemoryStateStore, new_memory_store, StateWriter},
     template::LoadedTemplate,
-    transaction::{TransactionError, TransactionProcessor, TransactionProcessorConfig},
+    transaction::{TransactionError, TransactionProcessor, TransactionProcessorConfig, MAX_CALL_DEPTH},
     wasm::LoadedWasmTemplate,
 };
 use tari_engine_types::{
@@ -32,6 +32,7 @@ use tari_engine_types::{
     component::{ComponentBody, ComponentHeader},
     id_provider::{IdProvider, ObjectIds},
     instruction::Instruction,
+    instruction_result::InstructionResult,
     resource_container::ResourceContainer,
     substate::{Substate, SubstateDiff, SubstateId},
     vault::Vault,
@@ -43,14 +44,19 @@ use tari_template_lib::{
     args::Arg,
     auth::OwnerRule,
     crypto::RistrettoPublicKeyBytes,
-    models::{Amount, ComponentAddress, EntityId, NonFungibleAddress, ObjectKey, TemplateAddress},
+    models::{Amount, ComponentAddress, EntityId, NonFungibleAddress, ObjectKey, ResourceAddress, TemplateAddress},
     prelude::{ComponentAccessRules, CONFIDENTIAL_TARI_RESOURCE_ADDRESS},
     Hash,
 };
-use tari_transaction::Transaction;
+use tari_transaction::{Transaction, TransactionBuilder};
 use tari_transaction_manifest::{parse_manifest, ManifestValue};
 
-use crate::{read_only_state_store::ReadOnlyStateStore, track_calls::TrackCallsModule, Package};
+use crate::{
+    read_only_state_store::ReadOnlyStateStore,
+    signer::{sign_transaction, TestSigner},
+    track_calls::TrackCallsModule,
+    Package,
+};
 
 pub fn test_faucet_component() -> ComponentAddress {
     ComponentAddress::new(ObjectKey::from_array([0xfau8; ObjectKey::LENGTH]))
@@ -68,6 +74,8 @@ pub struct TemplateTest {
     fee_table: FeeTable,
     virtual_substates: VirtualSubstates,
     key_seed: u8,
+    max_call_depth: usize,
+    enable_tracing: bool,
 }
 
 impl TemplateTest {
@@ -128,8 +136,13 @@ impl TemplateTest {
                 per_byte_storage_cost: 1,
                 per_event_cost: 1,
                 per_log_cost: 1,
+                per_hash_cost: 1,
+                per_hash_block_cost: 1,
+                per_signature_verification_cost: 1,
             },
             key_seed: 1,
+            max_call_depth: MAX_CALL_DEPTH,
+            enable_tracing: false,
         }
     }
 
@@ -176,6 +189,7 @@ impl TemplateTest {
                     owner_rule: OwnerRule::None,
                     access_rules: ComponentAccessRules::allow_all(),
                     entity_id,
+                    reentrancy_guard: false,
                     body: ComponentBody { state },
                 }),
             )
@@ -192,6 +206,16 @@ impl TemplateTest {
         self
     }
 
+    pub fn fees_enabled(&self) -> bool {
+        self.enable_fees
+    }
+
+    /// Records an execution trace for each executed transaction, see [`ExecuteResult::trace`]
+    pub fn enable_tracing(&mut self) -> &mut Self {
+        self.enable_tracing = true;
+        self
+    }
+
     pub fn fee_table(&self) -> &FeeTable {
         &self.fee_table
     }
@@ -201,15 +225,63 @@ impl TemplateTest {
         self
     }
 
+    pub fn set_max_call_depth(&mut self, max_call_depth: usize) -> &mut Self {
+        self.max_call_depth = max_call_depth;
+        self
+    }
+
     pub fn set_virtual_substate(&mut self, address: VirtualSubstateId, value: VirtualSubstate) -> &mut Self {
         self.virtual_substates.insert(address, value);
         self
     }
 
+    /// Returns the epoch that transactions currently execute in
+    pub fn current_epoch(&self) -> u64 {
+        match self.virtual_substates.get(&VirtualSubstateId::CurrentEpoch) {
+            Some(VirtualSubstate::CurrentEpoch(epoch)) => *epoch,
+            _ => panic!("Current epoch virtual substate is not set"),
+        }
+    }
+
+    pub fn set_epoch(&mut self, epoch: u64) -> &mut Self {
+        self.set_virtual_substate(VirtualSubstateId::CurrentEpoch, VirtualSubstate::CurrentEpoch(epoch))
+    }
+
+    /// Moves the current epoch forward by the given number of epochs
+    pub fn advance_epoch(&mut self, epochs: u64) -> &mut Self {
+        let epoch = self.current_epoch() + epochs;
+        self.set_epoch(epoch)
+    }
+
+    /// Captures the current substates, virtual substates (including the epoch) and last outputs so that they can be
+    /// restored later using [TemplateTest::restore]
+    pub fn snapshot(&self) -> StateSnapshot {
+        StateSnapshot {
+            state_store: self.state_store.clone(),
+            virtual_substates: self.virtual_substates.clone(),
+            last_outputs: self.last_outputs.clone(),
+        }
+    }
+
+    /// Resets the state to a previously captured snapshot. The same snapshot may be restored any number of times.
+    pub fn restore(&mut self, snapshot: &StateSnapshot) -> &mut Self {
+        self.state_store = snapshot.state_store.clone();
+        self.virtual_substates = snapshot.virtual_substates.clone();
+        self.last_outputs = snapshot.last_outputs.clone();
+        self
+    }
+
     pub fn read_only_state_store(&self) -> ReadOnlyStateStore<'_> {
         ReadOnlyStateStore::new(&self.state_store)
     }
 
+    /// Writes a substate directly to the state store without executing a transaction, e.g. to set up state that was
+    /// created by an earlier version of the engine
+    pub fn set_substate(&mut self, id: SubstateId, substate: Substate) -> &mut Self {
+        self.state_store.set_state(id, substate).unwrap();
+        self
+    }
+
     pub fn extract_component_value<T: DeserializeOwned>(&self, component_address: ComponentAddress, path: &str) -> T {
         self.read_only_state_store()
             .inspect_component(component_address)
@@ -219,6 +291,28 @@ impl TemplateTest {
             .unwrap_or_else(|| panic!("Expected component to have value at '{path}' but no value was found"))
     }
 
+    /// Returns the total balance of the resource held in the vaults of the component
+    pub fn get_component_balance(
+        &self,
+        component_address: ComponentAddress,
+        resource_address: ResourceAddress,
+    ) -> Amount {
+        self.read_only_state_store()
+            .get_component_balance(component_address, &resource_address)
+            .unwrap()
+    }
+
+    /// Returns how much the balance of the resource held by the component has changed since the snapshot was taken
+    pub fn balance_change_since(
+        &self,
+        snapshot: &StateSnapshot,
+        component_address: ComponentAddress,
+        resource_address: ResourceAddress,
+    ) -> Amount {
+        self.get_component_balance(component_address, resource_address) -
+            snapshot.get_component_balance(component_address, resource_address)
+    }
+
     pub fn default_signing_key(&self) -> &RistrettoSecretKey {
         &self.secret_key
     }
@@ -240,7 +334,7 @@ impl TemplateTest {
             .unwrap_or_else(|| panic!("No output of type {:?}", ty))
     }
 
-    fn commit_diff(&mut self, diff: &SubstateDiff) {
+    pub(crate) fn commit_diff(&mut self, diff: &SubstateDiff) {
         self.last_outputs.clear();
 
         for (address, _) in diff.down_iter() {
@@ -342,6 +436,38 @@ impl TemplateTest {
         result.finalize.execution_results[0].decode().unwrap()
     }
 
+    /// Calls a non-mutating method in read-only mode without a transaction
+    pub fn call_view<T>(&self, component_address: ComponentAddress, method_name: &str, args: Vec<Arg>) -> T
+    where T: DeserializeOwned {
+        self.try_call_view(component_address, method_name, args)
+            .unwrap()
+            .decode()
+            .unwrap()
+    }
+
+    pub fn try_call_view(
+        &self,
+        component_address: ComponentAddress,
+        method_name: &str,
+        args: Vec<Arg>,
+    ) -> Result<InstructionResult, TransactionError> {
+        let processor = TransactionProcessor::new(
+            TransactionProcessorConfig::builder()
+                .with_network(Network::LocalNet)
+                .with_max_call_depth(self.max_call_depth)
+                .build(),
+            self.package.clone(),
+            self.state_store.clone().into_read_only(),
+            AuthParams {
+                initial_ownership_proofs: vec![],
+            },
+            self.virtual_substates.clone(),
+            vec![Arc::new(self.track_calls.clone())],
+        );
+
+        processor.call_view(&component_address, method_name, args)
+    }
+
     pub fn get_instructions_to_pay_fee_from_faucet(&self) -> Vec<Instruction> {
         vec![Instruction::CallFunction {
             template_address: self.get_template_address("Faucet2"),
@@ -443,6 +569,12 @@ impl TemplateTest {
         seed
     }
 
+    /// Creates a new signer with a unique key
+    pub fn create_signer(&mut self) -> TestSigner {
+        let (secret_key, _) = create_key_pair_from_seed(self.next_key_seed());
+        TestSigner::from_secret_key(secret_key)
+    }
+
     pub fn create_owner_proof(&mut self) -> (NonFungibleAddress, RistrettoPublicKey, RistrettoSecretKey) {
         let (secret_key, public_key) = create_key_pair_from_seed(self.next_key_seed());
         let public_key_bytes = RistrettoPublicKeyBytes::from_bytes(public_key.as_bytes()).unwrap();
@@ -465,15 +597,26 @@ impl TemplateTest {
     }
 
     pub fn try_execute(
+        &mut self,
+        transaction: Transaction,
+        proofs: Vec<NonFungibleAddress>,
+    ) -> Result<ExecuteResult, TransactionError> {
+        self.try_execute_with_modules(transaction, proofs, vec![])
+    }
+
+    /// Executes the transaction with additional runtime modules (e.g. to measure the execution) without committing it
+    pub fn try_execute_with_modules(
         &mut self,
         mut transaction: Transaction,
         proofs: Vec<NonFungibleAddress>,
+        extra_modules: Vec<Arc<dyn RuntimeModule>>,
     ) -> Result<ExecuteResult, TransactionError> {
         let mut modules: Vec<Arc<dyn RuntimeModule>> = vec![Arc::new(self.track_calls.clone())];
 
         if self.enable_fees {
             modules.push(Arc::new(FeeModule::new(0, self.fee_table.clone())));
         }
+        modules.extend(extra_modules);
 
         let auth_params = AuthParams {
             initial_ownership_proofs: proofs,
@@ -481,6 +624,8 @@ impl TemplateTest {
         let processor = TransactionProcessor::new(
             TransactionProcessorConfig::builder()
                 .with_network(Network::LocalNet)
+                .with_max_call_depth(self.max_call_depth)
+                .with_tracing(self.enable_tracing)
                 .build(),
             self.package.clone(),
             self.state_store.clone().into_read_only(),
@@ -569,6 +714,27 @@ impl TemplateTest {
         result.expect_failure().clone()
     }
 
+    /// Signs the transaction by all signers and executes it with their owner proofs. Panics if the transaction fails.
+    pub fn execute_expect_success_signed_by(
+        &mut self,
+        builder: TransactionBuilder,
+        signers: &[&TestSigner],
+    ) -> ExecuteResult {
+        let (transaction, proofs) = sign_transaction(builder, signers);
+        self.execute_expect_success(transaction, proofs)
+    }
+
+    /// Signs the transaction by all signers and executes it with their owner proofs. Panics if the transaction
+    /// succeeds.
+    pub fn execute_expect_failure_signed_by(
+        &mut self,
+        builder: TransactionBuilder,
+        signers: &[&TestSigner],
+    ) -> RejectReason {
+        let (transaction, proofs) = sign_transaction(builder, signers);
+        self.execute_expect_failure(transaction, proofs)
+    }
+
     pub fn execute_and_commit(
         &mut self,
         instructions: Vec<Instruction>,
@@ -631,6 +797,36 @@ impl TemplateTest {
     }
 }
 
+/// A copy of the state of a [TemplateTest] at a point in time
+#[derive(Debug, Clone)]
+pub struct StateSnapshot {
+    state_store: MemoryStateStore,
+    virtual_substates: VirtualSubstates,
+    last_outputs: HashSet<SubstateId>,
+}
+
+impl StateSnapshot {
+    pub fn read_only_state_store(&self) -> ReadOnlyStateStore<'_> {
+        ReadOnlyStateStore::new(&self.state_store)
+    }
+
+    /// Returns the component's balance at the time of the snapshot. A component that did not exist yet has a zero
+    /// balance.
+    pub fn get_component_balance(
+        &self,
+        component_address: ComponentAddress,
+        resource_address: ResourceAddress,
+    ) -> Amount {
+        let store = self.read_only_state_store();
+        if !store.exists(&SubstateId::Component(component_address)).unwrap() {
+            return Amount::zero();
+        }
+        store
+            .get_component_balance(component_address, &resource_address)
+            .unwrap()
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum SubstateType {
     Component,
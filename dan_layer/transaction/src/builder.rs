@@ -255,7 +255,8 @@ impl TransactionBuilder {
         }
     }
 
-    pub fn build_and_seal(self, secret_key: &PrivateKey) -> Transaction {
+    /// Builds the transaction ready to be sealed. If no signatures have been added, the seal signer is authorized.
+    pub fn build_for_seal(self) -> UnsealedTransactionV1 {
         self.then(|builder| {
             // This is so that we dont have to add this in a lot of places - TODO: this is an assumption that may not
             // apply to all transactions
@@ -266,6 +267,9 @@ impl TransactionBuilder {
             }
         })
         .build()
-        .seal(secret_key)
+    }
+
+    pub fn build_and_seal(self, secret_key: &PrivateKey) -> Transaction {
+        self.build_for_seal().seal(secret_key)
     }
 }
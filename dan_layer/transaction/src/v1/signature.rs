@@ -59,7 +59,9 @@ impl TransactionSealSignature {
         &self.public_key
     }
 
-    fn create_message(transaction: &UnsealedTransactionV1) -> [u8; 64] {
+    /// Returns the message that is signed by the seal signer. This allows the seal signature to be created
+    /// externally.
+    pub fn create_message(transaction: &UnsealedTransactionV1) -> [u8; 64] {
         hasher64(EngineHashDomainLabel::TransactionSignature)
             .chain(&transaction.schema_version())
             .chain(transaction)
@@ -112,7 +114,9 @@ impl TransactionSignature {
         &self.public_key
     }
 
-    fn create_message(seal_signer: &PublicKey, transaction: &UnsignedTransactionV1) -> [u8; 64] {
+    /// Returns the message that is signed by a transaction signer. This allows the signature to be created
+    /// externally.
+    pub fn create_message(seal_signer: &PublicKey, transaction: &UnsignedTransactionV1) -> [u8; 64] {
         let signature_fields = TransactionSignatureFields::from(transaction);
         hasher64(EngineHashDomainLabel::TransactionSignature)
             .chain(seal_signer)
@@ -57,6 +57,13 @@ impl UnsealedTransactionV1 {
         self
     }
 
+    /// Adds a signature that was created externally. The signature must sign the message returned by
+    /// [TransactionSignature::create_message].
+    pub fn with_signature(mut self, signature: TransactionSignature) -> Self {
+        self.signatures.push(signature);
+        self
+    }
+
     pub fn unsigned_transaction(&self) -> &UnsignedTransactionV1 {
         &self.transaction
     }
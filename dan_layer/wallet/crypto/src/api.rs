@@ -11,13 +11,14 @@ use tari_crypto::{
 use tari_engine_types::confidential::{challenges, get_commitment_factory, ConfidentialOutput};
 use tari_template_lib::{
     crypto::{BalanceProofSignature, PedersonCommitmentBytes},
-    models::{Amount, ConfidentialOutputStatement, ConfidentialWithdrawProof, EncryptedData},
+    models::{Amount, ConfidentialWithdrawProof, EncryptedData},
 };
 
 use crate::{
     confidential_output::ConfidentialOutputMaskAndValue,
     kdfs,
-    proof::{create_confidential_output_statement, decrypt_data_and_mask, encrypt_data},
+    proof::{create_confidential_output_statement_with_additional_outputs, decrypt_data_and_mask, encrypt_data},
+    AdditionalOutput,
     ConfidentialProofError,
     ConfidentialProofStatement,
 };
@@ -30,11 +31,35 @@ pub fn create_withdraw_proof(
     change_statement: Option<&ConfidentialProofStatement>,
     change_revealed_amount: Amount,
 ) -> Result<ConfidentialWithdrawProof, WalletCryptoError> {
-    let output_proof = create_confidential_output_statement(
+    create_withdraw_proof_with_additional_outputs(
+        inputs,
+        input_revealed_amount,
+        output_statement,
+        output_revealed_amount,
+        change_statement,
+        change_revealed_amount,
+        &[],
+    )
+}
+
+/// Creates a withdraw proof with additional outputs. The resulting proof must be used with
+/// `Vault::withdraw_confidential_many`, which returns a bucket for the output followed by a bucket for each
+/// additional output.
+pub fn create_withdraw_proof_with_additional_outputs(
+    inputs: &[ConfidentialOutputMaskAndValue],
+    input_revealed_amount: Amount,
+    output_statement: Option<&ConfidentialProofStatement>,
+    output_revealed_amount: Amount,
+    change_statement: Option<&ConfidentialProofStatement>,
+    change_revealed_amount: Amount,
+    additional_outputs: &[AdditionalOutput],
+) -> Result<ConfidentialWithdrawProof, WalletCryptoError> {
+    let output_proof = create_confidential_output_statement_with_additional_outputs(
         output_statement,
         output_revealed_amount,
         change_statement,
         change_revealed_amount,
+        additional_outputs,
     )?;
     let (input_commitments, agg_input_mask) = inputs.iter().fold(
         (Vec::with_capacity(inputs.len()), RistrettoSecretKey::default()),
@@ -47,28 +72,29 @@ pub fn create_withdraw_proof(
         },
     );
 
-    let output_revealed_amount = output_proof.output_revealed_amount + output_proof.change_revealed_amount;
+    let agg_output_mask = output_statement
+        .into_iter()
+        .chain(change_statement)
+        .chain(additional_outputs.iter().filter_map(|o| o.statement.as_ref()))
+        .fold(RistrettoSecretKey::default(), |agg, stmt| agg + &stmt.mask);
+    let output_revealed_amount =
+        output_proof
+            .total_revealed_amount()
+            .ok_or_else(|| WalletCryptoError::InvalidArgument {
+                name: "output_revealed_amount",
+                details: "total revealed output amount overflows".to_string(),
+            })?;
     let balance_proof = generate_balance_proof(
         &agg_input_mask,
         input_revealed_amount,
-        output_statement.as_ref().map(|o| &o.mask),
-        change_statement.as_ref().map(|ch| &ch.mask),
+        &agg_output_mask,
         output_revealed_amount,
     );
 
-    let output_statement = output_proof.output_statement;
-    let change_statement = output_proof.change_statement;
-
     Ok(ConfidentialWithdrawProof {
         inputs: input_commitments,
         input_revealed_amount,
-        output_proof: ConfidentialOutputStatement {
-            output_statement,
-            change_statement,
-            range_proof: output_proof.range_proof,
-            output_revealed_amount: output_proof.output_revealed_amount,
-            change_revealed_amount: output_proof.change_revealed_amount,
-        },
+        output_proof,
         balance_proof,
     })
 }
@@ -146,13 +172,10 @@ fn create_commitment(mask: &RistrettoSecretKey, value: u64) -> PedersenCommitmen
 fn generate_balance_proof(
     input_mask: &RistrettoSecretKey,
     input_revealed_amount: Amount,
-    output_mask: Option<&RistrettoSecretKey>,
-    change_mask: Option<&RistrettoSecretKey>,
+    output_mask: &RistrettoSecretKey,
     output_reveal_amount: Amount,
 ) -> BalanceProofSignature {
-    let secret_excess = input_mask -
-        output_mask.unwrap_or(&RistrettoSecretKey::default()) -
-        change_mask.unwrap_or(&RistrettoSecretKey::default());
+    let secret_excess = input_mask - output_mask;
     if secret_excess == RistrettoSecretKey::default() {
         // This is a revealed only proof
         return BalanceProofSignature::zero();
@@ -23,3 +23,10 @@ impl ConfidentialProofStatement {
         get_commitment_factory().commit_value(&self.mask, self.amount.value() as u64)
     }
 }
+
+/// An additional output of a multi-output withdraw proof. The output may be confidential, revealed or both.
+#[derive(Debug, Clone)]
+pub struct AdditionalOutput {
+    pub statement: Option<ConfidentialProofStatement>,
+    pub revealed_amount: Amount,
+}
@@ -12,6 +12,8 @@ pub enum ConfidentialProofError {
     AeadError,
     #[error("Negative amount")]
     NegativeAmount,
+    #[error("Too many outputs: a range proof can contain at most {max} outputs but {given} were given")]
+    TooManyOutputs { max: usize, given: usize },
 }
 
 impl From<aead::Error> for ConfidentialProofError {
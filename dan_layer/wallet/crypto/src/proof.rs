@@ -16,7 +16,6 @@ use chacha20poly1305::{
 use digest::FixedOutput;
 use tari_crypto::{
     commitment::{ExtensionDegree, HomomorphicCommitmentFactory},
-    errors::RangeProofError,
     extended_range_proof::ExtendedRangeProofService,
     hashing::DomainSeparatedHasher,
     keys::{PublicKey, SecretKey},
@@ -29,18 +28,21 @@ use tari_crypto::{
     },
     tari_utilities::ByteArray,
 };
-use tari_engine_types::confidential::{challenges, get_commitment_factory, get_range_proof_service};
+use tari_engine_types::confidential::{
+    challenges,
+    get_commitment_factory,
+    get_range_proof_service_for,
+    MAX_RANGE_PROOF_COMMITMENTS,
+};
 use tari_hashing::TransactionSecureNonceKdfDomain;
-use tari_template_lib::{
-    crypto::RistrettoPublicKeyBytes,
-    models::{
-        Amount,
-        ConfidentialOutputStatement,
-        ConfidentialStatement,
-        EncryptedData,
-        ViewableBalanceProof,
-        ViewableBalanceProofChallengeFields,
-    },
+use tari_template_lib::models::{
+    AdditionalOutputStatement,
+    Amount,
+    ConfidentialOutputStatement,
+    ConfidentialStatement,
+    EncryptedData,
+    ViewableBalanceProof,
+    ViewableBalanceProofChallengeFields,
 };
 use tari_utilities::safe_array::SafeArray;
 use zeroize::{Zeroize, Zeroizing};
@@ -49,6 +51,7 @@ use crate::{
     byte_utils::copy_fixed,
     error::ConfidentialProofError,
     kdfs::EncryptedDataKey,
+    AdditionalOutput,
     ConfidentialProofStatement,
 };
 
@@ -58,48 +61,46 @@ pub fn create_confidential_output_statement(
     change_statement: Option<&ConfidentialProofStatement>,
     change_revealed_amount: Amount,
 ) -> Result<ConfidentialOutputStatement, ConfidentialProofError> {
-    let proof_change_statement = change_statement
-        .as_ref()
-        .map(|stmt| -> Result<_, ConfidentialProofError> {
-            let change_commitment = stmt.to_commitment();
-            Ok(ConfidentialStatement {
-                commitment: copy_fixed(change_commitment.as_bytes()),
-                sender_public_nonce: RistrettoPublicKeyBytes::from_bytes(stmt.sender_public_nonce.as_bytes())
-                    .expect("[generate_confidential_proof] change nonce"),
-                encrypted_data: stmt.encrypted_data.clone(),
-                minimum_value_promise: stmt.minimum_value_promise,
-                viewable_balance_proof: stmt.resource_view_key.as_ref().map(|view_key| {
-                    create_viewable_balance_proof(
-                        &stmt.mask,
-                        stmt.amount.as_u64_checked().unwrap(),
-                        &change_commitment,
-                        view_key,
-                    )
-                }),
+    create_confidential_output_statement_with_additional_outputs(
+        output_statement,
+        output_revealed_amount,
+        change_statement,
+        change_revealed_amount,
+        &[],
+    )
+}
+
+/// Creates an output statement with additional outputs, for use with `Vault::withdraw_confidential_many`. A single
+/// aggregated range proof is generated over the output, change and additional statements (in that order).
+pub fn create_confidential_output_statement_with_additional_outputs(
+    output_statement: Option<&ConfidentialProofStatement>,
+    output_revealed_amount: Amount,
+    change_statement: Option<&ConfidentialProofStatement>,
+    change_revealed_amount: Amount,
+    additional_outputs: &[AdditionalOutput],
+) -> Result<ConfidentialOutputStatement, ConfidentialProofError> {
+    let proof_output_statement = output_statement.map(create_confidential_statement).transpose()?;
+    let proof_change_statement = change_statement.map(create_confidential_statement).transpose()?;
+    let proof_additional_outputs = additional_outputs
+        .iter()
+        .map(|output| {
+            Ok(AdditionalOutputStatement {
+                statement: output
+                    .statement
+                    .as_ref()
+                    .map(create_confidential_statement)
+                    .transpose()?,
+                revealed_amount: output.revealed_amount,
             })
         })
-        .transpose()?;
-    let confidential_output_value = output_statement
-        .as_ref()
-        .map(|o| o.amount)
-        .unwrap_or_default()
-        .as_u64_checked()
-        .ok_or(ConfidentialProofError::NegativeAmount)?;
-
-    let proof_output_statement = output_statement.as_ref().map(|stmt| {
-        let commitment = stmt.to_commitment();
-        ConfidentialStatement {
-            commitment: copy_fixed(commitment.as_bytes()),
-            sender_public_nonce: copy_fixed(stmt.sender_public_nonce.as_bytes()),
-            encrypted_data: stmt.encrypted_data.clone(),
-            minimum_value_promise: stmt.minimum_value_promise,
-            viewable_balance_proof: stmt.resource_view_key.as_ref().map(|view_key| {
-                create_viewable_balance_proof(&stmt.mask, confidential_output_value, &commitment, view_key)
-            }),
-        }
-    });
+        .collect::<Result<_, ConfidentialProofError>>()?;
 
-    let output_range_proof = generate_extended_bullet_proof(output_statement, change_statement)?;
+    let output_range_proof = generate_extended_bullet_proof(
+        output_statement
+            .into_iter()
+            .chain(change_statement)
+            .chain(additional_outputs.iter().filter_map(|o| o.statement.as_ref())),
+    )?;
 
     Ok(ConfidentialOutputStatement {
         output_statement: proof_output_statement,
@@ -107,6 +108,27 @@ pub fn create_confidential_output_statement(
         range_proof: output_range_proof,
         output_revealed_amount,
         change_revealed_amount,
+        additional_outputs: proof_additional_outputs,
+    })
+}
+
+fn create_confidential_statement(
+    stmt: &ConfidentialProofStatement,
+) -> Result<ConfidentialStatement, ConfidentialProofError> {
+    let value = stmt
+        .amount
+        .as_u64_checked()
+        .ok_or(ConfidentialProofError::NegativeAmount)?;
+    let commitment = stmt.to_commitment();
+    Ok(ConfidentialStatement {
+        commitment: copy_fixed(commitment.as_bytes()),
+        sender_public_nonce: copy_fixed(stmt.sender_public_nonce.as_bytes()),
+        encrypted_data: stmt.encrypted_data.clone(),
+        minimum_value_promise: stmt.minimum_value_promise,
+        viewable_balance_proof: stmt
+            .resource_view_key
+            .as_ref()
+            .map(|view_key| create_viewable_balance_proof(&stmt.mask, value, &commitment, view_key)),
     })
 }
 
@@ -265,40 +287,35 @@ pub fn decrypt_data_and_mask(
     ))
 }
 
-fn generate_extended_bullet_proof(
-    output_statement: Option<&ConfidentialProofStatement>,
-    change_statement: Option<&ConfidentialProofStatement>,
-) -> Result<Vec<u8>, RangeProofError> {
-    if output_statement.is_none() && change_statement.is_none() {
+fn generate_extended_bullet_proof<'a, I: IntoIterator<Item = &'a ConfidentialProofStatement>>(
+    statements: I,
+) -> Result<Vec<u8>, ConfidentialProofError> {
+    let extended_witnesses = statements
+        .into_iter()
+        .map(|stmt| {
+            let extended_mask =
+                RistrettoExtendedMask::assign(ExtensionDegree::DefaultPedersen, vec![stmt.mask.clone()]).unwrap();
+            RistrettoExtendedWitness {
+                mask: extended_mask,
+                value: stmt.amount.value() as u64,
+                minimum_value_promise: stmt.minimum_value_promise,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if extended_witnesses.is_empty() {
         // We're only outputting revealed funds, so no need to generate a range proof (i.e. zero length is valid)
         return Ok(vec![]);
     }
-
-    let mut extended_witnesses = vec![];
-
-    let mut agg_factor = 0;
-    if let Some(stmt) = output_statement {
-        let extended_mask =
-            RistrettoExtendedMask::assign(ExtensionDegree::DefaultPedersen, vec![stmt.mask.clone()]).unwrap();
-        extended_witnesses.push(RistrettoExtendedWitness {
-            mask: extended_mask,
-            value: stmt.amount.value() as u64,
-            minimum_value_promise: stmt.minimum_value_promise,
+    if extended_witnesses.len() > MAX_RANGE_PROOF_COMMITMENTS {
+        return Err(ConfidentialProofError::TooManyOutputs {
+            max: MAX_RANGE_PROOF_COMMITMENTS,
+            given: extended_witnesses.len(),
         });
-        agg_factor += 1;
-    }
-    if let Some(stmt) = change_statement {
-        let extended_mask =
-            RistrettoExtendedMask::assign(ExtensionDegree::DefaultPedersen, vec![stmt.mask.clone()]).unwrap();
-        extended_witnesses.push(RistrettoExtendedWitness {
-            mask: extended_mask,
-            value: stmt.amount.value() as u64,
-            minimum_value_promise: stmt.minimum_value_promise,
-        });
-        agg_factor += 1;
     }
 
-    let output_range_proof = get_range_proof_service(agg_factor).construct_extended_proof(extended_witnesses, None)?;
+    let output_range_proof =
+        get_range_proof_service_for(extended_witnesses.len()).construct_extended_proof(extended_witnesses, None)?;
     Ok(output_range_proof)
 }
 
@@ -347,6 +364,62 @@ mod tests {
             proof.output_statement.as_mut().unwrap().minimum_value_promise = 1000;
             validate_confidential_proof(&proof, None).unwrap_err();
         }
+
+        #[test]
+        fn it_is_valid_with_additional_outputs() {
+            let statement = |amount: i64| ConfidentialProofStatement {
+                amount: Amount(amount),
+                minimum_value_promise: 0,
+                mask: RistrettoSecretKey::random(&mut OsRng),
+                sender_public_nonce: Default::default(),
+                encrypted_data: EncryptedData::try_from(vec![0; EncryptedData::min_size()]).unwrap(),
+                resource_view_key: None,
+            };
+            let additional_outputs = (1i64..=4)
+                .map(|i| AdditionalOutput {
+                    statement: Some(statement(i * 10)),
+                    revealed_amount: Amount(i),
+                })
+                .collect::<Vec<_>>();
+            let proof = create_confidential_output_statement_with_additional_outputs(
+                Some(&statement(100)),
+                Amount::zero(),
+                Some(&statement(50)),
+                Amount::zero(),
+                &additional_outputs,
+            )
+            .unwrap();
+
+            let validated = validate_confidential_proof(&proof, None).unwrap();
+            assert_eq!(validated.additional_outputs.len(), 4);
+            assert_eq!(proof.total_revealed_amount(), Some(Amount(10)));
+        }
+
+        #[test]
+        fn it_rejects_too_many_outputs() {
+            let additional_outputs = (0..MAX_RANGE_PROOF_COMMITMENTS)
+                .map(|_| AdditionalOutput {
+                    statement: Some(ConfidentialProofStatement {
+                        amount: 1.into(),
+                        minimum_value_promise: 0,
+                        mask: RistrettoSecretKey::random(&mut OsRng),
+                        sender_public_nonce: Default::default(),
+                        encrypted_data: EncryptedData::try_from(vec![0; EncryptedData::min_size()]).unwrap(),
+                        resource_view_key: None,
+                    }),
+                    revealed_amount: Amount::zero(),
+                })
+                .collect::<Vec<_>>();
+            let err = create_confidential_output_statement_with_additional_outputs(
+                None,
+                Amount::zero(),
+                None,
+                Amount::zero(),
+                &additional_outputs,
+            )
+            .unwrap_err();
+            assert!(matches!(err, ConfidentialProofError::TooManyOutputs { .. }));
+        }
     }
 
     mod encrypt_decrypt {
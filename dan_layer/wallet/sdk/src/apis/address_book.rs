@@ -0,0 +1,76 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use tari_dan_common_types::optional::{IsNotFoundError, Optional};
+
+use crate::{
+    models::Contact,
+    storage::{WalletStorageError, WalletStore, WalletStoreReader, WalletStoreWriter},
+};
+
+pub struct AddressBookApi<'a, TStore> {
+    store: &'a TStore,
+}
+
+impl<'a, TStore: WalletStore> AddressBookApi<'a, TStore> {
+    pub fn new(store: &'a TStore) -> Self {
+        Self { store }
+    }
+
+    pub fn add_contact(&self, contact: &Contact) -> Result<(), AddressBookApiError> {
+        if contact.name.trim().is_empty() {
+            return Err(AddressBookApiError::InvalidContactName {
+                name: contact.name.clone(),
+            });
+        }
+
+        self.store.with_write_tx(|tx| {
+            if tx.contacts_get_by_name(&contact.name).optional()?.is_some() {
+                return Err(AddressBookApiError::ContactNameAlreadyExists {
+                    name: contact.name.clone(),
+                });
+            }
+            tx.contacts_insert(contact)?;
+            Ok(())
+        })
+    }
+
+    pub fn get_contact(&self, name: &str) -> Result<Contact, AddressBookApiError> {
+        let mut tx = self.store.create_read_tx()?;
+        let contact = tx.contacts_get_by_name(name)?;
+        Ok(contact)
+    }
+
+    pub fn get_contacts(&self, offset: u64, limit: u64) -> Result<Vec<Contact>, AddressBookApiError> {
+        let mut tx = self.store.create_read_tx()?;
+        let contacts = tx.contacts_get_many(offset, limit)?;
+        Ok(contacts)
+    }
+
+    pub fn count(&self) -> Result<u64, AddressBookApiError> {
+        let mut tx = self.store.create_read_tx()?;
+        let count = tx.contacts_count()?;
+        Ok(count)
+    }
+
+    pub fn remove_contact(&self, name: &str) -> Result<Contact, AddressBookApiError> {
+        let contact = self.store.with_write_tx(|tx| tx.contacts_remove(name))?;
+        Ok(contact)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AddressBookApiError {
+    #[error("Store error: {0}")]
+    StoreError(#[from] WalletStorageError),
+    #[error("Contact name already exists: {name}")]
+    ContactNameAlreadyExists { name: String },
+    #[error("Invalid contact name: '{name}'")]
+    InvalidContactName { name: String },
+}
+
+impl IsNotFoundError for AddressBookApiError {
+    fn is_not_found_error(&self) -> bool {
+        matches!(self, Self::StoreError(e) if e.is_not_found_error() )
+    }
+}
@@ -8,10 +8,12 @@ use tari_dan_wallet_crypto::{
     create_confidential_output_statement,
     create_output_for_dest,
     create_withdraw_proof,
+    create_withdraw_proof_with_additional_outputs,
     encrypt_value_and_mask,
     extract_value_and_mask,
     kdfs,
     unblind_output,
+    AdditionalOutput,
     ConfidentialOutputMaskAndValue,
     ConfidentialProofError,
     ConfidentialProofStatement,
@@ -55,6 +57,29 @@ impl ConfidentialCryptoApi {
         Ok(proof)
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub fn generate_withdraw_proof_with_additional_outputs(
+        &self,
+        inputs: &[ConfidentialOutputMaskAndValue],
+        input_revealed_amount: Amount,
+        output_statement: Option<&ConfidentialProofStatement>,
+        output_revealed_amount: Amount,
+        change_statement: Option<&ConfidentialProofStatement>,
+        change_revealed_amount: Amount,
+        additional_outputs: &[AdditionalOutput],
+    ) -> Result<ConfidentialWithdrawProof, ConfidentialCryptoApiError> {
+        let proof = create_withdraw_proof_with_additional_outputs(
+            inputs,
+            input_revealed_amount,
+            output_statement,
+            output_revealed_amount,
+            change_statement,
+            change_revealed_amount,
+            additional_outputs,
+        )?;
+        Ok(proof)
+    }
+
     pub fn encrypt_value_and_mask(
         &self,
         amount: u64,
@@ -2,11 +2,10 @@
 //   SPDX-License-Identifier: BSD-3-Clause
 
 use log::*;
-use tari_common_types::types::PublicKey;
+use tari_common_types::types::PrivateKey;
 use tari_dan_common_types::optional::{IsNotFoundError, Optional};
 use tari_dan_wallet_crypto::{kdfs, ConfidentialOutputMaskAndValue};
 use tari_engine_types::{confidential::ConfidentialOutput, substate::SubstateId};
-use tari_key_manager::key_manager::DerivedKey;
 use tari_template_lib::models::Amount;
 use tari_transaction::TransactionId;
 
@@ -14,10 +13,10 @@ use crate::{
     apis::{
         accounts::{AccountsApi, AccountsApiError},
         confidential_crypto::{ConfidentialCryptoApi, ConfidentialCryptoApiError},
-        key_manager,
         key_manager::{KeyManagerApi, KeyManagerApiError},
     },
     models::{Account, ConfidentialOutputModel, ConfidentialProofId, OutputStatus},
+    signer::{TransactionSigner, TransactionSignerError},
     storage::{WalletStorageError, WalletStore, WalletStoreReader, WalletStoreWriter},
 };
 
@@ -28,6 +27,7 @@ pub struct ConfidentialOutputsApi<'a, TStore> {
     key_manager_api: KeyManagerApi<'a, TStore>,
     accounts_api: AccountsApi<'a, TStore>,
     crypto_api: ConfidentialCryptoApi,
+    signer: &'a dyn TransactionSigner,
 }
 
 impl<'a, TStore: WalletStore> ConfidentialOutputsApi<'a, TStore> {
@@ -36,12 +36,14 @@ impl<'a, TStore: WalletStore> ConfidentialOutputsApi<'a, TStore> {
         key_manager_api: KeyManagerApi<'a, TStore>,
         accounts_api: AccountsApi<'a, TStore>,
         crypto_api: ConfidentialCryptoApi,
+        signer: &'a dyn TransactionSigner,
     ) -> Self {
         Self {
             store,
             key_manager_api,
             accounts_api,
             crypto_api,
+            signer,
         }
     }
 
@@ -226,9 +228,19 @@ impl<'a, TStore: WalletStore> ConfidentialOutputsApi<'a, TStore> {
     ) -> Result<(), ConfidentialOutputsApiError> {
         let account = self.accounts_api.get_account_by_address(account_addr)?;
         // We do not support changing of account key at this time
-        let key = self
-            .key_manager_api
-            .derive_key(key_manager::TRANSACTION_BRANCH, account.key_index)?;
+        let key = match self.signer.secret_key(account.key_index) {
+            Ok(key) => key,
+            Err(TransactionSignerError::SecretKeyUnavailable { .. }) => {
+                warn!(
+                    target: LOG_TARGET,
+                    "Not tracking confidential outputs of account {} because its secret key is held by the external \
+                     signer",
+                    account.address
+                );
+                return Ok(());
+            },
+            Err(e) => return Err(e.into()),
+        };
         let mut tx = self.store.create_write_tx()?;
         for output in outputs {
             match tx.outputs_get_by_commitment(&output.commitment).optional()? {
@@ -266,14 +278,14 @@ impl<'a, TStore: WalletStore> ConfidentialOutputsApi<'a, TStore> {
     fn validate_output(
         &self,
         account: &Account,
-        key: &DerivedKey<PublicKey>,
+        key: &PrivateKey,
         vault_address: &SubstateId,
         output: &ConfidentialOutput,
     ) -> Result<ConfidentialOutputModel, ConfidentialOutputsApiError> {
         let unblinded_result = self.crypto_api.unblind_output(
             &output.commitment,
             &output.encrypted_data,
-            &key.key,
+            key,
             &output.stealth_public_nonce,
         );
         let (value, status) = match unblinded_result {
@@ -327,6 +339,8 @@ pub enum ConfidentialOutputsApiError {
     KeyManager(#[from] KeyManagerApiError),
     #[error("Accounts API error: {0}")]
     Accounts(#[from] AccountsApiError),
+    #[error("Signer error: {0}")]
+    Signer(#[from] TransactionSignerError),
     #[error("Invalid parameter `{param}`: {reason}")]
     InvalidParameter { param: &'static str, reason: String },
 }
@@ -9,13 +9,17 @@ use tari_bor::{Deserialize, Serialize};
 use tari_common_types::types::{PrivateKey, PublicKey};
 use tari_crypto::keys::PublicKey as _;
 use tari_dan_common_types::optional::{IsNotFoundError, Optional};
-use tari_dan_wallet_crypto::{ConfidentialOutputMaskAndValue, ConfidentialProofStatement};
-use tari_engine_types::{component::new_component_address_from_public_key, substate::SubstateId};
+use tari_dan_wallet_crypto::{AdditionalOutput, ConfidentialOutputMaskAndValue, ConfidentialProofStatement};
+use tari_engine_types::{
+    component::new_component_address_from_public_key,
+    confidential::MAX_RANGE_PROOF_COMMITMENTS,
+    substate::SubstateId,
+};
 use tari_template_builtin::ACCOUNT_TEMPLATE_ADDRESS;
 use tari_template_lib::{
     args,
     constants::CONFIDENTIAL_TARI_RESOURCE_ADDRESS,
-    models::{Amount, ComponentAddress, ResourceAddress},
+    models::{Amount, ComponentAddress, ConfidentialWithdrawProof, ResourceAddress},
 };
 use tari_transaction::Transaction;
 
@@ -28,19 +32,25 @@ use crate::{
         key_manager::{KeyManagerApi, KeyManagerApiError},
         substate::{SubstateApiError, SubstatesApi, ValidatorScanResult},
     },
-    models::{ConfidentialOutputModel, ConfidentialProofId, OutputStatus, VersionedSubstateId},
+    models::{Account, ConfidentialOutputModel, ConfidentialProofId, OutputStatus, VersionedSubstateId},
     network::WalletNetworkInterface,
+    signer::{TransactionSigner, TransactionSignerError},
     storage::{WalletStorageError, WalletStore},
 };
 
 const LOG_TARGET: &str = "tari::dan::wallet_sdk::apis::confidential_transfers";
 
+/// The maximum number of recipients of a batch transfer. Every recipient and the change output must fit in a single
+/// aggregated range proof.
+pub const MAX_BATCH_TRANSFER_RECIPIENTS: usize = MAX_RANGE_PROOF_COMMITMENTS - 1;
+
 pub struct ConfidentialTransferApi<'a, TStore, TNetworkInterface> {
     key_manager_api: KeyManagerApi<'a, TStore>,
     accounts_api: AccountsApi<'a, TStore>,
     outputs_api: ConfidentialOutputsApi<'a, TStore>,
     substate_api: SubstatesApi<'a, TStore, TNetworkInterface>,
     crypto_api: ConfidentialCryptoApi,
+    signer: &'a dyn TransactionSigner,
 }
 
 impl<'a, TStore, TNetworkInterface> ConfidentialTransferApi<'a, TStore, TNetworkInterface>
@@ -55,6 +65,7 @@ where
         outputs_api: ConfidentialOutputsApi<'a, TStore>,
         substate_api: SubstatesApi<'a, TStore, TNetworkInterface>,
         crypto_api: ConfidentialCryptoApi,
+        signer: &'a dyn TransactionSigner,
     ) -> Self {
         Self {
             key_manager_api,
@@ -62,6 +73,7 @@ where
             outputs_api,
             substate_api,
             crypto_api,
+            signer,
         }
     }
 
@@ -242,6 +254,8 @@ where
         }
 
         let account = self.accounts_api.get_account_by_address(&params.from_account.into())?;
+        // Fails before any funds are locked if the signer does not hold the account key in-process
+        let account_secret = self.signer.secret_key(account.key_index)?;
         let account_substate = self.substate_api.get_substate(&params.from_account.into())?;
         inputs.push(account_substate.address);
 
@@ -289,50 +303,14 @@ where
             ConfidentialTransferInputSelection::PreferRevealed,
         )?;
 
-        let account_secret = self
-            .key_manager_api
-            .derive_key(key_manager::TRANSACTION_BRANCH, account.key_index)?;
-        let account_public_key = PublicKey::from_secret_key(&account_secret.key);
-
-        // Generate fee proof
-        let fee_not_paid_by_revealed = params
-            .max_fee
-            .checked_sub_positive(fee_inputs_to_spend.revealed)
-            .expect("BUG: PreferRevealed did not pay <= the max_fee in revealed fees");
-        let confidential_change = fee_inputs_to_spend.total_confidential_amount() - fee_not_paid_by_revealed;
-        let maybe_fee_change_statement = if confidential_change.is_zero() {
-            // No change necessary
-            None
-        } else {
-            let statement = self.create_confidential_proof_statement(&account_public_key, confidential_change, None)?;
-
-            self.outputs_api.add_output(ConfidentialOutputModel {
-                account_address: account.address.clone(),
-                vault_address: src_vault.address.clone(),
-                commitment: statement.to_commitment(),
-                value: confidential_change.as_u64_checked().unwrap(),
-                sender_public_nonce: Some(statement.sender_public_nonce.clone()),
-                encryption_secret_key_index: account_secret.key_index,
-                encrypted_data: statement.encrypted_data.clone(),
-                public_asset_tag: None,
-                // TODO: We could technically spend this output in the main transaction, however, we cannot mark it
-                //       as unspent e.g. in the case of tx failure. We should allow spending of LockedUnconfirmed if
-                //       the locking transaction is the same.
-                status: OutputStatus::LockedUnconfirmed,
-                locked_by_proof: Some(fee_inputs_to_spend.proof_id),
-            })?;
-
-            Some(statement)
-        };
+        let account_public_key = PublicKey::from_secret_key(&account_secret);
 
-        let fee_withdraw_proof = self.crypto_api.generate_withdraw_proof(
-            fee_inputs_to_spend.confidential.as_slice(),
-            fee_inputs_to_spend.revealed,
-            None,
+        let fee_withdraw_proof = self.generate_fee_withdraw_proof(
+            &fee_inputs_to_spend,
+            &account,
+            &src_vault.address,
+            &account_secret,
             params.max_fee,
-            maybe_fee_change_statement.as_ref(),
-            // We always withdraw the exact amount of revealed required
-            Amount::zero(),
         )?;
 
         // Reserve and lock input funds
@@ -346,24 +324,7 @@ where
             Ok(inputs) => inputs,
             Err(e) => {
                 warn!(target: LOG_TARGET, "Unlocking fee fund locks after error: {}", e);
-                // This is a hack that addresses the case where input locking fails after the fee transaction. However
-                // any error after this point do not undo locking. This is a limitation of the current
-                // design - the db transaction should be passed in and automatically rolled back on error.
-                if let Err(err) = self.outputs_api.release_revealed_funds(fee_inputs_to_spend.proof_id) {
-                    error!(
-                        target: LOG_TARGET,
-                        "Failed to unlock revealed funds for transfer: {}",
-                        err
-                    );
-                }
-                if let Err(err) = self.outputs_api.release_proof_outputs(fee_inputs_to_spend.proof_id) {
-                    error!(
-                        target: LOG_TARGET,
-                        "Failed to release fee inputs for transfer: {}",
-                        err
-                    );
-                }
-
+                self.release_fee_locks(fee_inputs_to_spend.proof_id);
                 return Err(e);
             },
         };
@@ -416,7 +377,7 @@ where
                     commitment: statement.to_commitment(),
                     value: change_value,
                     sender_public_nonce: Some(statement.sender_public_nonce.clone()),
-                    encryption_secret_key_index: account_secret.key_index,
+                    encryption_secret_key_index: account.key_index,
                     encrypted_data: statement.encrypted_data.clone(),
                     public_asset_tag: None,
                     status: OutputStatus::LockedUnconfirmed,
@@ -465,7 +426,7 @@ where
             builder = builder.drop_all_proofs_in_workspace();
         }
 
-        let transaction = builder.build_and_seal(&account_secret.key);
+        let transaction = self.signer.build_and_seal(account.key_index, builder)?;
 
         self.outputs_api
             .proofs_set_transaction_hash(inputs_to_spend.proof_id, *transaction.id())?;
@@ -480,6 +441,353 @@ where
         })
     }
 
+    /// Transfers a confidential resource to many recipients in a single transaction with one fee payment. A single
+    /// withdraw proof is generated with an output per recipient and one change output, and the resulting buckets are
+    /// deposited into the respective recipient accounts.
+    pub async fn batch_transfer(
+        &self,
+        params: BatchTransferParams,
+    ) -> Result<BatchTransferOutput, ConfidentialTransferApiError> {
+        params.validate()?;
+        let total_amount = params.total_amount()?;
+
+        let account = self.accounts_api.get_account_by_address(&params.from_account.into())?;
+        // Fails before any funds are locked if the signer does not hold the account key in-process
+        let account_secret = self.signer.secret_key(account.key_index)?;
+
+        // Determine Transaction Inputs
+        let destinations = self.resolve_batch_destinations(&params.recipients).await?;
+        let mut inputs = destinations
+            .iter()
+            .filter(|d| d.exists)
+            .map(|d| d.account.clone())
+            .collect::<Vec<_>>();
+
+        let account_substate = self.substate_api.get_substate(&account.address)?;
+        inputs.push(account_substate.address);
+
+        // Add all versioned account child addresses as inputs
+        let child_addresses = self.substate_api.load_dependent_substates(&[&account.address])?;
+        inputs.extend(child_addresses);
+
+        let src_vault = self
+            .accounts_api
+            .get_vault_by_resource(&account.address, &params.resource_address)?;
+        let src_vault_substate = self.substate_api.get_substate(&src_vault.address)?;
+        inputs.push(src_vault_substate.address);
+
+        let resource_substate = self.scan_for_resource(params.resource_address).await?;
+        inputs.push(resource_substate.address.clone());
+        if let Some(resource_address) = params.proof_from_resource {
+            let resource_substate = self.scan_for_resource(resource_address).await?;
+            inputs.push(resource_substate.address);
+        }
+
+        let resource_view_key = resource_substate
+            .substate
+            .as_resource()
+            .ok_or_else(|| ConfidentialTransferApiError::UnexpectedIndexerResponse {
+                details: format!(
+                    "Expected indexer to return resource for address {}. It returned {}",
+                    params.resource_address, resource_substate.address
+                ),
+            })?
+            .view_key()
+            .cloned();
+
+        // Reserve and lock input funds for fees
+        let fee_inputs_to_spend = self.resolved_inputs_for_transfer(
+            params.from_account,
+            CONFIDENTIAL_TARI_RESOURCE_ADDRESS,
+            params.max_fee,
+            ConfidentialTransferInputSelection::PreferRevealed,
+        )?;
+
+        let fee_withdraw_proof = self.generate_fee_withdraw_proof(
+            &fee_inputs_to_spend,
+            &account,
+            &src_vault.address,
+            &account_secret,
+            params.max_fee,
+        )?;
+
+        // Reserve and lock input funds for all recipients
+        let inputs_to_spend = match self.resolved_inputs_for_transfer(
+            params.from_account,
+            params.resource_address,
+            total_amount,
+            params.input_selection,
+        ) {
+            Ok(inputs) => inputs,
+            Err(e) => {
+                warn!(target: LOG_TARGET, "Unlocking fee fund locks after error: {}", e);
+                self.release_fee_locks(fee_inputs_to_spend.proof_id);
+                return Err(e);
+            },
+        };
+
+        let proof = self.generate_batch_withdraw_proof(
+            &params,
+            &inputs_to_spend,
+            total_amount,
+            &account,
+            &src_vault.address,
+            &account_secret,
+            resource_view_key,
+        )?;
+
+        let from_account_address = params.from_account;
+        let mut builder = Transaction::builder()
+            .fee_transaction_pay_from_component_confidential(from_account_address, fee_withdraw_proof);
+
+        for destination in destinations.iter().filter(|d| !d.exists) {
+            builder = builder.create_account(destination.public_key.clone());
+        }
+
+        if let Some(ref badge) = params.proof_from_resource {
+            builder = builder
+                .call_method(from_account_address, "create_proof_for_resource", args![badge])
+                .put_last_instruction_output_on_workspace("proof");
+        }
+
+        // withdraw_confidential_many returns one bucket per recipient, in the order of the recipients
+        builder = builder
+            .call_method(from_account_address, "withdraw_confidential_many", args![
+                params.resource_address,
+                proof
+            ])
+            .put_last_instruction_output_on_workspace("buckets");
+
+        let mut recipients = Vec::with_capacity(params.recipients.len());
+        for (i, recipient) in params.recipients.iter().enumerate() {
+            let destination = destinations
+                .iter()
+                .find(|d| d.public_key == recipient.destination_public_key)
+                .expect("BUG: destination not resolved for recipient");
+            let to_account = destination.account.substate_id.as_component_address().unwrap();
+            builder = builder.call_method(to_account, "deposit", args![Workspace(format!("buckets.{}", i))]);
+
+            recipients.push(BatchTransferRecipientOutput {
+                destination_public_key: recipient.destination_public_key.clone(),
+                destination_account: to_account,
+                amount: recipient.amount,
+                account_created: !destination.exists,
+            });
+        }
+
+        if params.proof_from_resource.is_some() {
+            builder = builder.drop_all_proofs_in_workspace();
+        }
+
+        let transaction = self.signer.build_and_seal(account.key_index, builder)?;
+
+        self.outputs_api
+            .proofs_set_transaction_hash(inputs_to_spend.proof_id, *transaction.id())?;
+        self.outputs_api
+            .proofs_set_transaction_hash(fee_inputs_to_spend.proof_id, *transaction.id())?;
+
+        Ok(BatchTransferOutput {
+            transfer: TransferOutput {
+                transaction,
+                inputs,
+                fee_transaction_proof_id: Some(fee_inputs_to_spend.proof_id),
+                transaction_proof_id: Some(inputs_to_spend.proof_id),
+            },
+            recipients,
+        })
+    }
+
+    /// Resolves the account of each distinct recipient public key, in the order in which they first appear.
+    async fn resolve_batch_destinations(
+        &self,
+        recipients: &[BatchTransferRecipient],
+    ) -> Result<Vec<BatchTransferDestination>, ConfidentialTransferApiError> {
+        let mut destinations = Vec::<BatchTransferDestination>::with_capacity(recipients.len());
+        for recipient in recipients {
+            if destinations
+                .iter()
+                .any(|d| d.public_key == recipient.destination_public_key)
+            {
+                continue;
+            }
+            let (account, exists) = self
+                .resolve_destination_account(&recipient.destination_public_key)
+                .await?;
+            destinations.push(BatchTransferDestination {
+                public_key: recipient.destination_public_key.clone(),
+                account,
+                exists,
+            });
+        }
+        Ok(destinations)
+    }
+
+    async fn scan_for_resource(
+        &self,
+        resource_address: ResourceAddress,
+    ) -> Result<ValidatorScanResult, ConfidentialTransferApiError> {
+        let maybe_known_resource = self.substate_api.get_substate(&resource_address.into()).optional()?;
+        let resource_substate = self
+            .substate_api
+            .scan_for_substate(
+                &SubstateId::Resource(resource_address),
+                maybe_known_resource.map(|r| r.address.version),
+            )
+            .await?;
+        Ok(resource_substate)
+    }
+
+    /// Generates a single withdraw proof that pays every recipient from the locked inputs. The first recipient is
+    /// paid by the output statement and the rest by additional outputs. Any confidential change is recorded as a
+    /// locked, unconfirmed output of `change_vault`.
+    #[allow(clippy::too_many_arguments)]
+    fn generate_batch_withdraw_proof(
+        &self,
+        params: &BatchTransferParams,
+        inputs_to_spend: &InputsToSpend,
+        total_amount: Amount,
+        account: &Account,
+        change_vault: &SubstateId,
+        account_secret: &PrivateKey,
+        resource_view_key: Option<PublicKey>,
+    ) -> Result<ConfidentialWithdrawProof, ConfidentialTransferApiError> {
+        let mut outputs = Vec::with_capacity(params.recipients.len());
+        for recipient in &params.recipients {
+            let statement = self.create_confidential_proof_statement(
+                &recipient.destination_public_key,
+                params.confidential_amount_for(recipient),
+                resource_view_key.clone(),
+            )?;
+            outputs.push(AdditionalOutput {
+                statement: Some(statement).filter(|s| !s.amount.is_zero()),
+                revealed_amount: params.revealed_amount_for(recipient),
+            });
+        }
+
+        let remaining_left_to_pay = total_amount
+            .checked_sub_positive(inputs_to_spend.revealed)
+            .unwrap_or_else(|| {
+                panic!(
+                    "BUG: paid more revealed funds ({}) than the amount to pay ({})",
+                    inputs_to_spend.revealed, total_amount
+                )
+            });
+        let change_confidential_amount = inputs_to_spend
+            .total_confidential_amount()
+            .checked_sub_positive(remaining_left_to_pay)
+            .ok_or(ConfidentialTransferApiError::InsufficientFunds)?;
+
+        let maybe_change_statement = if change_confidential_amount.is_zero() {
+            None
+        } else {
+            let account_public_key = PublicKey::from_secret_key(account_secret);
+            let statement = self.create_confidential_proof_statement(
+                &account_public_key,
+                change_confidential_amount,
+                resource_view_key,
+            )?;
+            self.outputs_api.add_output(ConfidentialOutputModel {
+                account_address: account.address.clone(),
+                vault_address: change_vault.clone(),
+                commitment: statement.to_commitment(),
+                value: change_confidential_amount.as_u64_checked().unwrap(),
+                sender_public_nonce: Some(statement.sender_public_nonce.clone()),
+                encryption_secret_key_index: account.key_index,
+                encrypted_data: statement.encrypted_data.clone(),
+                public_asset_tag: None,
+                status: OutputStatus::LockedUnconfirmed,
+                locked_by_proof: Some(inputs_to_spend.proof_id),
+            })?;
+            Some(statement)
+        };
+
+        let first = outputs.remove(0);
+        let proof = self.crypto_api.generate_withdraw_proof_with_additional_outputs(
+            &inputs_to_spend.confidential,
+            inputs_to_spend.revealed,
+            first.statement.as_ref(),
+            first.revealed_amount,
+            maybe_change_statement.as_ref(),
+            Amount::zero(),
+            &outputs,
+        )?;
+
+        Ok(proof)
+    }
+
+    /// Generates the withdraw proof that pays the transaction fee from the locked fee inputs. Any confidential change
+    /// is recorded as a locked, unconfirmed output of `change_vault`.
+    fn generate_fee_withdraw_proof(
+        &self,
+        fee_inputs_to_spend: &InputsToSpend,
+        account: &Account,
+        change_vault: &SubstateId,
+        account_secret: &PrivateKey,
+        max_fee: Amount,
+    ) -> Result<ConfidentialWithdrawProof, ConfidentialTransferApiError> {
+        let account_public_key = PublicKey::from_secret_key(account_secret);
+        let fee_not_paid_by_revealed = max_fee
+            .checked_sub_positive(fee_inputs_to_spend.revealed)
+            .expect("BUG: PreferRevealed did not pay <= the max_fee in revealed fees");
+        let confidential_change = fee_inputs_to_spend.total_confidential_amount() - fee_not_paid_by_revealed;
+        let maybe_fee_change_statement = if confidential_change.is_zero() {
+            // No change necessary
+            None
+        } else {
+            let statement = self.create_confidential_proof_statement(&account_public_key, confidential_change, None)?;
+
+            self.outputs_api.add_output(ConfidentialOutputModel {
+                account_address: account.address.clone(),
+                vault_address: change_vault.clone(),
+                commitment: statement.to_commitment(),
+                value: confidential_change.as_u64_checked().unwrap(),
+                sender_public_nonce: Some(statement.sender_public_nonce.clone()),
+                encryption_secret_key_index: account.key_index,
+                encrypted_data: statement.encrypted_data.clone(),
+                public_asset_tag: None,
+                // TODO: We could technically spend this output in the main transaction, however, we cannot mark it
+                //       as unspent e.g. in the case of tx failure. We should allow spending of LockedUnconfirmed if
+                //       the locking transaction is the same.
+                status: OutputStatus::LockedUnconfirmed,
+                locked_by_proof: Some(fee_inputs_to_spend.proof_id),
+            })?;
+
+            Some(statement)
+        };
+
+        let fee_withdraw_proof = self.crypto_api.generate_withdraw_proof(
+            fee_inputs_to_spend.confidential.as_slice(),
+            fee_inputs_to_spend.revealed,
+            None,
+            max_fee,
+            maybe_fee_change_statement.as_ref(),
+            // We always withdraw the exact amount of revealed required
+            Amount::zero(),
+        )?;
+
+        Ok(fee_withdraw_proof)
+    }
+
+    /// This is a hack that addresses the case where input locking fails after the fee inputs have been locked.
+    /// However any error after this point do not undo locking. This is a limitation of the current design - the db
+    /// transaction should be passed in and automatically rolled back on error.
+    fn release_fee_locks(&self, fee_proof_id: ConfidentialProofId) {
+        if let Err(err) = self.outputs_api.release_revealed_funds(fee_proof_id) {
+            error!(
+                target: LOG_TARGET,
+                "Failed to unlock revealed funds for transfer: {}",
+                err
+            );
+        }
+        if let Err(err) = self.outputs_api.release_proof_outputs(fee_proof_id) {
+            error!(
+                target: LOG_TARGET,
+                "Failed to release fee inputs for transfer: {}",
+                err
+            );
+        }
+    }
+
     fn create_confidential_proof_statement(
         &self,
         dest_public_key: &PublicKey,
@@ -566,6 +874,112 @@ impl TransferParams {
     }
 }
 
+#[derive(Debug)]
+pub struct BatchTransferParams {
+    /// Spend from this account
+    pub from_account: ComponentAddress,
+    /// Strategy for input selection
+    pub input_selection: ConfidentialTransferInputSelection,
+    /// Address of the resource to transfer
+    pub resource_address: ResourceAddress,
+    /// The destinations and the amount to send to each
+    pub recipients: Vec<BatchTransferRecipient>,
+    /// Fee to lock for the transaction
+    pub max_fee: Amount,
+    /// If true, the outputs will contain only revealed amounts. Otherwise, only confidential amounts.
+    pub output_to_revealed: bool,
+    /// If some, instructions are added that create a access rule proof for this resource before calling withdraw
+    pub proof_from_resource: Option<ResourceAddress>,
+}
+
+impl BatchTransferParams {
+    /// Checks that there is at least one recipient, that every amount is positive and that the outputs fit in a
+    /// single withdraw proof.
+    pub fn validate(&self) -> Result<(), ConfidentialTransferApiError> {
+        if self.recipients.is_empty() {
+            return Err(ConfidentialTransferApiError::InvalidParameter {
+                param: "recipients",
+                reason: "At least one recipient is required".to_string(),
+            });
+        }
+        if self.recipients.len() > MAX_BATCH_TRANSFER_RECIPIENTS {
+            return Err(ConfidentialTransferApiError::InvalidParameter {
+                param: "recipients",
+                reason: format!(
+                    "At most {} recipients are allowed in a batch transfer",
+                    MAX_BATCH_TRANSFER_RECIPIENTS
+                ),
+            });
+        }
+        if let Some(recipient) = self.recipients.iter().find(|r| !r.amount.is_positive()) {
+            return Err(ConfidentialTransferApiError::InvalidParameter {
+                param: "recipients",
+                reason: format!(
+                    "Amount for recipient {} must be positive",
+                    recipient.destination_public_key
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    pub fn confidential_amount_for(&self, recipient: &BatchTransferRecipient) -> Amount {
+        if self.output_to_revealed {
+            Amount::zero()
+        } else {
+            recipient.amount
+        }
+    }
+
+    pub fn revealed_amount_for(&self, recipient: &BatchTransferRecipient) -> Amount {
+        if self.output_to_revealed {
+            recipient.amount
+        } else {
+            Amount::zero()
+        }
+    }
+
+    /// The sum of the amounts sent to all recipients, excluding fees
+    pub fn total_amount(&self) -> Result<Amount, ConfidentialTransferApiError> {
+        self.recipients
+            .iter()
+            .try_fold(Amount::zero(), |acc, r| acc.checked_add(r.amount))
+            .ok_or_else(|| ConfidentialTransferApiError::InvalidParameter {
+                param: "recipients",
+                reason: "Total amount overflows".to_string(),
+            })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BatchTransferRecipient {
+    /// Destination public key used to derive the destination account component
+    pub destination_public_key: PublicKey,
+    /// Amount to send to this destination
+    pub amount: Amount,
+}
+
+struct BatchTransferDestination {
+    public_key: PublicKey,
+    account: VersionedSubstateId,
+    exists: bool,
+}
+
+pub struct BatchTransferOutput {
+    pub transfer: TransferOutput,
+    /// One entry per requested recipient, in the same order
+    pub recipients: Vec<BatchTransferRecipientOutput>,
+}
+
+#[derive(Debug, Clone)]
+pub struct BatchTransferRecipientOutput {
+    pub destination_public_key: PublicKey,
+    pub destination_account: ComponentAddress,
+    pub amount: Amount,
+    /// True if the transaction creates the destination account
+    pub account_created: bool,
+}
+
 #[derive(Debug, Clone, Copy, Deserialize, Serialize)]
 #[cfg_attr(
     feature = "ts",
@@ -613,6 +1027,8 @@ pub enum ConfidentialTransferApiError {
     KeyManager(#[from] KeyManagerApiError),
     #[error("Accounts API error: {0}")]
     Accounts(#[from] AccountsApiError),
+    #[error("Signer error: {0}")]
+    Signer(#[from] TransactionSignerError),
     #[error("Invalid parameter `{param}`: {reason}")]
     InvalidParameter { param: &'static str, reason: String },
     #[error("Unexpected indexer response: {details}")]
@@ -41,6 +41,13 @@ impl<'a, TStore: WalletStore> KeyManagerApi<'a, TStore> {
         Ok(())
     }
 
+    /// Returns the index of every key in the branch and whether it is the active key, without deriving the keys.
+    pub fn get_all_key_indexes(&self, branch: &str) -> Result<Vec<(u64, bool)>, KeyManagerApiError> {
+        let mut tx = self.store.create_read_tx()?;
+        let all_keys = tx.key_manager_get_all(branch)?;
+        Ok(all_keys)
+    }
+
     pub fn get_all_keys(&self, branch: &str) -> Result<Vec<(u64, PublicKey, bool)>, KeyManagerApiError> {
         let mut tx = self.store.create_read_tx()?;
         let all_keys = tx.key_manager_get_all(branch)?;
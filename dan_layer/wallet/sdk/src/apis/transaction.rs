@@ -9,14 +9,25 @@ use tari_dan_common_types::{
     SubstateRequirement,
 };
 use tari_engine_types::{
+    execution_trace::ExecutionTrace,
     indexed_value::{IndexedValueError, IndexedWellKnownTypes},
     substate::SubstateDiff,
 };
-use tari_template_lib::prelude::ComponentAddress;
+use tari_template_lib::{
+    models::Amount,
+    prelude::{ComponentAddress, ResourceAddress},
+};
 use tari_transaction::{Transaction, TransactionId};
 
 use crate::{
-    models::{NewAccountInfo, TransactionStatus, VersionedSubstateId, WalletTransaction},
+    models::{
+        BalanceChange,
+        NewAccountInfo,
+        TransactionFilter,
+        TransactionStatus,
+        VersionedSubstateId,
+        WalletTransaction,
+    },
     network::{TransactionFinalizedResult, WalletNetworkInterface},
     storage::{WalletStorageError, WalletStore, WalletStoreReader, WalletStoreWriter},
 };
@@ -92,22 +103,25 @@ where
         Ok(())
     }
 
+    /// Submits a dry run transaction and returns the resulting wallet transaction along with the execution trace, if
+    /// `trace` is true and the network returned one.
     pub async fn submit_dry_run_transaction(
         &self,
         transaction: Transaction,
         required_substates: Vec<SubstateRequirement>,
-    ) -> Result<WalletTransaction, TransactionApiError> {
+        trace: bool,
+    ) -> Result<(WalletTransaction, Option<ExecutionTrace>), TransactionApiError> {
         self.store
             .with_write_tx(|tx| tx.transactions_insert(&transaction, &required_substates, None, true))?;
 
         let tx_id = *transaction.id();
         let query = self
             .network_interface
-            .submit_dry_run_transaction(transaction, required_substates)
+            .submit_dry_run_transaction(transaction, required_substates, trace)
             .await
             .map_err(|e| TransactionApiError::NetworkInterfaceError(e.to_string()))?;
 
-        match &query.result {
+        let trace = match &query.result {
             TransactionFinalizedResult::Pending => {
                 return Err(TransactionApiError::NetworkInterfaceError(
                     "Pending execution result returned from dry run".to_string(),
@@ -132,24 +146,110 @@ where
                         Some(*finalized_time),
                     )
                 })?;
+                execution_result.as_ref().and_then(|e| e.trace.clone())
             },
-        }
+        };
 
         let transaction = self.store.with_read_tx(|tx| tx.transactions_get(tx_id))?;
 
-        Ok(transaction)
+        Ok((transaction, trace))
     }
 
+    /// Fetches transactions matching the filter, most recently updated first. If `limit` is None, all matching
+    /// transactions after `offset` are returned.
     pub fn fetch_all(
         &self,
-        status: Option<TransactionStatus>,
-        component: Option<ComponentAddress>,
+        filter: &TransactionFilter,
+        offset: u64,
+        limit: Option<u64>,
     ) -> Result<Vec<WalletTransaction>, TransactionApiError> {
+        Self::validate_filter(filter)?;
         let mut tx = self.store.create_read_tx()?;
-        let transactions = tx.transactions_fetch_all(status, component)?;
+        let transactions = tx.transactions_fetch_all(filter, offset, limit)?;
         Ok(transactions)
     }
 
+    pub fn count(&self, filter: &TransactionFilter) -> Result<u64, TransactionApiError> {
+        Self::validate_filter(filter)?;
+        let mut tx = self.store.create_read_tx()?;
+        let count = tx.transactions_count(filter)?;
+        Ok(count)
+    }
+
+    /// Returns the changes to the revealed balances of the account's vaults, oldest first, derived from the substate
+    /// diffs of the account's finalized transactions.
+    pub fn balance_history(
+        &self,
+        account_address: ComponentAddress,
+        resource_address: Option<ResourceAddress>,
+    ) -> Result<Vec<BalanceChange>, TransactionApiError> {
+        let mut tx = self.store.create_read_tx()?;
+        let vaults = tx
+            .vaults_get_by_account(&account_address.into())?
+            .into_iter()
+            .filter(|v| resource_address.map_or(true, |r| v.resource_address == r))
+            .map(|v| v.address)
+            .collect::<Vec<_>>();
+        if vaults.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let filter = TransactionFilter {
+            component: Some(account_address),
+            resource: resource_address,
+            ..Default::default()
+        };
+        let mut transactions = tx.transactions_fetch_all(&filter, 0, None)?;
+        drop(tx);
+        transactions.retain(|t| {
+            matches!(
+                t.status,
+                TransactionStatus::Accepted | TransactionStatus::OnlyFeeAccepted
+            )
+        });
+        transactions.sort_by_key(|t| t.last_update_time);
+
+        let mut last_known_balances = HashMap::new();
+        let mut changes = Vec::new();
+        for transaction in transactions {
+            let Some(diff) = transaction.finalize.as_ref().and_then(|f| f.accept()) else {
+                continue;
+            };
+            for (id, substate) in diff.up_iter() {
+                if !vaults.contains(id) {
+                    continue;
+                }
+                let Some(vault) = substate.substate_value().vault() else {
+                    continue;
+                };
+                let balance_after = vault.balance();
+                let is_new_vault = diff.down_iter().all(|(down_id, _)| down_id != id);
+                let balance_before = last_known_balances
+                    .insert(id.clone(), balance_after)
+                    .or(is_new_vault.then(Amount::zero));
+                changes.push(BalanceChange {
+                    transaction_id: *transaction.transaction.id(),
+                    vault_address: id.clone(),
+                    resource_address: *vault.resource_address(),
+                    balance_before,
+                    balance_after,
+                    timestamp: transaction.last_update_time,
+                });
+            }
+        }
+
+        Ok(changes)
+    }
+
+    fn validate_filter(filter: &TransactionFilter) -> Result<(), TransactionApiError> {
+        if filter.direction.is_some() && filter.component.is_none() {
+            return Err(TransactionApiError::InvalidFilter {
+                details: "A direction filter requires a component filter".to_string(),
+            });
+        }
+        Ok(())
+    }
+
     pub async fn check_and_store_finalized_transaction(
         &self,
         transaction_id: TransactionId,
@@ -373,6 +473,8 @@ pub enum TransactionApiError {
     IndexedValueError(#[from] IndexedValueError),
     #[error("Invalid transaction query response: {details}")]
     InvalidTransactionQueryResponse { details: String },
+    #[error("Invalid transaction filter: {details}")]
+    InvalidFilter { details: String },
 }
 
 impl IsNotFoundError for TransactionApiError {
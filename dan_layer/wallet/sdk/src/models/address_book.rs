@@ -0,0 +1,36 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use std::fmt::{Display, Formatter};
+
+use tari_common_types::types::PublicKey;
+use tari_engine_types::component::new_component_address_from_public_key;
+use tari_template_builtin::ACCOUNT_TEMPLATE_ADDRESS;
+use tari_template_lib::models::ComponentAddress;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(ts_rs::TS),
+    ts(export, export_to = "../../bindings/src/types/")
+)]
+pub struct Contact {
+    pub name: String,
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    pub public_key: PublicKey,
+    pub memo: Option<String>,
+}
+
+impl Contact {
+    /// Returns the address of the account component owned by this contact's public key. The account may not exist
+    /// yet.
+    pub fn account_address(&self) -> ComponentAddress {
+        new_component_address_from_public_key(&ACCOUNT_TEMPLATE_ADDRESS, &self.public_key)
+    }
+}
+
+impl Display for Contact {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.name, self.public_key)
+    }
+}
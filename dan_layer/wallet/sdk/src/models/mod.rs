@@ -24,3 +24,6 @@ pub use vault::*;
 
 mod non_fungible_tokens;
 pub use non_fungible_tokens::*;
+
+mod address_book;
+pub use address_book::*;
@@ -8,9 +8,9 @@ use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 use tari_dan_common_types::SubstateRequirement;
 use tari_dan_storage::consensus_models::QuorumCertificate;
-use tari_engine_types::commit_result::FinalizeResult;
-use tari_template_lib::models::Amount;
-use tari_transaction::Transaction;
+use tari_engine_types::{commit_result::FinalizeResult, substate::SubstateId};
+use tari_template_lib::models::{Amount, ComponentAddress, ResourceAddress};
+use tari_transaction::{Transaction, TransactionId};
 #[cfg(feature = "ts")]
 use ts_rs::TS;
 
@@ -80,3 +80,82 @@ impl Display for TransactionStatus {
         write!(f, "{}", self.as_key_str())
     }
 }
+
+/// Filters applied when listing wallet transactions. All set fields must match.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TransactionFilter {
+    pub status: Option<TransactionStatus>,
+    /// Only transactions that reference this component in their instructions or fee instructions
+    pub component: Option<ComponentAddress>,
+    /// Only transactions that reference this resource in their instructions or result
+    pub resource: Option<ResourceAddress>,
+    /// Only transactions in this direction relative to `component`. Requires `component` to be set.
+    pub direction: Option<TransactionDirection>,
+    /// Only transactions created at or after this time
+    pub from_date: Option<NaiveDateTime>,
+    /// Only transactions created before this time
+    pub to_date: Option<NaiveDateTime>,
+}
+
+impl TransactionFilter {
+    pub fn with_status(mut self, status: TransactionStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn with_component(mut self, component: ComponentAddress) -> Self {
+        self.component = Some(component);
+        self
+    }
+}
+
+/// The direction of a transaction relative to a component. A transaction is outgoing if the component pays the
+/// transaction fee, otherwise it is incoming.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(TS), ts(export, export_to = "../../bindings/src/types/"))]
+pub enum TransactionDirection {
+    Incoming,
+    Outgoing,
+}
+
+impl FromStr for TransactionDirection {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "incoming" | "in" => Ok(TransactionDirection::Incoming),
+            "outgoing" | "out" => Ok(TransactionDirection::Outgoing),
+            _ => Err(anyhow!("Invalid TransactionDirection: {}", s)),
+        }
+    }
+}
+
+impl Display for TransactionDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TransactionDirection::Incoming => write!(f, "Incoming"),
+            TransactionDirection::Outgoing => write!(f, "Outgoing"),
+        }
+    }
+}
+
+/// A change to the revealed balance of an account vault caused by a finalized transaction.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(TS), ts(export, export_to = "../../bindings/src/types/"))]
+pub struct BalanceChange {
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    pub transaction_id: TransactionId,
+    pub vault_address: SubstateId,
+    pub resource_address: ResourceAddress,
+    /// The balance before the transaction. None if the previous balance of the vault is not known to the wallet.
+    pub balance_before: Option<Amount>,
+    pub balance_after: Amount,
+    pub timestamp: NaiveDateTime,
+}
+
+impl BalanceChange {
+    /// The signed change in balance, if the previous balance is known.
+    pub fn change(&self) -> Option<Amount> {
+        self.balance_before.map(|before| self.balance_after - before)
+    }
+}
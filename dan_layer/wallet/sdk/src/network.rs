@@ -41,10 +41,12 @@ pub trait WalletNetworkInterface {
         required_substates: Vec<SubstateRequirement>,
     ) -> Result<TransactionId, Self::Error>;
 
+    /// Executes the transaction without submitting it. If `trace` is true, the result includes an execution trace.
     async fn submit_dry_run_transaction(
         &self,
         transaction: Transaction,
         required_substates: Vec<SubstateRequirement>,
+        trace: bool,
     ) -> Result<TransactionQueryResult, Self::Error>;
 
     async fn query_transaction_result(
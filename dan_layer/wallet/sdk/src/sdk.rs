@@ -10,6 +10,7 @@ use tari_key_manager::cipher_seed::CipherSeed;
 use crate::{
     apis::{
         accounts::AccountsApi,
+        address_book::AddressBookApi,
         confidential_crypto::ConfidentialCryptoApi,
         confidential_outputs::ConfidentialOutputsApi,
         confidential_transfer::ConfidentialTransferApi,
@@ -21,6 +22,7 @@ use crate::{
         transaction::TransactionApi,
     },
     network::WalletNetworkInterface,
+    signer::{KeyManagerSigner, TransactionSigner},
     storage::{WalletStorageError, WalletStore},
 };
 
@@ -42,6 +44,7 @@ pub struct DanWalletSdk<TStore, TNetworkInterface> {
     network_interface: TNetworkInterface,
     config: WalletSdkConfig,
     cipher_seed: Arc<CipherSeed>,
+    signer: Arc<dyn TransactionSigner>,
 }
 
 impl<TStore, TNetworkInterface> DanWalletSdk<TStore, TNetworkInterface>
@@ -54,17 +57,32 @@ where
         store: TStore,
         indexer: TNetworkInterface,
         config: WalletSdkConfig,
-    ) -> Result<Self, WalletSdkError> {
-        let cipher_seed = Self::get_or_create_cipher_seed(&store)?;
+    ) -> Result<Self, WalletSdkError>
+    where
+        TStore: Clone + Send + Sync + 'static,
+    {
+        let cipher_seed = Arc::new(Self::get_or_create_cipher_seed(&store)?);
+        let signer = Arc::new(KeyManagerSigner::new(store.clone(), cipher_seed.clone()));
 
         Ok(Self {
             store,
             network_interface: indexer,
             config,
-            cipher_seed: Arc::new(cipher_seed),
+            cipher_seed,
+            signer,
         })
     }
 
+    /// Replaces the default key manager signer that provides account keys and signs transactions.
+    pub fn with_signer(mut self, signer: Arc<dyn TransactionSigner>) -> Self {
+        self.signer = signer;
+        self
+    }
+
+    pub fn signer(&self) -> &dyn TransactionSigner {
+        &*self.signer
+    }
+
     pub fn config_api(&self) -> ConfigApi<'_, TStore> {
         ConfigApi::new(&self.store)
     }
@@ -97,6 +115,10 @@ where
         AccountsApi::new(&self.store)
     }
 
+    pub fn address_book_api(&self) -> AddressBookApi<'_, TStore> {
+        AddressBookApi::new(&self.store)
+    }
+
     pub fn confidential_crypto_api(&self) -> ConfidentialCryptoApi {
         ConfidentialCryptoApi::new()
     }
@@ -111,6 +133,7 @@ where
             self.key_manager_api(),
             self.accounts_api(),
             self.confidential_crypto_api(),
+            self.signer(),
         )
     }
 
@@ -121,6 +144,7 @@ where
             self.confidential_outputs_api(),
             self.substate_api(),
             self.confidential_crypto_api(),
+            self.signer(),
         )
     }
 
@@ -0,0 +1,394 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use std::{
+    collections::HashMap,
+    fmt,
+    io,
+    io::{BufRead, BufReader, Write},
+    path::PathBuf,
+    process::{Child, ChildStdin, Command, Stdio},
+    sync::{mpsc, Arc, Mutex},
+    thread,
+    time::Duration,
+};
+
+use digest::crypto_common::rand_core::OsRng;
+use log::*;
+use serde::{Deserialize, Serialize};
+use tari_common_types::types::{PrivateKey, PublicKey, Signature};
+use tari_crypto::{keys::PublicKey as _, tari_utilities::hex::Hex};
+use tari_key_manager::cipher_seed::CipherSeed;
+use tari_transaction::{
+    Transaction,
+    TransactionBuilder,
+    TransactionSealSignature,
+    TransactionSignature,
+    UnsealedTransactionV1,
+    UnsignedTransactionV1,
+};
+use tokio::runtime::{Handle, RuntimeFlavor};
+
+use crate::{
+    apis::key_manager::{KeyManagerApi, KeyManagerApiError, TRANSACTION_BRANCH},
+    storage::WalletStore,
+};
+
+const LOG_TARGET: &str = "tari::dan::wallet_sdk::signer";
+
+/// The default time to wait for an external signer to respond to a request
+pub const DEFAULT_EXTERNAL_SIGNER_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Provides the account keys of the wallet and creates the seal and transaction signatures for transactions built by
+/// the wallet. Keys are identified by their index in the transaction key branch.
+pub trait TransactionSigner: fmt::Debug + Send + Sync {
+    fn public_key(&self, key_index: u64) -> Result<PublicKey, TransactionSignerError>;
+
+    fn sign_message(&self, key_index: u64, message: &[u8; 64]) -> Result<Signature, TransactionSignerError>;
+
+    /// Returns the secret key at `key_index`. Confidential outputs are encrypted to and decrypted with the account
+    /// secret key, so confidential operations need it in-process. Signers that keep keys outside the wallet return
+    /// [TransactionSignerError::SecretKeyUnavailable].
+    fn secret_key(&self, key_index: u64) -> Result<PrivateKey, TransactionSignerError> {
+        Err(TransactionSignerError::SecretKeyUnavailable { key_index })
+    }
+
+    fn seal_signature(
+        &self,
+        key_index: u64,
+        transaction: &UnsealedTransactionV1,
+    ) -> Result<TransactionSealSignature, TransactionSignerError> {
+        let public_key = self.public_key(key_index)?;
+        let message = TransactionSealSignature::create_message(transaction);
+        let signature = self.sign_message(key_index, &message)?;
+        Ok(TransactionSealSignature::new(public_key, signature))
+    }
+
+    fn transaction_signature(
+        &self,
+        key_index: u64,
+        seal_signer: &PublicKey,
+        transaction: &UnsignedTransactionV1,
+    ) -> Result<TransactionSignature, TransactionSignerError> {
+        let public_key = self.public_key(key_index)?;
+        let message = TransactionSignature::create_message(seal_signer, transaction);
+        let signature = self.sign_message(key_index, &message)?;
+        Ok(TransactionSignature::new(public_key, signature))
+    }
+
+    /// Equivalent to [TransactionBuilder::build_and_seal] using the key at `key_index` as the seal signer.
+    fn build_and_seal(
+        &self,
+        key_index: u64,
+        builder: TransactionBuilder,
+    ) -> Result<Transaction, TransactionSignerError> {
+        let transaction = builder.build_for_seal();
+        let seal_signature = self.seal_signature(key_index, &transaction)?;
+        Ok(Transaction::new(transaction, seal_signature))
+    }
+}
+
+/// The default signer that derives secret keys in-process from the wallet cipher seed.
+pub struct KeyManagerSigner<TStore> {
+    store: TStore,
+    cipher_seed: Arc<CipherSeed>,
+}
+
+impl<TStore> KeyManagerSigner<TStore> {
+    pub fn new(store: TStore, cipher_seed: Arc<CipherSeed>) -> Self {
+        Self { store, cipher_seed }
+    }
+}
+
+impl<TStore> fmt::Debug for KeyManagerSigner<TStore> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyManagerSigner").finish_non_exhaustive()
+    }
+}
+
+impl<TStore: WalletStore + Send + Sync> TransactionSigner for KeyManagerSigner<TStore> {
+    fn public_key(&self, key_index: u64) -> Result<PublicKey, TransactionSignerError> {
+        let key = KeyManagerApi::new(&self.store, &self.cipher_seed).derive_key(TRANSACTION_BRANCH, key_index)?;
+        Ok(PublicKey::from_secret_key(&key.key))
+    }
+
+    fn sign_message(&self, key_index: u64, message: &[u8; 64]) -> Result<Signature, TransactionSignerError> {
+        let key = KeyManagerApi::new(&self.store, &self.cipher_seed).derive_key(TRANSACTION_BRANCH, key_index)?;
+        let signature = Signature::sign(&key.key, message, &mut OsRng).expect("sign is infallible with Ristretto keys");
+        Ok(signature)
+    }
+
+    fn secret_key(&self, key_index: u64) -> Result<PrivateKey, TransactionSignerError> {
+        let key = KeyManagerApi::new(&self.store, &self.cipher_seed).derive_key(TRANSACTION_BRANCH, key_index)?;
+        Ok(key.key)
+    }
+}
+
+/// How to reach an external signer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExternalSignerTransport {
+    /// Spawn this program and exchange messages over its stdin and stdout
+    Process {
+        command: PathBuf,
+        #[serde(default)]
+        args: Vec<String>,
+    },
+    /// Connect to a signer listening on this unix socket
+    UnixSocket { path: PathBuf },
+}
+
+/// A signer that delegates signing to an external process, so that secret keys never enter the wallet.
+///
+/// Requests and responses are single-line JSON objects. Requests are `{"method":"public_key","key_index":0}` or
+/// `{"method":"sign","key_index":0,"message":"<hex>"}`. The signer responds with `{"public_key":"<hex>"}`,
+/// `{"public_nonce":"<hex>","signature":"<hex>"}` or `{"error":"<message>"}`. Every signature returned is verified
+/// before it is used. A request that is not answered within the timeout fails with
+/// [TransactionSignerError::Timeout].
+///
+/// Account public keys and addresses are always requested from the external signer, so its keys do not need to match
+/// the wallet seed. Secret keys never leave the signer, so confidential transfers, reveals and burn claims, which need
+/// the account secret key in-process, fail with [TransactionSignerError::SecretKeyUnavailable].
+#[derive(Debug)]
+pub struct ExternalSigner {
+    transport: ExternalSignerTransport,
+    timeout: Duration,
+    process: Mutex<Option<SignerProcess>>,
+    public_keys: Mutex<HashMap<u64, PublicKey>>,
+}
+
+impl ExternalSigner {
+    pub fn new(transport: ExternalSignerTransport) -> Self {
+        Self {
+            transport,
+            timeout: DEFAULT_EXTERNAL_SIGNER_TIMEOUT,
+            process: Mutex::new(None),
+            public_keys: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Sets the time to wait for the external signer to respond to a request
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    fn request(&self, request: &SignerRequest) -> Result<SignerResponse, TransactionSignerError> {
+        let mut line = serde_json::to_string(request).map_err(|e| TransactionSignerError::ProtocolError {
+            details: format!("Failed to encode request: {}", e),
+        })?;
+        line.push('\n');
+
+        let response = run_blocking(|| self.exchange(&line))?;
+
+        match serde_json::from_str(&response).map_err(|e| TransactionSignerError::ProtocolError {
+            details: format!("Invalid response '{}': {}", response.trim(), e),
+        })? {
+            SignerResponse::Error { error } => Err(TransactionSignerError::SignerRejected { details: error }),
+            resp => Ok(resp),
+        }
+    }
+
+    fn exchange(&self, line: &str) -> Result<String, TransactionSignerError> {
+        match &self.transport {
+            ExternalSignerTransport::Process { command, args } => {
+                let mut process = self.process.lock().unwrap();
+                if process.is_none() {
+                    *process = Some(SignerProcess::spawn(command, args)?);
+                }
+                let result = process.as_mut().unwrap().exchange(line, self.timeout);
+                if result.is_err() {
+                    // Respawn the process on the next request so that a late response is never read as the response
+                    // to a later request
+                    if let Some(mut p) = process.take() {
+                        let _ignore = p.child.kill();
+                    }
+                }
+                result
+            },
+            ExternalSignerTransport::UnixSocket { path } => exchange_over_socket(path, line, self.timeout),
+        }
+    }
+}
+
+/// Runs blocking signer I/O. On a multi-threaded tokio runtime, the worker thread is handed over to the blocking call
+/// so that other tasks are moved to other workers while waiting for the signer.
+fn run_blocking<T, F: FnOnce() -> T>(f: F) -> T {
+    match Handle::try_current() {
+        Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => tokio::task::block_in_place(f),
+        _ => f(),
+    }
+}
+
+impl TransactionSigner for ExternalSigner {
+    fn public_key(&self, key_index: u64) -> Result<PublicKey, TransactionSignerError> {
+        if let Some(public_key) = self.public_keys.lock().unwrap().get(&key_index) {
+            return Ok(public_key.clone());
+        }
+
+        let SignerResponse::PublicKey { public_key } = self.request(&SignerRequest::PublicKey { key_index })? else {
+            return Err(TransactionSignerError::ProtocolError {
+                details: "Expected a public key response".to_string(),
+            });
+        };
+        let public_key = PublicKey::from_hex(&public_key).map_err(|e| TransactionSignerError::ProtocolError {
+            details: format!("Invalid public key: {}", e),
+        })?;
+        self.public_keys.lock().unwrap().insert(key_index, public_key.clone());
+        Ok(public_key)
+    }
+
+    fn sign_message(&self, key_index: u64, message: &[u8; 64]) -> Result<Signature, TransactionSignerError> {
+        let public_key = self.public_key(key_index)?;
+        let SignerResponse::Signature {
+            public_nonce,
+            signature,
+        } = self.request(&SignerRequest::Sign {
+            key_index,
+            message: message.to_hex(),
+        })?
+        else {
+            return Err(TransactionSignerError::ProtocolError {
+                details: "Expected a signature response".to_string(),
+            });
+        };
+
+        let public_nonce = PublicKey::from_hex(&public_nonce).map_err(|e| TransactionSignerError::ProtocolError {
+            details: format!("Invalid public nonce: {}", e),
+        })?;
+        let signature = PrivateKey::from_hex(&signature).map_err(|e| TransactionSignerError::ProtocolError {
+            details: format!("Invalid signature: {}", e),
+        })?;
+        let signature = Signature::new(public_nonce, signature);
+        if !signature.verify(&public_key, message) {
+            return Err(TransactionSignerError::InvalidSignature { key_index });
+        }
+        Ok(signature)
+    }
+}
+
+#[derive(Debug)]
+struct SignerProcess {
+    child: Child,
+    stdin: ChildStdin,
+    responses: mpsc::Receiver<io::Result<String>>,
+}
+
+impl SignerProcess {
+    fn spawn(command: &PathBuf, args: &[String]) -> Result<Self, TransactionSignerError> {
+        info!(target: LOG_TARGET, "Starting external signer {}", command.display());
+        let mut child = Command::new(command)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()?;
+        let stdin = child.stdin.take().expect("stdin is piped");
+        let mut stdout = BufReader::new(child.stdout.take().expect("stdout is piped"));
+
+        // Responses are read on a separate thread so that waiting for a response can time out. The thread exits when
+        // the process closes its stdout, which happens at the latest when the process is killed.
+        let (tx, responses) = mpsc::channel();
+        thread::Builder::new()
+            .name("external-signer-reader".to_string())
+            .spawn(move || loop {
+                let response = read_response_line(&mut stdout);
+                let is_err = response.is_err();
+                if tx.send(response).is_err() || is_err {
+                    break;
+                }
+            })?;
+
+        Ok(Self {
+            child,
+            stdin,
+            responses,
+        })
+    }
+
+    fn exchange(&mut self, line: &str, timeout: Duration) -> Result<String, TransactionSignerError> {
+        self.stdin.write_all(line.as_bytes())?;
+        self.stdin.flush()?;
+        match self.responses.recv_timeout(timeout) {
+            Ok(response) => Ok(response?),
+            Err(mpsc::RecvTimeoutError::Timeout) => Err(TransactionSignerError::Timeout { timeout }),
+            Err(mpsc::RecvTimeoutError::Disconnected) => {
+                Err(io::Error::new(io::ErrorKind::UnexpectedEof, "External signer closed the connection").into())
+            },
+        }
+    }
+}
+
+impl Drop for SignerProcess {
+    fn drop(&mut self) {
+        let _ignore = self.child.kill();
+    }
+}
+
+#[cfg(unix)]
+fn exchange_over_socket(path: &PathBuf, line: &str, timeout: Duration) -> Result<String, TransactionSignerError> {
+    let map_timeout = |err: io::Error| match err.kind() {
+        io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut => TransactionSignerError::Timeout { timeout },
+        _ => err.into(),
+    };
+    let mut stream = std::os::unix::net::UnixStream::connect(path)?;
+    stream.set_read_timeout(Some(timeout))?;
+    stream.set_write_timeout(Some(timeout))?;
+    stream.write_all(line.as_bytes()).map_err(map_timeout)?;
+    stream.flush().map_err(map_timeout)?;
+    read_response_line(&mut BufReader::new(stream)).map_err(map_timeout)
+}
+
+#[cfg(not(unix))]
+fn exchange_over_socket(_path: &PathBuf, _line: &str, _timeout: Duration) -> Result<String, TransactionSignerError> {
+    Err(TransactionSignerError::ProtocolError {
+        details: "Unix socket signers are not supported on this platform".to_string(),
+    })
+}
+
+fn read_response_line<R: BufRead>(reader: &mut R) -> io::Result<String> {
+    let mut response = String::new();
+    if reader.read_line(&mut response)? == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "External signer closed the connection",
+        ));
+    }
+    Ok(response)
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "method", rename_all = "snake_case")]
+enum SignerRequest {
+    PublicKey { key_index: u64 },
+    Sign { key_index: u64, message: String },
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum SignerResponse {
+    Error { error: String },
+    PublicKey { public_key: String },
+    Signature { public_nonce: String, signature: String },
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TransactionSignerError {
+    #[error("Key manager error: {0}")]
+    KeyManagerError(#[from] KeyManagerApiError),
+    #[error("External signer IO error: {0}")]
+    IoError(#[from] io::Error),
+    #[error("External signer protocol error: {details}")]
+    ProtocolError { details: String },
+    #[error("External signer rejected the request: {details}")]
+    SignerRejected { details: String },
+    #[error("External signer returned an invalid signature for key index {key_index}")]
+    InvalidSignature { key_index: u64 },
+    #[error("External signer did not respond within {timeout:?}")]
+    Timeout { timeout: Duration },
+    #[error(
+        "The secret key at index {key_index} is held by the external signer. Confidential transactions are not \
+         supported with an external signer"
+    )]
+    SecretKeyUnavailable { key_index: u64 },
+}
@@ -21,10 +21,12 @@ use crate::models::{
     ConfidentialOutputModel,
     ConfidentialProofId,
     Config,
+    Contact,
     NewAccountInfo,
     NonFungibleToken,
     OutputStatus,
     SubstateModel,
+    TransactionFilter,
     TransactionStatus,
     VaultModel,
     VersionedSubstateId,
@@ -125,9 +127,11 @@ pub trait WalletStoreReader {
     fn transactions_get(&mut self, transaction_id: TransactionId) -> Result<WalletTransaction, WalletStorageError>;
     fn transactions_fetch_all(
         &mut self,
-        status: Option<TransactionStatus>,
-        component: Option<ComponentAddress>,
+        filter: &TransactionFilter,
+        offset: u64,
+        limit: Option<u64>,
     ) -> Result<Vec<WalletTransaction>, WalletStorageError>;
+    fn transactions_count(&mut self, filter: &TransactionFilter) -> Result<u64, WalletStorageError>;
     // Substates
     fn substates_get(&mut self, address: &SubstateId) -> Result<SubstateModel, WalletStorageError>;
     fn substates_get_all(
@@ -146,6 +150,11 @@ pub trait WalletStoreReader {
     fn accounts_get_by_name(&mut self, name: &str) -> Result<Account, WalletStorageError>;
     fn accounts_get_by_vault(&mut self, vault_address: &SubstateId) -> Result<Account, WalletStorageError>;
 
+    // Address book
+    fn contacts_get_by_name(&mut self, name: &str) -> Result<Contact, WalletStorageError>;
+    fn contacts_get_many(&mut self, offset: u64, limit: u64) -> Result<Vec<Contact>, WalletStorageError>;
+    fn contacts_count(&mut self) -> Result<u64, WalletStorageError>;
+
     // Vaults
     fn vaults_get(&mut self, address: &SubstateId) -> Result<VaultModel, WalletStorageError>;
     fn vaults_exists(&mut self, address: &SubstateId) -> Result<bool, WalletStorageError>;
@@ -267,6 +276,10 @@ pub trait WalletStoreWriter {
 
     fn accounts_update(&mut self, substate_id: &SubstateId, new_name: Option<&str>) -> Result<(), WalletStorageError>;
 
+    // Address book
+    fn contacts_insert(&mut self, contact: &Contact) -> Result<(), WalletStorageError>;
+    fn contacts_remove(&mut self, name: &str) -> Result<Contact, WalletStorageError>;
+
     // Vaults
     fn vaults_insert(&mut self, vault: VaultModel) -> Result<(), WalletStorageError>;
     fn vaults_update(
@@ -254,6 +254,7 @@ impl WalletNetworkInterface for PanicIndexer {
         &self,
         _transaction: Transaction,
         _required_substates: Vec<SubstateRequirement>,
+        _trace: bool,
     ) -> Result<TransactionQueryResult, Self::Error> {
         panic!("PanicIndexer called")
     }
@@ -0,0 +1,233 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use std::sync::Arc;
+
+use tari_common_types::types::PublicKey;
+use tari_crypto::keys::PublicKey as _;
+use tari_dan_wallet_sdk::{
+    signer::{KeyManagerSigner, TransactionSigner},
+    CipherSeed,
+};
+use tari_dan_wallet_storage_sqlite::SqliteWalletStore;
+use tari_template_lib::{
+    args,
+    models::{Amount, ComponentAddress, TemplateAddress},
+};
+use tari_transaction::Transaction;
+
+#[test]
+fn key_manager_signer_seals_transaction() {
+    let temp = tempfile::tempdir().unwrap();
+    let store = SqliteWalletStore::try_open(temp.path().join("data/wallet.sqlite")).unwrap();
+    store.run_migrations().unwrap();
+    let signer = KeyManagerSigner::new(store, Arc::new(CipherSeed::new()));
+
+    let builder = Transaction::builder().call_function(TemplateAddress::from_array([1; 32]), "new", args![]);
+    let transaction = signer.build_and_seal(1, builder).unwrap();
+
+    assert!(transaction.verify_all_signatures());
+    assert_eq!(
+        *transaction.seal_signature().public_key(),
+        signer.public_key(1).unwrap()
+    );
+    assert_ne!(signer.public_key(0).unwrap(), signer.public_key(1).unwrap());
+    assert_eq!(
+        PublicKey::from_secret_key(&signer.secret_key(1).unwrap()),
+        signer.public_key(1).unwrap()
+    );
+
+    let builder = Transaction::builder()
+        .fee_transaction_pay_from_component(ComponentAddress::from_array([1; 32]), Amount::new(1));
+    let transaction = signer.build_and_seal(0, builder).unwrap();
+    assert!(transaction.verify_all_signatures());
+}
+
+#[cfg(unix)]
+mod external_signer {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        os::unix::net::UnixListener,
+        path::PathBuf,
+        thread,
+        time::Duration,
+    };
+
+    use digest::crypto_common::rand_core::OsRng;
+    use tari_common_types::types::{PrivateKey, PublicKey, Signature};
+    use tari_crypto::{
+        keys::{PublicKey as _, SecretKey},
+        tari_utilities::hex::Hex,
+    };
+    use tari_dan_wallet_sdk::signer::{
+        ExternalSigner,
+        ExternalSignerTransport,
+        TransactionSigner,
+        TransactionSignerError,
+    };
+    use tari_template_lib::{args, models::TemplateAddress};
+    use tari_transaction::Transaction;
+
+    /// Serves each connection to the socket with `handler`, which maps a request line to a response line, or to
+    /// None to never respond.
+    fn spawn_stub_signer<F>(handler: F) -> (tempfile::TempDir, PathBuf)
+    where F: Fn(serde_json::Value) -> Option<String> + Send + 'static {
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join("signer.sock");
+        let listener = UnixListener::bind(&path).unwrap();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut line = String::new();
+                BufReader::new(&stream).read_line(&mut line).unwrap();
+                match handler(serde_json::from_str(&line).unwrap()) {
+                    Some(response) => {
+                        stream.write_all(response.as_bytes()).unwrap();
+                        stream.write_all(b"\n").unwrap();
+                    },
+                    None => {
+                        // Hold the connection open without responding
+                        thread::sleep(Duration::from_secs(5));
+                    },
+                }
+            }
+        });
+        (temp, path)
+    }
+
+    fn key_for_index(key_index: u64) -> PrivateKey {
+        PrivateKey::from(key_index + 1)
+    }
+
+    fn signing_handler(request: serde_json::Value) -> Option<String> {
+        let key = key_for_index(request["key_index"].as_u64().unwrap());
+        let response = match request["method"].as_str().unwrap() {
+            "public_key" => serde_json::json!({ "public_key": PublicKey::from_secret_key(&key).to_hex() }),
+            "sign" => {
+                let message = Vec::<u8>::from_hex(request["message"].as_str().unwrap()).unwrap();
+                let signature = Signature::sign(&key, message, &mut OsRng).unwrap();
+                serde_json::json!({
+                    "public_nonce": signature.get_public_nonce().to_hex(),
+                    "signature": signature.get_signature().to_hex(),
+                })
+            },
+            method => serde_json::json!({ "error": format!("unknown method {}", method) }),
+        };
+        Some(response.to_string())
+    }
+
+    #[test]
+    fn it_seals_a_transaction_with_the_external_signer() {
+        let (_temp, path) = spawn_stub_signer(signing_handler);
+        let signer = ExternalSigner::new(ExternalSignerTransport::UnixSocket { path });
+
+        assert_eq!(
+            signer.public_key(3).unwrap(),
+            PublicKey::from_secret_key(&key_for_index(3))
+        );
+
+        let builder = Transaction::builder().call_function(TemplateAddress::from_array([1; 32]), "new", args![]);
+        let transaction = signer.build_and_seal(3, builder).unwrap();
+        assert!(transaction.verify_all_signatures());
+        assert_eq!(
+            *transaction.seal_signature().public_key(),
+            PublicKey::from_secret_key(&key_for_index(3))
+        );
+    }
+
+    #[test]
+    fn it_does_not_expose_secret_keys() {
+        let (_temp, path) = spawn_stub_signer(signing_handler);
+        let signer = ExternalSigner::new(ExternalSignerTransport::UnixSocket { path });
+
+        let err = signer.secret_key(3).unwrap_err();
+        assert!(matches!(err, TransactionSignerError::SecretKeyUnavailable {
+            key_index: 3
+        }));
+    }
+
+    #[test]
+    fn it_rejects_a_signature_for_the_wrong_key() {
+        let (_temp, path) = spawn_stub_signer(|request| {
+            if request["method"] == "sign" {
+                // Sign with a key other than the one for the requested index
+                let key = PrivateKey::random(&mut OsRng);
+                let message = Vec::<u8>::from_hex(request["message"].as_str().unwrap()).unwrap();
+                let signature = Signature::sign(&key, message, &mut OsRng).unwrap();
+                return Some(
+                    serde_json::json!({
+                        "public_nonce": signature.get_public_nonce().to_hex(),
+                        "signature": signature.get_signature().to_hex(),
+                    })
+                    .to_string(),
+                );
+            }
+            signing_handler(request)
+        });
+        let signer = ExternalSigner::new(ExternalSignerTransport::UnixSocket { path });
+
+        let err = signer.sign_message(0, &[1u8; 64]).unwrap_err();
+        assert!(matches!(err, TransactionSignerError::InvalidSignature { key_index: 0 }));
+    }
+
+    #[test]
+    fn it_fails_on_a_malformed_response() {
+        let (_temp, path) = spawn_stub_signer(|_| Some("this is not json".to_string()));
+        let signer = ExternalSigner::new(ExternalSignerTransport::UnixSocket { path });
+
+        let err = signer.public_key(0).unwrap_err();
+        assert!(matches!(err, TransactionSignerError::ProtocolError { .. }));
+    }
+
+    #[test]
+    fn it_surfaces_errors_from_the_signer() {
+        let (_temp, path) = spawn_stub_signer(|_| Some(r#"{"error":"user declined"}"#.to_string()));
+        let signer = ExternalSigner::new(ExternalSignerTransport::UnixSocket { path });
+
+        let err = signer.public_key(0).unwrap_err();
+        assert!(matches!(err, TransactionSignerError::SignerRejected { details } if details == "user declined"));
+    }
+
+    #[test]
+    fn it_times_out_if_the_socket_signer_does_not_respond() {
+        let (_temp, path) = spawn_stub_signer(|_| None);
+        let signer =
+            ExternalSigner::new(ExternalSignerTransport::UnixSocket { path }).with_timeout(Duration::from_millis(100));
+
+        let err = signer.public_key(0).unwrap_err();
+        assert!(matches!(err, TransactionSignerError::Timeout { .. }));
+    }
+
+    #[test]
+    fn it_times_out_if_the_process_signer_does_not_respond() {
+        let signer = ExternalSigner::new(ExternalSignerTransport::Process {
+            command: "sh".into(),
+            args: vec!["-c".to_string(), "cat > /dev/null".to_string()],
+        })
+        .with_timeout(Duration::from_millis(100));
+
+        let err = signer.public_key(0).unwrap_err();
+        assert!(matches!(err, TransactionSignerError::Timeout { .. }));
+    }
+
+    #[test]
+    fn it_fails_on_a_malformed_response_from_the_process_signer() {
+        let signer = ExternalSigner::new(ExternalSignerTransport::Process {
+            command: "sh".into(),
+            args: vec!["-c".to_string(), "read line; echo garbage".to_string()],
+        });
+
+        let err = signer.public_key(0).unwrap_err();
+        assert!(matches!(err, TransactionSignerError::ProtocolError { .. }));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn it_signs_from_within_a_multi_threaded_runtime() {
+        let (_temp, path) = spawn_stub_signer(signing_handler);
+        let signer = ExternalSigner::new(ExternalSignerTransport::UnixSocket { path });
+
+        let builder = Transaction::builder().call_function(TemplateAddress::from_array([1; 32]), "new", args![]);
+        let transaction = signer.build_and_seal(0, builder).unwrap();
+        assert!(transaction.verify_all_signatures());
+    }
+}
@@ -0,0 +1,12 @@
+-- Address book
+CREATE TABLE contacts
+(
+    id         INTEGER  NOT NULL PRIMARY KEY AUTOINCREMENT,
+    name       TEXT     NOT NULL,
+    public_key TEXT     NOT NULL,
+    memo       TEXT     NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE UNIQUE INDEX contacts_uniq_name ON contacts (name);
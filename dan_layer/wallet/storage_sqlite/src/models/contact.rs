@@ -0,0 +1,37 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use chrono::NaiveDateTime;
+use diesel::{Identifiable, Queryable};
+use tari_common_types::types::PublicKey;
+use tari_dan_wallet_sdk::storage::WalletStorageError;
+use tari_utilities::hex::Hex;
+
+use crate::schema::contacts;
+
+#[derive(Debug, Clone, Queryable, Identifiable)]
+#[diesel(table_name = contacts)]
+pub struct Contact {
+    pub id: i32,
+    pub name: String,
+    pub public_key: String,
+    pub memo: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl TryFrom<Contact> for tari_dan_wallet_sdk::models::Contact {
+    type Error = WalletStorageError;
+
+    fn try_from(contact: Contact) -> Result<Self, Self::Error> {
+        Ok(Self {
+            public_key: PublicKey::from_hex(&contact.public_key).map_err(|e| WalletStorageError::DecodingError {
+                operation: "try_from",
+                item: "contacts.public_key",
+                details: e.to_string(),
+            })?,
+            name: contact.name,
+            memo: contact.memo,
+        })
+    }
+}
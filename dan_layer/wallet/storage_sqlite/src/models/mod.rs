@@ -7,6 +7,9 @@ pub use account::Account;
 mod config;
 pub use config::Config;
 
+mod contact;
+pub use contact::Contact;
+
 mod output;
 pub use output::ConfidentialOutput;
 
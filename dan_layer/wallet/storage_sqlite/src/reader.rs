@@ -7,6 +7,7 @@ use bigdecimal::{BigDecimal, ToPrimitive};
 use diesel::{
     dsl::sum,
     sql_query,
+    sqlite::Sqlite,
     BoolExpressionMethods,
     JoinOnDsl,
     OptionalExtension,
@@ -25,9 +26,12 @@ use tari_dan_wallet_sdk::{
         ConfidentialOutputModel,
         ConfidentialProofId,
         Config,
+        Contact,
         NonFungibleToken,
         OutputStatus,
         SubstateModel,
+        TransactionDirection,
+        TransactionFilter,
         TransactionStatus,
         VaultModel,
         WalletTransaction,
@@ -205,30 +209,34 @@ impl WalletStoreReader for ReadTransaction<'_> {
 
     fn transactions_fetch_all(
         &mut self,
-        status: Option<TransactionStatus>,
-        component: Option<ComponentAddress>,
+        filter: &TransactionFilter,
+        offset: u64,
+        limit: Option<u64>,
     ) -> Result<Vec<WalletTransaction>, WalletStorageError> {
         use crate::schema::transactions;
 
-        let mut rows = transactions::table.into_boxed().filter(transactions::dry_run.eq(false));
-        if let Some(status) = status {
-            rows = rows.filter(transactions::status.eq(status.as_key_str()));
-        }
-        if let Some(component) = component {
-            rows = rows.filter(
-                transactions::instructions
-                    .like(format!("%{}%", component))
-                    .or(transactions::fee_instructions.like(format!("%{}%", component))),
-            );
+        let mut rows = filtered_transactions_query(filter)
+            .order(transactions::updated_at.desc())
+            .offset(offset as i64);
+        if let Some(limit) = limit {
+            rows = rows.limit(limit as i64);
         }
         let rows = rows
-            .order(transactions::updated_at.desc())
             .load::<models::Transaction>(self.connection())
             .map_err(|e| WalletStorageError::general("transactions_fetch_all", e))?;
 
         rows.into_iter().map(|row| row.try_into_wallet_transaction()).collect()
     }
 
+    fn transactions_count(&mut self, filter: &TransactionFilter) -> Result<u64, WalletStorageError> {
+        let count = filtered_transactions_query(filter)
+            .count()
+            .first::<i64>(self.connection())
+            .map_err(|e| WalletStorageError::general("transactions_count", e))?;
+
+        Ok(count as u64)
+    }
+
     // -------------------------------- Substates -------------------------------- //
     fn substates_get(&mut self, address: &SubstateId) -> Result<SubstateModel, WalletStorageError> {
         use crate::schema::substates;
@@ -435,6 +443,48 @@ impl WalletStoreReader for ReadTransaction<'_> {
         Ok(account)
     }
 
+    // -------------------------------- Address book -------------------------------- //
+    fn contacts_get_by_name(&mut self, name: &str) -> Result<Contact, WalletStorageError> {
+        use crate::schema::contacts;
+
+        let row = contacts::table
+            .filter(contacts::name.eq(name))
+            .first::<models::Contact>(self.connection())
+            .optional()
+            .map_err(|e| WalletStorageError::general("contacts_get_by_name", e))?
+            .ok_or_else(|| WalletStorageError::NotFound {
+                operation: "contacts_get_by_name",
+                entity: "contact".to_string(),
+                key: name.to_string(),
+            })?;
+
+        row.try_into()
+    }
+
+    fn contacts_get_many(&mut self, offset: u64, limit: u64) -> Result<Vec<Contact>, WalletStorageError> {
+        use crate::schema::contacts;
+
+        let rows = contacts::table
+            .order_by(contacts::name.asc())
+            .limit(limit as i64)
+            .offset(offset as i64)
+            .load::<models::Contact>(self.connection())
+            .map_err(|e| WalletStorageError::general("contacts_get_many", e))?;
+
+        rows.into_iter().map(TryInto::try_into).collect()
+    }
+
+    fn contacts_count(&mut self) -> Result<u64, WalletStorageError> {
+        use crate::schema::contacts;
+
+        let count = contacts::table
+            .count()
+            .first::<i64>(self.connection())
+            .map_err(|e| WalletStorageError::general("contacts_count", e))?;
+
+        Ok(count as u64)
+    }
+
     // -------------------------------- Vaults -------------------------------- //
     fn vaults_get(&mut self, address: &SubstateId) -> Result<VaultModel, WalletStorageError> {
         use crate::schema::{accounts, vaults};
@@ -831,3 +881,44 @@ impl Drop for ReadTransaction<'_> {
         }
     }
 }
+
+fn filtered_transactions_query(filter: &TransactionFilter) -> crate::schema::transactions::BoxedQuery<'static, Sqlite> {
+    use crate::schema::transactions;
+
+    let mut rows = transactions::table.into_boxed().filter(transactions::dry_run.eq(false));
+    if let Some(status) = filter.status {
+        rows = rows.filter(transactions::status.eq(status.as_key_str()));
+    }
+    if let Some(component) = filter.component {
+        let pattern = format!("%{}%", component);
+        rows = match filter.direction {
+            Some(TransactionDirection::Outgoing) => rows.filter(transactions::fee_instructions.like(pattern)),
+            Some(TransactionDirection::Incoming) => rows.filter(
+                transactions::instructions
+                    .like(pattern.clone())
+                    .and(transactions::fee_instructions.not_like(pattern)),
+            ),
+            None => rows.filter(
+                transactions::instructions
+                    .like(pattern.clone())
+                    .or(transactions::fee_instructions.like(pattern)),
+            ),
+        };
+    }
+    if let Some(resource) = filter.resource {
+        let pattern = format!("%{}%", resource);
+        rows = rows.filter(
+            transactions::instructions
+                .like(pattern.clone())
+                .or(transactions::fee_instructions.like(pattern.clone()))
+                .or(transactions::result.like(pattern).assume_not_null()),
+        );
+    }
+    if let Some(from_date) = filter.from_date {
+        rows = rows.filter(transactions::created_at.ge(from_date));
+    }
+    if let Some(to_date) = filter.to_date {
+        rows = rows.filter(transactions::created_at.lt(to_date));
+    }
+    rows
+}
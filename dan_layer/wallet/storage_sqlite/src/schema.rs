@@ -33,6 +33,17 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    contacts (id) {
+        id -> Integer,
+        name -> Text,
+        public_key -> Text,
+        memo -> Nullable<Text>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     key_manager_states (id) {
         id -> Integer,
@@ -154,6 +165,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     accounts,
     auth_status,
     config,
+    contacts,
     key_manager_states,
     non_fungible_tokens,
     outputs,
@@ -20,6 +20,7 @@ use tari_dan_wallet_sdk::{
     models::{
         ConfidentialOutputModel,
         ConfidentialProofId,
+        Contact,
         NewAccountInfo,
         NonFungibleToken,
         OutputStatus,
@@ -490,6 +491,34 @@ impl WalletStoreWriter for WriteTransaction<'_> {
         Ok(())
     }
 
+    // -------------------------------- Address book -------------------------------- //
+    fn contacts_insert(&mut self, contact: &Contact) -> Result<(), WalletStorageError> {
+        use crate::schema::contacts;
+
+        diesel::insert_into(contacts::table)
+            .values((
+                contacts::name.eq(&contact.name),
+                contacts::public_key.eq(contact.public_key.to_hex()),
+                contacts::memo.eq(contact.memo.as_deref()),
+            ))
+            .execute(self.connection())
+            .map_err(|e| WalletStorageError::general("contacts_insert", e))?;
+
+        Ok(())
+    }
+
+    fn contacts_remove(&mut self, name: &str) -> Result<Contact, WalletStorageError> {
+        use crate::schema::contacts;
+
+        let contact = self.transaction.contacts_get_by_name(name)?;
+        diesel::delete(contacts::table)
+            .filter(contacts::name.eq(name))
+            .execute(self.connection())
+            .map_err(|e| WalletStorageError::general("contacts_remove", e))?;
+
+        Ok(contact)
+    }
+
     fn vaults_insert(&mut self, vault: VaultModel) -> Result<(), WalletStorageError> {
         use crate::schema::{accounts, vaults};
 
@@ -0,0 +1,40 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use tari_common_types::types::PublicKey;
+use tari_dan_wallet_sdk::{
+    models::Contact,
+    storage::{WalletStorageError, WalletStore, WalletStoreReader, WalletStoreWriter},
+};
+use tari_dan_wallet_storage_sqlite::SqliteWalletStore;
+
+#[test]
+fn insert_get_and_remove_contact() {
+    let db = SqliteWalletStore::try_open(":memory:").unwrap();
+    db.run_migrations().unwrap();
+    let contact = Contact {
+        name: "alice".to_string(),
+        public_key: PublicKey::default(),
+        memo: Some("payroll".to_string()),
+    };
+
+    let mut tx = db.create_write_tx().unwrap();
+    tx.contacts_insert(&contact).unwrap();
+    tx.commit().unwrap();
+
+    let mut tx = db.create_read_tx().unwrap();
+    let stored = tx.contacts_get_by_name("alice").unwrap();
+    assert_eq!(stored.public_key, contact.public_key);
+    assert_eq!(stored.memo.as_deref(), Some("payroll"));
+    assert_eq!(tx.contacts_count().unwrap(), 1);
+    drop(tx);
+
+    let mut tx = db.create_write_tx().unwrap();
+    let removed = tx.contacts_remove("alice").unwrap();
+    assert_eq!(removed.name, "alice");
+    tx.commit().unwrap();
+
+    let mut tx = db.create_read_tx().unwrap();
+    let err = tx.contacts_get_by_name("alice").unwrap_err();
+    assert!(matches!(err, WalletStorageError::NotFound { .. }));
+}
@@ -4,10 +4,14 @@
 use tari_common_types::types::PrivateKey;
 use tari_dan_common_types::optional::Optional;
 use tari_dan_wallet_sdk::{
-    models::TransactionStatus,
+    models::{TransactionDirection, TransactionFilter, TransactionStatus},
     storage::{WalletStore, WalletStoreReader, WalletStoreWriter},
 };
 use tari_dan_wallet_storage_sqlite::SqliteWalletStore;
+use tari_template_lib::{
+    args,
+    models::{Amount, ComponentAddress},
+};
 use tari_transaction::{Transaction, TransactionId};
 
 fn build_transaction() -> Transaction {
@@ -31,3 +35,68 @@ fn get_and_insert_transaction() {
     assert_eq!(transaction.id(), returned.transaction.id());
     assert_eq!(returned.status, TransactionStatus::default());
 }
+
+fn build_transfer(from: ComponentAddress, to: ComponentAddress) -> Transaction {
+    Transaction::builder()
+        .fee_transaction_pay_from_component(from, Amount(1000))
+        .call_method(to, "deposit", args![])
+        .build_and_seal(&PrivateKey::default())
+}
+
+#[test]
+fn fetch_all_filters_and_paginates() {
+    let db = SqliteWalletStore::try_open(":memory:").unwrap();
+    db.run_migrations().unwrap();
+    let alice = ComponentAddress::from_array([1u8; 32]);
+    let bob = ComponentAddress::from_array([2u8; 32]);
+    let carol = ComponentAddress::from_array([3u8; 32]);
+
+    let mut tx = db.create_write_tx().unwrap();
+    let alice_to_bob = build_transfer(alice, bob);
+    let bob_to_carol = build_transfer(bob, carol);
+    let alice_to_carol = build_transfer(alice, carol);
+    for transaction in [&alice_to_bob, &bob_to_carol, &alice_to_carol] {
+        tx.transactions_insert(transaction, &[], None, false).unwrap();
+    }
+    tx.transactions_set_result_and_status(
+        *alice_to_carol.id(),
+        None,
+        None,
+        None,
+        TransactionStatus::Accepted,
+        None,
+        None,
+    )
+    .unwrap();
+    tx.commit().unwrap();
+
+    let mut tx = db.create_read_tx().unwrap();
+    let all = TransactionFilter::default();
+    assert_eq!(tx.transactions_count(&all).unwrap(), 3);
+    assert_eq!(tx.transactions_fetch_all(&all, 0, Some(2)).unwrap().len(), 2);
+    assert_eq!(tx.transactions_fetch_all(&all, 2, Some(2)).unwrap().len(), 1);
+
+    let accepted = TransactionFilter::default().with_status(TransactionStatus::Accepted);
+    let found = tx.transactions_fetch_all(&accepted, 0, None).unwrap();
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].transaction.id(), alice_to_carol.id());
+
+    let bob_filter = TransactionFilter::default().with_component(bob);
+    assert_eq!(tx.transactions_count(&bob_filter).unwrap(), 2);
+
+    let bob_incoming = TransactionFilter {
+        direction: Some(TransactionDirection::Incoming),
+        ..bob_filter.clone()
+    };
+    let found = tx.transactions_fetch_all(&bob_incoming, 0, None).unwrap();
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].transaction.id(), alice_to_bob.id());
+
+    let bob_outgoing = TransactionFilter {
+        direction: Some(TransactionDirection::Outgoing),
+        ..bob_filter
+    };
+    let found = tx.transactions_fetch_all(&bob_outgoing, 0, None).unwrap();
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].transaction.id(), bob_to_carol.id());
+}
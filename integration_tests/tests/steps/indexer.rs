@@ -1,10 +1,7 @@
 //   Copyright 2023 The Tari Project
 //   SPDX-License-Identifier: BSD-3-Clause
 
-use std::{
-    collections::{BTreeMap, HashMap},
-    str::FromStr,
-};
+use std::{collections::HashMap, str::FromStr};
 
 use cucumber::{given, then, when};
 use integration_tests::{
@@ -120,9 +117,10 @@ async fn works_indexer_graphql(world: &mut TariWorld, indexer_name: String) {
     assert_eq!(res[0].template_address, template_address);
     assert_eq!(res[0].tx_hash, tx_hash);
     assert_eq!(res[0].topic, "my_event");
+    // The payload is returned as a CBOR map encoded as JSON
     assert_eq!(
-        res[0].payload,
-        BTreeMap::from([("my".to_string(), "event".to_string())])
+        res[0].payload.0,
+        serde_json::json!({ "Map": [[{ "Text": "my" }, { "Text": "event" }]] })
     );
 }
 
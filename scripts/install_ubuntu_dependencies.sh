@@ -7,6 +7,7 @@ apt-get install --no-install-recommends --assume-yes \
   libssl-dev \
   pkg-config \
   libsqlite3-dev \
+  libpq-dev \
   git \
   cmake \
   dh-autoreconf \
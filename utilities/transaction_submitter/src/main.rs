@@ -105,6 +105,7 @@ async fn stress_test(args: StressTestArgs) -> anyhow::Result<Option<StressTestRe
                     .submit_transaction(SubmitTransactionRequest {
                         transaction,
                         is_dry_run: false,
+                        trace: false,
                     })
                     .await
                 {